@@ -11,7 +11,7 @@ use _pydantic_core::SchemaValidator;
 
 fn build_schema_validator_with_globals(py: Python, code: &str, globals: Option<&PyDict>) -> SchemaValidator {
     let schema: &PyDict = py.eval(code, globals, None).unwrap().extract().unwrap();
-    SchemaValidator::py_new(py, schema, None).unwrap()
+    SchemaValidator::py_new(py, schema, None, false).unwrap()
 }
 
 fn build_schema_validator(py: Python, code: &str) -> SchemaValidator {
@@ -445,7 +445,7 @@ fn complete_model(bench: &mut Bencher) {
 
         let complete_schema = py.import("complete_schema").unwrap();
         let schema = complete_schema.call_method0("schema").unwrap();
-        let validator = SchemaValidator::py_new(py, schema, None).unwrap();
+        let validator = SchemaValidator::py_new(py, schema, None, false).unwrap();
 
         let input = complete_schema.call_method0("input_data_lax").unwrap();
         let input = black_box(input);
@@ -2,6 +2,7 @@ from __future__ import annotations
 
 import sys as _sys
 from typing import Any as _Any
+from typing import Literal as _Literal
 
 from ._pydantic_core import (
     ArgsKwargs,
@@ -18,9 +19,11 @@ from ._pydantic_core import (
     SchemaSerializer,
     SchemaValidator,
     Some,
+    TzInfo,
     Url,
     ValidationError,
     __version__,
+    register_validator,
     to_json,
     to_jsonable_python,
 )
@@ -44,6 +47,7 @@ __all__ = [
     'SchemaValidator',
     'SchemaSerializer',
     'Some',
+    'TzInfo',
     'Url',
     'MultiHostUrl',
     'ArgsKwargs',
@@ -51,6 +55,7 @@ __all__ = [
     'PydanticUndefinedType',
     'SchemaError',
     'ErrorDetails',
+    'ErrorInputType',
     'InitErrorDetails',
     'ValidationError',
     'PydanticCustomError',
@@ -61,15 +66,22 @@ __all__ = [
     'PydanticSerializationUnexpectedValue',
     'to_json',
     'to_jsonable_python',
+    'register_validator',
 ]
 
 
+class ErrorInputType(_TypedDict):
+    source: _Literal['python', 'json'] | None
+    type: str
+
+
 class ErrorDetails(_TypedDict):
     type: str
     loc: tuple[int | str, ...]
     msg: str
     input: _Any
     ctx: _NotRequired[dict[str, str | int | float]]
+    input_type: _NotRequired[ErrorInputType]
 
 
 class InitErrorDetails(_TypedDict):
@@ -93,3 +105,10 @@ class MultiHostHost(_TypedDict):
     password: str | None
     host: str | None
     port: int | None
+
+
+class MultiHostUrlBuildHost(_TypedDict):
+    host: str
+    port: _NotRequired[int | None]
+    username: _NotRequired[str | None]
+    password: _NotRequired[str | None]
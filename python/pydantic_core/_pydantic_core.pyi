@@ -1,11 +1,12 @@
 from __future__ import annotations
 
+import datetime
 import decimal
 import sys
-from typing import Any, Callable, Generic, Type, TypeVar
+from typing import Any, Callable, Generic, Iterable, Type, TypeVar, overload
 
-from pydantic_core import ErrorDetails, ErrorTypeInfo, InitErrorDetails, MultiHostHost
-from pydantic_core.core_schema import CoreConfig, CoreSchema, ErrorType
+from pydantic_core import ErrorDetails, ErrorTypeInfo, InitErrorDetails, MultiHostHost, MultiHostUrlBuildHost
+from pydantic_core.core_schema import CoreConfig, CoreSchema, CustomValidatorBuilder, ErrorType
 
 if sys.version_info < (3, 8):
     from typing_extensions import final
@@ -24,6 +25,7 @@ __all__ = [
     'build_profile',
     'ArgsKwargs',
     'SchemaValidator',
+    'IteratedValidation',
     'SchemaSerializer',
     'Url',
     'MultiHostUrl',
@@ -58,9 +60,30 @@ class Some(Generic[_T]):
 
 @final
 class SchemaValidator:
-    def __init__(self, schema: CoreSchema, config: CoreConfig | None = None) -> None: ...
+    def __init__(self, schema: CoreSchema, config: CoreConfig | None = None, *, collect_stats: bool = False) -> None: ...
     @property
     def title(self) -> str: ...
+    @property
+    def input_schema(self) -> CoreSchema:
+        """
+        The schema the validator was built from after validation, `SchemaValidator(v.input_schema, v.config)` builds
+        an equivalent validator. Dicts and lists are copies, functions and other values are returned by reference.
+
+        This is the schema as given, not the effective schema: defaults aren't filled in, the config isn't merged
+        into it and definition references aren't resolved.
+        """
+    @property
+    def config(self) -> CoreConfig | None: ...
+    def __copy__(self) -> Self:
+        """
+        A new validator built from the same schema and config, classes and functions in the schema are shared
+        with the original.
+        """
+    def __deepcopy__(self, memo: Any) -> Self:
+        """
+        A new validator built from a deep copy of the schema and config, so classes and functions in the schema
+        follow `memo`.
+        """
     def validate_python(
         self,
         input: Any,
@@ -78,7 +101,22 @@ class SchemaValidator:
         from_attributes: bool | None = None,
         context: Any = None,
         self_instance: Any | None = None,
-    ) -> bool: ...
+    ) -> bool:
+        """
+        Whether `input` passes validation, validation stops at the first error and no `ValidationError` is created.
+        Internal errors, e.g. exceptions other than `ValueError` and `AssertionError` raised in functions, are raised.
+        """
+    def isinstance_json(
+        self,
+        input: str | bytes | bytearray,
+        *,
+        strict: bool | None = None,
+        context: Any = None,
+        self_instance: Any | None = None,
+    ) -> bool:
+        """
+        Like `isinstance_python` but for JSON data, invalid JSON returns `False`.
+        """
     def validate_json(
         self,
         input: str | bytes | bytearray,
@@ -87,6 +125,52 @@ class SchemaValidator:
         context: Any = None,
         self_instance: Any | None = None,
     ) -> Any: ...
+    @overload
+    def validate_many(
+        self,
+        inputs: Iterable[Any],
+        *,
+        mode: Literal['python', 'json'] = 'python',
+        on_error: Literal['raise'] = 'raise',
+        strict: bool | None = None,
+        from_attributes: bool | None = None,
+        context: Any = None,
+    ) -> list[Any]: ...
+    @overload
+    def validate_many(
+        self,
+        inputs: Iterable[Any],
+        *,
+        mode: Literal['python', 'json'] = 'python',
+        on_error: Literal['collect'],
+        strict: bool | None = None,
+        from_attributes: bool | None = None,
+        context: Any = None,
+    ) -> list[tuple[int, Any | ValidationError]]: ...
+    def validate_partial(
+        self,
+        input: Any,
+        *,
+        strict: bool | None = None,
+        from_attributes: bool | None = None,
+        context: Any = None,
+    ) -> tuple[Any, ValidationError | None]:
+        """
+        Validate a list, set, frozenset or variable length tuple, keeping whichever items are valid.
+
+        Returns `(output, error)`, where `output` contains the valid items and `error` is a `ValidationError`
+        for the invalid items and any `min_length` violation, or `None` if the input was entirely valid.
+        Errors which apply to the whole input, e.g. the wrong type or `max_length`, are still raised.
+        """
+    def validate_iterated(
+        self, *, strict: bool | None = None, from_attributes: bool | None = None, context: Any = None
+    ) -> IteratedValidation:
+        """
+        Start validating a list, set, frozenset or variable length tuple whose items are passed in batches,
+        e.g. as they're received from an async generator, without collecting them into one input first.
+
+        Pass each batch to `IteratedValidation.feed`, then call `IteratedValidation.finish` to get the output.
+        """
     def validate_assignment(
         self,
         obj: Any,
@@ -98,12 +182,51 @@ class SchemaValidator:
         context: Any = None,
     ) -> dict[str, Any]: ...
     def get_default_value(self, *, strict: bool | None = None, context: Any = None) -> Some | None: ...
+    def stats(self) -> list[dict[str, Any]]:
+        """
+        Statistics for each schema node, only collected if the validator was created with `collect_stats=True`.
+
+        Each dict has `path`, `name`, `calls`, `failures` and `time` (seconds spent in the node itself, excluding
+        time spent in nested nodes).
+        """
+    def reset_stats(self) -> None: ...
+    def memory_breakdown(self) -> list[dict[str, Any]]:
+        """
+        Estimated memory used by each node of the built validator, excluding memory owned by python objects.
+
+        Each dict has `path`, `name` and `bytes`, definitions have paths starting `('definitions', index)`.
+        """
+    def __sizeof__(self) -> int: ...
+    def debug_repr(self) -> str:
+        """
+        `repr` of the validator including the whole built validator tree and its definitions, for debugging.
+        """
 
 _IncEx: TypeAlias = set[int] | set[str] | dict[int, _IncEx] | dict[str, _IncEx] | None
 
+@final
+class IteratedValidation:
+    def feed(self, items: Iterable[Any]) -> None:
+        """
+        Validate each item in `items`, item errors are raised by `finish` after all the batches have been fed,
+        unless `fail_fast` or `max_errors` stop validation early.
+
+        Raises:
+            ValidationError: If the collection is longer than `max_length`, or validation stopped early.
+        """
+    def finish(self) -> Any:
+        """
+        Check the collection has at least `min_length` items and return it.
+
+        Raises:
+            ValidationError: If any item was invalid or the collection is shorter than `min_length`.
+        """
+
 @final
 class SchemaSerializer:
     def __init__(self, schema: CoreSchema, config: CoreConfig | None = None) -> None: ...
+    @property
+    def title(self) -> str: ...
     def to_python(
         self,
         value: Any,
@@ -133,7 +256,27 @@ class SchemaSerializer:
         round_trip: bool = False,
         warnings: bool = True,
         fallback: Callable[[Any], Any] | None = None,
+        capacity: int | None = None,
     ) -> bytes: ...
+    def __copy__(self) -> Self:
+        """
+        A new serializer built from the same schema and config, classes and functions in the schema are shared
+        with the original.
+        """
+    def __deepcopy__(self, memo: Any) -> Self:
+        """
+        A new serializer built from a deep copy of the schema and config, so classes and functions in the schema
+        follow `memo`.
+        """
+    def memory_breakdown(self) -> list[dict[str, Any]]:
+        """
+        Estimated memory used by each node of the built serializer, see `SchemaValidator.memory_breakdown`.
+        """
+    def __sizeof__(self) -> int: ...
+    def debug_repr(self) -> str:
+        """
+        `repr` of the serializer including the whole built serializer tree and its definitions, for debugging.
+        """
 
 def to_json(
     value: Any,
@@ -148,6 +291,7 @@ def to_json(
     bytes_mode: Literal['utf8', 'base64'] = 'utf8',
     serialize_unknown: bool = False,
     fallback: Callable[[Any], Any] | None = None,
+    capacity: int | None = None,
 ) -> bytes: ...
 def to_jsonable_python(
     value: Any,
@@ -187,6 +331,22 @@ class Url(SupportsAllComparisons):
     def __repr__(self) -> str: ...
     def __str__(self) -> str: ...
     def __deepcopy__(self, memo: dict) -> str: ...
+    @classmethod
+    def build(
+        cls,
+        *,
+        scheme: str,
+        host: str,
+        username: str | None = None,
+        password: str | None = None,
+        port: int | None = None,
+        path: str | None = None,
+        query: str | None = None,
+        fragment: str | None = None,
+    ) -> Self:
+        """
+        Build a URL from its components, percent-encoding them where required.
+        """
 
 class MultiHostUrl(SupportsAllComparisons):
     def __init__(self, url: str) -> None: ...
@@ -204,9 +364,38 @@ class MultiHostUrl(SupportsAllComparisons):
     def __repr__(self) -> str: ...
     def __str__(self) -> str: ...
     def __deepcopy__(self, memo: dict) -> Self: ...
+    @classmethod
+    def build(
+        cls,
+        *,
+        scheme: str,
+        hosts: list[MultiHostUrlBuildHost] | None = None,
+        path: str | None = None,
+        query: str | None = None,
+        query_params: list[tuple[str, str]] | None = None,
+        fragment: str | None = None,
+    ) -> Self:
+        """
+        Build a URL from its components, percent-encoding them where required. Only one of `query` and
+        `query_params` may be set.
+        """
+    def with_host_added(self, host: MultiHostUrlBuildHost) -> Self:
+        """
+        A copy of this URL with `host` added after the existing hosts.
+        """
 
 @final
 class SchemaError(Exception):
+    @property
+    def path(self) -> tuple[str | int, ...]:
+        """
+        Keys and indexes leading from the top level schema to the schema which caused the error.
+        """
+    @property
+    def kind(self) -> str | None:
+        """
+        The `type` of the schema which caused the error, `None` if it's not known.
+        """
     def error_count(self) -> int: ...
     def errors(self) -> list[ErrorDetails]: ...
 
@@ -228,8 +417,21 @@ class ValidationError(ValueError):
     @property
     def title(self) -> str: ...
     def error_count(self) -> int: ...
-    def errors(self, *, include_url: bool = True, include_context: bool = True) -> list[ErrorDetails]: ...
-    def json(self, *, indent: int | None = None, include_url: bool = True, include_context: bool = True) -> str: ...
+    def errors(
+        self, *, include_url: bool = True, include_context: bool = True, include_input_type: bool = False
+    ) -> list[ErrorDetails]:
+        """
+        The details of each error, with `include_input_type=True` each error has an `input_type` key recording
+        whether its input came from python or JSON and the name of the input's python type.
+        """
+    def json(
+        self,
+        *,
+        indent: int | None = None,
+        include_url: bool = True,
+        include_context: bool = True,
+        include_input_type: bool = False,
+    ) -> str: ...
 
 @final
 class PydanticCustomError(ValueError):
@@ -281,6 +483,18 @@ class ArgsKwargs:
     @property
     def kwargs(self) -> dict[str, Any] | None: ...
 
+class TzInfo(datetime.tzinfo):
+    """
+    A fixed offset timezone, used for aware datetimes and times created by validators.
+    """
+
+    def __init__(self, seconds: int) -> None: ...
+    def tzname(self, _dt: datetime.datetime | None) -> str | None: ...
+    def utcoffset(self, _dt: datetime.datetime | None) -> datetime.timedelta: ...
+    def dst(self, _dt: datetime.datetime | None) -> datetime.timedelta | None: ...
+    def fromutc(self, dt: datetime.datetime) -> datetime.datetime: ...
+    def __deepcopy__(self, _memo: dict[Any, Any]) -> TzInfo: ...
+
 @final
 class PydanticUndefinedType:
     def __copy__(self) -> Self: ...
@@ -292,3 +506,10 @@ def list_all_errors() -> list[ErrorTypeInfo]:
     """
     Get information about all built-in errors.
     """
+
+def register_validator(schema_type: str, builder: CustomValidatorBuilder) -> None:
+    """
+    Register a builder for schemas with `'type': schema_type`, it's called with the schema and config when a
+    validator is built and should return an object with a `validate(input_value, strict, info)` method.
+    Serialization of these schemas falls back to inference.
+    """
@@ -56,9 +56,25 @@ class CoreConfig(TypedDict, total=False):
         str_to_lower: Whether to convert string fields to lowercase.
         str_to_upper: Whether to convert string fields to uppercase.
         allow_inf_nan: Whether to allow infinity and NaN values for float fields. Default is `True`.
+        allow_number_protocols: Whether int and float fields should accept objects defining `__int__` in lax mode.
+            Note that `__int__` may truncate (e.g. `Fraction(7, 2)` becomes `3`); `bool` is never converted.
+            Default is `False`.
         ser_json_timedelta: The serialization option for `timedelta` values. Default is 'iso8601'.
         ser_json_bytes: The serialization option for `bytes` values. Default is 'utf8'.
         hide_input_in_errors: Whether to hide input data from `ValidationError` representation.
+        lenient_surrogates: Whether to replace lone surrogate escapes in JSON strings (e.g. `"\\ud83d"`) with U+FFFD
+            rather than raising a `json_invalid` error. Default is `False`.
+        config_propagation: How the `config` of model, dataclass and typed dict schemas combines with the config of
+            the enclosing schema: 'merge' uses the enclosing config's `str_*`, `allow_inf_nan`,
+            `allow_number_protocols` and `recursion_limit` settings where they aren't set here, 'none' ignores it.
+            Other keys, like `title` and `extra_fields_behavior`, are never inherited. Default is 'merge'.
+        fail_fast: Whether list, set, frozenset and variable length tuple validation should stop after the first
+            item error rather than collecting every error. Default is `False`.
+        max_errors: The number of item errors after which list, set, frozenset and variable length tuple validation
+            stops, a `too_many_errors` error is added after them if any items were left unchecked. Default is no limit.
+        recursion_limit: The most recursive schema references which may be nested inside each other while validating,
+            deeper input raises a `recursion_limit_exceeded` error. Default is 255 (123 on PyPy and wasm), which is
+            also the maximum since deeper nesting could overflow the stack.
     """
 
     title: str
@@ -85,11 +101,23 @@ class CoreConfig(TypedDict, total=False):
     str_to_upper: bool
     # fields related to float fields only
     allow_inf_nan: bool  # default: True
+    # fields related to int and float fields
+    allow_number_protocols: bool  # default: False
     # the config options are used to customise serialization to JSON
     ser_json_timedelta: Literal['iso8601', 'float']  # default: 'iso8601'
     ser_json_bytes: Literal['utf8', 'base64']  # default: 'utf8'
     # used to hide input data from ValidationError repr
     hide_input_in_errors: bool
+    # replace lone surrogates in JSON strings with U+FFFD instead of raising an error
+    lenient_surrogates: bool  # default: False
+    # whether the config of the enclosing schema applies to model, dataclass and typed dict configs
+    config_propagation: Literal['merge', 'none']  # default: 'merge'
+    # stop validating collection items after the first error
+    fail_fast: bool  # default: False
+    # stop validating collection items after this many errors
+    max_errors: int
+    # how deeply recursive schemas may be nested while validating
+    recursion_limit: int  # default: 255
 
 
 IncExCall: TypeAlias = 'set[int | str] | dict[int | str, IncExCall] | None'
@@ -164,6 +192,14 @@ class ValidationInfo(Protocol):
         """The type of input data we are currently validating"""
         ...
 
+    @property
+    def index(self) -> int | None:
+        """
+        The index of the item being validated if this validator is applied to the items of a list, tuple, set,
+        frozenset or generator.
+        """
+        ...
+
 
 class FieldValidationInfo(ValidationInfo, Protocol):
     """
@@ -184,6 +220,21 @@ class FieldValidationInfo(ValidationInfo, Protocol):
         ...
 
 
+class CustomValidator(Protocol):
+    """
+    Object returned by builders passed to `register_validator`.
+    """
+
+    def validate(self, input_value: Any, strict: bool, info: ValidationInfo) -> Any:
+        """
+        Validate `input_value`, raise `ValueError`, `AssertionError` or `PydanticCustomError` if it's invalid.
+        """
+        ...
+
+
+CustomValidatorBuilder = Callable[[Dict[str, Any], Optional[CoreConfig]], CustomValidator]
+
+
 ExpectedSerializationTypes = Literal[
     'none',
     'int',
@@ -662,6 +713,7 @@ class StringSchema(TypedDict, total=False):
     pattern: str
     max_length: int
     min_length: int
+    length_unit: Literal['codepoint', 'byte', 'grapheme']  # default: 'codepoint'
     strip_whitespace: bool
     to_lower: bool
     to_upper: bool
@@ -676,6 +728,7 @@ def str_schema(
     pattern: str | None = None,
     max_length: int | None = None,
     min_length: int | None = None,
+    length_unit: Literal['codepoint', 'byte', 'grapheme'] | None = None,
     strip_whitespace: bool | None = None,
     to_lower: bool | None = None,
     to_upper: bool | None = None,
@@ -699,6 +752,9 @@ def str_schema(
         pattern: A regex pattern that the value must match
         max_length: The value must be at most this length
         min_length: The value must be at least this length
+        length_unit: How `max_length` and `min_length` are measured, in unicode code points, UTF-8 bytes or
+            grapheme clusters (what a user would see as one character), measured after `strip_whitespace`,
+            when set, length errors include the measured `actual_length` in their context
         strip_whitespace: Whether to strip whitespace from the value
         to_lower: Whether to convert the value to lowercase
         to_upper: Whether to convert the value to uppercase
@@ -712,6 +768,7 @@ def str_schema(
         pattern=pattern,
         max_length=max_length,
         min_length=min_length,
+        length_unit=length_unit,
         strip_whitespace=strip_whitespace,
         to_lower=to_lower,
         to_upper=to_upper,
@@ -782,6 +839,7 @@ class DateSchema(TypedDict, total=False):
     # defaults to current local utc offset from `time.localtime().tm_gmtoff`
     # value is restricted to -86_400 < offset < 86_400 by bounds in generate_self_schema.py
     now_utc_offset: int
+    numeric_mode: Literal['unix-seconds', 'unix-ms', 'ordinal', 'reject']
     ref: str
     metadata: Any
     serialization: SerSchema
@@ -796,6 +854,7 @@ def date_schema(
     gt: date | None = None,
     now_op: Literal['past', 'future'] | None = None,
     now_utc_offset: int | None = None,
+    numeric_mode: Literal['unix-seconds', 'unix-ms', 'ordinal', 'reject'] | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
@@ -820,6 +879,8 @@ def date_schema(
         gt: The value must be strictly greater than this date
         now_op: The value must be in the past or future relative to the current date
         now_utc_offset: The value must be in the past or future relative to the current date with this utc offset
+        numeric_mode: How to interpret int and float input and numeric strings like `'1672531200'`, by default
+            unix seconds or milliseconds are inferred from the magnitude of the number, 'reject' forbids numeric input
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -833,6 +894,7 @@ def date_schema(
         gt=gt,
         now_op=now_op,
         now_utc_offset=now_utc_offset,
+        numeric_mode=numeric_mode,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -913,6 +975,7 @@ class DatetimeSchema(TypedDict, total=False):
     # defaults to current local utc offset from `time.localtime().tm_gmtoff`
     # value is restricted to -86_400 < offset < 86_400 by bounds in generate_self_schema.py
     now_utc_offset: int
+    numeric_mode: Literal['unix-seconds', 'unix-ms', 'ordinal', 'reject']
     ref: str
     metadata: Any
     serialization: SerSchema
@@ -928,6 +991,7 @@ def datetime_schema(
     now_op: Literal['past', 'future'] | None = None,
     tz_constraint: Literal['aware', 'naive'] | int | None = None,
     now_utc_offset: int | None = None,
+    numeric_mode: Literal['unix-seconds', 'unix-ms', 'ordinal', 'reject'] | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
@@ -955,6 +1019,8 @@ def datetime_schema(
         tz_constraint: The value must be timezone aware or naive, or an int to indicate required tz offset
             TODO: use of a tzinfo where offset changes based on the datetime is not yet supported
         now_utc_offset: The value must be in the past or future relative to the current datetime with this utc offset
+        numeric_mode: How to interpret int and float input and numeric strings like `'1672531200'`, by default
+            unix seconds or milliseconds are inferred from the magnitude of the number, 'reject' forbids numeric input
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -969,6 +1035,7 @@ def datetime_schema(
         now_op=now_op,
         tz_constraint=tz_constraint,
         now_utc_offset=now_utc_offset,
+        numeric_mode=numeric_mode,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -1204,7 +1271,10 @@ class ListSchema(TypedDict, total=False):
     items_schema: CoreSchema
     min_length: int
     max_length: int
+    fail_fast: bool  # default: False
+    max_errors: int
     strict: bool
+    items_strict: bool
     ref: str
     metadata: Any
     serialization: IncExSeqOrElseSerSchema
@@ -1215,7 +1285,10 @@ def list_schema(
     *,
     min_length: int | None = None,
     max_length: int | None = None,
+    fail_fast: bool | None = None,
+    max_errors: int | None = None,
     strict: bool | None = None,
+    items_strict: bool | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: IncExSeqOrElseSerSchema | None = None,
@@ -1235,7 +1308,12 @@ def list_schema(
         items_schema: The value must be a list of items that match this schema
         min_length: The value must be a list with at least this many items
         max_length: The value must be a list with at most this many items
+        fail_fast: Stop validating items after the first error, so at most one item error is reported
+        max_errors: Stop validating items once this many errors have been collected, a `too_many_errors` error
+            is added after them if any items were left unchecked
         strict: The value must be a list with exactly this many items
+        items_strict: Whether items are validated in strict mode, overriding the strictness the list
+            is validated with, e.g. a lax list with strict items
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -1245,7 +1323,10 @@ def list_schema(
         items_schema=items_schema,
         min_length=min_length,
         max_length=max_length,
+        fail_fast=fail_fast,
+        max_errors=max_errors,
         strict=strict,
+        items_strict=items_strict,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -1256,6 +1337,9 @@ class TuplePositionalSchema(TypedDict, total=False):
     type: Required[Literal['tuple-positional']]
     items_schema: Required[List[CoreSchema]]
     extra_schema: CoreSchema
+    variadic_item_index: int
+    min_length: int
+    max_length: int
     strict: bool
     ref: str
     metadata: Any
@@ -1266,6 +1350,9 @@ def tuple_positional_schema(
     items_schema: list[CoreSchema],
     *,
     extra_schema: CoreSchema | None = None,
+    variadic_item_index: int | None = None,
+    min_length: int | None = None,
+    max_length: int | None = None,
     strict: bool | None = None,
     ref: str | None = None,
     metadata: Any = None,
@@ -1290,6 +1377,10 @@ def tuple_positional_schema(
             This was inspired by JSON schema's `prefixItems` and `items` fields.
             In python's `typing.Tuple`, you can't specify a type for "extra" items -- they must all be the same type
             if the length is variable. So this field won't be set from a `typing.Tuple` annotation on a pydantic model.
+        variadic_item_index: The index of the last item in `items_schema`, which any items after the positional ones
+            must match, e.g. `tuple[int, *tuple[str, ...]]` has `variadic_item_index=1`
+        min_length: The value must have at least this many items after the positional ones
+        max_length: The value must have at most this many items after the positional ones
         strict: The value must be a tuple with exactly this many items
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
@@ -1299,6 +1390,9 @@ def tuple_positional_schema(
         type='tuple-positional',
         items_schema=items_schema,
         extra_schema=extra_schema,
+        variadic_item_index=variadic_item_index,
+        min_length=min_length,
+        max_length=max_length,
         strict=strict,
         ref=ref,
         metadata=metadata,
@@ -1311,7 +1405,10 @@ class TupleVariableSchema(TypedDict, total=False):
     items_schema: CoreSchema
     min_length: int
     max_length: int
+    fail_fast: bool  # default: False
+    max_errors: int
     strict: bool
+    items_strict: bool
     ref: str
     metadata: Any
     serialization: IncExSeqOrElseSerSchema
@@ -1322,7 +1419,10 @@ def tuple_variable_schema(
     *,
     min_length: int | None = None,
     max_length: int | None = None,
+    fail_fast: bool | None = None,
+    max_errors: int | None = None,
     strict: bool | None = None,
+    items_strict: bool | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: IncExSeqOrElseSerSchema | None = None,
@@ -1344,7 +1444,12 @@ def tuple_variable_schema(
         items_schema: The value must be a tuple with items that match this schema
         min_length: The value must be a tuple with at least this many items
         max_length: The value must be a tuple with at most this many items
+        fail_fast: Stop validating items after the first error, so at most one item error is reported
+        max_errors: Stop validating items once this many errors have been collected, a `too_many_errors` error
+            is added after them if any items were left unchecked
         strict: The value must be a tuple with exactly this many items
+        items_strict: Whether items are validated in strict mode, overriding the strictness the tuple
+            is validated with, e.g. a lax tuple with strict items
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -1354,7 +1459,10 @@ def tuple_variable_schema(
         items_schema=items_schema,
         min_length=min_length,
         max_length=max_length,
+        fail_fast=fail_fast,
+        max_errors=max_errors,
         strict=strict,
+        items_strict=items_strict,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -1366,7 +1474,12 @@ class SetSchema(TypedDict, total=False):
     items_schema: CoreSchema
     min_length: int
     max_length: int
+    unique_items_error: bool  # default: False
+    fail_fast: bool  # default: False
+    max_errors: int
+    hash_key: Callable[[Any], Hashable]
     strict: bool
+    items_strict: bool
     ref: str
     metadata: Any
     serialization: SerSchema
@@ -1377,7 +1490,12 @@ def set_schema(
     *,
     min_length: int | None = None,
     max_length: int | None = None,
+    unique_items_error: bool | None = None,
+    fail_fast: bool | None = None,
+    max_errors: int | None = None,
+    hash_key: Callable[[Any], Hashable] | None = None,
     strict: bool | None = None,
+    items_strict: bool | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
@@ -1399,7 +1517,16 @@ def set_schema(
         items_schema: The value must be a set with items that match this schema
         min_length: The value must be a set with at least this many items
         max_length: The value must be a set with at most this many items
+        unique_items_error: Whether a set which only has fewer than `min_length` items because duplicates were
+            removed should raise a `duplicate_items` error rather than `too_short`
+        fail_fast: Stop validating items after the first error, so at most one item error is reported
+        max_errors: Stop validating items once this many errors have been collected, a `too_many_errors` error
+            is added after them if any items were left unchecked
+        hash_key: A function called with each validated item to get the key used to remove duplicates, the first
+            item with each key is kept, exceptions it raises are errors for the item
         strict: The value must be a set with exactly this many items
+        items_strict: Whether items are validated in strict mode, overriding the strictness the set
+            is validated with, e.g. a lax set with strict items
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -1409,7 +1536,12 @@ def set_schema(
         items_schema=items_schema,
         min_length=min_length,
         max_length=max_length,
+        unique_items_error=unique_items_error,
+        fail_fast=fail_fast,
+        max_errors=max_errors,
+        hash_key=hash_key,
         strict=strict,
+        items_strict=items_strict,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -1421,7 +1553,12 @@ class FrozenSetSchema(TypedDict, total=False):
     items_schema: CoreSchema
     min_length: int
     max_length: int
+    unique_items_error: bool  # default: False
+    fail_fast: bool  # default: False
+    max_errors: int
+    hash_key: Callable[[Any], Hashable]
     strict: bool
+    items_strict: bool
     ref: str
     metadata: Any
     serialization: SerSchema
@@ -1432,7 +1569,12 @@ def frozenset_schema(
     *,
     min_length: int | None = None,
     max_length: int | None = None,
+    unique_items_error: bool | None = None,
+    fail_fast: bool | None = None,
+    max_errors: int | None = None,
+    hash_key: Callable[[Any], Hashable] | None = None,
     strict: bool | None = None,
+    items_strict: bool | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
@@ -1454,7 +1596,16 @@ def frozenset_schema(
         items_schema: The value must be a frozenset with items that match this schema
         min_length: The value must be a frozenset with at least this many items
         max_length: The value must be a frozenset with at most this many items
+        unique_items_error: Whether a frozenset which only has fewer than `min_length` items because duplicates were
+            removed should raise a `duplicate_items` error rather than `too_short`
+        fail_fast: Stop validating items after the first error, so at most one item error is reported
+        max_errors: Stop validating items once this many errors have been collected, a `too_many_errors` error
+            is added after them if any items were left unchecked
+        hash_key: A function called with each validated item to get the key used to remove duplicates, the first
+            item with each key is kept, exceptions it raises are errors for the item
         strict: The value must be a frozenset with exactly this many items
+        items_strict: Whether items are validated in strict mode, overriding the strictness the frozenset
+            is validated with, e.g. a lax frozenset with strict items
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -1464,6 +1615,77 @@ def frozenset_schema(
         items_schema=items_schema,
         min_length=min_length,
         max_length=max_length,
+        unique_items_error=unique_items_error,
+        fail_fast=fail_fast,
+        max_errors=max_errors,
+        hash_key=hash_key,
+        strict=strict,
+        items_strict=items_strict,
+        ref=ref,
+        metadata=metadata,
+        serialization=serialization,
+    )
+
+
+class CounterSchema(TypedDict, total=False):
+    type: Required[Literal['counter']]
+    items_schema: CoreSchema
+    min_count: int
+    max_count: int
+    max_keys: int
+    max_input_length: int
+    strict: bool
+    ref: str
+    metadata: Any
+    serialization: SerSchema
+
+
+def counter_schema(
+    items_schema: CoreSchema | None = None,
+    *,
+    min_count: int | None = None,
+    max_count: int | None = None,
+    max_keys: int | None = None,
+    max_input_length: int | None = None,
+    strict: bool | None = None,
+    ref: str | None = None,
+    metadata: Any = None,
+    serialization: SerSchema | None = None,
+) -> CounterSchema:
+    """
+    Returns a schema that matches a `collections.Counter` of items of a given schema, e.g.:
+
+    ```py
+    from collections import Counter
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.counter_schema(items_schema=core_schema.str_schema(), max_count=2)
+    v = SchemaValidator(schema)
+    assert v.validate_python(['a', 'b', 'a']) == Counter({'a': 2, 'b': 1})
+    assert v.validate_python({'a': '2'}) == Counter({'a': 2})
+    ```
+
+    Mappings are validated as items and their non-negative integer counts, in lax mode any other iterable is
+    validated as items and the occurrences of each item are counted.
+
+    Args:
+        items_schema: The keys of the counter must match this schema
+        min_count: Each item must occur at least this many times
+        max_count: Each item must occur at most this many times
+        max_keys: The counter must have at most this many distinct items
+        max_input_length: An iterable input must yield at most this many items
+        strict: The value must be a `collections.Counter` instance, or an object in JSON
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return dict_not_none(
+        type='counter',
+        items_schema=items_schema,
+        min_count=min_count,
+        max_count=max_count,
+        max_keys=max_keys,
+        max_input_length=max_input_length,
         strict=strict,
         ref=ref,
         metadata=metadata,
@@ -2828,6 +3050,7 @@ class ModelFieldsSchema(TypedDict, total=False):
     extra_behavior: ExtraBehavior
     populate_by_name: bool  # replaces `allow_population_by_field_name` in pydantic v1
     from_attributes: bool
+    lookup_mode: Literal['mapping-then-attributes', 'attributes-then-mapping']
     ref: str
     metadata: Any
     serialization: SerSchema
@@ -2843,6 +3066,7 @@ def model_fields_schema(
     extra_behavior: ExtraBehavior | None = None,
     populate_by_name: bool | None = None,
     from_attributes: bool | None = None,
+    lookup_mode: Literal['mapping-then-attributes', 'attributes-then-mapping'] | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
@@ -2872,6 +3096,9 @@ def model_fields_schema(
         extra_behavior: The extra behavior to use for the typed dict
         populate_by_name: Whether the typed dict should populate by name
         from_attributes: Whether the typed dict should be populated from attributes
+        lookup_mode: Look up each field on objects which are not mappings by trying both item access and attribute
+            access in the order given, attributes are never read from mappings. Implies `from_attributes` unless
+            validation is called with `from_attributes=False`
         serialization: Custom serialization schema
     """
     return dict_not_none(
@@ -2884,6 +3111,7 @@ def model_fields_schema(
         extra_behavior=extra_behavior,
         populate_by_name=populate_by_name,
         from_attributes=from_attributes,
+        lookup_mode=lookup_mode,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -3682,6 +3910,7 @@ if not MYPY:
         TupleVariableSchema,
         SetSchema,
         FrozenSetSchema,
+        CounterSchema,
         GeneratorSchema,
         DictSchema,
         AfterValidatorFunctionSchema,
@@ -3735,6 +3964,7 @@ CoreSchemaType = Literal[
     'tuple-variable',
     'set',
     'frozenset',
+    'counter',
     'generator',
     'dict',
     'function-after',
@@ -3773,6 +4003,7 @@ ErrorType = Literal[
     'json_invalid',
     'json_type',
     'recursion_loop',
+    'recursion_limit_exceeded',
     'missing',
     'frozen_field',
     'frozen_instance',
@@ -3794,6 +4025,7 @@ ErrorType = Literal[
     'too_long',
     'iterable_type',
     'iteration_error',
+    'too_many_errors',
     'string_type',
     'string_sub_type',
     'string_unicode',
@@ -3805,6 +4037,9 @@ ErrorType = Literal[
     'list_type',
     'tuple_type',
     'set_type',
+    'unhashable_item',
+    'hash_key_error',
+    'duplicate_items',
     'bool_type',
     'bool_parsing',
     'int_type',
@@ -3838,6 +4073,9 @@ ErrorType = Literal[
     'time_delta_type',
     'time_delta_parsing',
     'frozen_set_type',
+    'counter_type',
+    'count_too_small',
+    'count_too_large',
     'is_instance_of',
     'is_subclass_of',
     'callable_type',
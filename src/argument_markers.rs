@@ -2,7 +2,8 @@ use pyo3::basic::CompareOp;
 use pyo3::exceptions::PyNotImplementedError;
 use pyo3::prelude::*;
 use pyo3::sync::GILOnceCell;
-use pyo3::types::{PyDict, PyTuple};
+use pyo3::types::{PyDict, PyFrozenSet, PyString, PyTuple, PyType};
+use pyo3::AsPyPointer;
 
 use crate::tools::safe_repr;
 
@@ -14,6 +15,17 @@ pub struct ArgsKwargs {
 }
 
 impl ArgsKwargs {
+    /// Empty kwargs are stored as `None` so `ArgsKwargs((1,), {}) == ArgsKwargs((1,))`
+    pub fn new(py: Python, args: &PyTuple, kwargs: Option<&PyDict>) -> Self {
+        Self {
+            args: args.into_py(py),
+            kwargs: match kwargs {
+                Some(d) if !d.is_empty() => Some(d.into_py(py)),
+                _ => None,
+            },
+        }
+    }
+
     fn eq(&self, py: Python, other: &Self) -> PyResult<bool> {
         if self.args.as_ref(py).eq(other.args.as_ref(py))? {
             match (&self.kwargs, &other.kwargs) {
@@ -31,13 +43,7 @@ impl ArgsKwargs {
 impl ArgsKwargs {
     #[new]
     fn py_new(py: Python, args: &PyTuple, kwargs: Option<&PyDict>) -> Self {
-        Self {
-            args: args.into_py(py),
-            kwargs: match kwargs {
-                Some(d) if !d.is_empty() => Some(d.into_py(py)),
-                _ => None,
-            },
-        }
+        Self::new(py, args, kwargs)
     }
 
     fn __richcmp__(&self, other: &Self, op: CompareOp, py: Python<'_>) -> PyObject {
@@ -54,12 +60,41 @@ impl ArgsKwargs {
         }
     }
 
+    // kwargs are unordered as far as equality is concerned, so they're hashed as a frozenset of items
+    fn __hash__(&self, py: Python) -> PyResult<isize> {
+        let kwargs = match self.kwargs {
+            Some(ref d) => PyFrozenSet::new(py, d.as_ref(py).items())?,
+            None => PyFrozenSet::empty(py)?,
+        };
+        PyTuple::new(py, [self.args.as_ref(py).as_ref(), kwargs.as_ref()]).hash()
+    }
+
+    // like a call, e.g. `ArgsKwargs(1, 2, x=3)`
     pub fn __repr__(&self, py: Python) -> String {
-        let args = safe_repr(self.args.as_ref(py));
-        match self.kwargs {
-            Some(ref d) => format!("ArgsKwargs({args}, {})", safe_repr(d.as_ref(py))),
-            None => format!("ArgsKwargs({args})"),
+        let mut items: Vec<String> = self
+            .args
+            .as_ref(py)
+            .iter()
+            .map(|arg| safe_repr(arg).to_string())
+            .collect();
+        if let Some(ref kwargs) = self.kwargs {
+            for (key, value) in kwargs.as_ref(py) {
+                match key.downcast::<PyString>() {
+                    Ok(key) => items.push(format!("{}={}", key.to_string_lossy(), safe_repr(value))),
+                    Err(_) => items.push(format!("**{{{}: {}}}", safe_repr(key), safe_repr(value))),
+                }
+            }
         }
+        format!("ArgsKwargs({})", items.join(", "))
+    }
+
+    pub fn __reduce__(slf: &PyCell<Self>) -> (&PyType, (&PyTuple, Option<&PyDict>)) {
+        let py = slf.py();
+        let this = slf.get();
+        (
+            slf.get_type(),
+            (this.args.as_ref(py), this.kwargs.as_ref().map(|d| d.as_ref(py))),
+        )
     }
 }
 
@@ -80,9 +115,7 @@ impl PydanticUndefinedType {
 
     #[staticmethod]
     pub fn new(py: Python) -> Py<Self> {
-        UNDEFINED_CELL
-            .get_or_init(py, || PydanticUndefinedType {}.into_py(py).extract(py).unwrap())
-            .clone()
+        Self::get(py).clone()
     }
 
     fn __repr__(&self) -> &'static str {
@@ -104,7 +137,20 @@ impl PydanticUndefinedType {
 }
 
 impl PydanticUndefinedType {
+    fn get(py: Python) -> &'static Py<Self> {
+        UNDEFINED_CELL.get_or_init(py, || PydanticUndefinedType {}.into_py(py).extract(py).unwrap())
+    }
+
     pub fn py_undefined() -> Py<Self> {
         Python::with_gil(PydanticUndefinedType::new)
     }
+
+    /// Whether `identity` (see `Input::identity`) is that of `PydanticUndefined`, this avoids creating
+    /// a new reference to either object
+    pub fn is_undefined_identity(py: Python, identity: Option<usize>) -> bool {
+        match identity {
+            Some(identity) => identity == Self::get(py).as_ptr() as usize,
+            None => false,
+        }
+    }
 }
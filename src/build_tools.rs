@@ -3,11 +3,12 @@ use std::fmt;
 
 use pyo3::exceptions::PyException;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyList, PyString};
+use pyo3::types::{PyDict, PyList, PyString, PyTuple};
 use pyo3::{intern, FromPyObject, PyErrArguments};
 
-use crate::errors::{ErrorMode, ValError};
+use crate::errors::{ErrorMode, ErrorType, LocItem, ValError};
 use crate::tools::SchemaDict;
+use crate::validators::SCHEMA_TYPES;
 use crate::ValidationError;
 
 pub fn schema_or_config<'py, T>(
@@ -44,6 +45,21 @@ pub fn is_strict(schema: &PyDict, config: Option<&PyDict>) -> PyResult<bool> {
     Ok(schema_or_config_same(schema, config, intern!(py, "strict"))?.unwrap_or(false))
 }
 
+/// `fail_fast` from the schema or config, stop validating items of a collection after the first error
+pub fn is_fail_fast(schema: &PyDict, config: Option<&PyDict>) -> PyResult<bool> {
+    let py = schema.py();
+    Ok(schema_or_config_same(schema, config, intern!(py, "fail_fast"))?.unwrap_or(false))
+}
+
+/// `max_errors` from the schema or config, the number of item errors after which a collection stops validating
+pub fn get_max_errors(schema: &PyDict, config: Option<&PyDict>) -> PyResult<Option<usize>> {
+    let py = schema.py();
+    match schema_or_config_same(schema, config, intern!(py, "max_errors"))? {
+        Some(0) => py_schema_err!("`max_errors` must be greater than 0"),
+        max_errors => Ok(max_errors),
+    }
+}
+
 enum SchemaErrorEnum {
     Message(String),
     ValidationError(ValidationError),
@@ -52,7 +68,15 @@ enum SchemaErrorEnum {
 // we could perhaps do clever things here to store each schema error, or have different types for the top
 // level error group, and other errors, we could perhaps also support error groups!?
 #[pyclass(extends=PyException, module="pydantic_core._pydantic_core")]
-pub struct SchemaError(SchemaErrorEnum);
+pub struct SchemaError {
+    error: SchemaErrorEnum,
+    // keys and indexes from the top level schema to the schema which couldn't be built
+    path: Vec<LocItem>,
+    // the `type` of that schema
+    kind: Option<String>,
+    // near-miss suggestions for an unknown schema type, shown after a schema validation error
+    suggestion: String,
+}
 
 impl fmt::Debug for SchemaError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -81,13 +105,28 @@ impl SchemaError {
         PyErr::new::<SchemaError, A>(args)
     }
 
-    pub fn from_val_error(py: Python, error: ValError) -> PyErr {
+    /// Convert errors from validating `schema` against the self-schema, `path` and `kind` point to the schema where the
+    /// first error occurred
+    pub fn from_val_error(py: Python, error: ValError, schema: &PyAny) -> PyErr {
         match error {
             ValError::LineErrors(raw_errors) => {
+                let suggestion = match raw_errors.first().map(|e| &e.error_type) {
+                    Some(ErrorType::UnionTagInvalid { tag, .. }) => did_you_mean(tag, SCHEMA_TYPES),
+                    _ => String::new(),
+                };
                 let line_errors = raw_errors.into_iter().map(|e| e.into_py(py)).collect();
                 let validation_error =
                     ValidationError::new(line_errors, "Schema".to_object(py), ErrorMode::Python, false);
-                let schema_error = SchemaError(SchemaErrorEnum::ValidationError(validation_error));
+                let (path, kind) = match locate_first_error(py, &validation_error, schema) {
+                    Ok(location) => location,
+                    Err(err) => return err,
+                };
+                let schema_error = SchemaError {
+                    error: SchemaErrorEnum::ValidationError(validation_error),
+                    path,
+                    kind,
+                    suggestion,
+                };
                 match Py::new(py, schema_error) {
                     Ok(err) => PyErr::from_value(err.into_ref(py)),
                     Err(err) => err,
@@ -99,8 +138,39 @@ impl SchemaError {
         }
     }
 
+    /// Add the location of the schema which couldn't be built to a `SchemaError` raised while building validators,
+    /// other errors are returned unchanged
+    pub fn with_location(py: Python, err: PyErr, path: Vec<LocItem>, kind: Option<String>) -> PyErr {
+        let message = match err.value(py).extract::<PyRef<SchemaError>>() {
+            Ok(schema_error) => match schema_error.error {
+                SchemaErrorEnum::Message(ref message) => Some(message.clone()),
+                _ => None,
+            },
+            Err(_) => None,
+        };
+        let Some(message) = message else {
+            return err;
+        };
+        let mut location = Vec::with_capacity(2);
+        if !path.is_empty() {
+            location.push(format!("path={}", format_path(&path)));
+        }
+        if let Some(ref kind) = kind {
+            location.push(format!("type={kind}"));
+        }
+        if location.is_empty() {
+            return err;
+        }
+        let err = Self::new_err(format!("{message}\n  [{}]", location.join(", ")));
+        if let Ok(mut schema_error) = err.value(py).extract::<PyRefMut<SchemaError>>() {
+            schema_error.path = path;
+            schema_error.kind = kind;
+        }
+        err
+    }
+
     fn message(&self) -> &str {
-        match &self.0 {
+        match &self.error {
             SchemaErrorEnum::Message(message) => message.as_str(),
             SchemaErrorEnum::ValidationError(_) => "<ValidationError>",
         }
@@ -111,34 +181,60 @@ impl SchemaError {
 impl SchemaError {
     #[new]
     fn py_new(message: String) -> Self {
-        Self(SchemaErrorEnum::Message(message))
+        Self {
+            error: SchemaErrorEnum::Message(message),
+            path: Vec::new(),
+            kind: None,
+            suggestion: String::new(),
+        }
+    }
+
+    #[getter]
+    fn path(&self, py: Python) -> Py<PyTuple> {
+        PyTuple::new(py, self.path.iter().map(|item| item.to_object(py))).into_py(py)
+    }
+
+    #[getter]
+    fn kind(&self) -> Option<&str> {
+        self.kind.as_deref()
     }
 
     fn error_count(&self) -> usize {
-        match &self.0 {
+        match &self.error {
             SchemaErrorEnum::Message(_) => 0,
             SchemaErrorEnum::ValidationError(error) => error.error_count(),
         }
     }
 
     fn errors(&self, py: Python) -> PyResult<Py<PyList>> {
-        match &self.0 {
+        match &self.error {
             SchemaErrorEnum::Message(_) => Ok(PyList::empty(py).into_py(py)),
-            SchemaErrorEnum::ValidationError(error) => error.errors(py, false, false),
+            SchemaErrorEnum::ValidationError(error) => error.errors(py, false, false, false),
         }
     }
 
     fn __str__(&self, py: Python) -> String {
-        match &self.0 {
+        match &self.error {
             SchemaErrorEnum::Message(message) => message.clone(),
-            SchemaErrorEnum::ValidationError(error) => error.display(py, Some("Invalid Schema:"), false),
+            SchemaErrorEnum::ValidationError(error) => self.display_validation_error(py, error),
         }
     }
 
     fn __repr__(&self, py: Python) -> String {
-        match &self.0 {
+        match &self.error {
             SchemaErrorEnum::Message(message) => format!("SchemaError({message:?})"),
-            SchemaErrorEnum::ValidationError(error) => error.display(py, Some("Invalid Schema:"), false),
+            SchemaErrorEnum::ValidationError(error) => self.display_validation_error(py, error),
+        }
+    }
+}
+
+impl SchemaError {
+    fn display_validation_error(&self, py: Python, error: &ValidationError) -> String {
+        let display = error.display(py, Some("Invalid Schema:"), false);
+        if self.suggestion.is_empty() {
+            display
+        } else {
+            format!("{display}\n  {}", self.suggestion)
         }
     }
 }
@@ -194,3 +290,189 @@ impl ExtraBehavior {
         Ok(res)
     }
 }
+
+/// The `items_schema` of a tuple-positional schema split into the positional items and the schema for any further
+/// items, which is either `extra_schema` or the last item if `variadic_item_index` is set
+pub fn tuple_positional_items(schema: &PyDict) -> PyResult<(Vec<&PyAny>, Option<&PyAny>)> {
+    let py = schema.py();
+    let items: &PyList = schema.get_as_req(intern!(py, "items_schema"))?;
+    let extra_schema = schema.get_item(intern!(py, "extra_schema"));
+    match schema.get_as::<usize>(intern!(py, "variadic_item_index"))? {
+        Some(_) if extra_schema.is_some() => {
+            py_schema_err!("`variadic_item_index` and `extra_schema` cannot both be set")
+        }
+        Some(index) if index + 1 != items.len() => {
+            py_schema_err!("`variadic_item_index` must be the index of the last item in `items_schema`")
+        }
+        Some(index) => Ok((items.iter().take(index).collect(), Some(items.get_item(index)?))),
+        None => Ok((items.iter().collect(), extra_schema)),
+    }
+}
+
+/// Config keys which nested models, dataclasses and typed dicts inherit from the enclosing config, these only
+/// change how values are coerced or limit validation as a whole, keys describing the schema itself (e.g. `title`
+/// or `extra_fields_behavior`) always come from the schema's own config
+const INHERITED_CONFIG_KEYS: &[&str] = &[
+    "str_max_length",
+    "str_min_length",
+    "str_strip_whitespace",
+    "str_to_lower",
+    "str_to_upper",
+    "allow_inf_nan",
+    "allow_number_protocols",
+    // applies to the whole validator, so nested schemas can't reset it
+    "recursion_limit",
+];
+
+/// The config for a schema with its own `config` key (models, dataclasses and typed dicts): the inheritable keys
+/// of the parent config are merged under the schema's config so keys set on the schema win, unless the schema's
+/// config sets `config_propagation='none'` in which case the parent config is ignored
+pub fn merge_config<'py>(parent: Option<&'py PyDict>, config: Option<&'py PyDict>) -> PyResult<Option<&'py PyDict>> {
+    let parent = match parent {
+        Some(parent) => parent,
+        None => return Ok(config),
+    };
+    let py = parent.py();
+    if let Some(config) = config {
+        match config.get_as::<&str>(intern!(py, "config_propagation"))? {
+            Some("none") => return Ok(Some(config)),
+            None | Some("merge") => (),
+            Some(s) => return py_schema_err!("Invalid config_propagation: `{}`, expected `merge` or `none`", s),
+        }
+    }
+    let merged = PyDict::new(py);
+    for key in INHERITED_CONFIG_KEYS {
+        if let Some(value) = parent.get_item(key) {
+            merged.set_item(key, value)?;
+        }
+    }
+    if let Some(config) = config {
+        merged.update(config.as_mapping())?;
+    }
+    match (config, merged.is_empty()) {
+        // nothing inherited, so keep the schema's own config, or no config at all
+        (config, true) => Ok(config),
+        (_, false) => Ok(Some(merged)),
+    }
+}
+
+/// A hint listing the entries in `options` which are close to `value`, empty if there are none
+pub fn did_you_mean(value: &str, options: &[&str]) -> String {
+    let max_distance = (value.chars().count() / 3).max(1);
+    let prefix = format!("{value}-");
+    let mut candidates: Vec<(usize, &str)> = options
+        .iter()
+        .filter_map(|option| match levenshtein(value, option) {
+            d if d <= max_distance => Some((d, *option)),
+            // e.g. "tuple" for "tuple-positional" and "tuple-variable"
+            d if option.starts_with(&prefix) => Some((d, *option)),
+            _ => None,
+        })
+        .collect();
+    candidates.sort_by_key(|(d, _)| *d);
+    let names: Vec<String> = candidates.iter().take(3).map(|(_, o)| format!("'{o}'")).collect();
+    match names.as_slice() {
+        [] => String::new(),
+        [name] => format!("Did you mean {name}?"),
+        [init @ .., last] => format!("Did you mean {} or {last}?", init.join(", ")),
+    }
+}
+
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut previous = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous + usize::from(ca != *cb);
+            previous = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(previous + 1);
+        }
+    }
+    row[b.len()]
+}
+
+fn format_path(path: &[LocItem]) -> String {
+    path.iter().map(ToString::to_string).collect::<Vec<_>>().join(".")
+}
+
+/// Follow the `loc` of the first error through `schema`, skipping the schema types which tagged unions in the
+/// self-schema add to locations
+fn locate_first_error(py: Python, error: &ValidationError, schema: &PyAny) -> PyResult<(Vec<LocItem>, Option<String>)> {
+    let errors = error.errors(py, false, false, false)?;
+    let loc: &PyTuple = match errors.as_ref(py).iter().next() {
+        Some(first) => first.get_item(intern!(py, "loc"))?.downcast()?,
+        None => return Ok((Vec::new(), None)),
+    };
+    let schema_type = |value: &PyAny| -> Option<String> {
+        let dict: &PyDict = value.downcast().ok()?;
+        dict.get_item(intern!(py, "type"))?.extract().ok()
+    };
+
+    let mut current = schema;
+    let mut path = Vec::new();
+    let mut kind = schema_type(current);
+    for item in loc {
+        let next = if let Ok(dict) = current.downcast::<PyDict>() {
+            match dict.get_item(item) {
+                Some(value) => Some(value),
+                None if kind.as_deref() == item.extract::<&str>().ok() => continue,
+                None => None,
+            }
+        } else if current.downcast::<PyList>().is_ok() || current.downcast::<PyTuple>().is_ok() {
+            current.get_item(item).ok()
+        } else {
+            None
+        };
+        match next {
+            Some(value) => {
+                path.push(LocItem::try_from(item)?);
+                current = value;
+                if let Some(t) = schema_type(current) {
+                    kind = Some(t);
+                }
+            }
+            None => break,
+        }
+    }
+    Ok((path, kind))
+}
+
+/// The schemas currently being built, outermost first, each with its path within the schema which contains it,
+/// used to report which schema a build error came from
+#[derive(Debug, Clone, Default)]
+pub struct SchemaStack {
+    schemas: Vec<(Vec<LocItem>, Py<PyDict>)>,
+    // the schemas being built when the innermost error was raised
+    error: Option<Vec<(Vec<LocItem>, Py<PyDict>)>>,
+}
+
+impl SchemaStack {
+    pub fn push(&mut self, path: &[LocItem], schema: &PyDict) {
+        self.schemas.push((path.to_vec(), schema.into()));
+    }
+
+    pub fn pop(&mut self, ok: bool) {
+        match self.error {
+            // errors from deeper schemas were handled if this schema could be built
+            Some(ref error) if ok && error.len() >= self.schemas.len() => self.error = None,
+            None if !ok => self.error = Some(self.schemas.clone()),
+            _ => (),
+        }
+        self.schemas.pop();
+    }
+
+    /// Add the location of the innermost schema which failed to build to `err`
+    pub fn locate_error(&self, py: Python, err: PyErr) -> PyErr {
+        let Some(ref schemas) = self.error else {
+            return err;
+        };
+        let path = schemas.iter().flat_map(|(path, _)| path.iter().cloned()).collect();
+        let kind = schemas
+            .last()
+            .and_then(|(_, schema)| schema.as_ref(py).get_item(intern!(py, "type")))
+            .and_then(|t| t.extract().ok());
+        SchemaError::with_location(py, err, path, kind)
+    }
+}
@@ -4,12 +4,15 @@
 /// We use DefinitionsBuilder to collect the references / definitions into a single vector
 /// and then get a definition from a reference using an integer id (just for performance of not using a HashMap)
 use std::collections::hash_map::Entry;
+use std::sync::Arc;
 
 use pyo3::prelude::*;
 
-use ahash::AHashMap;
+use ahash::{AHashMap, AHashSet};
+use regex::Regex;
 
-use crate::build_tools::py_schema_err;
+use crate::build_tools::{py_schema_err, SchemaStack};
+use crate::validators::{NodeStats, StatsCollector};
 
 // An integer id for the reference
 pub type ReferenceId = usize;
@@ -24,7 +27,9 @@ pub type ReferenceId = usize;
 /// They get indexed by a ReferenceId, which are integer identifiers
 /// that are handed out and managed by DefinitionsBuilder when the Schema{Validator,Serializer}
 /// gets build.
-pub type Definitions<T> = [T];
+/// Definitions are held in an `Arc` so references to definitions which aren't recursive can share them directly,
+/// see `DefinitionsBuilder::complete_definitions`.
+pub type Definitions<T> = [Arc<T>];
 
 #[derive(Clone, Debug)]
 struct Definition<T> {
@@ -35,15 +40,54 @@ struct Definition<T> {
 #[derive(Clone, Debug)]
 pub struct DefinitionsBuilder<T> {
     definitions: AHashMap<String, Definition<T>>,
+    // ids of the definitions currently being built via `build_definition`, innermost last
+    building: Vec<ReferenceId>,
+    // (referrer, referenced) pairs, used to find which definitions can reference themselves
+    references: AHashSet<(ReferenceId, ReferenceId)>,
+    // completed definitions which can't reference themselves, see `complete_definitions`
+    completed: AHashMap<ReferenceId, Arc<T>>,
+    // compiled patterns, see `get_regex`
+    regexes: AHashMap<String, Arc<Regex>>,
+    // set when validators should record statistics, see `SchemaValidator(collect_stats=True)`
+    stats: Option<StatsCollector>,
+    // schemas currently being built, used to report where build errors come from
+    schemas: SchemaStack,
 }
 
 impl<T: Clone + std::fmt::Debug> DefinitionsBuilder<T> {
     pub fn new() -> Self {
         Self {
             definitions: AHashMap::new(),
+            building: Vec::new(),
+            references: AHashSet::new(),
+            completed: AHashMap::new(),
+            regexes: AHashMap::new(),
+            stats: None,
+            schemas: SchemaStack::default(),
         }
     }
 
+    /// Start wrapping validators built from here on in `StatsValidator`s
+    pub fn collect_stats(&mut self) {
+        self.stats = Some(StatsCollector::default());
+    }
+
+    pub fn stats_mut(&mut self) -> Option<&mut StatsCollector> {
+        self.stats.as_mut()
+    }
+
+    pub fn take_stats(&mut self) -> Option<Vec<Arc<NodeStats>>> {
+        self.stats.take().map(StatsCollector::finish)
+    }
+
+    pub fn schemas_mut(&mut self) -> &mut SchemaStack {
+        &mut self.schemas
+    }
+
+    pub fn schemas(&self) -> &SchemaStack {
+        &self.schemas
+    }
+
     /// Get a ReferenceId for the given reference string.
     // This ReferenceId can later be used to retrieve a definition
     pub fn get_reference_id(&mut self, reference: &str) -> ReferenceId {
@@ -51,7 +95,7 @@ impl<T: Clone + std::fmt::Debug> DefinitionsBuilder<T> {
         // We either need a String copy or two hashmap lookups
         // Neither is better than the other
         // We opted for the easier outward facing API
-        match self.definitions.entry(reference.to_string()) {
+        let id = match self.definitions.entry(reference.to_string()) {
             Entry::Occupied(entry) => entry.get().id,
             Entry::Vacant(entry) => {
                 entry.insert(Definition {
@@ -60,7 +104,35 @@ impl<T: Clone + std::fmt::Debug> DefinitionsBuilder<T> {
                 });
                 next_id
             }
+        };
+        if let Some(referrer) = self.building.last() {
+            self.references.insert((*referrer, id));
         }
+        id
+    }
+
+    /// Get the compiled regex for `pattern`, it's only compiled the first time it's seen so every use of the
+    /// same pattern in a schema shares one `Regex`
+    pub fn get_regex(&mut self, pattern: &str) -> Result<Arc<Regex>, regex::Error> {
+        if let Some(regex) = self.regexes.get(pattern) {
+            return Ok(regex.clone());
+        }
+        let regex = Arc::new(Regex::new(pattern)?);
+        self.regexes.insert(pattern.to_string(), regex.clone());
+        Ok(regex)
+    }
+
+    /// Build a definition with `build` and add it, references made while building it are recorded against it
+    pub fn build_definition(
+        &mut self,
+        reference: String,
+        build: impl FnOnce(&mut Self) -> PyResult<T>,
+    ) -> PyResult<ReferenceId> {
+        let id = self.get_reference_id(&reference);
+        self.building.push(id);
+        let value = build(self);
+        self.building.pop();
+        self.add_definition(reference, value?)
     }
 
     /// Add a definition, returning the ReferenceId that maps to it
@@ -97,8 +169,15 @@ impl<T: Clone + std::fmt::Debug> DefinitionsBuilder<T> {
         }
     }
 
+    /// Retrieve the completed version of a definition which can't reference itself, if it has been completed.
+    /// References to it can share this rather than looking the definition up (and guarding against recursion)
+    /// at runtime
+    pub fn get_completed(&self, reference_id: ReferenceId) -> Option<&Arc<T>> {
+        self.completed.get(&reference_id)
+    }
+
     /// Consume this Definitions into a vector of items, indexed by each items ReferenceId
-    pub fn finish(self) -> PyResult<Vec<T>> {
+    pub fn finish(self) -> PyResult<Vec<Arc<T>>> {
         // We need to create a vec of defs according to the order in their ids
         let mut defs: Vec<(usize, T)> = Vec::new();
         for (reference, def) in self.definitions {
@@ -108,6 +187,81 @@ impl<T: Clone + std::fmt::Debug> DefinitionsBuilder<T> {
             }
         }
         defs.sort_by_key(|(id, _)| *id);
-        Ok(defs.into_iter().map(|(_, v)| v).collect())
+        Ok(defs.into_iter().map(|(_, v)| Arc::new(v)).collect())
+    }
+
+    /// Like `finish`, but each definition is completed with `complete` first. Definitions which can't reference
+    /// themselves are completed before those that can, with the definitions they reference completed before them,
+    /// and are made available via `get_completed` as they're completed.
+    pub fn complete_definitions(&mut self, complete: impl Fn(&mut T, &Self) -> PyResult<()>) -> PyResult<Vec<Arc<T>>> {
+        let mut references: AHashMap<ReferenceId, Vec<ReferenceId>> = AHashMap::new();
+        for (referrer, id) in &self.references {
+            references.entry(*referrer).or_default().push(*id);
+        }
+        let recursive: AHashSet<ReferenceId> = references
+            .keys()
+            .copied()
+            .filter(|id| references_itself(*id, &references))
+            .collect();
+
+        let mut ids: Vec<ReferenceId> = self.definitions.values().map(|def| def.id).collect();
+        ids.sort_unstable();
+        let mut order: Vec<ReferenceId> = Vec::with_capacity(ids.len());
+        let mut visited: AHashSet<ReferenceId> = AHashSet::new();
+        for id in &ids {
+            if !recursive.contains(id) {
+                dependencies_first(*id, &references, &recursive, &mut visited, &mut order);
+            }
+        }
+        order.extend(ids.iter().filter(|id| recursive.contains(id)));
+
+        let mut slots: Vec<Option<Arc<T>>> = vec![None; ids.len()];
+        for id in order {
+            let mut value = self.get_definition(id)?.clone();
+            complete(&mut value, self)?;
+            let value = Arc::new(value);
+            if !recursive.contains(&id) {
+                self.completed.insert(id, value.clone());
+            }
+            slots[id] = Some(value);
+        }
+        // every slot is filled since ids are handed out sequentially and `get_definition` fails for unfilled ones
+        Ok(slots.into_iter().flatten().collect())
+    }
+}
+
+/// Whether `start` can be reached by following references from `start`
+fn references_itself(start: ReferenceId, references: &AHashMap<ReferenceId, Vec<ReferenceId>>) -> bool {
+    let mut seen: AHashSet<ReferenceId> = AHashSet::new();
+    let mut stack: Vec<ReferenceId> = references[&start].clone();
+    while let Some(id) = stack.pop() {
+        if id == start {
+            return true;
+        }
+        if seen.insert(id) {
+            if let Some(ids) = references.get(&id) {
+                stack.extend(ids);
+            }
+        }
+    }
+    false
+}
+
+/// Depth first post-order walk of the non-recursive definitions referenced by `id`, so each definition
+/// is added to `order` after the definitions it references
+fn dependencies_first(
+    id: ReferenceId,
+    references: &AHashMap<ReferenceId, Vec<ReferenceId>>,
+    recursive: &AHashSet<ReferenceId>,
+    visited: &mut AHashSet<ReferenceId>,
+    order: &mut Vec<ReferenceId>,
+) {
+    if visited.insert(id) {
+        for referenced in references.get(&id).into_iter().flatten() {
+            if !recursive.contains(referenced) {
+                dependencies_first(*referenced, references, recursive, visited, order);
+            }
+        }
+        order.push(id);
     }
 }
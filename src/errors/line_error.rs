@@ -2,7 +2,7 @@ use pyo3::exceptions::PyTypeError;
 use pyo3::prelude::*;
 use pyo3::PyDowncastError;
 
-use crate::input::{Input, JsonInput};
+use crate::input::{Input, InputType, JsonInput};
 
 use super::location::{LocItem, Location};
 use super::types::ErrorType;
@@ -81,29 +81,37 @@ pub struct ValLineError<'a> {
     // location is reversed so that adding an "outer" location item is pushing, it's reversed before showing to the user
     pub location: Location,
     pub input_value: InputValue<'a>,
+    // whether the input came from python or JSON, `None` if it's not known, e.g. for custom input values
+    pub input_type: Option<InputType>,
 }
 
 impl<'a> ValLineError<'a> {
     pub fn new(error_type: ErrorType, input: &'a impl Input<'a>) -> ValLineError<'a> {
+        let input_value = input.as_error_value();
         Self {
             error_type,
-            input_value: input.as_error_value(),
+            input_type: input_value.input_type(),
+            input_value,
             location: Location::default(),
         }
     }
 
     pub fn new_with_loc(error_type: ErrorType, input: &'a impl Input<'a>, loc: impl Into<LocItem>) -> ValLineError<'a> {
+        let input_value = input.as_error_value();
         Self {
             error_type,
-            input_value: input.as_error_value(),
+            input_type: input_value.input_type(),
+            input_value,
             location: Location::new_some(loc.into()),
         }
     }
 
     pub fn new_with_full_loc(error_type: ErrorType, input: &'a impl Input<'a>, location: Location) -> ValLineError<'a> {
+        let input_value = input.as_error_value();
         Self {
             error_type,
-            input_value: input.as_error_value(),
+            input_type: input_value.input_type(),
+            input_value,
             location,
         }
     }
@@ -111,6 +119,7 @@ impl<'a> ValLineError<'a> {
     pub fn new_custom_input(error_type: ErrorType, input_value: InputValue<'a>) -> ValLineError<'a> {
         Self {
             error_type,
+            input_type: input_value.input_type(),
             input_value,
             location: Location::default(),
         }
@@ -134,6 +143,7 @@ impl<'a> ValLineError<'a> {
         ValLineError {
             error_type: self.error_type.clone(),
             input_value: InputValue::<'py>::from(self.input_value.to_object(py)),
+            input_type: self.input_type,
             location: self.location.clone(),
         }
     }
@@ -147,6 +157,17 @@ pub enum InputValue<'a> {
     PyObject(PyObject),
 }
 
+impl<'a> InputValue<'a> {
+    fn input_type(&self) -> Option<InputType> {
+        match self {
+            Self::PyAny(_) => Some(InputType::Python),
+            // strings are only used for JSON object keys
+            Self::JsonInput(_) | Self::String(_) => Some(InputType::Json),
+            Self::PyObject(_) => None,
+        }
+    }
+}
+
 impl<'a> From<PyObject> for InputValue<'a> {
     fn from(py_object: PyObject) -> Self {
         Self::PyObject(py_object)
@@ -6,6 +6,7 @@ use pyo3::prelude::*;
 use pyo3::types::{PyList, PyString, PyTuple};
 use serde::ser::SerializeSeq;
 use serde::{Serialize, Serializer};
+use smallvec::SmallVec;
 
 use crate::lookup_key::{LookupPath, PathItem};
 use crate::tools::extract_i64;
@@ -115,16 +116,21 @@ impl Serialize for LocItem {
 /// Note: location in List is stored in **REVERSE** so adding an "outer" item to location involves
 /// pushing to the vec which is faster than inserting and shifting everything along.
 /// Then when "using" location in `Display` and `ToPyObject` order has to be reversed
+/// Most locations are only a few items deep, so the first `LOC_INLINE_ITEMS` are stored inline
+/// to avoid a heap allocation for every error.
 #[derive(Clone)]
 #[cfg_attr(debug_assertions, derive(Debug))]
 pub enum Location {
     // no location, avoid creating an unnecessary vec
     Empty,
-    // store the in a vec of LocItems, Note: this is the REVERSE of location, see above
-    // we could perhaps use a smallvec or similar here, probably only worth it if we store a Cow in LocItem
-    List(Vec<LocItem>),
+    // store the in a smallvec of LocItems, Note: this is the REVERSE of location, see above
+    List(LocItems),
 }
 
+const LOC_INLINE_ITEMS: usize = 3;
+
+type LocItems = SmallVec<[LocItem; LOC_INLINE_ITEMS]>;
+
 impl Default for Location {
     fn default() -> Self {
         Self::Empty
@@ -168,9 +174,9 @@ impl fmt::Display for Location {
 }
 
 impl Location {
-    /// create a new location vec with a value, 3 is plucked out of thin air, should it just be 1?
+    /// create a new location with a single value, stored inline
     pub fn new_some(item: LocItem) -> Self {
-        let mut loc = Vec::with_capacity(3);
+        let mut loc = LocItems::new();
         loc.push(item);
         Self::List(loc)
     }
@@ -210,7 +216,7 @@ impl TryFrom<Option<&PyAny>> for Location {
     /// Thus this expects the location to *not* be reversed and reverses it before storing it.
     fn try_from(location: Option<&PyAny>) -> PyResult<Self> {
         if let Some(location) = location {
-            let mut loc_vec: Vec<LocItem> = if let Ok(tuple) = location.downcast::<PyTuple>() {
+            let mut loc_vec: LocItems = if let Ok(tuple) = location.downcast::<PyTuple>() {
                 tuple.iter().map(LocItem::try_from).collect::<PyResult<_>>()?
             } else if let Ok(list) = location.downcast::<PyList>() {
                 list.iter().map(LocItem::try_from).collect::<PyResult<_>>()?
@@ -80,6 +80,9 @@ pub enum ErrorType {
     // ---------------------
     // recursion error
     RecursionLoop,
+    RecursionLimitExceeded {
+        max_depth: usize,
+    },
     // ---------------------
     // typed dict specific errors
     Missing,
@@ -144,6 +147,11 @@ pub enum ErrorType {
     IterationError {
         error: String,
     },
+    TooManyErrors {
+        max_errors: usize,
+        // only known when the input has a length
+        unchecked_items: Option<usize>,
+    },
     // ---------------------
     // string errors
     StringType,
@@ -151,9 +159,12 @@ pub enum ErrorType {
     StringUnicode,
     StringTooShort {
         min_length: usize,
+        // only set when the schema sets `length_unit`, so the context of other errors is unchanged
+        actual_length: Option<usize>,
     },
     StringTooLong {
         max_length: usize,
+        actual_length: Option<usize>,
     },
     StringPatternMismatch {
         pattern: String,
@@ -173,6 +184,15 @@ pub enum ErrorType {
     // ---------------------
     // set errors
     SetType,
+    UnhashableItem,
+    HashKeyError {
+        error: String,
+    },
+    DuplicateItems {
+        field_type: String,
+        min_length: usize,
+        actual_length: usize,
+    },
     // ---------------------
     // bool errors
     BoolType,
@@ -260,6 +280,17 @@ pub enum ErrorType {
     // frozenset errors
     FrozenSetType,
     // ---------------------
+    // counter errors
+    CounterType,
+    CountTooSmall {
+        min_count: usize,
+        actual_count: usize,
+    },
+    CountTooLarge {
+        max_count: usize,
+        actual_count: usize,
+    },
+    // ---------------------
     // introspection types - e.g. isinstance, callable
     IsInstanceOf {
         class: String,
@@ -342,6 +373,35 @@ fn do_nothing<T>(v: T) -> T {
 }
 
 macro_rules! extract_context {
+    // keys after `;` are optional, `None` if they're missing from the context
+    ($type:ident, $context:ident, $($key:ident: $type_:ty),* ; $($opt_key:ident: $opt_type:ty),+ $(,)?) => {{
+        let context = match $context {
+            Some(context) => context,
+            None => {
+                let context_parts = [$(format!("{}: {}", stringify!($key), stringify!($type_)),)*];
+                return py_err!(PyTypeError; "{} requires context: {{{}}}", stringify!($type), context_parts.join(", "));
+            }
+        };
+        Ok(Self::$type{
+            $(
+                $key: context
+                    .get_item(stringify!($key))
+                    .ok_or(py_error_type!(PyTypeError; "{}: '{}' required in context", stringify!($type), stringify!($key)))?
+                    .extract::<$type_>()
+                    .map_err(|_| py_error_type!(PyTypeError; "{}: '{}' context value must be a {}", stringify!($type), stringify!($key), stringify!($type_)))?,
+            )*
+            $(
+                $opt_key: match context.get_item(stringify!($opt_key)) {
+                    Some(value) => Some(
+                        value
+                        .extract::<$opt_type>()
+                        .map_err(|_| py_error_type!(PyTypeError; "{}: '{}' context value must be a {}", stringify!($type), stringify!($opt_key), stringify!($opt_type)))?
+                    ),
+                    None => None,
+                },
+            )+
+        })
+    }};
     ($type:ident, $context:ident, $($key:ident: $type_:ty),* $(,)?) => {
         extract_context!(do_nothing, $type, $context, $($key: $type_,)*)
     };
@@ -412,10 +472,32 @@ impl ErrorType {
                 max_length: usize,
                 actual_length: usize
             ),
+            Self::RecursionLimitExceeded { .. } => extract_context!(RecursionLimitExceeded, ctx, max_depth: usize),
             Self::IterationError { .. } => extract_context!(IterationError, ctx, error: String),
-            Self::StringTooShort { .. } => extract_context!(StringTooShort, ctx, min_length: usize),
-            Self::StringTooLong { .. } => extract_context!(StringTooLong, ctx, max_length: usize),
+            Self::HashKeyError { .. } => extract_context!(HashKeyError, ctx, error: String),
+            Self::TooManyErrors { .. } => {
+                extract_context!(TooManyErrors, ctx, max_errors: usize; unchecked_items: usize)
+            }
+            Self::DuplicateItems { .. } => extract_context!(
+                DuplicateItems,
+                ctx,
+                field_type: String,
+                min_length: usize,
+                actual_length: usize
+            ),
+            Self::StringTooShort { .. } => {
+                extract_context!(StringTooShort, ctx, min_length: usize; actual_length: usize)
+            }
+            Self::StringTooLong { .. } => {
+                extract_context!(StringTooLong, ctx, max_length: usize; actual_length: usize)
+            }
             Self::StringPatternMismatch { .. } => extract_context!(StringPatternMismatch, ctx, pattern: String),
+            Self::CountTooSmall { .. } => {
+                extract_context!(CountTooSmall, ctx, min_count: usize, actual_count: usize)
+            }
+            Self::CountTooLarge { .. } => {
+                extract_context!(CountTooLarge, ctx, max_count: usize, actual_count: usize)
+            }
             Self::MappingType { .. } => extract_context!(Cow::Owned, MappingType, ctx, error: String),
             Self::BytesTooShort { .. } => extract_context!(BytesTooShort, ctx, min_length: usize),
             Self::BytesTooLong { .. } => extract_context!(BytesTooLong, ctx, max_length: usize),
@@ -465,6 +547,7 @@ impl ErrorType {
             Self::JsonInvalid {..} => "Invalid JSON: {error}",
             Self::JsonType => "JSON input should be string, bytes or bytearray",
             Self::RecursionLoop => "Recursion error - cyclic reference detected",
+            Self::RecursionLimitExceeded {..} => "Recursion error - input is nested more than {max_depth} levels deep",
             Self::Missing => "Field required",
             Self::FrozenField => "Field is frozen",
             Self::FrozenInstance => "Instance is frozen",
@@ -486,6 +569,7 @@ impl ErrorType {
             Self::TooLong {..} => "{field_type} should have at most {max_length} item{expected_plural} after validation, not {actual_length}",
             Self::IterableType => "Input should be iterable",
             Self::IterationError {..} => "Error iterating over object, error: {error}",
+            Self::TooManyErrors {..} => "Validation stopped after {max_errors} error{expected_plural}, remaining items were not checked",
             Self::StringType => "Input should be a valid string",
             Self::StringSubType => "Input should be a string, not an instance of a subclass of str",
             Self::StringUnicode => "Input should be a valid string, unable to parse raw data as a unicode string",
@@ -497,6 +581,9 @@ impl ErrorType {
             Self::ListType => "Input should be a valid list",
             Self::TupleType => "Input should be a valid tuple",
             Self::SetType => "Input should be a valid set",
+            Self::UnhashableItem => "Set items should be hashable",
+            Self::HashKeyError {..} => "Error calling hash_key on set item, error: {error}",
+            Self::DuplicateItems {..} => "{field_type} should have at least {min_length} unique item{expected_plural} after validation, not {actual_length}",
             Self::BoolType => "Input should be a valid boolean",
             Self::BoolParsing => "Input should be a valid boolean, unable to interpret input",
             Self::IntType => "Input should be a valid integer",
@@ -531,6 +618,9 @@ impl ErrorType {
             Self::TimeDeltaType => "Input should be a valid timedelta",
             Self::TimeDeltaParsing {..} => "Input should be a valid timedelta, {error}",
             Self::FrozenSetType => "Input should be a valid frozenset",
+            Self::CounterType => "Input should be a valid counter, mapping of counts or iterable",
+            Self::CountTooSmall {..} => "Item should occur at least {min_count} time{expected_plural}, not {actual_count}",
+            Self::CountTooLarge {..} => "Item should occur at most {max_count} time{expected_plural}, not {actual_count}",
             Self::IsInstanceOf {..} => "Input should be an instance of {class}",
             Self::IsSubclassOf {..} => "Input should be a subclass of {class}",
             Self::CallableType => "Input should be callable",
@@ -562,6 +652,7 @@ impl ErrorType {
             }
             Self::TimeDeltaType => "Input should be a valid duration",
             Self::TimeDeltaParsing { .. } => "Input should be a valid duration, {error}",
+            Self::CounterType => "Input should be a valid object of counts or array",
             Self::ArgumentsType => "Arguments must be an array or an object",
             _ => self.message_template_python(),
         }
@@ -624,10 +715,50 @@ impl ErrorType {
                 let expected_plural = plural_s(*max_length);
                 to_string_render!(tmpl, field_type, max_length, actual_length, expected_plural)
             }
+            Self::RecursionLimitExceeded { max_depth } => to_string_render!(tmpl, max_depth),
             Self::IterationError { error } => render!(tmpl, error),
-            Self::StringTooShort { min_length } => to_string_render!(tmpl, min_length),
-            Self::StringTooLong { max_length } => to_string_render!(tmpl, max_length),
+            Self::HashKeyError { error } => render!(tmpl, error),
+            Self::TooManyErrors { max_errors, .. } => {
+                let expected_plural = plural_s(*max_errors);
+                to_string_render!(tmpl, max_errors, expected_plural)
+            }
+            Self::DuplicateItems {
+                field_type,
+                min_length,
+                actual_length,
+            } => {
+                let expected_plural = plural_s(*min_length);
+                to_string_render!(tmpl, field_type, min_length, actual_length, expected_plural)
+            }
+            Self::StringTooShort {
+                min_length,
+                actual_length,
+            } => match actual_length {
+                Some(actual_length) => to_string_render!(tmpl, min_length, actual_length),
+                None => to_string_render!(tmpl, min_length),
+            },
+            Self::StringTooLong {
+                max_length,
+                actual_length,
+            } => match actual_length {
+                Some(actual_length) => to_string_render!(tmpl, max_length, actual_length),
+                None => to_string_render!(tmpl, max_length),
+            },
             Self::StringPatternMismatch { pattern } => render!(tmpl, pattern),
+            Self::CountTooSmall {
+                min_count,
+                actual_count,
+            } => {
+                let expected_plural = plural_s(*min_count);
+                to_string_render!(tmpl, min_count, actual_count, expected_plural)
+            }
+            Self::CountTooLarge {
+                max_count,
+                actual_count,
+            } => {
+                let expected_plural = plural_s(*max_count);
+                to_string_render!(tmpl, max_count, actual_count, expected_plural)
+            }
             Self::MappingType { error } => render!(tmpl, error),
             Self::BytesTooShort { min_length } => to_string_render!(tmpl, min_length),
             Self::BytesTooLong { max_length } => to_string_render!(tmpl, max_length),
@@ -683,10 +814,44 @@ impl ErrorType {
                 max_length,
                 actual_length,
             } => py_dict!(py, field_type, max_length, actual_length),
+            Self::RecursionLimitExceeded { max_depth } => py_dict!(py, max_depth),
             Self::IterationError { error } => py_dict!(py, error),
-            Self::StringTooShort { min_length } => py_dict!(py, min_length),
-            Self::StringTooLong { max_length } => py_dict!(py, max_length),
+            Self::HashKeyError { error } => py_dict!(py, error),
+            Self::TooManyErrors {
+                max_errors,
+                unchecked_items,
+            } => match unchecked_items {
+                Some(unchecked_items) => py_dict!(py, max_errors, unchecked_items),
+                None => py_dict!(py, max_errors),
+            },
+            Self::DuplicateItems {
+                field_type,
+                min_length,
+                actual_length,
+            } => py_dict!(py, field_type, min_length, actual_length),
+            Self::StringTooShort {
+                min_length,
+                actual_length,
+            } => match actual_length {
+                Some(actual_length) => py_dict!(py, min_length, actual_length),
+                None => py_dict!(py, min_length),
+            },
+            Self::StringTooLong {
+                max_length,
+                actual_length,
+            } => match actual_length {
+                Some(actual_length) => py_dict!(py, max_length, actual_length),
+                None => py_dict!(py, max_length),
+            },
             Self::StringPatternMismatch { pattern } => py_dict!(py, pattern),
+            Self::CountTooSmall {
+                min_count,
+                actual_count,
+            } => py_dict!(py, min_count, actual_count),
+            Self::CountTooLarge {
+                max_count,
+                actual_count,
+            } => py_dict!(py, max_count, actual_count),
             Self::MappingType { error } => py_dict!(py, error),
             Self::BytesTooShort { min_length } => py_dict!(py, min_length),
             Self::BytesTooLong { max_length } => py_dict!(py, max_length),
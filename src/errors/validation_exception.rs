@@ -7,7 +7,7 @@ use pyo3::ffi::Py_ssize_t;
 use pyo3::once_cell::GILOnceCell;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList, PyString};
-use pyo3::{ffi, intern};
+use pyo3::{ffi, intern, PyTraverseError, PyVisit};
 use serde::ser::{Error, SerializeMap, SerializeSeq};
 use serde::{Serialize, Serializer};
 
@@ -16,6 +16,7 @@ use serde_json::ser::PrettyFormatter;
 use crate::build_tools::py_schema_error_type;
 use crate::errors::LocItem;
 use crate::get_version;
+use crate::input::InputType;
 use crate::serializers::{SerMode, SerializationState};
 use crate::tools::{safe_repr, SchemaDict};
 
@@ -161,8 +162,14 @@ impl ValidationError {
         self.line_errors.len()
     }
 
-    #[pyo3(signature = (*, include_url = true, include_context = true))]
-    pub fn errors(&self, py: Python, include_url: bool, include_context: bool) -> PyResult<Py<PyList>> {
+    #[pyo3(signature = (*, include_url = true, include_context = true, include_input_type = false))]
+    pub fn errors(
+        &self,
+        py: Python,
+        include_url: bool,
+        include_context: bool,
+        include_input_type: bool,
+    ) -> PyResult<Py<PyList>> {
         let url_prefix = get_url_prefix(py, include_url);
         // taken approximately from the pyo3, but modified to return the error during iteration
         // https://github.com/PyO3/pyo3/blob/a3edbf4fcd595f0e234c87d4705eb600a9779130/src/types/list.rs#L27-L55
@@ -175,7 +182,7 @@ impl ValidationError {
             let list: Py<PyList> = Py::from_owned_ptr(py, ptr);
 
             for (index, line_error) in (0_isize..).zip(&self.line_errors) {
-                let item = line_error.as_dict(py, url_prefix, include_context, &self.error_mode)?;
+                let item = line_error.as_dict(py, url_prefix, include_context, include_input_type, &self.error_mode)?;
                 ffi::PyList_SET_ITEM(ptr, index, item.into_ptr());
             }
 
@@ -183,13 +190,14 @@ impl ValidationError {
         }
     }
 
-    #[pyo3(signature = (*, indent = None, include_url = true, include_context = true))]
+    #[pyo3(signature = (*, indent = None, include_url = true, include_context = true, include_input_type = false))]
     pub fn json<'py>(
         &self,
         py: Python<'py>,
         indent: Option<usize>,
         include_url: bool,
         include_context: bool,
+        include_input_type: bool,
     ) -> PyResult<&'py PyString> {
         let state = SerializationState::new("iso8601", "utf8")?;
         let extra = state.extra(py, &SerMode::Json, true, false, false, true, None);
@@ -198,6 +206,7 @@ impl ValidationError {
             line_errors: &self.line_errors,
             url_prefix: get_url_prefix(py, include_url),
             include_context,
+            include_input_type,
             extra: &extra,
             error_mode: &self.error_mode,
         };
@@ -267,6 +276,7 @@ pub struct PyLineError {
     error_type: ErrorType,
     location: Location,
     input_value: PyObject,
+    input_type: Option<InputType>,
 }
 
 impl<'a> IntoPy<PyLineError> for ValLineError<'a> {
@@ -275,6 +285,7 @@ impl<'a> IntoPy<PyLineError> for ValLineError<'a> {
             error_type: self.error_type,
             location: self.location,
             input_value: self.input_value.to_object(py),
+            input_type: self.input_type,
         }
     }
 }
@@ -286,6 +297,7 @@ impl<'a> IntoPy<ValLineError<'a>> for PyLineError {
             error_type: self.error_type,
             location: self.location,
             input_value: self.input_value.into(),
+            input_type: self.input_type,
         }
     }
 }
@@ -323,20 +335,35 @@ impl TryFrom<&PyAny> for PyLineError {
             error_type,
             location,
             input_value,
+            input_type: None,
         })
     }
 }
 
 impl PyLineError {
+    pub fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        visit.call(&self.input_value)
+    }
+
     fn get_error_url(&self, url_prefix: &str) -> String {
         format!("{url_prefix}{}", self.error_type.type_string())
     }
 
+    /// Where the input came from and the name of its python type, e.g. `{'source': 'json', 'type': 'int'}`,
+    /// `source` is `None` if it isn't known
+    fn input_type_dict<'py>(&self, py: Python<'py>) -> PyResult<&'py PyDict> {
+        let dict = PyDict::new(py);
+        dict.set_item("source", self.input_type.map(|input_type| input_type.into_py(py)))?;
+        dict.set_item("type", self.input_value.as_ref(py).get_type().name()?)?;
+        Ok(dict)
+    }
+
     pub fn as_dict(
         &self,
         py: Python,
         url_prefix: Option<&str>,
         include_context: bool,
+        include_input_type: bool,
         error_mode: &ErrorMode,
     ) -> PyResult<PyObject> {
         let dict = PyDict::new(py);
@@ -349,6 +376,9 @@ impl PyLineError {
                 dict.set_item("ctx", context)?;
             }
         }
+        if include_input_type {
+            dict.set_item("input_type", self.input_type_dict(py)?)?;
+        }
         if let Some(url_prefix) = url_prefix {
             match self.error_type {
                 ErrorType::CustomError { custom_error: _ } => {
@@ -424,6 +454,7 @@ struct ValidationErrorSerializer<'py> {
     line_errors: &'py [PyLineError],
     url_prefix: Option<&'py str>,
     include_context: bool,
+    include_input_type: bool,
     extra: &'py crate::serializers::Extra<'py>,
     error_mode: &'py ErrorMode,
 }
@@ -440,6 +471,7 @@ impl<'py> Serialize for ValidationErrorSerializer<'py> {
                 line_error,
                 url_prefix: self.url_prefix,
                 include_context: self.include_context,
+                include_input_type: self.include_input_type,
                 extra: self.extra,
                 error_mode: self.error_mode,
             };
@@ -454,6 +486,7 @@ struct PyLineErrorSerializer<'py> {
     line_error: &'py PyLineError,
     url_prefix: Option<&'py str>,
     include_context: bool,
+    include_input_type: bool,
     extra: &'py crate::serializers::Extra<'py>,
     error_mode: &'py ErrorMode,
 }
@@ -471,6 +504,9 @@ impl<'py> Serialize for PyLineErrorSerializer<'py> {
         if self.include_context {
             size += 1;
         }
+        if self.include_input_type {
+            size += 1;
+        }
         let mut map = serializer.serialize_map(Some(size))?;
 
         map.serialize_entry("type", &self.line_error.error_type.type_string())?;
@@ -494,6 +530,10 @@ impl<'py> Serialize for PyLineErrorSerializer<'py> {
                 map.serialize_entry("ctx", &self.extra.serialize_infer(context.as_ref(py)))?;
             }
         }
+        if self.include_input_type {
+            let input_type = self.line_error.input_type_dict(py).map_err(py_err_json::<S>)?;
+            map.serialize_entry("input_type", &self.extra.serialize_infer(input_type))?;
+        }
         if let Some(url_prefix) = self.url_prefix {
             map.serialize_entry("url", &self.line_error.get_error_url(url_prefix))?;
         }
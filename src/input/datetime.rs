@@ -1,8 +1,12 @@
+use ahash::AHashMap;
+use pyo3::exceptions::PyValueError;
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::{PyDate, PyDateTime, PyDelta, PyDeltaAccess, PyDict, PyTime, PyTzInfo};
+use pyo3::pyclass::CompareOp;
+use pyo3::types::{PyDate, PyDateTime, PyDelta, PyDeltaAccess, PyDict, PyTime, PyType, PyTzInfo};
 use speedate::{Date, DateTime, Duration, ParseError, Time};
 use std::borrow::Cow;
+use std::sync::{Mutex, MutexGuard, PoisonError};
 use strum::EnumMessage;
 
 use crate::errors::{ErrorType, ValError, ValResult};
@@ -191,8 +195,7 @@ impl<'a> EitherTime<'a> {
 fn time_as_tzinfo<'py>(py: Python<'py>, time: &Time) -> PyResult<Option<&'py PyTzInfo>> {
     match time.tz_offset {
         Some(offset) => {
-            let tz_info = TzInfo::new(offset);
-            let py_tz_info = Py::new(py, tz_info)?.to_object(py).into_ref(py);
+            let py_tz_info = TzInfo::cached(py, offset)?.into_ref(py);
             Ok(Some(py_tz_info.extract()?))
         }
         None => Ok(None),
@@ -415,22 +418,60 @@ pub fn float_as_duration<'a>(input: &'a impl Input<'a>, total_seconds: f64) -> V
         .map_err(|err| map_timedelta_err(input, err))
 }
 
+// `TzInfo`s are immutable, so one instance is shared for each offset, a mutex rather than relying on the GIL so
+// this is also safe on free-threaded python, uncontended locking is cheap compared to creating a datetime
+type TzInfoCache = Mutex<Option<AHashMap<i32, Py<TzInfo>>>>;
+static TZ_INFO_CACHE: TzInfoCache = Mutex::new(None);
+
+fn tz_info_cache() -> MutexGuard<'static, Option<AHashMap<i32, Py<TzInfo>>>> {
+    TZ_INFO_CACHE.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// A fixed offset timezone, used for aware datetimes and times created by validators
 #[pyclass(module = "pydantic_core._pydantic_core", extends = PyTzInfo)]
 #[derive(Clone)]
 #[cfg_attr(debug_assertions, derive(Debug))]
-struct TzInfo {
+pub struct TzInfo {
     seconds: i32,
 }
 
+impl TzInfo {
+    /// The shared `TzInfo` for an offset of `seconds`
+    pub fn cached(py: Python, seconds: i32) -> PyResult<Py<Self>> {
+        if let Some(tz_info) = tz_info_cache().as_ref().and_then(|c| c.get(&seconds)) {
+            return Ok(tz_info.clone_ref(py));
+        }
+        // the cache mustn't be locked while creating the instance: allocating can run the garbage collector and
+        // so arbitrary python code, which could switch threads or validate another datetime
+        let tz_info = Py::new(py, Self::py_new(seconds)?)?;
+        let tz_info = tz_info_cache()
+            .get_or_insert_with(AHashMap::new)
+            .entry(seconds)
+            .or_insert(tz_info)
+            .clone_ref(py);
+        Ok(tz_info)
+    }
+
+    fn offset<'p>(&self, py: Python<'p>) -> PyResult<&'p PyDelta> {
+        PyDelta::new(py, 0, self.seconds, 0, true)
+    }
+}
+
 #[pymethods]
 impl TzInfo {
     #[new]
-    fn new(seconds: i32) -> Self {
-        Self { seconds }
+    fn py_new(seconds: i32) -> PyResult<Self> {
+        if seconds.unsigned_abs() < 86_400 {
+            Ok(Self { seconds })
+        } else {
+            Err(PyValueError::new_err(format!(
+                "TzInfo offset must be strictly between -86400 and 86400 seconds, not {seconds}"
+            )))
+        }
     }
 
     fn utcoffset<'p>(&self, py: Python<'p>, _dt: &PyAny) -> PyResult<&'p PyDelta> {
-        PyDelta::new(py, 0, self.seconds, 0, true)
+        self.offset(py)
     }
 
     fn tzname(&self, _dt: &PyAny) -> String {
@@ -441,6 +482,10 @@ impl TzInfo {
         None
     }
 
+    fn fromutc<'p>(&self, dt: &'p PyDateTime) -> PyResult<&'p PyAny> {
+        dt.call_method1(intern!(dt.py(), "__add__"), (self.offset(dt.py())?,))
+    }
+
     fn __repr__(&self) -> String {
         format!("TzInfo({})", self.__str__())
     }
@@ -449,11 +494,42 @@ impl TzInfo {
         if self.seconds == 0 {
             "UTC".to_string()
         } else {
-            let mins = self.seconds / 60;
-            format!("{:+03}:{:02}", mins / 60, (mins % 60).abs())
+            // the sign applies to the whole offset, e.g. -00:30 has zero hours
+            let sign = if self.seconds < 0 { '-' } else { '+' };
+            let seconds = self.seconds.unsigned_abs();
+            let (hours, mins, secs) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+            // seconds are only included if there are any, as with `datetime.timezone`
+            match secs {
+                0 => format!("{sign}{hours:02}:{mins:02}"),
+                _ => format!("{sign}{hours:02}:{mins:02}:{secs:02}"),
+            }
+        }
+    }
+
+    // equal to any tzinfo with the same fixed offset, e.g. `datetime.timezone`
+    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
+        let py = other.py();
+        let Ok(other) = other.downcast::<PyTzInfo>() else {
+            return Ok(py.NotImplemented());
+        };
+        let other_offset = other.call_method1(intern!(py, "utcoffset"), (py.None(),))?;
+        let equal = self.offset(py)?.eq(other_offset)?;
+        match op {
+            CompareOp::Eq => Ok(equal.into_py(py)),
+            CompareOp::Ne => Ok((!equal).into_py(py)),
+            _ => Ok(py.NotImplemented()),
         }
     }
 
+    // the same as `datetime.timezone`, so they can be used interchangeably as keys
+    fn __hash__(&self, py: Python) -> PyResult<isize> {
+        self.offset(py)?.hash()
+    }
+
+    fn __reduce__(slf: &PyCell<Self>) -> PyResult<(&PyType, (i32,))> {
+        Ok((slf.get_type(), (slf.try_borrow()?.seconds,)))
+    }
+
     fn __deepcopy__(&self, py: Python, _memo: &PyDict) -> PyResult<Py<Self>> {
         Py::new(py, self.clone())
     }
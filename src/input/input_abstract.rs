@@ -48,6 +48,11 @@ pub trait Input<'a>: fmt::Debug + ToPyObject {
         false
     }
 
+    /// The input as an object whose attributes can be read as fields, used with `from_attributes`
+    fn input_from_attributes(&self) -> Option<&PyAny> {
+        None
+    }
+
     fn as_kwargs(&'a self, py: Python<'a>) -> Option<&'a PyDict>;
 
     fn input_is_subclass(&self, _class: &PyType) -> PyResult<bool> {
@@ -70,7 +75,7 @@ pub trait Input<'a>: fmt::Debug + ToPyObject {
 
     fn validate_dataclass_args(&'a self, dataclass_name: &str) -> ValResult<'a, GenericArguments<'a>>;
 
-    fn parse_json(&'a self) -> ValResult<'a, JsonInput>;
+    fn parse_json(&'a self, lenient_surrogates: bool) -> ValResult<'a, JsonInput>;
 
     fn validate_str(&'a self, strict: bool) -> ValResult<EitherString<'a>> {
         if strict {
@@ -124,6 +129,12 @@ pub trait Input<'a>: fmt::Debug + ToPyObject {
         self.strict_int()
     }
 
+    /// Extract an int by calling the input's `__int__` method, used as a last resort in lax mode
+    /// when `allow_number_protocols` is enabled
+    fn int_from_protocol(&'a self) -> Option<EitherInt<'a>> {
+        None
+    }
+
     /// Extract an EitherInt from the input, only allowing exact
     /// matches for an Int (no subclasses)
     fn exact_int(&'a self) -> ValResult<EitherInt<'a>> {
@@ -146,6 +157,11 @@ pub trait Input<'a>: fmt::Debug + ToPyObject {
         }
     }
     fn ultra_strict_float(&'a self) -> ValResult<EitherFloat<'a>>;
+    /// Extract a float from an input which only defines `__int__`, used as a last resort in lax mode
+    /// when `allow_number_protocols` is enabled, `__float__` is already used by `lax_float`
+    fn float_from_protocol(&'a self) -> Option<EitherFloat<'a>> {
+        None
+    }
     fn strict_float(&'a self) -> ValResult<EitherFloat<'a>>;
     #[cfg_attr(has_no_coverage, no_coverage)]
     fn lax_float(&'a self) -> ValResult<EitherFloat<'a>> {
@@ -264,6 +280,12 @@ pub trait Input<'a>: fmt::Debug + ToPyObject {
         self.strict_datetime()
     }
 
+    /// Numeric input (but not bools) and numeric strings like `"1672531200"` to be interpreted by date and datetime
+    /// validators with an explicit `numeric_mode`, `None` if the input isn't a number and should be validated as normal
+    fn as_temporal_number(&self) -> Option<f64> {
+        None
+    }
+
     fn validate_timedelta(&self, strict: bool) -> ValResult<EitherTimedelta> {
         if strict {
             self.strict_timedelta()
@@ -7,8 +7,8 @@ use super::datetime::{
     bytes_as_date, bytes_as_datetime, bytes_as_time, bytes_as_timedelta, float_as_datetime, float_as_duration,
     float_as_time, int_as_datetime, int_as_duration, int_as_time, EitherDate, EitherDateTime, EitherTime,
 };
-use super::parse_json::JsonArray;
-use super::shared::{float_as_int, int_as_bool, map_json_err, str_as_bool, str_as_int};
+use super::parse_json::{parse_json_str, JsonArray};
+use super::shared::{float_as_int, int_as_bool, map_json_err, str_as_bool, str_as_int, str_as_temporal_number};
 use super::{
     EitherBytes, EitherFloat, EitherInt, EitherString, EitherTimedelta, GenericArguments, GenericIterable,
     GenericIterator, GenericMapping, Input, JsonArgs, JsonInput,
@@ -64,9 +64,9 @@ impl<'a> Input<'a> for JsonInput {
         }
     }
 
-    fn parse_json(&'a self) -> ValResult<'a, JsonInput> {
+    fn parse_json(&'a self, lenient_surrogates: bool) -> ValResult<'a, JsonInput> {
         match self {
-            JsonInput::String(s) => serde_json::from_str(s.as_str()).map_err(|e| map_json_err(self, e)),
+            JsonInput::String(s) => parse_json_str(s, lenient_surrogates).map_err(|e| map_json_err(self, e)),
             _ => Err(ValError::new(ErrorType::JsonType, self)),
         }
     }
@@ -289,6 +289,16 @@ impl<'a> Input<'a> for JsonInput {
         }
     }
 
+    fn as_temporal_number(&self) -> Option<f64> {
+        match self {
+            JsonInput::Int(v) => Some(*v as f64),
+            JsonInput::Uint(v) => Some(*v as f64),
+            JsonInput::Float(v) => Some(*v),
+            JsonInput::String(v) => str_as_temporal_number(v),
+            _ => None,
+        }
+    }
+
     fn strict_timedelta(&self) -> ValResult<EitherTimedelta> {
         match self {
             JsonInput::String(v) => bytes_as_timedelta(self, v.as_bytes()),
@@ -335,8 +345,8 @@ impl<'a> Input<'a> for String {
         Err(ValError::new(ErrorType::DataclassType { class_name }, self))
     }
 
-    fn parse_json(&'a self) -> ValResult<'a, JsonInput> {
-        serde_json::from_str(self.as_str()).map_err(|e| map_json_err(self, e))
+    fn parse_json(&'a self, lenient_surrogates: bool) -> ValResult<'a, JsonInput> {
+        parse_json_str(self, lenient_surrogates).map_err(|e| map_json_err(self, e))
     }
 
     fn validate_str(&'a self, _strict: bool) -> ValResult<EitherString<'a>> {
@@ -11,7 +11,7 @@ use pyo3::types::{PyDictItems, PyDictKeys, PyDictValues};
 use pyo3::{intern, AsPyPointer, PyTypeInfo};
 
 use crate::errors::{ErrorType, InputValue, LocItem, ValError, ValResult};
-use crate::tools::{extract_i64, safe_repr};
+use crate::tools::{dataclass_to_dict, extract_i64, safe_repr};
 use crate::{ArgsKwargs, PyMultiHostUrl, PyUrl};
 
 use super::datetime::{
@@ -19,9 +19,10 @@ use super::datetime::{
     float_as_duration, float_as_time, int_as_datetime, int_as_duration, int_as_time, EitherDate, EitherDateTime,
     EitherTime,
 };
-use super::shared::{float_as_int, int_as_bool, map_json_err, str_as_bool, str_as_int};
+use super::parse_json::{json_bytes_as_utf8, parse_json_str};
+use super::shared::{float_as_int, int_as_bool, map_json_err, str_as_bool, str_as_int, str_as_temporal_number};
 use super::{
-    py_string_str, EitherBytes, EitherFloat, EitherInt, EitherString, EitherTimedelta, GenericArguments,
+    py_string_str, BytesBuffer, EitherBytes, EitherFloat, EitherInt, EitherString, EitherTimedelta, GenericArguments,
     GenericIterable, GenericIterator, GenericMapping, Input, JsonInput, PyArgs,
 };
 
@@ -109,7 +110,8 @@ impl<'a> Input<'a> for PyAny {
     }
 
     fn input_is_instance(&self, class: &PyType) -> Option<&PyAny> {
-        if self.is_instance(class).unwrap_or(false) {
+        // check for the exact type first to skip the isinstance protocol in the common case
+        if self.get_type().is(class) || self.is_instance(class).unwrap_or(false) {
             Some(self)
         } else {
             None
@@ -120,6 +122,14 @@ impl<'a> Input<'a> for PyAny {
         true
     }
 
+    fn input_from_attributes(&self) -> Option<&PyAny> {
+        if from_attributes_applicable(self) && !self.is_instance_of::<ArgsKwargs>() {
+            Some(self)
+        } else {
+            None
+        }
+    }
+
     fn as_kwargs(&'a self, _py: Python<'a>) -> Option<&'a PyDict> {
         self.downcast::<PyDict>().ok()
     }
@@ -172,14 +182,21 @@ impl<'a> Input<'a> for PyAny {
         }
     }
 
-    fn parse_json(&'a self) -> ValResult<'a, JsonInput> {
+    fn parse_json(&'a self, lenient_surrogates: bool) -> ValResult<'a, JsonInput> {
+        let py = self.py();
+        let parse = |data: &str| parse_json_str(data, lenient_surrogates);
         if let Ok(py_bytes) = self.downcast::<PyBytes>() {
-            serde_json::from_slice(py_bytes.as_bytes()).map_err(|e| map_json_err(self, e))
+            let bytes = py_bytes.as_bytes();
+            parse_json_allow_threads(py, bytes.len(), || json_bytes_as_utf8(bytes).and_then(parse))
+                .map_err(|e| map_json_err(self, e))
         } else if let Ok(py_str) = self.downcast::<PyString>() {
             let str = py_str.to_str()?;
-            serde_json::from_str(str).map_err(|e| map_json_err(self, e))
+            parse_json_allow_threads(py, str.len(), || parse(str)).map_err(|e| map_json_err(self, e))
         } else if let Ok(py_byte_array) = self.downcast::<PyByteArray>() {
-            serde_json::from_slice(unsafe { py_byte_array.as_bytes() }).map_err(|e| map_json_err(self, e))
+            // bytearrays are mutable, so we can't release the GIL while reading them
+            json_bytes_as_utf8(unsafe { py_byte_array.as_bytes() })
+                .and_then(parse)
+                .map_err(|e| map_json_err(self, e))
         } else {
             Err(ValError::new(ErrorType::JsonType, self))
         }
@@ -251,6 +268,8 @@ impl<'a> Input<'a> for PyAny {
             Ok(str.as_bytes().into())
         } else if let Ok(py_byte_array) = self.downcast::<PyByteArray>() {
             Ok(py_byte_array.to_vec().into())
+        } else if let Some(buffer) = BytesBuffer::get(self) {
+            Ok(buffer.into())
         } else {
             Err(ValError::new(ErrorType::BytesType, self))
         }
@@ -308,6 +327,14 @@ impl<'a> Input<'a> for PyAny {
         }
     }
 
+    fn int_from_protocol(&'a self) -> Option<EitherInt<'a>> {
+        int_from_dunder(self).map(EitherInt::Py)
+    }
+
+    fn float_from_protocol(&'a self) -> Option<EitherFloat<'a>> {
+        int_from_dunder(self)?.extract::<f64>().ok().map(EitherFloat::F64)
+    }
+
     fn ultra_strict_float(&'a self) -> ValResult<EitherFloat<'a>> {
         if self.is_instance_of::<PyInt>() {
             Err(ValError::new(ErrorType::FloatType, self))
@@ -391,7 +418,13 @@ impl<'a> Input<'a> for PyAny {
         } else {
             // otherwise we just call back to validate_dict if from_mapping is allowed, note that errors in this
             // case (correctly) won't hint about from_attributes
-            self.validate_dict(strict)
+            match self.validate_dict(strict) {
+                // in lax mode, dataclass instances are read field by field as if they were a dict
+                Err(ValError::LineErrors(_)) if !strict && is_dataclass_instance(self) => {
+                    Ok(dataclass_to_dict(self)?.into())
+                }
+                result => result,
+            }
         }
     }
 
@@ -602,6 +635,20 @@ impl<'a> Input<'a> for PyAny {
         }
     }
 
+    fn as_temporal_number(&self) -> Option<f64> {
+        if PyBool::is_exact_type_of(self) {
+            None
+        } else if let Ok(py_str) = self.downcast::<PyString>() {
+            str_as_temporal_number(py_str.to_str().ok()?)
+        } else if let Ok(py_bytes) = self.downcast::<PyBytes>() {
+            str_as_temporal_number(from_utf8(py_bytes.as_bytes()).ok()?)
+        } else if let Ok(int) = extract_i64(self) {
+            Some(int as f64)
+        } else {
+            self.extract::<f64>().ok()
+        }
+    }
+
     fn strict_timedelta(&self) -> ValResult<EitherTimedelta> {
         if let Ok(dt) = self.downcast::<PyDelta>() {
             Ok(dt.into())
@@ -628,6 +675,22 @@ impl<'a> Input<'a> for PyAny {
     }
 }
 
+/// Below this size, releasing and reacquiring the GIL costs more than parsing the JSON
+const JSON_ALLOW_THREADS_MIN_LENGTH: usize = 100_000;
+
+/// Parse JSON from an immutable buffer of `length` bytes, `JsonInput` contains no Python objects so for large
+/// inputs we can release the GIL while parsing, python objects are only created later during validation.
+fn parse_json_allow_threads<F>(py: Python, length: usize, parse: F) -> Result<JsonInput, String>
+where
+    F: Send + FnOnce() -> Result<JsonInput, String>,
+{
+    if length < JSON_ALLOW_THREADS_MIN_LENGTH {
+        parse()
+    } else {
+        py.allow_threads(parse)
+    }
+}
+
 /// Best effort check of whether it's likely to make sense to inspect obj for attributes and iterate over it
 /// with `obj.dir()`
 fn from_attributes_applicable(obj: &PyAny) -> bool {
@@ -645,6 +708,22 @@ fn from_attributes_applicable(obj: &PyAny) -> bool {
     !matches!(module_name, "builtins" | "datetime" | "collections")
 }
 
+fn is_dataclass_instance(obj: &PyAny) -> bool {
+    !PyType::is_type_of(obj) && obj.hasattr(intern!(obj.py(), "__dataclass_fields__")).unwrap_or(false)
+}
+
+/// Call `__int__` on `v`, bools are never converted this way and a result which isn't an int is ignored,
+/// as in strict mode a bool result isn't accepted as an int either
+fn int_from_dunder(v: &PyAny) -> Option<&PyAny> {
+    if PyBool::is_exact_type_of(v) {
+        return None;
+    }
+    match v.call_method0(intern!(v.py(), "__int__")) {
+        Ok(int) if PyInt::is_type_of(int) && !PyBool::is_type_of(int) => Some(int),
+        _ => None,
+    }
+}
+
 /// Utility for extracting a string from a PyAny, if possible.
 fn maybe_as_string(v: &PyAny, unicode_error: ErrorType) -> ValResult<Option<Cow<str>>> {
     if let Ok(py_string) = v.downcast::<PyString>() {
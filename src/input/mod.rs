@@ -10,6 +10,7 @@ mod parse_json;
 mod return_enums;
 mod shared;
 
+pub use datetime::TzInfo;
 pub(crate) use datetime::{
     pydate_as_date, pydatetime_as_datetime, pytime_as_time, pytimedelta_as_duration, EitherDate, EitherDateTime,
     EitherTime, EitherTimedelta,
@@ -17,9 +18,9 @@ pub(crate) use datetime::{
 pub(crate) use input_abstract::{Input, InputType};
 pub(crate) use parse_json::{JsonInput, JsonObject};
 pub(crate) use return_enums::{
-    py_string_str, AttributesGenericIterator, DictGenericIterator, EitherBytes, EitherFloat, EitherInt, EitherString,
-    GenericArguments, GenericIterable, GenericIterator, GenericMapping, Int, JsonArgs, JsonObjectGenericIterator,
-    MappingGenericIterator, PyArgs,
+    py_string_str, AttributesGenericIterator, BytesBuffer, DictGenericIterator, EitherBytes, EitherFloat, EitherInt,
+    EitherString, GenericArguments, GenericIterable, GenericIterator, GenericMapping, Int, JsonArgs,
+    JsonObjectGenericIterator, MappingGenericIterator, PyArgs,
 };
 
 // Defined here as it's not exported by pyo3
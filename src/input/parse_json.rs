@@ -177,3 +177,142 @@ impl<'de> Visitor<'de> for KeyDeserializer {
         unreachable!()
     }
 }
+
+/// Parse JSON from a string (bytes must be checked with `json_bytes_as_utf8` first), the error is the message to show
+/// in a `json_invalid` error.
+///
+/// Escaped surrogates which aren't part of a valid pair (e.g. `"\ud83d"` from JavaScript) can't be represented in a
+/// rust or python string, by default they're an error naming the surrogate and where it was found, with
+/// `lenient_surrogates` they're replaced with U+FFFD.
+pub fn parse_json_str(data: &str, lenient_surrogates: bool) -> Result<JsonInput, String> {
+    let err = match serde_json::from_str(data) {
+        Ok(value) => return Ok(value),
+        Err(err) => err,
+    };
+    let mut lone_surrogates = LoneSurrogates {
+        data: data.as_bytes(),
+        index: 0,
+    }
+    .peekable();
+    match lone_surrogates.peek() {
+        Some(_) if lenient_surrogates => {
+            // the replacement escape has the same length, so positions in any other error are unchanged
+            let mut data = data.to_string();
+            for (offset, _) in lone_surrogates {
+                data.replace_range(offset..offset + 6, r"\ufffd");
+            }
+            serde_json::from_str(&data).map_err(|e| e.to_string())
+        }
+        Some(&(offset, code)) => {
+            let (line, column) = line_column(data.as_bytes(), offset);
+            // only report the surrogate if it's what serde_json failed on, not if there's an earlier error
+            if (line, column) <= (err.line(), err.column()) {
+                Err(format!(
+                    "lone surrogate \\u{code:04x} at line {line} column {column} (offset {offset})"
+                ))
+            } else {
+                Err(err.to_string())
+            }
+        }
+        None => Err(err.to_string()),
+    }
+}
+
+/// Prepare JSON provided as bytes (rather than a string) for parsing: skip a UTF-8 byte order mark if present, give
+/// a clear error for UTF-16 and UTF-32 byte order marks since only UTF-8 is supported, and check the input is valid
+/// UTF-8 so invalid sequences are reported with their byte offset, the checked string is then parsed directly.
+pub fn json_bytes_as_utf8(data: &[u8]) -> Result<&str, String> {
+    const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+    // UTF-32 must be checked first since the UTF-32LE BOM starts with the UTF-16LE BOM
+    const OTHER_BOMS: [(&[u8], &str); 4] = [
+        (b"\x00\x00\xFE\xFF", "UTF-32BE"),
+        (b"\xFF\xFE\x00\x00", "UTF-32LE"),
+        (b"\xFE\xFF", "UTF-16BE"),
+        (b"\xFF\xFE", "UTF-16LE"),
+    ];
+    let (data, offset) = match data.strip_prefix(UTF8_BOM) {
+        Some(data) => (data, UTF8_BOM.len()),
+        None => {
+            if let Some((_, encoding)) = OTHER_BOMS.iter().find(|(bom, _)| data.starts_with(bom)) {
+                return Err(format!(
+                    "input starts with a {encoding} byte order mark, JSON bytes must be UTF-8 encoded"
+                ));
+            }
+            (data, 0)
+        }
+    };
+    match std::str::from_utf8(data) {
+        Ok(str) => Ok(str),
+        Err(err) => {
+            let position = offset + err.valid_up_to();
+            match err.error_len() {
+                Some(_) => Err(format!("invalid UTF-8 sequence at byte offset {position}")),
+                None => Err(format!(
+                    "incomplete UTF-8 sequence at end of input, byte offset {position}"
+                )),
+            }
+        }
+    }
+}
+
+/// Iterator over the offset and value of `\uXXXX` escapes in JSON which are surrogates not part of a valid pair.
+///
+/// We don't keep track of whether we're inside a string since escapes are invalid anywhere else.
+struct LoneSurrogates<'a> {
+    data: &'a [u8],
+    index: usize,
+}
+
+impl<'a> LoneSurrogates<'a> {
+    fn hex_escape(&self, offset: usize) -> Option<u16> {
+        let escape = self.data.get(offset..offset + 6)?;
+        if escape.starts_with(br"\u") {
+            u16::from_str_radix(std::str::from_utf8(&escape[2..]).ok()?, 16).ok()
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a> Iterator for LoneSurrogates<'a> {
+    type Item = (usize, u16);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.index < self.data.len() {
+            let offset = self.index;
+            if self.data[offset] != b'\\' {
+                self.index += 1;
+                continue;
+            }
+            let code = match self.hex_escape(offset) {
+                Some(code) => code,
+                None => {
+                    // skip the escaped character, e.g. so `\\ud83d` isn't mistaken for an escape
+                    self.index += 2;
+                    continue;
+                }
+            };
+            self.index += 6;
+            match code {
+                0xD800..=0xDBFF => match self.hex_escape(self.index) {
+                    Some(0xDC00..=0xDFFF) => self.index += 6,
+                    _ => return Some((offset, code)),
+                },
+                0xDC00..=0xDFFF => return Some((offset, code)),
+                _ => (),
+            }
+        }
+        None
+    }
+}
+
+/// Convert a byte offset to a 1-based line and column in the same way as serde_json
+fn line_column(data: &[u8], offset: usize) -> (usize, usize) {
+    let before = &data[..offset];
+    let line = before.iter().filter(|&&b| b == b'\n').count() + 1;
+    let column = match before.iter().rposition(|&b| b == b'\n') {
+        Some(newline) => offset - newline,
+        None => offset + 1,
+    };
+    (line, column)
+}
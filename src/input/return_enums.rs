@@ -18,10 +18,11 @@ use pyo3::types::PyFunction;
 #[cfg(not(PyPy))]
 use pyo3::PyTypeInfo;
 
+use crate::definitions::Definitions;
 use crate::errors::{py_err_string, ErrorType, InputValue, ValError, ValLineError, ValResult};
 use crate::recursion_guard::RecursionGuard;
 use crate::tools::py_err;
-use crate::validators::{CombinedValidator, Extra, Validator};
+use crate::validators::{convert_err, CombinedValidator, Extra, Validator};
 
 use super::parse_json::{JsonArray, JsonInput, JsonObject};
 use super::{py_error_on_minusone, Input};
@@ -145,22 +146,58 @@ macro_rules! any_next_error {
     };
 }
 
+/// Stops iteration once `max_errors` item errors have been collected, but only if there's another item to validate
+struct MaxErrorsCheck {
+    max_errors: Option<usize>,
+    // the number of items in the input if it has a length, to report how many weren't checked
+    input_len: Option<usize>,
+}
+
+impl MaxErrorsCheck {
+    /// Called before validating the item at `index`, if the limit has been reached `errors` is truncated to it
+    /// and a `too_many_errors` error for `input` is appended, returns `true` if iteration should stop
+    fn reached<'a>(&self, errors: &mut Vec<ValLineError<'a>>, index: usize, input: &'a impl Input<'a>) -> bool {
+        match self.max_errors {
+            Some(max_errors) if errors.len() >= max_errors => {
+                errors.truncate(max_errors);
+                let unchecked_items = self.input_len.map(|len| len.saturating_sub(index));
+                errors.push(ValLineError::new(
+                    ErrorType::TooManyErrors {
+                        max_errors,
+                        unchecked_items,
+                    },
+                    input,
+                ));
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 fn validate_iter_to_vec<'a, 's>(
     py: Python<'a>,
     iter: impl Iterator<Item = PyResult<&'a (impl Input<'a> + 'a)>>,
     capacity: usize,
     mut max_length_check: MaxLengthCheck<'a, impl Input<'a>>,
+    fail_fast: bool,
+    max_errors_check: MaxErrorsCheck,
     validator: &'s CombinedValidator,
     extra: &Extra,
-    definitions: &'a [CombinedValidator],
+    definitions: &'a Definitions<CombinedValidator>,
     recursion_guard: &'s mut RecursionGuard,
+    partial_errors: Option<&mut Vec<ValLineError<'a>>>,
 ) -> ValResult<'a, Vec<PyObject>> {
     let mut output: Vec<PyObject> = Vec::with_capacity(capacity);
     let mut errors: Vec<ValLineError> = Vec::new();
     for (index, item_result) in iter.enumerate() {
+        if max_errors_check.reached(&mut errors, index, max_length_check.input) {
+            break;
+        }
         let item = item_result.map_err(|e| any_next_error!(py, e, max_length_check.input, index))?;
-        match validator.validate(py, item, extra, definitions, recursion_guard) {
+        let item_extra = extra.with_index(Some(index));
+        match validator.validate(py, item, &item_extra, definitions, recursion_guard) {
             Ok(item) => {
                 max_length_check.incr()?;
                 output.push(item);
@@ -168,16 +205,32 @@ fn validate_iter_to_vec<'a, 's>(
             Err(ValError::LineErrors(line_errors)) => {
                 max_length_check.incr()?;
                 errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index.into())));
+                if fail_fast {
+                    break;
+                }
             }
             Err(ValError::Omit) => (),
             Err(err) => return Err(err),
         }
     }
 
-    if errors.is_empty() {
-        Ok(output)
-    } else {
-        Err(ValError::LineErrors(errors))
+    finish_items(output, errors, partial_errors)
+}
+
+/// Item errors fail validation, unless `partial_errors` is set, in which case they're added to it
+/// and the valid items are returned
+fn finish_items<'a, T>(
+    output: T,
+    errors: Vec<ValLineError<'a>>,
+    partial_errors: Option<&mut Vec<ValLineError<'a>>>,
+) -> ValResult<'a, T> {
+    match partial_errors {
+        Some(partial_errors) => {
+            partial_errors.extend(errors);
+            Ok(output)
+        }
+        None if errors.is_empty() => Ok(output),
+        None => Err(ValError::LineErrors(errors)),
     }
 }
 
@@ -212,6 +265,10 @@ impl BuildSet for &PyFrozenSet {
     }
 }
 
+/// Validate items into `set`, returns the number of items added to the set including duplicates.
+///
+/// With `hash_key`, items are deduplicated by the result of calling it on each validated item rather than by the
+/// item itself, and the first item with each key is kept
 #[allow(clippy::too_many_arguments)]
 fn validate_iter_to_set<'a, 's>(
     py: Python<'a>,
@@ -220,43 +277,100 @@ fn validate_iter_to_set<'a, 's>(
     input: &'a (impl Input<'a> + 'a),
     field_type: &'static str,
     max_length: Option<usize>,
+    fail_fast: bool,
+    max_errors_check: MaxErrorsCheck,
+    hash_key: Option<&'s PyObject>,
     validator: &'s CombinedValidator,
     extra: &Extra,
-    definitions: &'a [CombinedValidator],
+    definitions: &'a Definitions<CombinedValidator>,
     recursion_guard: &'s mut RecursionGuard,
-) -> ValResult<'a, ()> {
+    partial_errors: Option<&mut Vec<ValLineError<'a>>>,
+) -> ValResult<'a, usize> {
     let mut errors: Vec<ValLineError> = Vec::new();
+    let mut items_added: usize = 0;
+    let seen_keys = match hash_key {
+        Some(_) => Some(PySet::empty(py)?),
+        None => None,
+    };
+    // `AnyValidator` would just return the item, so we can skip calling it
+    let validate_items = !matches!(validator.inner(), CombinedValidator::Any(_));
     for (index, item_result) in iter.enumerate() {
+        if max_errors_check.reached(&mut errors, index, input) {
+            break;
+        }
         let item = item_result.map_err(|e| any_next_error!(py, e, input, index))?;
-        match validator.validate(py, item, extra, definitions, recursion_guard) {
-            Ok(item) => {
-                set.build_add(item)?;
-                if let Some(max_length) = max_length {
-                    let actual_length = set.build_len();
-                    if actual_length > max_length {
-                        return Err(ValError::new(
-                            ErrorType::TooLong {
-                                field_type: field_type.to_string(),
-                                max_length,
-                                actual_length,
-                            },
-                            input,
-                        ));
+        let item_extra = extra.with_index(Some(index));
+        let item_result = match validate_items {
+            true => validator.validate(py, item, &item_extra, definitions, recursion_guard),
+            false => Ok(item.to_object(py)),
+        };
+        let item_result = match (hash_key, seen_keys) {
+            (Some(hash_key), Some(seen_keys)) => item_result.and_then(|output| {
+                let key = hash_key.call1(py, (&output,)).map_err(|e| hash_key_err(py, e, item))?;
+                match seen_keys.contains(&key) {
+                    Ok(true) => Ok(None),
+                    Ok(false) => {
+                        seen_keys.add(key)?;
+                        Ok(Some(output))
+                    }
+                    Err(err) if err.is_instance_of::<PyTypeError>(py) => {
+                        Err(ValError::new(ErrorType::UnhashableItem, item))
                     }
+                    Err(err) => Err(err.into()),
                 }
-            }
+            }),
+            _ => item_result.map(Some),
+        };
+        match item_result {
+            // an item with the same key as an earlier item
+            Ok(None) => items_added += 1,
+            Ok(Some(output)) => match set.build_add(output) {
+                Ok(()) => {
+                    items_added += 1;
+                    if let Some(max_length) = max_length {
+                        let actual_length = set.build_len();
+                        if actual_length > max_length {
+                            return Err(ValError::new(
+                                ErrorType::TooLong {
+                                    field_type: field_type.to_string(),
+                                    max_length,
+                                    actual_length,
+                                },
+                                input,
+                            ));
+                        }
+                    }
+                }
+                Err(err) if err.is_instance_of::<PyTypeError>(py) => {
+                    errors.push(ValLineError::new_with_loc(ErrorType::UnhashableItem, item, index));
+                }
+                Err(err) => return Err(err.into()),
+            },
             Err(ValError::LineErrors(line_errors)) => {
                 errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index.into())));
             }
             Err(ValError::Omit) => (),
             Err(err) => return Err(err),
         }
+        if fail_fast && !errors.is_empty() {
+            break;
+        }
     }
 
-    if errors.is_empty() {
-        Ok(())
-    } else {
-        Err(ValError::LineErrors(errors))
+    finish_items(items_added, errors, partial_errors)
+}
+
+/// Exceptions raised by `hash_key` are errors for the item, `ValueError` and `AssertionError` as from a validator
+/// function, anything else as a `hash_key_error`
+fn hash_key_err<'a>(py: Python<'a>, err: PyErr, item: &'a impl Input<'a>) -> ValError<'a> {
+    match convert_err(py, err, item) {
+        ValError::InternalErr(err) => ValError::new(
+            ErrorType::HashKeyError {
+                error: py_err_string(py, err),
+            },
+            item,
+        ),
+        err => err,
     }
 }
 
@@ -264,19 +378,37 @@ fn no_validator_iter_to_vec<'a, 's>(
     py: Python<'a>,
     input: &'a (impl Input<'a> + 'a),
     iter: impl Iterator<Item = PyResult<&'a (impl Input<'a> + 'a)>>,
+    capacity: usize,
     mut max_length_check: MaxLengthCheck<'a, impl Input<'a>>,
 ) -> ValResult<'a, Vec<PyObject>> {
-    iter.enumerate()
-        .map(|(index, result)| {
-            let v = result.map_err(|e| any_next_error!(py, e, input, index))?;
-            max_length_check.incr()?;
-            Ok(v.to_object(py))
-        })
-        .collect()
+    let mut output: Vec<PyObject> = Vec::with_capacity(capacity);
+    for (index, result) in iter.enumerate() {
+        let v = result.map_err(|e| any_next_error!(py, e, input, index))?;
+        max_length_check.incr()?;
+        output.push(v.to_object(py));
+    }
+    Ok(output)
 }
 
 // pretty arbitrary default capacity when creating vecs from iteration
 static DEFAULT_CAPACITY: usize = 10;
+// the most capacity we'll allocate up front for inputs without a length, since `__length_hint__`
+// and `max_length` are only estimates of the number of items
+static MAX_ESTIMATED_CAPACITY: usize = 1 << 20;
+
+/// `operator.length_hint(iter, 0)`, errors raised by `__length_hint__` are ignored
+fn length_hint(iter: &PyIterator) -> Option<usize> {
+    let hint = unsafe { ffi::PyObject_LengthHint(iter.as_ptr(), 0) };
+    if hint < 0 {
+        // clear the error
+        PyErr::take(iter.py());
+        return None;
+    }
+    match hint {
+        0 => None,
+        hint => Some(hint as usize),
+    }
+}
 
 impl<'a> GenericIterable<'a> {
     pub fn generic_len(&self) -> Option<usize> {
@@ -301,22 +433,46 @@ impl<'a> GenericIterable<'a> {
         }
     }
 
+    /// Capacity for a vec of the items, inputs without a length use `__length_hint__` or `max_length`
+    /// if available, clamped so a wrong estimate can't cause a huge allocation
+    fn output_capacity(&self, max_length: Option<usize>) -> usize {
+        if let Some(len) = self.generic_len() {
+            return len;
+        }
+        let hint = match self {
+            GenericIterable::Iterator(iter) => length_hint(iter),
+            _ => None,
+        };
+        let estimate = match (hint, max_length) {
+            (Some(hint), Some(max_length)) => hint.min(max_length),
+            (Some(hint), None) => hint,
+            (None, Some(max_length)) => max_length,
+            (None, None) => DEFAULT_CAPACITY,
+        };
+        estimate.min(MAX_ESTIMATED_CAPACITY)
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn validate_to_vec<'s>(
         &'s self,
         py: Python<'a>,
         input: &'a impl Input<'a>,
         max_length: Option<usize>,
+        fail_fast: bool,
+        max_errors: Option<usize>,
         field_type: &'static str,
         validator: &'s CombinedValidator,
         extra: &Extra,
-        definitions: &'a [CombinedValidator],
+        definitions: &'a Definitions<CombinedValidator>,
         recursion_guard: &'s mut RecursionGuard,
+        partial_errors: Option<&mut Vec<ValLineError<'a>>>,
     ) -> ValResult<'a, Vec<PyObject>> {
-        let capacity = self
-            .generic_len()
-            .unwrap_or_else(|| max_length.unwrap_or(DEFAULT_CAPACITY));
+        let capacity = self.output_capacity(max_length);
         let max_length_check = MaxLengthCheck::new(max_length, field_type, input);
+        let max_errors_check = MaxErrorsCheck {
+            max_errors,
+            input_len: self.generic_len(),
+        };
 
         macro_rules! validate {
             ($iter:expr) => {
@@ -325,10 +481,13 @@ impl<'a> GenericIterable<'a> {
                     $iter,
                     capacity,
                     max_length_check,
+                    fail_fast || extra.fail_fast,
+                    max_errors_check,
                     validator,
                     extra,
                     definitions,
                     recursion_guard,
+                    partial_errors,
                 )
             };
         }
@@ -352,12 +511,21 @@ impl<'a> GenericIterable<'a> {
         set: impl BuildSet,
         input: &'a impl Input<'a>,
         max_length: Option<usize>,
+        fail_fast: bool,
+        max_errors: Option<usize>,
+        hash_key: Option<&'s PyObject>,
         field_type: &'static str,
         validator: &'s CombinedValidator,
         extra: &Extra,
-        definitions: &'a [CombinedValidator],
+        definitions: &'a Definitions<CombinedValidator>,
         recursion_guard: &'s mut RecursionGuard,
-    ) -> ValResult<'a, ()> {
+        partial_errors: Option<&mut Vec<ValLineError<'a>>>,
+    ) -> ValResult<'a, usize> {
+        let max_errors_check = MaxErrorsCheck {
+            max_errors,
+            input_len: self.generic_len(),
+        };
+
         macro_rules! validate_set {
             ($iter:expr) => {
                 validate_iter_to_set(
@@ -367,10 +535,14 @@ impl<'a> GenericIterable<'a> {
                     input,
                     field_type,
                     max_length,
+                    fail_fast || extra.fail_fast,
+                    max_errors_check,
+                    hash_key,
                     validator,
                     extra,
                     definitions,
                     recursion_guard,
+                    partial_errors,
                 )
             };
         }
@@ -394,31 +566,24 @@ impl<'a> GenericIterable<'a> {
         field_type: &'static str,
         max_length: Option<usize>,
     ) -> ValResult<'a, Vec<PyObject>> {
+        let capacity = self.output_capacity(max_length);
         let max_length_check = MaxLengthCheck::new(max_length, field_type, input);
 
+        macro_rules! to_vec {
+            ($iter:expr) => {
+                no_validator_iter_to_vec(py, input, $iter, capacity, max_length_check)
+            };
+        }
+
         match self {
-            GenericIterable::List(collection) => {
-                no_validator_iter_to_vec(py, input, collection.iter().map(Ok), max_length_check)
-            }
-            GenericIterable::Tuple(collection) => {
-                no_validator_iter_to_vec(py, input, collection.iter().map(Ok), max_length_check)
-            }
-            GenericIterable::Set(collection) => {
-                no_validator_iter_to_vec(py, input, collection.iter().map(Ok), max_length_check)
-            }
-            GenericIterable::FrozenSet(collection) => {
-                no_validator_iter_to_vec(py, input, collection.iter().map(Ok), max_length_check)
-            }
-            GenericIterable::Sequence(collection) => {
-                no_validator_iter_to_vec(py, input, collection.iter()?, max_length_check)
-            }
-            GenericIterable::Iterator(collection) => {
-                no_validator_iter_to_vec(py, input, collection.iter()?, max_length_check)
-            }
-            GenericIterable::JsonArray(collection) => {
-                no_validator_iter_to_vec(py, input, collection.iter().map(Ok), max_length_check)
-            }
-            other => no_validator_iter_to_vec(py, input, other.as_sequence_iterator(py)?, max_length_check),
+            GenericIterable::List(collection) => to_vec!(collection.iter().map(Ok)),
+            GenericIterable::Tuple(collection) => to_vec!(collection.iter().map(Ok)),
+            GenericIterable::Set(collection) => to_vec!(collection.iter().map(Ok)),
+            GenericIterable::FrozenSet(collection) => to_vec!(collection.iter().map(Ok)),
+            GenericIterable::Sequence(collection) => to_vec!(collection.iter()?),
+            GenericIterable::Iterator(collection) => to_vec!(collection.iter()?),
+            GenericIterable::JsonArray(collection) => to_vec!(collection.iter().map(Ok)),
+            other => to_vec!(other.as_sequence_iterator(py)?),
         }
     }
 }
@@ -786,6 +951,7 @@ pub fn py_string_str(py_str: &PyString) -> ValResult<&str> {
 pub enum EitherBytes<'a> {
     Cow(Cow<'a, [u8]>),
     Py(&'a PyBytes),
+    Buffer(BytesBuffer),
 }
 
 impl<'a> From<Vec<u8>> for EitherBytes<'a> {
@@ -806,11 +972,18 @@ impl<'a> From<&'a PyBytes> for EitherBytes<'a> {
     }
 }
 
+impl<'a> From<BytesBuffer> for EitherBytes<'a> {
+    fn from(buffer: BytesBuffer) -> Self {
+        Self::Buffer(buffer)
+    }
+}
+
 impl<'a> EitherBytes<'a> {
     pub fn len(&'a self) -> PyResult<usize> {
         match self {
             EitherBytes::Cow(bytes) => Ok(bytes.len()),
             EitherBytes::Py(py_bytes) => py_bytes.len(),
+            EitherBytes::Buffer(buffer) => Ok(buffer.len()),
         }
     }
 }
@@ -820,10 +993,68 @@ impl<'a> IntoPy<PyObject> for EitherBytes<'a> {
         match self {
             EitherBytes::Cow(bytes) => PyBytes::new(py, &bytes).into_py(py),
             EitherBytes::Py(py_bytes) => py_bytes.into_py(py),
+            EitherBytes::Buffer(buffer) => PyBytes::new(py, buffer.as_slice()).into_py(py),
+        }
+    }
+}
+
+/// A view onto the memory of any object implementing the buffer protocol (e.g. `memoryview`, `mmap`,
+/// numpy arrays), the data is only copied when the value is converted to a python `bytes` object,
+/// so length constraints can be checked without copying
+pub struct BytesBuffer {
+    view: Box<ffi::Py_buffer>,
+}
+
+impl BytesBuffer {
+    /// Returns `None` if `obj` doesn't support the buffer protocol, or if its buffer isn't C-contiguous
+    pub fn get(obj: &PyAny) -> Option<Self> {
+        let py = obj.py();
+        unsafe {
+            if ffi::PyObject_CheckBuffer(obj.as_ptr()) == 0 {
+                return None;
+            }
+            let mut view: Box<ffi::Py_buffer> = Box::new(std::mem::zeroed());
+            if ffi::PyObject_GetBuffer(obj.as_ptr(), view.as_mut(), ffi::PyBUF_FULL_RO) == -1 {
+                // discard the error, the caller raises a `bytes_type` validation error instead
+                drop(PyErr::fetch(py));
+                return None;
+            }
+            // from here `view` is released when `buffer` is dropped
+            let buffer = Self { view };
+            if ffi::PyBuffer_IsContiguous(buffer.view.as_ref(), b'C' as std::os::raw::c_char) == 0 {
+                None
+            } else {
+                Some(buffer)
+            }
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.view.len as usize
+    }
+
+    pub fn as_slice(&self) -> &[u8] {
+        if self.view.buf.is_null() || self.view.len == 0 {
+            &[]
+        } else {
+            unsafe { std::slice::from_raw_parts(self.view.buf as *const u8, self.len()) }
         }
     }
 }
 
+impl Drop for BytesBuffer {
+    fn drop(&mut self) {
+        // buffers are only ever created and dropped while validating, when the GIL is held
+        unsafe { ffi::PyBuffer_Release(self.view.as_mut()) }
+    }
+}
+
+impl std::fmt::Debug for BytesBuffer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "BytesBuffer(len={})", self.len())
+    }
+}
+
 #[cfg_attr(debug_assertions, derive(Debug))]
 pub enum EitherInt<'a> {
     I64(i64),
@@ -5,13 +5,8 @@ use crate::input::EitherInt;
 
 use super::Input;
 
-pub fn map_json_err<'a>(input: &'a impl Input<'a>, error: serde_json::Error) -> ValError<'a> {
-    ValError::new(
-        ErrorType::JsonInvalid {
-            error: error.to_string(),
-        },
-        input,
-    )
+pub fn map_json_err<'a>(input: &'a impl Input<'a>, error: String) -> ValError<'a> {
+    ValError::new(ErrorType::JsonInvalid { error }, input)
 }
 
 pub fn str_as_bool<'a>(input: &'a impl Input<'a>, str: &str) -> ValResult<'a, bool> {
@@ -46,6 +41,19 @@ pub fn int_as_bool<'a>(input: &'a impl Input<'a>, int: i64) -> ValResult<'a, boo
     }
 }
 
+/// Strings of digits like `"1672531200"` or `"-1.5"` which date and datetime validators interpret as numbers,
+/// other strings (e.g. `"inf"` or `"2023-01-01"`) are parsed as dates and datetimes as usual
+pub fn str_as_temporal_number(str: &str) -> Option<f64> {
+    let digits = str.strip_prefix('-').unwrap_or(str);
+    let mut parts = digits.splitn(2, '.');
+    let is_digits = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+    match (parts.next(), parts.next()) {
+        (Some(int), None) if is_digits(int) => str.parse().ok(),
+        (Some(int), Some(fraction)) if is_digits(int) && is_digits(fraction) => str.parse().ok(),
+        _ => None,
+    }
+}
+
 /// parse a string as an int
 ///
 /// max length of the input is 4300, see
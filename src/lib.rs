@@ -8,6 +8,11 @@ use pyo3::prelude::*;
 #[global_allocator]
 static GLOBAL: mimalloc::MiMalloc = mimalloc::MiMalloc;
 
+// pyo3 converts panics into `PanicException`s, but only if they unwind, otherwise a bug would abort the interpreter,
+// in pyodide that means the whole page
+#[cfg(all(target_family = "wasm", panic = "abort"))]
+compile_error!("pydantic-core must be built with `-C panic=unwind` for wasm targets");
+
 mod argument_markers;
 mod build_tools;
 mod definitions;
@@ -15,6 +20,7 @@ mod errors;
 mod input;
 mod lazy_index_map;
 mod lookup_key;
+mod memory;
 mod recursion_guard;
 mod serializers;
 mod tools;
@@ -28,10 +34,11 @@ pub use build_tools::SchemaError;
 pub use errors::{
     list_all_errors, PydanticCustomError, PydanticKnownError, PydanticOmit, PydanticUseDefault, ValidationError,
 };
+pub use input::TzInfo;
 pub use serializers::{
     to_json, to_jsonable_python, PydanticSerializationError, PydanticSerializationUnexpectedValue, SchemaSerializer,
 };
-pub use validators::{PySome, SchemaValidator};
+pub use validators::{register_validator, IteratedValidation, PySome, SchemaValidator};
 
 pub fn get_version() -> String {
     let version = env!("CARGO_PKG_VERSION");
@@ -51,6 +58,7 @@ fn _pydantic_core(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<PydanticUndefinedType>()?;
     m.add_class::<PySome>()?;
     m.add_class::<SchemaValidator>()?;
+    m.add_class::<IteratedValidation>()?;
     m.add_class::<ValidationError>()?;
     m.add_class::<SchemaError>()?;
     m.add_class::<PydanticCustomError>()?;
@@ -63,9 +71,11 @@ fn _pydantic_core(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<PyMultiHostUrl>()?;
     m.add_class::<ArgsKwargs>()?;
     m.add_class::<SchemaSerializer>()?;
+    m.add_class::<TzInfo>()?;
     m.add_function(wrap_pyfunction!(to_json, m)?)?;
     m.add_function(wrap_pyfunction!(to_jsonable_python, m)?)?;
     m.add_function(wrap_pyfunction!(list_all_errors, m)?)?;
+    m.add_function(wrap_pyfunction!(register_validator, m)?)?;
 
     #[cfg(not(feature = "mimalloc"))]
     m.setattr("__pydantic_core_default_allocator__", true)?; // uses setattr so this is not in __all__
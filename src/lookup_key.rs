@@ -2,19 +2,21 @@ use core::slice::Iter;
 use std::fmt;
 
 use pyo3::exceptions::{PyAttributeError, PyTypeError};
+use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyList, PyMapping, PyString};
+use pyo3::types::{PyDict, PyList, PyString};
 
 use crate::build_tools::py_schema_err;
 use crate::errors::{ErrorType, ValLineError};
 use crate::input::{Input, JsonInput, JsonObject};
-use crate::tools::{extract_i64, py_err};
+use crate::memory::HeapSize;
+use crate::tools::{extract_i64, py_err, SchemaDict};
 
 /// Used for getting items from python dicts, python objects, or JSON objects, in different ways
 #[derive(Debug, Clone)]
 pub(crate) enum LookupKey {
     /// simply look up a key in a dict, equivalent to `d.get(key)`
-    /// we save both the string and pystring to save creating the pystring for python
+    /// we save both the string and an interned pystring to save creating and hashing the pystring for python
     Simple {
         key: String,
         py_key: Py<PyString>,
@@ -49,6 +51,24 @@ impl fmt::Display for LookupKey {
     }
 }
 
+/// Order in which item and attribute access are tried when looking up fields on objects which are not mappings
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum LookupMode {
+    MappingThenAttributes,
+    AttributesThenMapping,
+}
+
+impl LookupMode {
+    pub fn from_py(schema: &PyDict) -> PyResult<Option<Self>> {
+        match schema.get_as::<&str>(intern!(schema.py(), "lookup_mode"))? {
+            Some("mapping-then-attributes") => Ok(Some(Self::MappingThenAttributes)),
+            Some("attributes-then-mapping") => Ok(Some(Self::AttributesThenMapping)),
+            Some(s) => py_schema_err!("Invalid lookup_mode {:?}", s),
+            None => Ok(None),
+        }
+    }
+}
+
 macro_rules! py_string {
     ($py:ident, $str:expr) => {
         PyString::intern($py, $str).into()
@@ -58,17 +78,17 @@ macro_rules! py_string {
 impl LookupKey {
     pub fn from_py(py: Python, value: &PyAny, alt_alias: Option<&str>) -> PyResult<Self> {
         if let Ok(alias_py) = value.downcast::<PyString>() {
-            let alias: &str = alias_py.extract()?;
+            let alias: &str = alias_py.to_str()?;
             match alt_alias {
                 Some(alt_alias) => Ok(Self::Choice {
                     key1: alias.to_string(),
-                    py_key1: alias_py.into_py(py),
-                    path1: LookupPath::from_str(py, alias, Some(alias_py)),
+                    py_key1: py_string!(py, alias),
+                    path1: LookupPath::from_str(py, alias),
                     key2: alt_alias.to_string(),
                     py_key2: py_string!(py, alt_alias),
-                    path2: LookupPath::from_str(py, alt_alias, None),
+                    path2: LookupPath::from_str(py, alt_alias),
                 }),
-                None => Ok(Self::simple(py, alias, Some(alias_py))),
+                None => Ok(Self::simple(py, alias)),
             }
         } else {
             let list: &PyList = value.downcast()?;
@@ -84,25 +104,21 @@ impl LookupKey {
             };
 
             if let Some(alt_alias) = alt_alias {
-                locs.push(LookupPath::from_str(py, alt_alias, None));
+                locs.push(LookupPath::from_str(py, alt_alias));
             }
             Ok(Self::PathChoices(locs))
         }
     }
 
     pub fn from_string(py: Python, key: &str) -> Self {
-        Self::simple(py, key, None)
+        Self::simple(py, key)
     }
 
-    fn simple(py: Python, key: &str, opt_py_key: Option<&PyString>) -> Self {
-        let py_key = match opt_py_key {
-            Some(py_key) => py_key.into_py(py),
-            None => py_string!(py, key),
-        };
+    fn simple(py: Python, key: &str) -> Self {
         Self::Simple {
             key: key.to_string(),
-            py_key,
-            path: LookupPath::from_str(py, key, opt_py_key),
+            py_key: py_string!(py, key),
+            path: LookupPath::from_str(py, key),
         }
     }
 
@@ -143,9 +159,10 @@ impl LookupKey {
         }
     }
 
+    /// Look up the key with `__getitem__`, used for mappings, and by `py_get_item_or_attr` for other objects
     pub fn py_get_mapping_item<'data, 's>(
         &'s self,
-        dict: &'data PyMapping,
+        dict: &'data PyAny,
     ) -> PyResult<Option<(&'s LookupPath, &'data PyAny)>> {
         match self {
             Self::Simple { py_key, path, .. } => match dict.get_item(py_key) {
@@ -169,7 +186,7 @@ impl LookupKey {
                 for path in path_choices {
                     // iterate over the path and plug each value into the py_any from the last step, starting with dict
                     // this could just be a loop but should be somewhat faster with a functional design
-                    if let Some(v) = path.iter().try_fold(dict as &PyAny, |d, loc| loc.py_get_item(d)) {
+                    if let Some(v) = path.iter().try_fold(dict, |d, loc| loc.py_get_item(d)) {
                         // Successfully found an item, return it
                         return Ok(Some((path, v)));
                     }
@@ -232,6 +249,26 @@ impl LookupKey {
         }
     }
 
+    /// Look up the key using both item and attribute access in the order given by `mode`, all choices are tried
+    /// with the first access path before moving to the second. Errors from the last access path attempted take
+    /// precedence, errors from the first are only returned if the second finds nothing.
+    ///
+    /// This is for objects which aren't mappings, attributes are never read from mappings so a key can't find a
+    /// method like `items` by mistake.
+    pub fn py_get_item_or_attr<'data, 's>(
+        &'s self,
+        obj: &'data PyAny,
+        kwargs: Option<&'data PyDict>,
+        mode: LookupMode,
+    ) -> PyResult<Option<(&'s LookupPath, &'data PyAny)>> {
+        let get_item = || self.py_get_mapping_item(obj);
+        let get_attr = || self.py_get_attr(obj, kwargs);
+        match mode {
+            LookupMode::MappingThenAttributes => lookup_fallback(get_item(), get_attr),
+            LookupMode::AttributesThenMapping => lookup_fallback(get_attr(), get_item),
+        }
+    }
+
     pub fn json_get<'data, 's>(
         &'s self,
         dict: &'data JsonObject,
@@ -299,6 +336,16 @@ impl LookupKey {
     }
 }
 
+fn lookup_fallback<T>(first: PyResult<Option<T>>, second: impl FnOnce() -> PyResult<Option<T>>) -> PyResult<Option<T>> {
+    match first {
+        Ok(Some(found)) => Ok(Some(found)),
+        first => match second() {
+            Ok(None) => first,
+            result => result,
+        },
+    }
+}
+
 #[derive(Debug, Clone)]
 pub(crate) struct LookupPath(Vec<PathItem>);
 
@@ -315,12 +362,8 @@ impl fmt::Display for LookupPath {
 }
 
 impl LookupPath {
-    fn from_str(py: Python, key: &str, py_key: Option<&PyString>) -> Self {
-        let py_key = match py_key {
-            Some(py_key) => py_key.into_py(py),
-            None => py_string!(py, key),
-        };
-        Self(vec![PathItem::S(key.to_string(), py_key)])
+    fn from_str(py: Python, key: &str) -> Self {
+        Self(vec![PathItem::S(key.to_string(), py_string!(py, key))])
     }
 
     fn from_list(obj: &PyAny) -> PyResult<LookupPath> {
@@ -368,7 +411,7 @@ impl LookupPath {
 #[derive(Debug, Clone)]
 pub(crate) enum PathItem {
     /// string type key, used to get or identify items from a dict or anything that implements `__getitem__`
-    /// as above we store both the string and pystring to save creating the pystring for python
+    /// as above we store both the string and an interned pystring to save creating the pystring for python
     S(String, Py<PyString>),
     /// integer key, used to get items from a list, tuple OR a dict with int keys `Dict[int, ...]` (python only)
     Pos(usize),
@@ -401,8 +444,9 @@ impl ToPyObject for PathItem {
 impl PathItem {
     pub fn from_py(index: usize, obj: &PyAny) -> PyResult<Self> {
         if let Ok(py_str_key) = obj.downcast::<PyString>() {
-            let str_key = py_str_key.to_str()?.to_string();
-            Ok(Self::S(str_key, py_str_key.into()))
+            let str_key = py_str_key.to_str()?;
+            let py = obj.py();
+            Ok(Self::S(str_key.to_string(), py_string!(py, str_key)))
         } else if let Ok(usize_key) = obj.extract::<usize>() {
             if index == 0 {
                 py_err!(PyTypeError; "The first item in an alias path should be a string")
@@ -481,7 +525,7 @@ impl PathItem {
 /// wrapper around `getattr` that returns `Ok(None)` for attribute errors, but returns other errors
 /// We don't check `try_from_attributes` because that check was performed on the top level object before we got here
 fn py_get_attrs<'a>(obj: &'a PyAny, attr_name: &Py<PyString>) -> PyResult<Option<&'a PyAny>> {
-    match obj.getattr(attr_name.extract::<&PyString>(obj.py())?) {
+    match obj.getattr(attr_name.as_ref(obj.py())) {
         Ok(attr) => Ok(Some(attr)),
         Err(err) => {
             if err.get_type(obj.py()).is_subclass_of::<PyAttributeError>()? {
@@ -492,3 +536,34 @@ fn py_get_attrs<'a>(obj: &'a PyAny, attr_name: &Py<PyString>) -> PyResult<Option
         }
     }
 }
+
+impl HeapSize for LookupKey {
+    fn heap_size(&self) -> usize {
+        match self {
+            Self::Simple { key, path, .. } => key.heap_size() + path.heap_size(),
+            Self::Choice {
+                key1,
+                path1,
+                key2,
+                path2,
+                ..
+            } => key1.heap_size() + path1.heap_size() + key2.heap_size() + path2.heap_size(),
+            Self::PathChoices(paths) => paths.heap_size(),
+        }
+    }
+}
+
+impl HeapSize for LookupPath {
+    fn heap_size(&self) -> usize {
+        self.0.heap_size()
+    }
+}
+
+impl HeapSize for PathItem {
+    fn heap_size(&self) -> usize {
+        match self {
+            Self::S(key, _) => key.heap_size(),
+            Self::Pos(_) | Self::Neg(_) => 0,
+        }
+    }
+}
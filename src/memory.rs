@@ -0,0 +1,203 @@
+use std::mem::size_of;
+use std::sync::Arc;
+
+use ahash::{AHashMap, AHashSet};
+use num_bigint::BigInt;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList, PyTuple};
+use regex::Regex;
+
+use crate::errors::LocItem;
+use crate::input::Int;
+
+/// Estimated heap memory owned by a value in bytes, used for `__sizeof__` and `SchemaValidator.memory_breakdown`.
+/// Allocator overhead is ignored, as is memory owned by python objects since python accounts for that itself.
+pub trait HeapSize {
+    fn heap_size(&self) -> usize;
+}
+
+macro_rules! no_heap {
+    ($($t:ty),+) => {
+        $(
+            impl HeapSize for $t {
+                fn heap_size(&self) -> usize {
+                    0
+                }
+            }
+        )+
+    };
+}
+no_heap!(bool, u16, i64, usize, isize);
+
+impl<T> HeapSize for Py<T> {
+    fn heap_size(&self) -> usize {
+        0
+    }
+}
+
+impl HeapSize for String {
+    fn heap_size(&self) -> usize {
+        self.capacity()
+    }
+}
+
+impl<T: HeapSize> HeapSize for Option<T> {
+    fn heap_size(&self) -> usize {
+        self.as_ref().map_or(0, HeapSize::heap_size)
+    }
+}
+
+impl<T: HeapSize> HeapSize for Box<T> {
+    fn heap_size(&self) -> usize {
+        size_of::<T>() + self.as_ref().heap_size()
+    }
+}
+
+impl<A: HeapSize, B: HeapSize> HeapSize for (A, B) {
+    fn heap_size(&self) -> usize {
+        self.0.heap_size() + self.1.heap_size()
+    }
+}
+
+impl<T: HeapSize> HeapSize for Vec<T> {
+    fn heap_size(&self) -> usize {
+        self.capacity() * size_of::<T>() + self.iter().map(HeapSize::heap_size).sum::<usize>()
+    }
+}
+
+/// hashbrown stores entries in a power of two number of buckets, with one control byte per bucket
+fn table_size(capacity: usize, entry_size: usize) -> usize {
+    match capacity {
+        0 => 0,
+        _ => (capacity * 8 / 7).next_power_of_two() * (entry_size + 1),
+    }
+}
+
+impl<K: HeapSize, V: HeapSize> HeapSize for AHashMap<K, V> {
+    fn heap_size(&self) -> usize {
+        table_size(self.capacity(), size_of::<(K, V)>())
+            + self.iter().map(|(k, v)| k.heap_size() + v.heap_size()).sum::<usize>()
+    }
+}
+
+impl<T: HeapSize> HeapSize for AHashSet<T> {
+    fn heap_size(&self) -> usize {
+        table_size(self.capacity(), size_of::<T>()) + self.iter().map(HeapSize::heap_size).sum::<usize>()
+    }
+}
+
+impl HeapSize for Int {
+    fn heap_size(&self) -> usize {
+        match self {
+            Self::I64(_) => 0,
+            Self::Big(big) => big.heap_size(),
+        }
+    }
+}
+
+impl HeapSize for BigInt {
+    fn heap_size(&self) -> usize {
+        // stored as a vec of u64 digits
+        self.iter_u64_digits().len() * size_of::<u64>()
+    }
+}
+
+// a rough estimate of the compiled size of a regex per byte of its pattern, the regex crate doesn't expose the size
+// of the compiled program and it varies a lot, e.g. with unicode classes, so this only aims to be the right order
+const REGEX_BYTES_PER_PATTERN_BYTE: usize = 64;
+// the minimum overhead of a compiled regex, its caches and its search strategy
+const REGEX_BASE_SIZE: usize = 2048;
+
+impl HeapSize for Regex {
+    fn heap_size(&self) -> usize {
+        REGEX_BASE_SIZE + self.as_str().len() * (1 + REGEX_BYTES_PER_PATTERN_BYTE)
+    }
+}
+
+/// A node in a built validator or serializer tree for `MemoryUsage` to walk
+pub trait MemoryNode {
+    fn node_name(&self) -> &str;
+
+    /// Estimated heap memory in bytes owned by this node, including the storage of child nodes, each of which
+    /// must be passed to `memory.visit` to record its own memory
+    fn memory_usage(&self, memory: &mut MemoryUsage) -> usize;
+}
+
+struct NodeMemory {
+    path: Vec<LocItem>,
+    name: String,
+    bytes: usize,
+}
+
+/// Collects the estimated memory of each node in a built validator or serializer tree, the path of each node is
+/// its location in the schema, e.g. `('fields', 'a', 'schema')`
+#[derive(Default)]
+pub struct MemoryUsage {
+    path: Vec<LocItem>,
+    nodes: Vec<NodeMemory>,
+    // the addresses of `Arc`s already counted
+    shared: AHashSet<usize>,
+}
+
+impl MemoryUsage {
+    pub fn visit(&mut self, path: &[LocItem], node: &impl MemoryNode) {
+        self.path.extend_from_slice(path);
+        let index = self.nodes.len();
+        self.nodes.push(NodeMemory {
+            path: self.path.clone(),
+            name: node.node_name().to_string(),
+            bytes: 0,
+        });
+        self.nodes[index].bytes = node.memory_usage(self);
+        self.path.truncate(self.path.len() - path.len());
+    }
+
+    /// Visit a boxed child node, returns the size of the box's allocation to be included in the parent's memory
+    pub fn visit_boxed<T: MemoryNode>(&mut self, path: &[LocItem], node: &T) -> usize {
+        self.visit(path, node);
+        size_of::<T>()
+    }
+
+    /// Visit a list of child nodes, each labelled by its index after `path`, returns the size of the list's
+    /// allocation to be included in the parent's memory
+    pub fn visit_vec<T: MemoryNode>(&mut self, path: &str, nodes: &Vec<T>) -> usize {
+        for (index, node) in nodes.iter().enumerate() {
+            self.visit(&[path.into(), index.into()], node);
+        }
+        nodes.capacity() * size_of::<T>()
+    }
+
+    /// The memory of a value held in an `Arc`, which is only counted against the first node holding it since the
+    /// value may be shared with other nodes, e.g. a regex used by several string validators
+    pub fn visit_shared<T: HeapSize>(&mut self, value: &Arc<T>) -> usize {
+        if self.shared.insert(Arc::as_ptr(value) as usize) {
+            // the strong and weak counts
+            2 * size_of::<usize>() + size_of::<T>() + value.as_ref().heap_size()
+        } else {
+            0
+        }
+    }
+
+    pub fn total(&self) -> usize {
+        self.nodes.iter().map(|node| node.bytes).sum()
+    }
+
+    pub fn to_list<'py>(&self, py: Python<'py>) -> PyResult<&'py PyList> {
+        let output = PyList::empty(py);
+        for node in &self.nodes {
+            let dict = PyDict::new(py);
+            let path = PyTuple::new(py, node.path.iter().map(|item| item.to_object(py)));
+            dict.set_item(intern!(py, "path"), path)?;
+            dict.set_item(intern!(py, "name"), &node.name)?;
+            dict.set_item(intern!(py, "bytes"), node.bytes)?;
+            output.append(dict)?;
+        }
+        Ok(output)
+    }
+}
+
+/// Storage for a list of definitions, each definition is then visited as a node
+pub fn definitions_size<T>(definitions: &Vec<Arc<T>>) -> usize {
+    definitions.capacity() * size_of::<Arc<T>>() + definitions.len() * (2 * size_of::<usize>() + size_of::<T>())
+}
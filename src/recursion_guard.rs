@@ -13,28 +13,81 @@ type RecursionKey = (
 /// It's used in `validators/definition` to detect when a reference is reused within itself.
 #[derive(Debug, Clone, Default)]
 pub struct RecursionGuard {
-    ids: Option<AHashSet<RecursionKey>>,
-    // see validators/definition::BACKUP_GUARD_LIMIT for details
+    ids: RecursionStack,
+    // see validators/definitions::DEFAULT_RECURSION_LIMIT for details
     // depth could be a hashmap {validator_id => depth} but for simplicity and performance it's easier to just
     // use one number for all validators
     depth: u16,
 }
 
-impl RecursionGuard {
-    // insert a new id into the set, return whether the set already had the id in it
-    pub fn contains_or_insert(&mut self, obj_id: usize, node_id: usize) -> bool {
-        match self.ids {
+// A hash set is (very) slow to create and destroy, and the number of ids in the guard at once is the depth of
+// recursion, so for the common (shallow) case we store ids inline and search them linearly
+const ARRAY_SIZE: usize = 16;
+
+#[derive(Debug, Clone)]
+enum RecursionStack {
+    Array {
+        data: [RecursionKey; ARRAY_SIZE],
+        len: usize,
+    },
+    Set(AHashSet<RecursionKey>),
+}
+
+impl Default for RecursionStack {
+    fn default() -> Self {
+        Self::Array {
+            data: [(0, 0); ARRAY_SIZE],
+            len: 0,
+        }
+    }
+}
+
+impl RecursionStack {
+    // insert a new key, return whether the key was already present
+    fn contains_or_insert(&mut self, key: RecursionKey) -> bool {
+        match self {
+            Self::Array { data, len } => {
+                if data[..*len].contains(&key) {
+                    true
+                } else if *len < ARRAY_SIZE {
+                    data[*len] = key;
+                    *len += 1;
+                    false
+                } else {
+                    let mut set: AHashSet<RecursionKey> = AHashSet::with_capacity(ARRAY_SIZE * 2);
+                    set.extend(data.iter().copied());
+                    set.insert(key);
+                    *self = Self::Set(set);
+                    false
+                }
+            }
             // https://doc.rust-lang.org/std/collections/struct.HashSet.html#method.insert
             // "If the set did not have this value present, `true` is returned."
-            Some(ref mut set) => !set.insert((obj_id, node_id)),
-            None => {
-                let mut set: AHashSet<RecursionKey> = AHashSet::with_capacity(10);
-                set.insert((obj_id, node_id));
-                self.ids = Some(set);
-                false
+            Self::Set(set) => !set.insert(key),
+        }
+    }
+
+    fn remove(&mut self, key: &RecursionKey) {
+        match self {
+            Self::Array { data, len } => {
+                // keys are almost always removed in the reverse order they were inserted, so search from the end
+                if let Some(index) = data[..*len].iter().rposition(|k| k == key) {
+                    data.copy_within(index + 1..*len, index);
+                    *len -= 1;
+                }
+            }
+            Self::Set(set) => {
+                set.remove(key);
             }
         }
     }
+}
+
+impl RecursionGuard {
+    // insert a new id into the set, return whether the set already had the id in it
+    pub fn contains_or_insert(&mut self, obj_id: usize, node_id: usize) -> bool {
+        self.ids.contains_or_insert((obj_id, node_id))
+    }
 
     // see #143 this is used as a backup in case the identity check recursion guard fails
     pub fn incr_depth(&mut self) -> u16 {
@@ -47,11 +100,6 @@ impl RecursionGuard {
     }
 
     pub fn remove(&mut self, obj_id: usize, node_id: usize) {
-        match self.ids {
-            Some(ref mut set) => {
-                set.remove(&(obj_id, node_id));
-            }
-            None => unreachable!(),
-        };
+        self.ids.remove(&(obj_id, node_id));
     }
 }
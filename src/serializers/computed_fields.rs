@@ -1,11 +1,14 @@
-use pyo3::intern;
+use std::mem::size_of;
+
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList, PyString};
+use pyo3::{intern, PyTraverseError, PyVisit};
 use serde::ser::SerializeMap;
 use serde::Serialize;
 
 use crate::build_tools::py_schema_error_type;
 use crate::definitions::DefinitionsBuilder;
+use crate::memory::{HeapSize, MemoryUsage};
 use crate::serializers::filter::SchemaFilter;
 use crate::serializers::shared::{BuildSerializer, CombinedSerializer, PydanticSerializer, TypeSerializer};
 use crate::tools::SchemaDict;
@@ -34,6 +37,29 @@ impl ComputedFields {
         }
     }
 
+    pub fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        for field in &self.0 {
+            field.serializer.py_gc_traverse(visit)?;
+        }
+        Ok(())
+    }
+
+    pub fn memory_usage(&self, memory: &mut MemoryUsage) -> usize {
+        let mut bytes = self.0.capacity() * size_of::<ComputedField>();
+        for field in &self.0 {
+            bytes += field.property_name.heap_size() + field.alias.heap_size();
+            memory.visit(
+                &[
+                    "computed_fields".into(),
+                    field.property_name.as_str().into(),
+                    "return_schema".into(),
+                ],
+                &field.serializer,
+            );
+        }
+        bytes
+    }
+
     pub fn len(&self) -> usize {
         self.0.len()
     }
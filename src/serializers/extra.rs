@@ -1,5 +1,6 @@
 use std::cell::RefCell;
 use std::fmt;
+use std::sync::Arc;
 
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
@@ -156,7 +157,7 @@ impl SerCheck {
 #[cfg_attr(debug_assertions, derive(Debug))]
 pub(crate) struct ExtraOwned {
     mode: SerMode,
-    definitions: Vec<CombinedSerializer>,
+    definitions: Vec<Arc<CombinedSerializer>>,
     warnings: CollectWarnings,
     by_alias: bool,
     exclude_unset: bool,
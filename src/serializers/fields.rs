@@ -2,10 +2,12 @@ use std::borrow::Cow;
 
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyString};
+use pyo3::{PyTraverseError, PyVisit};
 
 use ahash::AHashMap;
 use serde::ser::SerializeMap;
 
+use crate::memory::{HeapSize, MemoryUsage};
 use crate::serializers::extra::SerCheck;
 use crate::PydanticSerializationUnexpectedValue;
 
@@ -17,6 +19,13 @@ use super::infer::{infer_json_key, infer_serialize, infer_to_python, SerializeIn
 use super::shared::PydanticSerializer;
 use super::shared::{CombinedSerializer, TypeSerializer};
 
+impl HeapSize for SerField {
+    fn heap_size(&self) -> usize {
+        // the serializer is visited as its own node
+        self.alias.heap_size()
+    }
+}
+
 /// representation of a field for serialization
 #[derive(Debug, Clone)]
 pub(super) struct SerField {
@@ -143,6 +152,18 @@ macro_rules! option_length {
 }
 
 impl TypeSerializer for GeneralFieldsSerializer {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        for field in self.fields.values() {
+            if let Some(ref serializer) = field.serializer {
+                serializer.py_gc_traverse(visit)?;
+            }
+        }
+        if let Some(ref computed_fields) = self.computed_fields {
+            computed_fields.py_gc_traverse(visit)?;
+        }
+        Ok(())
+    }
+
     fn to_python(
         &self,
         value: &PyAny,
@@ -315,6 +336,19 @@ impl TypeSerializer for GeneralFieldsSerializer {
     fn get_name(&self) -> &str {
         "general-fields"
     }
+
+    fn memory_usage(&self, memory: &mut MemoryUsage) -> usize {
+        let mut bytes = self.fields.heap_size() + self.filter.heap_size();
+        for (key, field) in &self.fields {
+            if let Some(ref serializer) = field.serializer {
+                memory.visit(&["fields".into(), key.as_str().into(), "schema".into()], serializer);
+            }
+        }
+        if let Some(ref computed_fields) = self.computed_fields {
+            bytes += computed_fields.memory_usage(memory);
+        }
+        bytes
+    }
 }
 
 fn key_str(key: &PyAny) -> PyResult<&str> {
@@ -7,6 +7,7 @@ use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyBool, PyDict, PySet};
 
+use crate::memory::HeapSize;
 use crate::tools::SchemaDict;
 
 #[derive(Debug, Clone, Default)]
@@ -15,6 +16,12 @@ pub(crate) struct SchemaFilter<T> {
     exclude: Option<AHashSet<T>>,
 }
 
+impl<T: HeapSize> HeapSize for SchemaFilter<T> {
+    fn heap_size(&self) -> usize {
+        self.include.heap_size() + self.exclude.heap_size()
+    }
+}
+
 fn map_negative_index(value: &PyAny, len: Option<usize>) -> PyResult<&PyAny> {
     let py = value.py();
     match len {
@@ -4,9 +4,10 @@ use pyo3::exceptions::PyTypeError;
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{
-    PyByteArray, PyBytes, PyDate, PyDateTime, PyDelta, PyDict, PyFrozenSet, PyIterator, PyList, PySet, PyString,
-    PyTime, PyTuple,
+    PyBool, PyByteArray, PyBytes, PyDate, PyDateTime, PyDelta, PyDict, PyFloat, PyFrozenSet, PyIterator, PyList,
+    PyLong, PySet, PyString, PyTime, PyTuple,
 };
+use pyo3::PyTypeInfo;
 
 use serde::ser::{Error, Serialize, SerializeMap, SerializeSeq, Serializer};
 
@@ -14,14 +15,13 @@ use crate::serializers::errors::SERIALIZATION_ERR_MARKER;
 use crate::serializers::filter::SchemaFilter;
 use crate::serializers::shared::{PydanticSerializer, TypeSerializer};
 use crate::serializers::SchemaSerializer;
-use crate::tools::{extract_i64, py_err, safe_repr};
+use crate::tools::{dataclass_to_dict, extract_i64, py_err, safe_repr};
 use crate::url::{PyMultiHostUrl, PyUrl};
 
 use super::errors::{py_err_se_err, PydanticSerializationError};
 use super::extra::{Extra, SerMode};
 use super::filter::AnyFilter;
 use super::ob_type::ObType;
-use super::shared::dataclass_to_dict;
 
 pub(crate) fn infer_to_python(
     value: &PyAny,
@@ -311,6 +311,80 @@ impl<'py> Serialize for SerializeInfer<'py> {
     }
 }
 
+/// Nesting depth after which `SerializePlainJson` hands containers back to `infer_serialize`, whose recursion
+/// guard catches cyclic data.
+const MAX_PLAIN_JSON_DEPTH: u8 = 32;
+
+/// Fast path for plain data, e.g. an `Any` field holding parsed JSON: exact `dict`, `list`, `str`, `int`, `float`,
+/// `bool` and `None` are written directly without type lookup, filtering or recursion guard checks,
+/// anything else falls back to `infer_serialize`.
+struct SerializePlainJson<'py> {
+    value: &'py PyAny,
+    extra: &'py Extra<'py>,
+    depth: u8,
+}
+
+impl<'py> SerializePlainJson<'py> {
+    fn is_plain_container(value: &PyAny) -> bool {
+        PyDict::is_exact_type_of(value) || PyList::is_exact_type_of(value)
+    }
+
+    fn nested(&self, value: &'py PyAny) -> Self {
+        Self {
+            value,
+            extra: self.extra,
+            depth: self.depth + 1,
+        }
+    }
+}
+
+impl<'py> Serialize for SerializePlainJson<'py> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let value = self.value;
+        if PyString::is_exact_type_of(value) {
+            // safety: exact type checked above
+            let py_str: &PyString = unsafe { value.downcast_unchecked() };
+            return super::type_serializers::string::serialize_py_str(py_str, serializer);
+        } else if value.is_none() {
+            return serializer.serialize_none();
+        } else if PyBool::is_exact_type_of(value) {
+            let py_bool: &PyBool = unsafe { value.downcast_unchecked() };
+            return serializer.serialize_bool(py_bool.is_true());
+        } else if PyLong::is_exact_type_of(value) {
+            // ints too large for i64 take the normal route
+            if let Ok(int) = value.extract::<i64>() {
+                return serializer.serialize_i64(int);
+            }
+        } else if PyFloat::is_exact_type_of(value) {
+            let py_float: &PyFloat = unsafe { value.downcast_unchecked() };
+            return serializer.serialize_f64(py_float.value());
+        } else if self.depth < MAX_PLAIN_JSON_DEPTH {
+            if PyDict::is_exact_type_of(value) {
+                let py_dict: &PyDict = unsafe { value.downcast_unchecked() };
+                let mut map = serializer.serialize_map(Some(py_dict.len()))?;
+                for (key, value) in py_dict {
+                    if PyString::is_exact_type_of(key) {
+                        let py_str: &PyString = unsafe { key.downcast_unchecked() };
+                        map.serialize_entry(py_str.to_str().map_err(py_err_se_err)?, &self.nested(value))?;
+                    } else {
+                        let key = infer_json_key(key, self.extra).map_err(py_err_se_err)?;
+                        map.serialize_entry(&key, &self.nested(value))?;
+                    }
+                }
+                return map.end();
+            } else if PyList::is_exact_type_of(value) {
+                let py_list: &PyList = unsafe { value.downcast_unchecked() };
+                let mut seq = serializer.serialize_seq(Some(py_list.len()))?;
+                for element in py_list {
+                    seq.serialize_element(&self.nested(element))?;
+                }
+                return seq.end();
+            }
+        }
+        infer_serialize(value, serializer, None, None, self.extra)
+    }
+}
+
 pub(crate) fn infer_serialize<S: Serializer>(
     value: &PyAny,
     serializer: S,
@@ -423,6 +497,16 @@ pub(crate) fn infer_serialize_known<S: Serializer>(
             let bytes = unsafe { py_byte_array.as_bytes() };
             extra.config.bytes_mode.serialize_bytes(bytes, serializer)
         }
+        // with `serialize_unknown`, cycles are written as "..." where they're first detected, so the fast path
+        // (which only checks for cycles every `MAX_PLAIN_JSON_DEPTH` levels) can't be used
+        ObType::Dict | ObType::List
+            if include.is_none()
+                && exclude.is_none()
+                && !extra.serialize_unknown
+                && SerializePlainJson::is_plain_container(value) =>
+        {
+            SerializePlainJson { value, extra, depth: 0 }.serialize(serializer)
+        }
         ObType::Dict => serialize_dict!(value.downcast::<PyDict>().map_err(py_err_se_err)?),
         ObType::List => serialize_seq_filter!(PyList),
         ObType::Tuple => serialize_seq_filter!(PyTuple),
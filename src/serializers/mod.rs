@@ -1,10 +1,14 @@
 use std::fmt::Debug;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
 
 use pyo3::prelude::*;
-use pyo3::types::{PyBytes, PyDict};
-use pyo3::{PyTraverseError, PyVisit};
+use pyo3::types::{PyDict, PyList};
+use pyo3::{intern, PyTraverseError, PyVisit};
 
 use crate::definitions::DefinitionsBuilder;
+use crate::memory::{definitions_size, MemoryUsage};
+use crate::tools::{deepcopy, safe_repr};
 use crate::validators::SelfValidator;
 
 use config::SerializationConfig;
@@ -12,7 +16,7 @@ pub use errors::{PydanticSerializationError, PydanticSerializationUnexpectedValu
 use extra::{CollectWarnings, SerRecursionGuard};
 pub(crate) use extra::{Extra, SerMode, SerializationState};
 pub use shared::CombinedSerializer;
-use shared::{to_json_bytes, BuildSerializer, TypeSerializer};
+use shared::{to_json_py_bytes, BuildSerializer, TypeSerializer};
 
 mod computed_fields;
 mod config;
@@ -26,15 +30,65 @@ mod shared;
 mod type_serializers;
 
 #[pyclass(module = "pydantic_core._pydantic_core")]
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 pub struct SchemaSerializer {
     serializer: CombinedSerializer,
-    definitions: Vec<CombinedSerializer>,
-    json_size: usize,
+    definitions: Vec<Arc<CombinedSerializer>>,
+    // the size of the last JSON output, used as the initial capacity for the next, atomic so `to_json` only needs a
+    // shared borrow and can run on several threads at once
+    json_size: AtomicUsize,
     config: SerializationConfig,
+    // the schema and config the serializer was built from, used for pickling
+    schema: Py<PyDict>,
+    core_config: Option<Py<PyDict>>,
+    #[pyo3(get)]
+    title: PyObject,
+}
+
+impl Clone for SchemaSerializer {
+    fn clone(&self) -> Self {
+        Self {
+            serializer: self.serializer.clone(),
+            definitions: self.definitions.clone(),
+            json_size: AtomicUsize::new(self.json_size.load(Ordering::Relaxed)),
+            config: self.config.clone(),
+            schema: self.schema.clone(),
+            core_config: self.core_config.clone(),
+            title: self.title.clone(),
+        }
+    }
 }
 
 impl SchemaSerializer {
+    fn memory_usage(&self) -> MemoryUsage {
+        let mut memory = MemoryUsage::default();
+        memory.visit(&[], &self.serializer);
+        for (index, definition) in self.definitions.iter().enumerate() {
+            memory.visit(&["definitions".into(), index.into()], definition.as_ref());
+        }
+        memory
+    }
+
+    /// The title, schema type and config shown by `repr`
+    fn repr_summary(&self, py: Python) -> String {
+        let schema_type = self
+            .schema
+            .as_ref(py)
+            .get_item(intern!(py, "type"))
+            .and_then(|t| t.extract::<&str>().ok())
+            .map_or_else(|| "None".to_string(), |t| format!("{t:?}"));
+        // the title is usually a string, but comes from the config as-is
+        let title = match self.title.extract::<&str>(py) {
+            Ok(title) => format!("{title:?}"),
+            Err(_) => safe_repr(self.title.as_ref(py)).into_owned(),
+        };
+        let config = self
+            .core_config
+            .as_ref()
+            .map_or("None".into(), |c| safe_repr(c.as_ref(py)));
+        format!("title={title}, schema_type={schema_type}, config={config}")
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub(crate) fn build_extra<'b, 'a: 'b>(
         &'b self,
@@ -73,18 +127,62 @@ impl SchemaSerializer {
     #[new]
     pub fn py_new(py: Python, schema: &PyDict, config: Option<&PyDict>) -> PyResult<Self> {
         let self_validator = SelfValidator::new(py)?;
-        let schema = self_validator.validate_schema(py, schema)?;
+        let schema: &PyDict = self_validator.validate_schema(py, schema)?.downcast()?;
         let mut definitions_builder = DefinitionsBuilder::new();
 
-        let serializer = CombinedSerializer::build(schema.downcast()?, config, &mut definitions_builder)?;
+        let serializer = CombinedSerializer::build(schema, config, &mut definitions_builder)?;
+        let title = match config.and_then(|c| c.get_item(intern!(py, "title"))) {
+            Some(t) => t.into_py(py),
+            None => serializer.get_name().into_py(py),
+        };
         Ok(Self {
             serializer,
             definitions: definitions_builder.finish()?,
-            json_size: 1024,
+            json_size: AtomicUsize::new(1024),
             config: SerializationConfig::from_config(config)?,
+            schema: schema.into_py(py),
+            core_config: config.map(|c| c.into_py(py)),
+            title,
         })
     }
 
+    pub fn __reduce__(slf: &PyCell<Self>) -> PyResult<PyObject> {
+        let py = slf.py();
+        let this = slf.try_borrow()?;
+        let args = (this.schema.as_ref(py), this.core_config.as_ref().map(|c| c.as_ref(py)));
+        Ok((slf.get_type(), args).into_py(py))
+    }
+
+    /// Rebuild the serializer from the same schema and config, objects within the schema (e.g. functions and
+    /// classes) are shared with the original rather than copied
+    pub fn __copy__(&self, py: Python) -> PyResult<Self> {
+        let serializer = Self::py_new(
+            py,
+            self.schema.as_ref(py),
+            self.core_config.as_ref().map(|c| c.as_ref(py)),
+        )?;
+        serializer
+            .json_size
+            .store(self.json_size.load(Ordering::Relaxed), Ordering::Relaxed);
+        Ok(serializer)
+    }
+
+    /// Rebuild the serializer from a deep copy of the schema and config, so objects within the schema
+    /// (e.g. functions and classes) follow `memo`
+    #[pyo3(signature = (memo, /))]
+    pub fn __deepcopy__(&self, py: Python, memo: &PyAny) -> PyResult<Self> {
+        let schema: &PyDict = deepcopy(self.schema.as_ref(py), memo)?.downcast()?;
+        let config = match self.core_config {
+            Some(ref config) => Some(deepcopy(config.as_ref(py), memo)?.downcast()?),
+            None => None,
+        };
+        let serializer = Self::py_new(py, schema, config)?;
+        serializer
+            .json_size
+            .store(self.json_size.load(Ordering::Relaxed), Ordering::Relaxed);
+        Ok(serializer)
+    }
+
     #[allow(clippy::too_many_arguments)]
     #[pyo3(signature = (value, *, mode = None, include = None, exclude = None, by_alias = true,
         exclude_unset = false, exclude_defaults = false, exclude_none = false, round_trip = false, warnings = true,
@@ -128,9 +226,9 @@ impl SchemaSerializer {
     #[allow(clippy::too_many_arguments)]
     #[pyo3(signature = (value, *, indent = None, include = None, exclude = None, by_alias = true,
         exclude_unset = false, exclude_defaults = false, exclude_none = false, round_trip = false, warnings = true,
-        fallback = None))]
+        fallback = None, capacity = None))]
     pub fn to_json(
-        &mut self,
+        &self,
         py: Python,
         value: &PyAny,
         indent: Option<usize>,
@@ -143,6 +241,7 @@ impl SchemaSerializer {
         round_trip: bool,
         warnings: bool,
         fallback: Option<&PyAny>,
+        capacity: Option<usize>,
     ) -> PyResult<PyObject> {
         let warnings = CollectWarnings::new(warnings);
         let rec_guard = SerRecursionGuard::default();
@@ -159,42 +258,69 @@ impl SchemaSerializer {
             false,
             fallback,
         );
-        let bytes = to_json_bytes(
+        let py_bytes = to_json_py_bytes(
+            py,
             value,
             &self.serializer,
             include,
             exclude,
             &extra,
             indent,
-            self.json_size,
+            capacity.unwrap_or_else(|| self.json_size.load(Ordering::Relaxed)),
         )?;
 
         warnings.final_check(py)?;
 
-        self.json_size = bytes.len();
-        let py_bytes = PyBytes::new(py, &bytes);
+        self.json_size.store(py_bytes.as_bytes().len(), Ordering::Relaxed);
         Ok(py_bytes.into())
     }
 
-    pub fn __repr__(&self) -> String {
+    /// Estimated memory used by each node of the built serializer, definitions are listed separately
+    pub fn memory_breakdown<'py>(&self, py: Python<'py>) -> PyResult<&'py PyList> {
+        self.memory_usage().to_list(py)
+    }
+
+    pub fn __sizeof__(&self) -> usize {
+        std::mem::size_of::<PyCell<Self>>() + definitions_size(&self.definitions) + self.memory_usage().total()
+    }
+
+    pub fn __repr__(&self, py: Python) -> String {
+        format!("SchemaSerializer({})", self.repr_summary(py))
+    }
+
+    /// `repr` including the whole built serializer and its definitions, for debugging
+    pub fn debug_repr(&self, py: Python) -> String {
         format!(
-            "SchemaSerializer(serializer={:#?}, definitions={:#?})",
-            self.serializer, self.definitions
+            "SchemaSerializer({}, serializer={:#?}, definitions={:#?})",
+            self.repr_summary(py),
+            self.serializer,
+            self.definitions,
         )
     }
 
     fn __traverse__(&self, visit: PyVisit<'_>) -> Result<(), PyTraverseError> {
         self.serializer.py_gc_traverse(&visit)?;
+        visit.call(&self.schema)?;
+        if let Some(ref config) = self.core_config {
+            visit.call(config)?;
+        }
+        visit.call(&self.title)?;
         for slot in &self.definitions {
             slot.py_gc_traverse(&visit)?;
         }
         Ok(())
     }
 
+    /// No serializer releases anything in `py_gc_clear`, cycles through a serializer are broken by the garbage
+    /// collector clearing the Python objects in them instead, e.g. the `__dict__` of a model class whose serializer
+    /// refers back to it. Definitions which are also held by a serializer function's handler or a generator still
+    /// being serialized can't be borrowed mutably and are skipped, which loses nothing for the same reason.
     fn __clear__(&mut self) {
         self.serializer.py_gc_clear();
         for slot in &mut self.definitions {
-            slot.py_gc_clear();
+            if let Some(slot) = Arc::get_mut(slot) {
+                slot.py_gc_clear();
+            }
         }
     }
 }
@@ -203,7 +329,7 @@ impl SchemaSerializer {
 #[pyfunction]
 #[pyo3(signature = (value, *, indent = None, include = None, exclude = None, by_alias = true,
     exclude_none = false, round_trip = false, timedelta_mode = "iso8601", bytes_mode = "utf8",
-    serialize_unknown = false, fallback = None))]
+    serialize_unknown = false, fallback = None, capacity = None))]
 pub fn to_json(
     py: Python,
     value: &PyAny,
@@ -217,6 +343,7 @@ pub fn to_json(
     bytes_mode: &str,
     serialize_unknown: bool,
     fallback: Option<&PyAny>,
+    capacity: Option<usize>,
 ) -> PyResult<PyObject> {
     let state = SerializationState::new(timedelta_mode, bytes_mode)?;
     let extra = state.extra(
@@ -229,9 +356,9 @@ pub fn to_json(
         fallback,
     );
     let serializer = type_serializers::any::AnySerializer::default().into();
-    let bytes = to_json_bytes(value, &serializer, include, exclude, &extra, indent, 1024)?;
+    let capacity = capacity.unwrap_or(1024);
+    let py_bytes = to_json_py_bytes(py, value, &serializer, include, exclude, &extra, indent, capacity)?;
     state.final_check(py)?;
-    let py_bytes = PyBytes::new(py, &bytes);
     Ok(py_bytes.into())
 }
 
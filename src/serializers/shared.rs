@@ -1,10 +1,10 @@
 use std::borrow::Cow;
+use std::cell::RefCell;
 use std::fmt::Debug;
 
 use pyo3::exceptions::PyTypeError;
-use pyo3::once_cell::GILOnceCell;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyString};
+use pyo3::types::{PyBytes, PyDict};
 use pyo3::{intern, PyTraverseError, PyVisit};
 
 use enum_dispatch::enum_dispatch;
@@ -14,7 +14,9 @@ use serde_json::ser::PrettyFormatter;
 use crate::build_tools::py_schema_err;
 use crate::build_tools::py_schema_error_type;
 use crate::definitions::DefinitionsBuilder;
+use crate::memory::{MemoryNode, MemoryUsage};
 use crate::tools::{py_err, SchemaDict};
+use crate::validators::registered_builder;
 
 use super::errors::se_err_py_err;
 use super::extra::Extra;
@@ -66,6 +68,10 @@ macro_rules! combined_serializer {
                             Err(err) => py_schema_err!("Error building `{}` serializer:\n  {}", lookup_type, err),
                         },
                     )*
+                    // schema types registered with `register_validator` are serialized by inference
+                    _ if registered_builder(schema.py(), lookup_type).is_some() => {
+                        super::type_serializers::any::AnySerializer::build(schema, config, definitions)
+                    }
                     _ => py_schema_err!("Unknown serialization schema type: `{}`", lookup_type),
                 }
             }
@@ -96,6 +102,7 @@ combined_serializer! {
         super::type_serializers::other::IsInstanceBuilder;
         super::type_serializers::other::IsSubclassBuilder;
         super::type_serializers::other::CallableBuilder;
+        super::type_serializers::other::CounterBuilder;
         super::type_serializers::definitions::DefinitionsSerializerBuilder;
         super::type_serializers::dataclass::DataclassArgsBuilder;
         super::type_serializers::function::FunctionBeforeSerializerBuilder;
@@ -265,6 +272,21 @@ pub(crate) trait TypeSerializer: Send + Sync + Clone + Debug {
     fn get_default(&self, _py: Python) -> PyResult<Option<PyObject>> {
         Ok(None)
     }
+
+    /// Estimated heap memory owned by this serializer in bytes, see `MemoryNode::memory_usage`
+    fn memory_usage(&self, _memory: &mut MemoryUsage) -> usize {
+        0
+    }
+}
+
+impl MemoryNode for CombinedSerializer {
+    fn node_name(&self) -> &str {
+        self.get_name()
+    }
+
+    fn memory_usage(&self, memory: &mut MemoryUsage) -> usize {
+        TypeSerializer::memory_usage(self, memory)
+    }
 }
 
 pub(crate) struct PydanticSerializer<'py> {
@@ -309,10 +331,61 @@ pub(crate) fn to_json_bytes(
     extra: &Extra,
     indent: Option<usize>,
     json_size: usize,
+) -> PyResult<Vec<u8>> {
+    let writer: Vec<u8> = Vec::with_capacity(json_size);
+    write_json(writer, value, serializer, include, exclude, extra, indent)
+}
+
+/// Buffers larger than this aren't kept for reuse, so one huge document doesn't pin memory for the life of the thread
+const MAX_REUSED_JSON_BUFFER: usize = 8 * 1024 * 1024;
+
+thread_local! {
+    static JSON_BUFFER: RefCell<Vec<u8>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Like `to_json_bytes` but writes into a buffer reused between calls on the same thread,
+/// the output is copied out into a new `bytes` object.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn to_json_py_bytes<'py>(
+    py: Python<'py>,
+    value: &PyAny,
+    serializer: &CombinedSerializer,
+    include: Option<&PyAny>,
+    exclude: Option<&PyAny>,
+    extra: &Extra,
+    indent: Option<usize>,
+    capacity: usize,
+) -> PyResult<&'py PyBytes> {
+    // take the buffer rather than borrowing it, so nested calls (e.g. from a `fallback` function) just
+    // get a new empty buffer
+    let mut writer = JSON_BUFFER.with(RefCell::take);
+    // the capacity is only a hint, so a huge one mustn't abort with an allocation failure
+    writer.reserve(capacity.min(MAX_REUSED_JSON_BUFFER));
+    let mut bytes = write_json(writer, value, serializer, include, exclude, extra, indent)?;
+    let py_bytes = PyBytes::new(py, &bytes);
+    if bytes.capacity() <= MAX_REUSED_JSON_BUFFER {
+        bytes.clear();
+        JSON_BUFFER.with(|buffer| {
+            let mut buffer = buffer.borrow_mut();
+            if bytes.capacity() > buffer.capacity() {
+                *buffer = bytes;
+            }
+        });
+    }
+    Ok(py_bytes)
+}
+
+fn write_json(
+    writer: Vec<u8>,
+    value: &PyAny,
+    serializer: &CombinedSerializer,
+    include: Option<&PyAny>,
+    exclude: Option<&PyAny>,
+    extra: &Extra,
+    indent: Option<usize>,
 ) -> PyResult<Vec<u8>> {
     let serializer = PydanticSerializer::new(value, serializer, include, exclude, extra);
 
-    let writer: Vec<u8> = Vec::with_capacity(json_size);
     let bytes = match indent {
         Some(indent) => {
             let indent = vec![b' '; indent];
@@ -329,30 +402,3 @@ pub(crate) fn to_json_bytes(
     };
     Ok(bytes)
 }
-
-static DC_FIELD_MARKER: GILOnceCell<PyObject> = GILOnceCell::new();
-
-/// needed to match the logic from dataclasses.fields `tuple(f for f in fields.values() if f._field_type is _FIELD)`
-pub(super) fn get_field_marker(py: Python<'_>) -> PyResult<&PyAny> {
-    let field_type_marker_obj = DC_FIELD_MARKER.get_or_try_init(py, || {
-        let field_ = py.import("dataclasses")?.getattr("_FIELD")?;
-        Ok::<PyObject, PyErr>(field_.into_py(py))
-    })?;
-    Ok(field_type_marker_obj.as_ref(py))
-}
-
-pub(super) fn dataclass_to_dict(dc: &PyAny) -> PyResult<&PyDict> {
-    let py = dc.py();
-    let dc_fields: &PyDict = dc.getattr(intern!(py, "__dataclass_fields__"))?.downcast()?;
-    let dict = PyDict::new(py);
-
-    let field_type_marker = get_field_marker(py)?;
-    for (field_name, field) in dc_fields.iter() {
-        let field_type = field.getattr(intern!(py, "_field_type"))?;
-        if field_type.is(field_type_marker) {
-            let field_name: &PyString = field_name.downcast()?;
-            dict.set_item(field_name, dc.getattr(field_name)?)?;
-        }
-    }
-    Ok(dict)
-}
@@ -7,6 +7,7 @@ use ahash::AHashMap;
 
 use crate::build_tools::{py_schema_error_type, ExtraBehavior};
 use crate::definitions::DefinitionsBuilder;
+use crate::memory::{HeapSize, MemoryUsage};
 use crate::tools::SchemaDict;
 
 use super::{
@@ -186,4 +187,10 @@ impl TypeSerializer for DataclassSerializer {
     fn retry_with_lax_check(&self) -> bool {
         true
     }
+
+    fn memory_usage(&self, memory: &mut MemoryUsage) -> usize {
+        memory.visit_boxed(&["schema".into()], self.serializer.as_ref())
+            + self.fields.heap_size()
+            + self.name.heap_size()
+    }
 }
@@ -1,12 +1,13 @@
 use std::borrow::Cow;
 
-use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
+use pyo3::{intern, PyTraverseError, PyVisit};
 
 use serde::ser::SerializeMap;
 
 use crate::definitions::DefinitionsBuilder;
+use crate::memory::{HeapSize, MemoryUsage};
 use crate::tools::SchemaDict;
 
 use super::any::AnySerializer;
@@ -66,6 +67,12 @@ impl BuildSerializer for DictSerializer {
 }
 
 impl TypeSerializer for DictSerializer {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        self.key_serializer.py_gc_traverse(visit)?;
+        self.value_serializer.py_gc_traverse(visit)?;
+        Ok(())
+    }
+
     fn to_python(
         &self,
         value: &PyAny,
@@ -138,4 +145,11 @@ impl TypeSerializer for DictSerializer {
     fn get_name(&self) -> &str {
         &self.name
     }
+
+    fn memory_usage(&self, memory: &mut MemoryUsage) -> usize {
+        memory.visit_boxed(&["keys_schema".into()], self.key_serializer.as_ref())
+            + memory.visit_boxed(&["values_schema".into()], self.value_serializer.as_ref())
+            + self.filter.heap_size()
+            + self.name.heap_size()
+    }
 }
@@ -1,8 +1,8 @@
 use std::borrow::Cow;
 
-use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyString};
+use pyo3::{intern, PyTraverseError, PyVisit};
 
 use serde::ser::Error;
 
@@ -105,6 +105,10 @@ impl FormatSerializer {
 }
 
 impl TypeSerializer for FormatSerializer {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        visit.call(&self.format_func)
+    }
+
     fn to_python(
         &self,
         value: &PyAny,
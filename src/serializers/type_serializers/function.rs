@@ -1,13 +1,14 @@
 use std::borrow::Cow;
 
 use pyo3::exceptions::{PyAttributeError, PyRecursionError, PyRuntimeError};
-use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
+use pyo3::{intern, PyTraverseError, PyVisit};
 
 use pyo3::types::PyString;
 
 use crate::definitions::DefinitionsBuilder;
+use crate::memory::{HeapSize, MemoryUsage};
 use crate::tools::SchemaDict;
 use crate::tools::{function_name, py_err, py_error_type};
 use crate::{PydanticOmit, PydanticSerializationUnexpectedValue};
@@ -201,6 +202,12 @@ fn on_error(py: Python, err: PyErr, function_name: &str, extra: &Extra) -> PyRes
 macro_rules! function_type_serializer {
     ($name:ident) => {
         impl TypeSerializer for $name {
+            fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+                visit.call(&self.func)?;
+                self.return_serializer.py_gc_traverse(visit)?;
+                self.child_py_gc_traverse(visit)
+            }
+
             fn to_python(
                 &self,
                 value: &PyAny,
@@ -265,10 +272,33 @@ macro_rules! function_type_serializer {
             fn get_name(&self) -> &str {
                 &self.name
             }
+
+            fn memory_usage(&self, memory: &mut MemoryUsage) -> usize {
+                memory.visit_boxed(&["return_schema".into()], self.return_serializer.as_ref())
+                    + self.child_memory_usage(memory)
+                    + self.name.heap_size()
+                    + self.function_name.heap_size()
+            }
         }
     };
 }
 
+impl FunctionPlainSerializer {
+    fn child_py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        match self.fallback_serializer {
+            Some(ref serializer) => serializer.py_gc_traverse(visit),
+            None => Ok(()),
+        }
+    }
+
+    fn child_memory_usage(&self, memory: &mut MemoryUsage) -> usize {
+        match self.fallback_serializer {
+            Some(ref serializer) => memory.visit_boxed(&["schema".into()], serializer.as_ref()),
+            None => 0,
+        }
+    }
+}
+
 function_type_serializer!(FunctionPlainSerializer);
 
 fn copy_outer_schema(schema: &PyDict) -> PyResult<&PyDict> {
@@ -399,6 +429,16 @@ impl FunctionWrapSerializer {
     }
 }
 
+impl FunctionWrapSerializer {
+    fn child_py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        self.serializer.py_gc_traverse(visit)
+    }
+
+    fn child_memory_usage(&self, memory: &mut MemoryUsage) -> usize {
+        memory.visit_boxed(&["schema".into()], self.serializer.as_ref())
+    }
+}
+
 function_type_serializer!(FunctionWrapSerializer);
 
 #[pyclass(module = "pydantic_core._pydantic_core")]
@@ -1,12 +1,13 @@
 use std::borrow::Cow;
 
-use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyIterator};
+use pyo3::{intern, PyTraverseError, PyVisit};
 
 use serde::ser::SerializeSeq;
 
 use crate::definitions::DefinitionsBuilder;
+use crate::memory::{HeapSize, MemoryUsage};
 use crate::tools::SchemaDict;
 
 use super::any::AnySerializer;
@@ -43,6 +44,10 @@ impl BuildSerializer for GeneratorSerializer {
 }
 
 impl TypeSerializer for GeneratorSerializer {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        self.item_serializer.py_gc_traverse(visit)
+    }
+
     fn to_python(
         &self,
         value: &PyAny,
@@ -135,6 +140,10 @@ impl TypeSerializer for GeneratorSerializer {
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
+
+    fn memory_usage(&self, memory: &mut MemoryUsage) -> usize {
+        memory.visit_boxed(&["items_schema".into()], self.item_serializer.as_ref()) + self.filter.heap_size()
+    }
 }
 
 #[pyclass(module = "pydantic_core._pydantic_core")]
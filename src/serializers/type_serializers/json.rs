@@ -1,13 +1,14 @@
 use std::borrow::Cow;
 use std::str::from_utf8;
 
-use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
+use pyo3::{intern, PyTraverseError, PyVisit};
 
 use serde::ser::Error;
 
 use crate::definitions::DefinitionsBuilder;
+use crate::memory::MemoryUsage;
 use crate::tools::SchemaDict;
 
 use super::any::AnySerializer;
@@ -43,6 +44,10 @@ impl BuildSerializer for JsonSerializer {
 }
 
 impl TypeSerializer for JsonSerializer {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        self.serializer.py_gc_traverse(visit)
+    }
+
     fn to_python(
         &self,
         value: &PyAny,
@@ -95,4 +100,8 @@ impl TypeSerializer for JsonSerializer {
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
+
+    fn memory_usage(&self, memory: &mut MemoryUsage) -> usize {
+        memory.visit_boxed(&["schema".into()], self.serializer.as_ref())
+    }
 }
@@ -1,11 +1,12 @@
 use std::borrow::Cow;
 
-use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
+use pyo3::{intern, PyTraverseError, PyVisit};
 
 use super::{BuildSerializer, CombinedSerializer, Extra, TypeSerializer};
 use crate::definitions::DefinitionsBuilder;
+use crate::memory::{HeapSize, MemoryUsage};
 use crate::tools::SchemaDict;
 
 #[derive(Debug, Clone)]
@@ -46,6 +47,12 @@ impl BuildSerializer for JsonOrPythonSerializer {
 }
 
 impl TypeSerializer for JsonOrPythonSerializer {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        self.json.py_gc_traverse(visit)?;
+        self.python.py_gc_traverse(visit)?;
+        Ok(())
+    }
+
     fn to_python(
         &self,
         value: &PyAny,
@@ -74,4 +81,10 @@ impl TypeSerializer for JsonOrPythonSerializer {
     fn get_name(&self) -> &str {
         &self.name
     }
+
+    fn memory_usage(&self, memory: &mut MemoryUsage) -> usize {
+        memory.visit_boxed(&["json_schema".into()], self.json.as_ref())
+            + memory.visit_boxed(&["python_schema".into()], self.python.as_ref())
+            + self.name.heap_size()
+    }
 }
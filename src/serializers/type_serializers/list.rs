@@ -1,12 +1,13 @@
 use std::borrow::Cow;
 
-use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList};
+use pyo3::{intern, PyTraverseError, PyVisit};
 
 use serde::ser::SerializeSeq;
 
 use crate::definitions::DefinitionsBuilder;
+use crate::memory::{HeapSize, MemoryUsage};
 use crate::tools::SchemaDict;
 
 use super::any::AnySerializer;
@@ -46,6 +47,10 @@ impl BuildSerializer for ListSerializer {
 }
 
 impl TypeSerializer for ListSerializer {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        self.item_serializer.py_gc_traverse(visit)
+    }
+
     fn to_python(
         &self,
         value: &PyAny,
@@ -114,4 +119,10 @@ impl TypeSerializer for ListSerializer {
     fn get_name(&self) -> &str {
         &self.name
     }
+
+    fn memory_usage(&self, memory: &mut MemoryUsage) -> usize {
+        memory.visit_boxed(&["items_schema".into()], self.item_serializer.as_ref())
+            + self.filter.heap_size()
+            + self.name.heap_size()
+    }
 }
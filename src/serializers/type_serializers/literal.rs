@@ -3,12 +3,14 @@ use std::borrow::Cow;
 use pyo3::prelude::*;
 use pyo3::types::{PyBool, PyDict, PyList, PyString};
 use pyo3::{intern, PyTypeInfo};
+use pyo3::{PyTraverseError, PyVisit};
 
 use ahash::AHashSet;
 use serde::Serialize;
 
 use crate::build_tools::py_schema_err;
 use crate::definitions::DefinitionsBuilder;
+use crate::memory::{HeapSize, MemoryUsage};
 use crate::tools::{extract_i64, SchemaDict};
 
 use super::{
@@ -105,6 +107,13 @@ impl LiteralSerializer {
 }
 
 impl TypeSerializer for LiteralSerializer {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        if let Some(ref expected_py) = self.expected_py {
+            visit.call(expected_py)?;
+        }
+        Ok(())
+    }
+
     fn to_python(
         &self,
         value: &PyAny,
@@ -164,4 +173,8 @@ impl TypeSerializer for LiteralSerializer {
     fn get_name(&self) -> &str {
         &self.name
     }
+
+    fn memory_usage(&self, _memory: &mut MemoryUsage) -> usize {
+        self.expected_int.heap_size() + self.expected_str.heap_size() + self.name.heap_size()
+    }
 }
@@ -13,6 +13,7 @@ use super::{
 };
 use crate::build_tools::{py_schema_error_type, ExtraBehavior};
 use crate::definitions::DefinitionsBuilder;
+use crate::memory::{HeapSize, MemoryUsage};
 use crate::serializers::errors::PydanticSerializationUnexpectedValue;
 use crate::tools::SchemaDict;
 
@@ -228,4 +229,8 @@ impl TypeSerializer for ModelSerializer {
     fn retry_with_lax_check(&self) -> bool {
         true
     }
+
+    fn memory_usage(&self, memory: &mut MemoryUsage) -> usize {
+        memory.visit_boxed(&["schema".into()], self.serializer.as_ref()) + self.name.heap_size()
+    }
 }
@@ -1,10 +1,11 @@
 use std::borrow::Cow;
 
-use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
+use pyo3::{intern, PyTraverseError, PyVisit};
 
 use crate::definitions::DefinitionsBuilder;
+use crate::memory::MemoryUsage;
 use crate::tools::SchemaDict;
 
 use super::{infer_json_key_known, BuildSerializer, CombinedSerializer, Extra, IsType, ObType, TypeSerializer};
@@ -31,6 +32,10 @@ impl BuildSerializer for NullableSerializer {
 }
 
 impl TypeSerializer for NullableSerializer {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        self.serializer.py_gc_traverse(visit)
+    }
+
     fn to_python(
         &self,
         value: &PyAny,
@@ -76,4 +81,8 @@ impl TypeSerializer for NullableSerializer {
     fn retry_with_lax_check(&self) -> bool {
         self.serializer.retry_with_lax_check()
     }
+
+    fn memory_usage(&self, memory: &mut MemoryUsage) -> usize {
+        memory.visit_boxed(&["schema".into()], self.serializer.as_ref())
+    }
 }
@@ -7,6 +7,7 @@ use crate::definitions::DefinitionsBuilder;
 use crate::tools::SchemaDict;
 
 use super::any::AnySerializer;
+use super::dict::DictSerializer;
 use super::{BuildSerializer, CombinedSerializer};
 
 pub struct ChainBuilder;
@@ -91,6 +92,30 @@ impl BuildSerializer for ArgumentsBuilder {
     }
 }
 
+pub struct CounterBuilder;
+
+impl BuildSerializer for CounterBuilder {
+    const EXPECTED_TYPE: &'static str = "counter";
+
+    /// Counters are serialized as a dict of items to their integer counts
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        definitions: &mut DefinitionsBuilder<CombinedSerializer>,
+    ) -> PyResult<CombinedSerializer> {
+        let py = schema.py();
+        let dict_schema = PyDict::new(py);
+        dict_schema.set_item(intern!(py, "type"), intern!(py, "dict"))?;
+        if let Some(items_schema) = schema.get_item(intern!(py, "items_schema")) {
+            dict_schema.set_item(intern!(py, "keys_schema"), items_schema)?;
+        }
+        let int_schema = PyDict::new(py);
+        int_schema.set_item(intern!(py, "type"), intern!(py, "int"))?;
+        dict_schema.set_item(intern!(py, "values_schema"), int_schema)?;
+        DictSerializer::build(dict_schema, config, definitions)
+    }
+}
+
 macro_rules! any_build_serializer {
     ($struct_name:ident, $expected_type:literal) => {
         pub struct $struct_name;
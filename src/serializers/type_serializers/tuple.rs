@@ -1,11 +1,13 @@
-use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList, PyTuple};
+use pyo3::{intern, PyTraverseError, PyVisit};
 use std::borrow::Cow;
 
 use serde::ser::SerializeSeq;
 
+use crate::build_tools::tuple_positional_items;
 use crate::definitions::DefinitionsBuilder;
+use crate::memory::{HeapSize, MemoryUsage};
 use crate::tools::SchemaDict;
 
 use super::any::AnySerializer;
@@ -48,6 +50,10 @@ impl BuildSerializer for TupleVariableSerializer {
 }
 
 impl TypeSerializer for TupleVariableSerializer {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        self.item_serializer.py_gc_traverse(visit)
+    }
+
     fn to_python(
         &self,
         value: &PyAny,
@@ -136,6 +142,12 @@ impl TypeSerializer for TupleVariableSerializer {
     fn get_name(&self) -> &str {
         &self.name
     }
+
+    fn memory_usage(&self, memory: &mut MemoryUsage) -> usize {
+        memory.visit_boxed(&["items_schema".into()], self.item_serializer.as_ref())
+            + self.filter.heap_size()
+            + self.name.heap_size()
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -154,15 +166,14 @@ impl BuildSerializer for TuplePositionalSerializer {
         config: Option<&PyDict>,
         definitions: &mut DefinitionsBuilder<CombinedSerializer>,
     ) -> PyResult<CombinedSerializer> {
-        let py = schema.py();
-        let items: &PyList = schema.get_as_req(intern!(py, "items_schema"))?;
+        let (items, extra_schema) = tuple_positional_items(schema)?;
 
-        let extra_serializer = match schema.get_as::<&PyDict>(intern!(py, "extra_schema"))? {
-            Some(extra_schema) => CombinedSerializer::build(extra_schema, config, definitions)?,
+        let extra_serializer = match extra_schema {
+            Some(extra_schema) => CombinedSerializer::build(extra_schema.downcast()?, config, definitions)?,
             None => AnySerializer::build(schema, config, definitions)?,
         };
         let items_serializers: Vec<CombinedSerializer> = items
-            .iter()
+            .into_iter()
             .map(|item| CombinedSerializer::build(item.downcast()?, config, definitions))
             .collect::<PyResult<_>>()?;
 
@@ -182,6 +193,14 @@ impl BuildSerializer for TuplePositionalSerializer {
 }
 
 impl TypeSerializer for TuplePositionalSerializer {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        for serializer in &self.items_serializers {
+            serializer.py_gc_traverse(visit)?;
+        }
+        self.extra_serializer.py_gc_traverse(visit)?;
+        Ok(())
+    }
+
     fn to_python(
         &self,
         value: &PyAny,
@@ -314,6 +333,13 @@ impl TypeSerializer for TuplePositionalSerializer {
     fn get_name(&self) -> &str {
         &self.name
     }
+
+    fn memory_usage(&self, memory: &mut MemoryUsage) -> usize {
+        memory.visit_vec("items_schema", &self.items_serializers)
+            + memory.visit_boxed(&["extra_schema".into()], self.extra_serializer.as_ref())
+            + self.filter.heap_size()
+            + self.name.heap_size()
+    }
 }
 
 pub(crate) struct KeyBuilder {
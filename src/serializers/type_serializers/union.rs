@@ -1,10 +1,11 @@
-use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList};
+use pyo3::{intern, PyTraverseError, PyVisit};
 use std::borrow::Cow;
 
 use crate::build_tools::py_schema_err;
 use crate::definitions::DefinitionsBuilder;
+use crate::memory::{HeapSize, MemoryUsage};
 use crate::tools::SchemaDict;
 use crate::PydanticSerializationUnexpectedValue;
 
@@ -60,6 +61,13 @@ impl UnionSerializer {
 }
 
 impl TypeSerializer for UnionSerializer {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        for choice in &self.choices {
+            choice.py_gc_traverse(visit)?;
+        }
+        Ok(())
+    }
+
     fn to_python(
         &self,
         value: &PyAny,
@@ -169,6 +177,10 @@ impl TypeSerializer for UnionSerializer {
     fn retry_with_lax_check(&self) -> bool {
         self.choices.iter().any(TypeSerializer::retry_with_lax_check)
     }
+
+    fn memory_usage(&self, memory: &mut MemoryUsage) -> usize {
+        memory.visit_vec("choices", &self.choices) + self.name.heap_size()
+    }
 }
 
 pub struct TaggedUnionBuilder;
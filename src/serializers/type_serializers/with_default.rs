@@ -1,10 +1,11 @@
 use std::borrow::Cow;
 
-use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
+use pyo3::{intern, PyTraverseError, PyVisit};
 
 use crate::definitions::DefinitionsBuilder;
+use crate::memory::MemoryUsage;
 use crate::tools::SchemaDict;
 use crate::validators::DefaultType;
 
@@ -35,6 +36,12 @@ impl BuildSerializer for WithDefaultSerializer {
 }
 
 impl TypeSerializer for WithDefaultSerializer {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        self.default.py_gc_traverse(visit)?;
+        self.serializer.py_gc_traverse(visit)?;
+        Ok(())
+    }
+
     fn to_python(
         &self,
         value: &PyAny,
@@ -72,4 +79,8 @@ impl TypeSerializer for WithDefaultSerializer {
     fn get_default(&self, py: Python) -> PyResult<Option<PyObject>> {
         self.default.default_value(py)
     }
+
+    fn memory_usage(&self, memory: &mut MemoryUsage) -> usize {
+        memory.visit_boxed(&["schema".into()], self.serializer.as_ref())
+    }
 }
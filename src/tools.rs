@@ -1,6 +1,7 @@
 use std::borrow::Cow;
 
 use pyo3::exceptions::{PyKeyError, PyTypeError};
+use pyo3::once_cell::GILOnceCell;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyInt, PyString};
 use pyo3::{intern, FromPyObject, PyTypeInfo};
@@ -106,3 +107,42 @@ pub fn extract_i64(v: &PyAny) -> PyResult<i64> {
         py_err!(PyTypeError; "expected int, got {}", safe_repr(v))
     }
 }
+
+static DC_FIELD_MARKER: GILOnceCell<PyObject> = GILOnceCell::new();
+
+/// needed to match the logic from dataclasses.fields `tuple(f for f in fields.values() if f._field_type is _FIELD)`
+fn get_field_marker(py: Python<'_>) -> PyResult<&PyAny> {
+    let field_type_marker_obj = DC_FIELD_MARKER.get_or_try_init(py, || {
+        let field_ = py.import("dataclasses")?.getattr("_FIELD")?;
+        Ok::<PyObject, PyErr>(field_.into_py(py))
+    })?;
+    Ok(field_type_marker_obj.as_ref(py))
+}
+
+pub fn dataclass_to_dict(dc: &PyAny) -> PyResult<&PyDict> {
+    let py = dc.py();
+    let dc_fields: &PyDict = dc.getattr(intern!(py, "__dataclass_fields__"))?.downcast()?;
+    let dict = PyDict::new(py);
+
+    let field_type_marker = get_field_marker(py)?;
+    for (field_name, field) in dc_fields.iter() {
+        let field_type = field.getattr(intern!(py, "_field_type"))?;
+        if field_type.is(field_type_marker) {
+            let field_name: &PyString = field_name.downcast()?;
+            dict.set_item(field_name, dc.getattr(field_name)?)?;
+        }
+    }
+    Ok(dict)
+}
+
+static DEEPCOPY: GILOnceCell<PyObject> = GILOnceCell::new();
+
+/// `copy.deepcopy(value, memo)`
+pub fn deepcopy<'py>(value: &'py PyAny, memo: &PyAny) -> PyResult<&'py PyAny> {
+    let py = value.py();
+    let deepcopy = DEEPCOPY.get_or_try_init(py, || {
+        let deepcopy = py.import("copy")?.getattr("deepcopy")?;
+        Ok::<PyObject, PyErr>(deepcopy.into_py(py))
+    })?;
+    deepcopy.as_ref(py).call1((value, memo))
+}
@@ -1,13 +1,17 @@
+use std::borrow::Cow;
 use std::collections::hash_map::DefaultHasher;
 use std::hash::{Hash, Hasher};
 
 use idna::punycode::decode_to_string;
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::intern;
 use pyo3::once_cell::GILOnceCell;
 use pyo3::prelude::*;
 use pyo3::pyclass::CompareOp;
-use pyo3::types::PyDict;
-use url::Url;
+use pyo3::types::{PyDict, PyList, PyType};
+use url::{Position, Url};
 
+use crate::tools::SchemaDict;
 use crate::SchemaValidator;
 
 static SCHEMA_DEFINITION_URL: GILOnceCell<SchemaValidator> = GILOnceCell::new();
@@ -32,7 +36,7 @@ impl PyUrl {
 fn build_schema_validator(py: Python, schema_type: &str) -> SchemaValidator {
     let schema: &PyDict = PyDict::new(py);
     schema.set_item("type", schema_type).unwrap();
-    SchemaValidator::py_new(py, schema, None).unwrap()
+    SchemaValidator::py_new(py, schema, None, false).unwrap()
 }
 
 #[pymethods]
@@ -147,8 +151,80 @@ impl PyUrl {
         self.clone().into_py(py)
     }
 
-    fn __getnewargs__(&self) -> (&str,) {
-        (self.__str__(),)
+    pub fn __reduce__(slf: &PyCell<Self>) -> PyResult<(&PyType, (String,))> {
+        Ok((slf.get_type(), (slf.try_borrow()?.__str__().to_string(),)))
+    }
+
+    /// Build a URL from its components, percent-encoding them where required, the result is validated
+    /// like any other URL string passed to `cls`
+    #[classmethod]
+    #[pyo3(signature = (
+        *, scheme, host, username=None, password=None, port=None, path=None, query=None, fragment=None
+    ))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn build<'a>(
+        cls: &'a PyType,
+        scheme: &str,
+        host: &str,
+        username: Option<&str>,
+        password: Option<&str>,
+        port: Option<u16>,
+        path: Option<&str>,
+        query: Option<&str>,
+        fragment: Option<&str>,
+    ) -> PyResult<&'a PyAny> {
+        let mut url = build_host_url(scheme, host, username, password, port)?;
+        if let Some(path) = path {
+            url.set_path(path);
+        }
+        url.set_query(query);
+        url.set_fragment(fragment);
+        cls.call1((url.as_str(),))
+    }
+}
+
+/// Build a URL with just a scheme and authority, the userinfo is percent-encoded
+fn build_host_url(
+    scheme: &str,
+    host: &str,
+    username: Option<&str>,
+    password: Option<&str>,
+    port: Option<u16>,
+) -> PyResult<Url> {
+    let mut url = parse_scheme_host(scheme, host)?;
+    if let Some(username) = username {
+        url.set_username(username)
+            .map_err(|_| PyValueError::new_err("a username can't be set on this URL"))?;
+    }
+    if password.is_some() {
+        url.set_password(password)
+            .map_err(|_| PyValueError::new_err("a password can't be set on this URL"))?;
+    }
+    if port.is_some() {
+        url.set_port(port)
+            .map_err(|_| PyValueError::new_err("a port can't be set on this URL"))?;
+    }
+    Ok(url)
+}
+
+/// Parse a URL with just a scheme and host, IPv6 addresses may be given with or without brackets
+fn parse_scheme_host(scheme: &str, host: &str) -> PyResult<Url> {
+    let host = match host.contains(':') && !host.starts_with('[') {
+        true => Cow::Owned(format!("[{host}]")),
+        false => Cow::Borrowed(host),
+    };
+    let url = Url::parse(&format!("{scheme}://{host}"))
+        .map_err(|err| PyValueError::new_err(format!("Invalid scheme {scheme:?} or host {host:?}: {err}")))?;
+    // anything which isn't part of the host, e.g. a `/` or `@`, would change the meaning of the URL
+    let host_only = url.username().is_empty()
+        && url.port().is_none()
+        && matches!(url.path(), "" | "/")
+        && url.query().is_none()
+        && url.fragment().is_none();
+    if url.has_host() && host_only {
+        Ok(url)
+    } else {
+        Err(PyValueError::new_err(format!("Invalid host {host:?}")))
     }
 }
 
@@ -311,9 +387,103 @@ impl PyMultiHostUrl {
         self.clone().into_py(py)
     }
 
-    fn __getnewargs__(&self) -> (String,) {
-        (self.__str__(),)
+    pub fn __reduce__(slf: &PyCell<Self>) -> PyResult<(&PyType, (String,))> {
+        Ok((slf.get_type(), (slf.try_borrow()?.__str__(),)))
+    }
+
+    /// Build a URL from its components, each host is a dict with a `host` and optionally a `port`, `username` and
+    /// `password`, the result is validated like any other URL string passed to `cls`
+    #[classmethod]
+    #[pyo3(signature = (*, scheme, hosts=None, path=None, query=None, query_params=None, fragment=None))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn build<'a>(
+        cls: &'a PyType,
+        scheme: &str,
+        hosts: Option<&PyList>,
+        path: Option<&str>,
+        query: Option<&str>,
+        query_params: Option<Vec<(String, String)>>,
+        fragment: Option<&str>,
+    ) -> PyResult<&'a PyAny> {
+        let host_urls = match hosts {
+            Some(hosts) => hosts
+                .iter()
+                .map(|host| build_host_dict_url(scheme, host))
+                .collect::<PyResult<Vec<Url>>>()?,
+            None => Vec::new(),
+        };
+        // the path, query and fragment are percent-encoded by setting them on the last host's URL
+        let mut ref_url = match host_urls.last() {
+            Some(url) => url.clone(),
+            None => Url::parse(&format!("{scheme}://"))
+                .map_err(|err| PyValueError::new_err(format!("Invalid scheme {scheme:?} without hosts: {err}")))?,
+        };
+        if let Some(path) = path {
+            ref_url.set_path(path);
+        }
+        match (query, query_params) {
+            (Some(_), Some(_)) => return Err(PyValueError::new_err("only one of query and query_params may be set")),
+            (_, Some(query_params)) => {
+                ref_url.query_pairs_mut().clear().extend_pairs(query_params);
+            }
+            (query, None) => ref_url.set_query(query),
+        }
+        ref_url.set_fragment(fragment);
+        cls.call1((join_hosts(&host_urls, &ref_url),))
     }
+
+    /// A copy of this URL with `host` added after the existing hosts, `host` is a dict as used by `build`
+    pub fn with_host_added<'a>(slf: &'a PyCell<Self>, host: &PyAny) -> PyResult<&'a PyAny> {
+        let this = slf.try_borrow()?;
+        let ref_url = &this.ref_url.lib_url;
+        let mut host_urls = this.extra_urls.clone().unwrap_or_default();
+        if ref_url.has_host() {
+            host_urls.push(ref_url.clone());
+        }
+        host_urls.push(build_host_dict_url(ref_url.scheme(), host)?);
+        let url = join_hosts(&host_urls, ref_url);
+        slf.get_type().call1((url,))
+    }
+}
+
+/// Build a URL with the scheme and authority of a host dict passed to `MultiHostUrl.build`
+fn build_host_dict_url(scheme: &str, host: &PyAny) -> PyResult<Url> {
+    let py = host.py();
+    let dict: &PyDict = host
+        .downcast()
+        .map_err(|_| PyTypeError::new_err("each host must be a dict"))?;
+    for key in dict.keys() {
+        let key: &str = key.extract()?;
+        if !matches!(key, "host" | "port" | "username" | "password") {
+            return Err(PyValueError::new_err(format!("unexpected host key {key:?}")));
+        }
+    }
+    let host_name: &str = match dict.get_item(intern!(py, "host")) {
+        Some(host_name) => host_name.extract()?,
+        None => return Err(PyValueError::new_err("each host must have a \"host\"")),
+    };
+    build_host_url(
+        scheme,
+        host_name,
+        dict.get_as(intern!(py, "username"))?,
+        dict.get_as(intern!(py, "password"))?,
+        dict.get_as(intern!(py, "port"))?,
+    )
+}
+
+/// A multi-host URL string made from the authorities of `host_urls` followed by the path, query and fragment
+/// of `ref_url`
+fn join_hosts(host_urls: &[Url], ref_url: &Url) -> String {
+    let authorities = host_urls
+        .iter()
+        .map(|url| &url[Position::BeforeUsername..Position::AfterPort])
+        .collect::<Vec<&str>>()
+        .join(",");
+    format!(
+        "{}://{authorities}{}",
+        ref_url.scheme(),
+        &ref_url[Position::BeforePath..]
+    )
 }
 
 fn host_to_dict<'a>(py: Python<'a>, lib_url: &Url) -> PyResult<&'a PyDict> {
@@ -1,6 +1,8 @@
-use pyo3::intern;
+use std::mem::size_of;
+
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList, PyString, PyTuple};
+use pyo3::{intern, PyTraverseError, PyVisit};
 
 use ahash::AHashSet;
 
@@ -9,6 +11,7 @@ use crate::build_tools::schema_or_config_same;
 use crate::errors::{ErrorType, ValError, ValLineError, ValResult};
 use crate::input::{GenericArguments, Input};
 use crate::lookup_key::LookupKey;
+use crate::memory::{HeapSize, MemoryUsage};
 use crate::recursion_guard::RecursionGuard;
 use crate::tools::SchemaDict;
 
@@ -77,7 +80,8 @@ impl BuildValidator for ArgumentsValidator {
 
             let schema: &PyAny = arg.get_as_req(intern!(py, "schema"))?;
 
-            let validator = match build_validator(schema, config, definitions) {
+            let path = ["arguments_schema".into(), arg_index.into(), "schema".into()];
+            let validator = match build_validator(&path, schema, config, definitions) {
                 Ok(v) => v,
                 Err(err) => return py_schema_err!("Parameter '{}':\n  {}", name, err),
             };
@@ -110,11 +114,21 @@ impl BuildValidator for ArgumentsValidator {
             parameters,
             positional_params_count,
             var_args_validator: match schema.get_item(intern!(py, "var_args_schema")) {
-                Some(v) => Some(Box::new(build_validator(v, config, definitions)?)),
+                Some(v) => Some(Box::new(build_validator(
+                    &["var_args_schema".into()],
+                    v,
+                    config,
+                    definitions,
+                )?)),
                 None => None,
             },
             var_kwargs_validator: match schema.get_item(intern!(py, "var_kwargs_schema")) {
-                Some(v) => Some(Box::new(build_validator(v, config, definitions)?)),
+                Some(v) => Some(Box::new(build_validator(
+                    &["var_kwargs_schema".into()],
+                    v,
+                    config,
+                    definitions,
+                )?)),
                 None => None,
             },
             loc_by_alias: config.get_as(intern!(py, "loc_by_alias"))?.unwrap_or(true),
@@ -152,6 +166,19 @@ macro_rules! json_slice {
 pub(super) use json_slice;
 
 impl Validator for ArgumentsValidator {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        for parameter in &self.parameters {
+            parameter.validator.py_gc_traverse(visit)?;
+        }
+        if let Some(ref validator) = self.var_args_validator {
+            validator.py_gc_traverse(visit)?;
+        }
+        if let Some(ref validator) = self.var_kwargs_validator {
+            validator.py_gc_traverse(visit)?;
+        }
+        Ok(())
+    }
+
     fn validate<'s, 'data>(
         &'s self,
         py: Python<'data>,
@@ -171,6 +198,9 @@ impl Validator for ArgumentsValidator {
             ($args:ident, $get_method:ident, $get_macro:ident, $slice_macro:ident) => {{
                 // go through arguments getting the value from args or kwargs and validating it
                 for (index, parameter) in self.parameters.iter().enumerate() {
+                    if extra.fail_fast && !errors.is_empty() {
+                        break;
+                    }
                     let mut pos_value = None;
                     if let Some(args) = $args.args {
                         if parameter.positional {
@@ -252,6 +282,9 @@ impl Validator for ArgumentsValidator {
                     if len > self.positional_params_count {
                         if let Some(ref validator) = self.var_args_validator {
                             for (index, item) in $slice_macro!(args, self.positional_params_count, len).iter().enumerate() {
+                                if extra.fail_fast && !errors.is_empty() {
+                                    break;
+                                }
                                 match validator.validate(py, item, extra, definitions, recursion_guard) {
                                     Ok(value) => output_args.push(value),
                                     Err(ValError::LineErrors(line_errors)) => {
@@ -266,6 +299,9 @@ impl Validator for ArgumentsValidator {
                             }
                         } else {
                             for (index, item) in $slice_macro!(args, self.positional_params_count, len).iter().enumerate() {
+                                if extra.fail_fast && !errors.is_empty() {
+                                    break;
+                                }
                                 errors.push(ValLineError::new_with_loc(
                                     ErrorType::UnexpectedPositionalArgument,
                                     item,
@@ -279,6 +315,9 @@ impl Validator for ArgumentsValidator {
                 if let Some(kwargs) = $args.kwargs {
                     if kwargs.len() > used_kwargs.len() {
                         for (raw_key, value) in kwargs.iter() {
+                            if extra.fail_fast && !errors.is_empty() {
+                                break;
+                            }
                             let either_str = match raw_key.strict_str() {
                                 Ok(k) => k,
                                 Err(ValError::LineErrors(line_errors)) => {
@@ -354,4 +393,22 @@ impl Validator for ArgumentsValidator {
         };
         Ok(())
     }
+
+    fn memory_usage(&self, memory: &mut MemoryUsage) -> usize {
+        let mut bytes = self.parameters.capacity() * size_of::<Parameter>();
+        for (index, parameter) in self.parameters.iter().enumerate() {
+            bytes += parameter.name.heap_size() + parameter.kw_lookup_key.heap_size();
+            memory.visit(
+                &["arguments_schema".into(), index.into(), "schema".into()],
+                &parameter.validator,
+            );
+        }
+        if let Some(ref validator) = self.var_args_validator {
+            bytes += memory.visit_boxed(&["var_args_schema".into()], validator.as_ref());
+        }
+        if let Some(ref validator) = self.var_kwargs_validator {
+            bytes += memory.visit_boxed(&["var_kwargs_schema".into()], validator.as_ref());
+        }
+        bytes
+    }
 }
@@ -1,5 +1,5 @@
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyBool, PyDict};
 
 use crate::build_tools::is_strict;
 use crate::errors::ValResult;
@@ -37,9 +37,9 @@ impl Validator for BoolValidator {
         _definitions: &'data Definitions<CombinedValidator>,
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        // TODO in theory this could be quicker if we used PyBool rather than going to a bool
-        // and back again, might be worth profiling?
-        Ok(input.validate_bool(extra.strict.unwrap_or(self.strict))?.into_py(py))
+        // `PyBool::new` returns the `True` / `False` singletons
+        let value = input.validate_bool(extra.strict.unwrap_or(self.strict))?;
+        Ok(PyBool::new(py, value).into_py(py))
     }
 
     fn different_strict_behavior(
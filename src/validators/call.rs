@@ -1,10 +1,11 @@
 use pyo3::exceptions::PyTypeError;
-use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyTuple};
+use pyo3::{intern, PyTraverseError, PyVisit};
 
 use crate::errors::ValResult;
 use crate::input::Input;
+use crate::memory::{HeapSize, MemoryUsage};
 use crate::recursion_guard::RecursionGuard;
 use crate::tools::SchemaDict;
 
@@ -29,11 +30,21 @@ impl BuildValidator for CallValidator {
         let py = schema.py();
 
         let arguments_schema: &PyAny = schema.get_as_req(intern!(py, "arguments_schema"))?;
-        let arguments_validator = Box::new(build_validator(arguments_schema, config, definitions)?);
+        let arguments_validator = Box::new(build_validator(
+            &["arguments_schema".into()],
+            arguments_schema,
+            config,
+            definitions,
+        )?);
 
         let return_schema = schema.get_item(intern!(py, "return_schema"));
         let return_validator = match return_schema {
-            Some(return_schema) => Some(Box::new(build_validator(return_schema, config, definitions)?)),
+            Some(return_schema) => Some(Box::new(build_validator(
+                &["return_schema".into()],
+                return_schema,
+                config,
+                definitions,
+            )?)),
             None => None,
         };
         let function: &PyAny = schema.get_as_req(intern!(py, "function"))?;
@@ -66,6 +77,15 @@ impl BuildValidator for CallValidator {
 }
 
 impl Validator for CallValidator {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        visit.call(&self.function)?;
+        self.arguments_validator.py_gc_traverse(visit)?;
+        if let Some(ref validator) = self.return_validator {
+            validator.py_gc_traverse(visit)?;
+        }
+        Ok(())
+    }
+
     fn validate<'s, 'data>(
         &'s self,
         py: Python<'data>,
@@ -121,4 +141,12 @@ impl Validator for CallValidator {
             None => Ok(()),
         }
     }
+
+    fn memory_usage(&self, memory: &mut MemoryUsage) -> usize {
+        let mut bytes = memory.visit_boxed(&["arguments_schema".into()], self.arguments_validator.as_ref());
+        if let Some(ref validator) = self.return_validator {
+            bytes += memory.visit_boxed(&["return_schema".into()], validator.as_ref());
+        }
+        bytes + self.name.heap_size()
+    }
 }
@@ -1,10 +1,11 @@
-use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList};
+use pyo3::{intern, PyTraverseError, PyVisit};
 
 use crate::build_tools::py_schema_err;
 use crate::errors::ValResult;
 use crate::input::Input;
+use crate::memory::{HeapSize, MemoryUsage};
 use crate::recursion_guard::RecursionGuard;
 use crate::tools::SchemaDict;
 
@@ -27,7 +28,8 @@ impl BuildValidator for ChainValidator {
         let steps: Vec<CombinedValidator> = schema
             .get_as_req::<&PyList>(intern!(schema.py(), "steps"))?
             .iter()
-            .map(|step| build_validator_steps(step, config, definitions))
+            .enumerate()
+            .map(|(index, step)| build_validator_steps(index, step, config, definitions))
             .collect::<PyResult<Vec<Vec<CombinedValidator>>>>()?
             .into_iter()
             .flatten()
@@ -55,11 +57,15 @@ impl BuildValidator for ChainValidator {
 // either a vec of the steps from a nested `ChainValidator`, or a length-1 vec containing the validator
 // to be flattened into `steps` above
 fn build_validator_steps<'a>(
+    index: usize,
     step: &'a PyAny,
     config: Option<&'a PyDict>,
     definitions: &mut DefinitionsBuilder<CombinedValidator>,
 ) -> PyResult<Vec<CombinedValidator>> {
-    let validator = build_validator(step, config, definitions)?;
+    let validator = match build_validator(&["steps".into(), index.into()], step, config, definitions)? {
+        validator if matches!(validator.inner(), CombinedValidator::Chain(_)) => validator.into_inner(),
+        validator => validator,
+    };
     if let CombinedValidator::Chain(chain_validator) = validator {
         Ok(chain_validator.steps)
     } else {
@@ -68,6 +74,13 @@ fn build_validator_steps<'a>(
 }
 
 impl Validator for ChainValidator {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        for step in &self.steps {
+            step.py_gc_traverse(visit)?;
+        }
+        Ok(())
+    }
+
     fn validate<'s, 'data>(
         &'s self,
         py: Python<'data>,
@@ -102,4 +115,8 @@ impl Validator for ChainValidator {
     fn complete(&mut self, definitions: &DefinitionsBuilder<CombinedValidator>) -> PyResult<()> {
         self.steps.iter_mut().try_for_each(|v| v.complete(definitions))
     }
+
+    fn memory_usage(&self, memory: &mut MemoryUsage) -> usize {
+        memory.visit_vec("steps", &self.steps) + self.name.heap_size()
+    }
 }
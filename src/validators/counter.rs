@@ -0,0 +1,411 @@
+use pyo3::exceptions::PyTypeError;
+use pyo3::once_cell::GILOnceCell;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyType};
+use pyo3::{intern, PyTraverseError, PyVisit};
+
+use crate::build_tools::is_strict;
+use crate::errors::{py_err_string, ErrorType, LocItem, ValError, ValLineError, ValResult};
+use crate::input::{
+    DictGenericIterator, GenericIterable, GenericMapping, Input, Int, JsonObjectGenericIterator, MappingGenericIterator,
+};
+use crate::memory::{HeapSize, MemoryUsage};
+use crate::recursion_guard::RecursionGuard;
+use crate::tools::SchemaDict;
+
+use super::any::AnyValidator;
+use super::{
+    build_validator, capped_name, BuildValidator, CombinedValidator, Definitions, DefinitionsBuilder, Extra, Validator,
+};
+
+static COUNTER_TYPE: GILOnceCell<Py<PyType>> = GILOnceCell::new();
+
+/// `collections.Counter`
+fn counter_type(py: Python<'_>) -> PyResult<&PyType> {
+    let counter_type = COUNTER_TYPE.get_or_try_init(py, || {
+        let counter_type: &PyType = py.import("collections")?.getattr("Counter")?.downcast()?;
+        Ok::<Py<PyType>, PyErr>(counter_type.into())
+    })?;
+    Ok(counter_type.as_ref(py))
+}
+
+#[derive(Debug, Clone)]
+pub struct CounterValidator {
+    strict: bool,
+    item_validator: Box<CombinedValidator>,
+    min_count: Option<usize>,
+    max_count: Option<usize>,
+    max_keys: Option<usize>,
+    max_input_length: Option<usize>,
+    name: String,
+}
+
+impl BuildValidator for CounterValidator {
+    const EXPECTED_TYPE: &'static str = "counter";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        definitions: &mut DefinitionsBuilder<CombinedValidator>,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let item_validator = match schema.get_item(intern!(py, "items_schema")) {
+            Some(d) => Box::new(build_validator(&["items_schema".into()], d, config, definitions)?),
+            None => Box::new(AnyValidator::build(schema, config, definitions)?),
+        };
+        let name = format!("{}[{}]", Self::EXPECTED_TYPE, capped_name(item_validator.get_name()));
+        Ok(Self {
+            strict: is_strict(schema, config)?,
+            item_validator,
+            min_count: schema.get_as(intern!(py, "min_count"))?,
+            max_count: schema.get_as(intern!(py, "max_count"))?,
+            max_keys: schema.get_as(intern!(py, "max_keys"))?,
+            max_input_length: schema.get_as(intern!(py, "max_input_length"))?,
+            name,
+        }
+        .into())
+    }
+}
+
+impl Validator for CounterValidator {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        self.item_validator.py_gc_traverse(visit)
+    }
+
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        definitions: &'data Definitions<CombinedValidator>,
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        let strict = extra.strict.unwrap_or(self.strict);
+        let counter_type = counter_type(py)?;
+        if strict && input.is_python() && input.input_is_instance(counter_type).is_none() {
+            return Err(ValError::new(ErrorType::CounterType, input));
+        }
+        let output: &PyDict = counter_type.call0()?.downcast()?;
+
+        match input.validate_dict(strict) {
+            Ok(GenericMapping::PyDict(dict)) => self.count_mapping(
+                py,
+                DictGenericIterator::new(dict)?,
+                output,
+                strict,
+                extra,
+                definitions,
+                recursion_guard,
+            )?,
+            Ok(GenericMapping::PyMapping(mapping)) => self.count_mapping(
+                py,
+                MappingGenericIterator::new(mapping)?,
+                output,
+                strict,
+                extra,
+                definitions,
+                recursion_guard,
+            )?,
+            Ok(GenericMapping::PyGetAttr(_, _)) => unreachable!(),
+            Ok(GenericMapping::JsonObject(json_object)) => self.count_mapping(
+                py,
+                JsonObjectGenericIterator::new(json_object)?,
+                output,
+                strict,
+                extra,
+                definitions,
+                recursion_guard,
+            )?,
+            Err(ValError::LineErrors(_)) if !strict => match input.validate_list(false) {
+                Ok(collection) => {
+                    self.count_items(py, input, &collection, output, extra, definitions, recursion_guard)?;
+                }
+                Err(ValError::LineErrors(_)) => return Err(ValError::new(ErrorType::CounterType, input)),
+                Err(err) => return Err(err),
+            },
+            Err(ValError::LineErrors(_)) => return Err(ValError::new(ErrorType::CounterType, input)),
+            Err(err) => return Err(err),
+        }
+
+        self.check_counts(input, output)?;
+        Ok(output.into_py(py))
+    }
+
+    fn different_strict_behavior(
+        &self,
+        definitions: Option<&DefinitionsBuilder<CombinedValidator>>,
+        ultra_strict: bool,
+    ) -> bool {
+        if ultra_strict {
+            self.item_validator.different_strict_behavior(definitions, true)
+        } else {
+            true
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    fn complete(&mut self, definitions: &DefinitionsBuilder<CombinedValidator>) -> PyResult<()> {
+        self.item_validator.complete(definitions)
+    }
+
+    fn memory_usage(&self, memory: &mut MemoryUsage) -> usize {
+        memory.visit_boxed(&["items_schema".into()], self.item_validator.as_ref()) + self.name.heap_size()
+    }
+}
+
+impl CounterValidator {
+    /// Validate the keys and counts of a mapping, counts of keys which are equal after validation are summed
+    #[allow(clippy::too_many_arguments)]
+    fn count_mapping<'s, 'data, K, V>(
+        &'s self,
+        py: Python<'data>,
+        items: impl Iterator<Item = ValResult<'data, (&'data K, &'data V)>>,
+        output: &'data PyDict,
+        strict: bool,
+        extra: &Extra,
+        definitions: &'data Definitions<CombinedValidator>,
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, ()>
+    where
+        K: Input<'data> + 'data,
+        V: Input<'data> + 'data,
+    {
+        let mut errors: Vec<ValLineError> = Vec::new();
+        let extra = &extra.with_index(None);
+        for item_result in items {
+            if extra.fail_fast && !errors.is_empty() {
+                break;
+            }
+            let (key, value) = item_result?;
+            let output_key = match self
+                .item_validator
+                .validate(py, key, extra, definitions, recursion_guard)
+            {
+                Ok(output_key) => Some(output_key),
+                Err(ValError::LineErrors(line_errors)) => {
+                    for err in line_errors {
+                        errors.push(
+                            err.with_outer_location("[key]".into())
+                                .with_outer_location(key.as_loc_item()),
+                        );
+                    }
+                    None
+                }
+                Err(ValError::Omit) => continue,
+                Err(err) => return Err(err),
+            };
+            let count = match value.validate_int(strict).and_then(|int| int.into_i64(py)) {
+                Ok(count) if count < 0 => {
+                    errors.push(ValLineError::new_with_loc(
+                        ErrorType::GreaterThanEqual { ge: Int::I64(0).into() },
+                        value,
+                        key.as_loc_item(),
+                    ));
+                    None
+                }
+                Ok(count) => Some(count),
+                Err(ValError::LineErrors(line_errors)) => {
+                    for err in line_errors {
+                        errors.push(err.with_outer_location(key.as_loc_item()));
+                    }
+                    None
+                }
+                Err(err) => return Err(err),
+            };
+            if let (Some(output_key), Some(count)) = (output_key, count) {
+                match add_count(py, output, output_key, count) {
+                    Ok(true) => (),
+                    // keys which are equal after validation have their counts summed
+                    Ok(false) => errors.push(ValLineError::new_with_loc(
+                        ErrorType::LessThanEqual {
+                            le: Int::I64(i64::MAX).into(),
+                        },
+                        value,
+                        key.as_loc_item(),
+                    )),
+                    Err(err) => errors.push(unhashable_error(py, err, key, key.as_loc_item())?),
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(ValError::LineErrors(errors))
+        }
+    }
+
+    /// Validate and count the items of an iterable as they're consumed, so a generator with too many items or
+    /// too many distinct items isn't consumed past the limit
+    #[allow(clippy::too_many_arguments)]
+    fn count_items<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        collection: &GenericIterable<'data>,
+        output: &'data PyDict,
+        extra: &Extra,
+        definitions: &'data Definitions<CombinedValidator>,
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, ()> {
+        macro_rules! count {
+            ($iter:expr) => {
+                self.count_iter(py, input, $iter, output, extra, definitions, recursion_guard)
+            };
+        }
+
+        match collection {
+            GenericIterable::List(collection) => count!(collection.iter().map(Ok)),
+            GenericIterable::Tuple(collection) => count!(collection.iter().map(Ok)),
+            GenericIterable::Set(collection) => count!(collection.iter().map(Ok)),
+            GenericIterable::FrozenSet(collection) => count!(collection.iter().map(Ok)),
+            GenericIterable::Sequence(collection) => count!(collection.iter()?),
+            GenericIterable::Iterator(collection) => count!(collection.iter()?),
+            GenericIterable::JsonArray(collection) => count!(collection.iter().map(Ok)),
+            other => count!(other.as_sequence_iterator(py)?),
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn count_iter<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        iter: impl Iterator<Item = PyResult<&'data (impl Input<'data> + 'data)>>,
+        output: &'data PyDict,
+        extra: &Extra,
+        definitions: &'data Definitions<CombinedValidator>,
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, ()> {
+        let mut errors: Vec<ValLineError> = Vec::new();
+        let mut input_length: usize = 0;
+        for (index, item_result) in iter.enumerate() {
+            let item = item_result.map_err(|e| {
+                ValError::new_with_loc(
+                    ErrorType::IterationError {
+                        error: py_err_string(py, e),
+                    },
+                    input,
+                    index,
+                )
+            })?;
+            let item_extra = extra.with_index(Some(index));
+            let item_result = self
+                .item_validator
+                .validate(py, item, &item_extra, definitions, recursion_guard);
+            if matches!(item_result, Ok(_) | Err(ValError::LineErrors(_))) {
+                input_length += 1;
+                if let Some(max_input_length) = self.max_input_length {
+                    if input_length > max_input_length {
+                        return Err(too_long_error(input, max_input_length, input_length));
+                    }
+                }
+            }
+            match item_result {
+                // counting items one at a time can't overflow
+                Ok(output_key) => match add_count(py, output, output_key, 1) {
+                    Ok(_) => {
+                        if let Some(max_keys) = self.max_keys {
+                            if output.len() > max_keys {
+                                return Err(too_long_error(input, max_keys, output.len()));
+                            }
+                        }
+                    }
+                    Err(err) => errors.push(unhashable_error(py, err, item, index.into())?),
+                },
+                Err(ValError::LineErrors(line_errors)) => {
+                    errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index.into())));
+                }
+                Err(ValError::Omit) => (),
+                Err(err) => return Err(err),
+            }
+            if extra.fail_fast && !errors.is_empty() {
+                break;
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(ValError::LineErrors(errors))
+        }
+    }
+
+    /// Check the count of each distinct item, then the number of distinct items
+    fn check_counts<'data>(&self, input: &'data impl Input<'data>, output: &'data PyDict) -> ValResult<'data, ()> {
+        if self.min_count.is_some() || self.max_count.is_some() {
+            let mut errors: Vec<ValLineError> = Vec::new();
+            for (key, count) in output {
+                // counts are validated as non-negative above
+                let actual_count: usize = count.extract()?;
+                match (self.min_count, self.max_count) {
+                    (Some(min_count), _) if actual_count < min_count => errors.push(ValLineError::new_with_loc(
+                        ErrorType::CountTooSmall {
+                            min_count,
+                            actual_count,
+                        },
+                        key,
+                        key.as_loc_item(),
+                    )),
+                    (_, Some(max_count)) if actual_count > max_count => errors.push(ValLineError::new_with_loc(
+                        ErrorType::CountTooLarge {
+                            max_count,
+                            actual_count,
+                        },
+                        key,
+                        key.as_loc_item(),
+                    )),
+                    _ => (),
+                }
+            }
+            if !errors.is_empty() {
+                return Err(ValError::LineErrors(errors));
+            }
+        }
+        match self.max_keys {
+            Some(max_keys) if output.len() > max_keys => Err(too_long_error(input, max_keys, output.len())),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Add `count` to the count of `key` in `output`, returns `false` without changing the count if the total
+/// would overflow
+fn add_count(py: Python, output: &PyDict, key: PyObject, count: i64) -> PyResult<bool> {
+    let current = match output.get_item(key.as_ref(py)) {
+        Some(current) => current.extract::<i64>()?,
+        None => 0,
+    };
+    match current.checked_add(count) {
+        Some(total) => output.set_item(key, total).map(|()| true),
+        None => Ok(false),
+    }
+}
+
+fn too_long_error<'data>(input: &'data impl Input<'data>, max_length: usize, actual_length: usize) -> ValError<'data> {
+    ValError::new(
+        ErrorType::TooLong {
+            field_type: "Counter".to_string(),
+            max_length,
+            actual_length,
+        },
+        input,
+    )
+}
+
+/// Convert the error from adding an unhashable key to `output` into a line error
+fn unhashable_error<'data>(
+    py: Python<'data>,
+    err: PyErr,
+    input: &'data impl Input<'data>,
+    loc: LocItem,
+) -> ValResult<'data, ValLineError<'data>> {
+    if err.is_instance_of::<PyTypeError>(py) {
+        Ok(ValLineError::new_with_loc(ErrorType::UnhashableItem, input, loc))
+    } else {
+        Err(err.into())
+    }
+}
@@ -0,0 +1,154 @@
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use pyo3::{intern, PyTraverseError, PyVisit};
+
+use crate::build_tools::{is_strict, py_schema_err, py_schema_error_type};
+use crate::errors::ValResult;
+use crate::input::Input;
+use crate::memory::{HeapSize, MemoryUsage};
+use crate::recursion_guard::RecursionGuard;
+
+use super::function::{convert_err, ValidationInfo};
+use super::{reset_self_schema, CombinedValidator, Definitions, DefinitionsBuilder, Extra, Validator, SCHEMA_TYPES};
+
+/// Builders for schema types registered with `register_validator` in registration order, a mutex rather than
+/// relying on the GIL so this is also safe on free-threaded python, it's never locked while calling python code
+static REGISTRY: Mutex<Vec<(String, PyObject)>> = Mutex::new(Vec::new());
+
+fn registry() -> MutexGuard<'static, Vec<(String, PyObject)>> {
+    REGISTRY.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Register `builder` to build validators for schemas with `'type': schema_type`, the builder is called with the
+/// schema and config and should return an object with a `validate(input_value, strict, info)` method
+#[pyfunction]
+pub fn register_validator(py: Python, schema_type: &str, builder: &PyAny) -> PyResult<()> {
+    if SCHEMA_TYPES.contains(&schema_type) {
+        return Err(PyValueError::new_err(format!(
+            "Cannot register a validator for the built-in schema type \"{schema_type}\""
+        )));
+    }
+    if !builder.is_callable() {
+        return Err(PyTypeError::new_err("builder must be callable"));
+    }
+    let builder = builder.into_py(py);
+    let mut registry = registry();
+    // a replaced builder is dropped after the lock is released since dropping can run arbitrary python code
+    let previous = match registry.iter_mut().find(|(t, _)| t == schema_type) {
+        Some((_, existing)) => Some(std::mem::replace(existing, builder)),
+        None => {
+            registry.push((schema_type.to_string(), builder));
+            None
+        }
+    };
+    drop(registry);
+    drop(previous);
+    // the self-schema needs to accept the new schema type
+    reset_self_schema();
+    Ok(())
+}
+
+pub fn registered_builder<'py>(py: Python<'py>, schema_type: &str) -> Option<&'py PyAny> {
+    let builder = registry()
+        .iter()
+        .find(|(t, _)| t == schema_type)
+        .map(|(_, builder)| builder.clone_ref(py));
+    builder.map(|b| b.into_ref(py))
+}
+
+pub fn registered_schema_types() -> Vec<String> {
+    registry().iter().map(|(t, _)| t.clone()).collect()
+}
+
+/// Schema types are never unregistered, so the count identifies which types a self-schema was built with
+pub fn registered_schema_count() -> usize {
+    registry().len()
+}
+
+#[derive(Debug, Clone)]
+pub struct CustomValidator {
+    validator: PyObject,
+    config: PyObject,
+    strict: bool,
+    name: String,
+}
+
+impl CustomValidator {
+    pub fn build(
+        schema_type: &str,
+        builder: &PyAny,
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        _definitions: &mut DefinitionsBuilder<CombinedValidator>,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let validator = builder
+            .call1((schema, config))
+            .map_err(|err| py_schema_error_type!("Error building \"{}\" validator:\n  {}", schema_type, err))?;
+        match validator.getattr(intern!(py, "validate")) {
+            Ok(validate) if validate.is_callable() => (),
+            _ => {
+                return py_schema_err!(
+                    "Error building \"{}\" validator:\n  validator must have a `validate` method",
+                    schema_type
+                )
+            }
+        }
+        Ok(Self {
+            validator: validator.into_py(py),
+            config: match config {
+                Some(c) => c.into(),
+                None => py.None(),
+            },
+            strict: is_strict(schema, config)?,
+            name: schema_type.to_string(),
+        }
+        .into())
+    }
+}
+
+impl Validator for CustomValidator {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        visit.call(&self.validator)?;
+        visit.call(&self.config)?;
+        Ok(())
+    }
+
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        _definitions: &'data Definitions<CombinedValidator>,
+        _recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        let info = ValidationInfo::new(py, extra, &self.config, false)?;
+        let strict = extra.strict.unwrap_or(self.strict);
+        self.validator
+            .call_method1(py, intern!(py, "validate"), (input.to_object(py), strict, info))
+            .map_err(|e| convert_err(py, e, input))
+    }
+
+    fn different_strict_behavior(
+        &self,
+        _definitions: Option<&DefinitionsBuilder<CombinedValidator>>,
+        ultra_strict: bool,
+    ) -> bool {
+        !ultra_strict
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    fn complete(&mut self, _definitions: &DefinitionsBuilder<CombinedValidator>) -> PyResult<()> {
+        Ok(())
+    }
+
+    fn memory_usage(&self, _memory: &mut MemoryUsage) -> usize {
+        self.name.heap_size()
+    }
+}
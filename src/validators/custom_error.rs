@@ -1,14 +1,17 @@
-use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
+use pyo3::{intern, PyTraverseError, PyVisit};
 
 use crate::build_tools::py_schema_err;
 use crate::errors::{ErrorType, PydanticCustomError, PydanticKnownError, ValError, ValResult};
 use crate::input::Input;
+use crate::memory::{HeapSize, MemoryUsage};
 use crate::recursion_guard::RecursionGuard;
 use crate::tools::SchemaDict;
 
-use super::{build_validator, BuildValidator, CombinedValidator, Definitions, DefinitionsBuilder, Extra, Validator};
+use super::{
+    build_validator, capped_name, BuildValidator, CombinedValidator, Definitions, DefinitionsBuilder, Extra, Validator,
+};
 
 #[derive(Debug, Clone)]
 pub enum CustomError {
@@ -74,8 +77,8 @@ impl BuildValidator for CustomErrorValidator {
     ) -> PyResult<CombinedValidator> {
         let custom_error = CustomError::build(schema, config, definitions)?.unwrap();
         let schema: &PyAny = schema.get_as_req(intern!(schema.py(), "schema"))?;
-        let validator = Box::new(build_validator(schema, config, definitions)?);
-        let name = format!("{}[{}]", Self::EXPECTED_TYPE, validator.get_name());
+        let validator = Box::new(build_validator(&["schema".into()], schema, config, definitions)?);
+        let name = format!("{}[{}]", Self::EXPECTED_TYPE, capped_name(validator.get_name()));
         Ok(Self {
             validator,
             custom_error,
@@ -86,6 +89,10 @@ impl BuildValidator for CustomErrorValidator {
 }
 
 impl Validator for CustomErrorValidator {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        self.validator.py_gc_traverse(visit)
+    }
+
     fn validate<'s, 'data>(
         &'s self,
         py: Python<'data>,
@@ -114,4 +121,8 @@ impl Validator for CustomErrorValidator {
     fn complete(&mut self, definitions: &DefinitionsBuilder<CombinedValidator>) -> PyResult<()> {
         self.validator.complete(definitions)
     }
+
+    fn memory_usage(&self, memory: &mut MemoryUsage) -> usize {
+        memory.visit_boxed(&["schema".into()], self.validator.as_ref()) + self.name.heap_size()
+    }
 }
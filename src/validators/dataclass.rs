@@ -1,15 +1,18 @@
-use pyo3::exceptions::PyKeyError;
-use pyo3::intern;
+use std::mem::size_of;
+
+use pyo3::exceptions::{PyAttributeError, PyKeyError};
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList, PyString, PyTuple, PyType};
+use pyo3::{intern, PyTraverseError, PyVisit};
 
 use ahash::AHashSet;
 
 use crate::build_tools::py_schema_err;
-use crate::build_tools::{is_strict, schema_or_config_same, ExtraBehavior};
-use crate::errors::{ErrorType, ValError, ValLineError, ValResult};
+use crate::build_tools::{is_strict, merge_config, schema_or_config_same, ExtraBehavior};
+use crate::errors::{py_err_string, ErrorType, ValError, ValLineError, ValResult};
 use crate::input::{GenericArguments, Input};
 use crate::lookup_key::LookupKey;
+use crate::memory::{HeapSize, MemoryUsage};
 use crate::recursion_guard::RecursionGuard;
 use crate::tools::SchemaDict;
 use crate::validators::function::convert_err;
@@ -59,7 +62,7 @@ impl BuildValidator for DataclassArgsValidator {
 
         let mut positional_count = 0;
 
-        for field in fields_schema {
+        for (field_index, field) in fields_schema.iter().enumerate() {
             let field: &PyDict = field.downcast()?;
 
             let py_name: &PyString = field.get_as_req(intern!(py, "name"))?;
@@ -75,7 +78,8 @@ impl BuildValidator for DataclassArgsValidator {
 
             let schema: &PyAny = field.get_as_req(intern!(py, "schema"))?;
 
-            let validator = match build_validator(schema, config, definitions) {
+            let path = ["fields".into(), field_index.into(), "schema".into()];
+            let validator = match build_validator(&path, schema, config, definitions) {
                 Ok(v) => v,
                 Err(err) => return py_schema_err!("Field '{}':\n  {}", name, err),
             };
@@ -124,6 +128,13 @@ impl BuildValidator for DataclassArgsValidator {
 }
 
 impl Validator for DataclassArgsValidator {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        for field in &self.fields {
+            field.validator.py_gc_traverse(visit)?;
+        }
+        Ok(())
+    }
+
     fn validate<'s, 'data>(
         &'s self,
         py: Python<'data>,
@@ -142,6 +153,7 @@ impl Validator for DataclassArgsValidator {
 
         let extra = Extra {
             data: Some(output_dict),
+            index: None,
             ..*extra
         };
 
@@ -162,6 +174,9 @@ impl Validator for DataclassArgsValidator {
             ($args:ident, $get_method:ident, $get_macro:ident, $slice_macro:ident) => {{
                 // go through fields getting the value from args or kwargs and validating it
                 for (index, field) in self.fields.iter().enumerate() {
+                    if extra.fail_fast && !errors.is_empty() {
+                        break;
+                    }
                     let extra = Extra {
                         field_name: Some(&field.name),
                         ..extra
@@ -250,6 +265,9 @@ impl Validator for DataclassArgsValidator {
                     let len = args.len();
                     if len > self.positional_count {
                         for (index, item) in $slice_macro!(args, self.positional_count, len).iter().enumerate() {
+                            if extra.fail_fast && !errors.is_empty() {
+                                break;
+                            }
                             errors.push(ValLineError::new_with_loc(
                                 ErrorType::UnexpectedPositionalArgument,
                                 item,
@@ -262,6 +280,9 @@ impl Validator for DataclassArgsValidator {
                 if let Some(kwargs) = $args.kwargs {
                     if kwargs.len() != used_keys.len() {
                         for (raw_key, value) in kwargs.iter() {
+                            if extra.fail_fast && !errors.is_empty() {
+                                break;
+                            }
                             match raw_key.strict_str() {
                                 Ok(either_str) => {
                                     if !used_keys.contains(either_str.as_cow()?.as_ref()) {
@@ -405,6 +426,15 @@ impl Validator for DataclassArgsValidator {
             .iter_mut()
             .try_for_each(|field| field.validator.complete(definitions))
     }
+
+    fn memory_usage(&self, memory: &mut MemoryUsage) -> usize {
+        let mut bytes = self.fields.capacity() * size_of::<Field>();
+        for (index, field) in self.fields.iter().enumerate() {
+            bytes += field.name.heap_size() + field.lookup_key.heap_size();
+            memory.visit(&["fields".into(), index.into(), "schema".into()], &field.validator);
+        }
+        bytes + self.dataclass_name.heap_size() + self.validator_name.heap_size()
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -425,13 +455,13 @@ impl BuildValidator for DataclassValidator {
 
     fn build(
         schema: &PyDict,
-        _config: Option<&PyDict>,
+        parent_config: Option<&PyDict>,
         definitions: &mut DefinitionsBuilder<CombinedValidator>,
     ) -> PyResult<CombinedValidator> {
         let py = schema.py();
 
-        // dataclasses ignore the parent config and always use the config from this dataclasses
-        let config = schema.get_as(intern!(py, "config"))?;
+        // the config from this dataclass is merged over the parent config
+        let config = merge_config(parent_config, schema.get_as(intern!(py, "config"))?)?;
 
         let class: &PyType = schema.get_as_req(intern!(py, "cls"))?;
         let name = match schema.get_as_req::<String>(intern!(py, "cls_name")) {
@@ -439,7 +469,7 @@ impl BuildValidator for DataclassValidator {
             Err(_) => class.getattr(intern!(py, "__name__"))?.extract()?,
         };
         let sub_schema: &PyAny = schema.get_as_req(intern!(py, "schema"))?;
-        let validator = build_validator(sub_schema, config, definitions)?;
+        let validator = build_validator(&["schema".into()], sub_schema, config, definitions)?;
 
         let post_init = if schema.get_as::<bool>(intern!(py, "post_init"))?.unwrap_or(false) {
             Some(PyString::intern(py, "__post_init__").into_py(py))
@@ -473,6 +503,12 @@ impl BuildValidator for DataclassValidator {
 }
 
 impl Validator for DataclassValidator {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        visit.call(&self.class)?;
+        self.validator.py_gc_traverse(visit)?;
+        Ok(())
+    }
+
     fn validate<'s, 'data>(
         &'s self,
         py: Python<'data>,
@@ -508,9 +544,16 @@ impl Validator for DataclassValidator {
                 input,
             ))
         } else {
-            let val_output = self
-                .validator
-                .validate(py, input, extra, definitions, recursion_guard)?;
+            let val_output = match input.input_from_attributes() {
+                Some(obj) if extra.from_attributes.unwrap_or(false) => {
+                    let input_dict: &PyAny = self.attributes_to_dict(py, obj)?;
+                    self.validator
+                        .validate(py, input_dict, extra, definitions, recursion_guard)?
+                }
+                _ => self
+                    .validator
+                    .validate(py, input, extra, definitions, recursion_guard)?,
+            };
             let dc = create_class(self.class.as_ref(py))?;
             self.set_dict_call(py, dc.as_ref(py), val_output, input)?;
             Ok(dc)
@@ -578,6 +621,12 @@ impl Validator for DataclassValidator {
     fn complete(&mut self, definitions: &DefinitionsBuilder<CombinedValidator>) -> PyResult<()> {
         self.validator.complete(definitions)
     }
+
+    fn memory_usage(&self, memory: &mut MemoryUsage) -> usize {
+        memory.visit_boxed(&["schema".into()], self.validator.as_ref())
+            + self.fields.heap_size()
+            + self.name.heap_size()
+    }
 }
 
 impl DataclassValidator {
@@ -616,6 +665,34 @@ impl DataclassValidator {
         Ok(dict)
     }
 
+    /// Like `dataclass_to_dict` but for arbitrary objects with `from_attributes`, missing attributes are left out
+    /// so they're reported as missing fields
+    fn attributes_to_dict<'data>(&self, py: Python<'data>, obj: &'data PyAny) -> ValResult<'data, &'data PyDict> {
+        let dict = PyDict::new(py);
+        let mut errors: Vec<ValLineError> = Vec::new();
+
+        for field_name in &self.fields {
+            let field_name = field_name.as_ref(py);
+            match obj.getattr(field_name) {
+                Ok(value) => dict.set_item(field_name, value)?,
+                Err(err) if err.is_instance_of::<PyAttributeError>(py) => (),
+                Err(err) => errors.push(ValLineError::new_with_loc(
+                    ErrorType::GetAttributeError {
+                        error: py_err_string(py, err),
+                    },
+                    obj,
+                    field_name.to_str()?,
+                )),
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(dict)
+        } else {
+            Err(ValError::LineErrors(errors))
+        }
+    }
+
     fn set_dict_call<'s, 'data>(
         &'s self,
         py: Python<'data>,
@@ -2,20 +2,20 @@ use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDate, PyDict, PyString};
 use speedate::{Date, Time};
-use strum::EnumMessage;
 
-use crate::build_tools::{is_strict, py_schema_error_type};
+use crate::build_tools::is_strict;
 use crate::errors::{ErrorType, ValError, ValResult};
 use crate::input::{EitherDate, Input};
 use crate::recursion_guard::RecursionGuard;
 use crate::tools::SchemaDict;
-use crate::validators::datetime::{NowConstraint, NowOp};
+use crate::validators::datetime::{NowConstraint, NowOp, NumericMode};
 
 use super::{BuildValidator, CombinedValidator, Definitions, DefinitionsBuilder, Extra, Validator};
 
 #[derive(Debug, Clone)]
 pub struct DateValidator {
     strict: bool,
+    numeric_mode: Option<NumericMode>,
     constraints: Option<DateConstraints>,
 }
 
@@ -29,6 +29,7 @@ impl BuildValidator for DateValidator {
     ) -> PyResult<CombinedValidator> {
         Ok(Self {
             strict: is_strict(schema, config)?,
+            numeric_mode: NumericMode::from_py(schema)?,
             constraints: DateConstraints::from_py(schema)?,
         }
         .into())
@@ -44,16 +45,25 @@ impl Validator for DateValidator {
         _definitions: &'data Definitions<CombinedValidator>,
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        let date = match input.validate_date(extra.strict.unwrap_or(self.strict)) {
-            Ok(date) => date,
-            // if the date error was an internal error, return that immediately
-            Err(ValError::InternalErr(internal_err)) => return Err(ValError::InternalErr(internal_err)),
-            Err(date_err) => match self.strict {
-                // if we're in strict mode, we doing try coercing from a date
-                true => return Err(date_err),
-                // otherwise, try creating a date from a datetime input
-                false => date_from_datetime(input, date_err),
-            }?,
+        let strict = extra.strict.unwrap_or(self.strict);
+        // with an explicit `numeric_mode`, numbers and numeric strings never fall through to the inferred parsing
+        let number = match strict {
+            true => None,
+            false => self.numeric_mode.zip(input.as_temporal_number()),
+        };
+        let date = match number.map(|(numeric_mode, number)| date_from_number(input, numeric_mode, number)) {
+            Some(date) => date?,
+            None => match input.validate_date(strict) {
+                Ok(date) => date,
+                // if the date error was an internal error, return that immediately
+                Err(ValError::InternalErr(internal_err)) => return Err(ValError::InternalErr(internal_err)),
+                Err(date_err) => match strict {
+                    // if we're in strict mode, we doing try coercing from a date
+                    true => return Err(date_err),
+                    // otherwise, try creating a date from a datetime input
+                    false => date_from_datetime(input, date_err)?,
+                },
+            },
         };
         if let Some(constraints) = &self.constraints {
             let raw_date = date.as_raw()?;
@@ -79,10 +89,7 @@ impl Validator for DateValidator {
             check_constraint!(gt, GreaterThan);
 
             if let Some(ref today_constraint) = constraints.today {
-                let offset = today_constraint.utc_offset(py)?;
-                let today = Date::today(offset).map_err(|e| {
-                    py_schema_error_type!("Date::today() error: {}", e.get_documentation().unwrap_or("unknown"))
-                })?;
+                let today = today_constraint.now(py)?.date;
                 // `if let Some(c)` to match behaviour of gt/lt/le/ge
                 if let Some(c) = raw_date.partial_cmp(&today) {
                     let date_compliant = today_constraint.op.compare(c);
@@ -162,6 +169,25 @@ fn date_from_datetime<'data>(
     }
 }
 
+/// With an explicit `numeric_mode`, numbers are interpreted as a datetime which must then be an "exact date"
+fn date_from_number<'data>(
+    input: &'data impl Input<'data>,
+    numeric_mode: NumericMode,
+    number: f64,
+) -> ValResult<'data, EitherDate<'data>> {
+    if numeric_mode == NumericMode::Reject {
+        return Err(ValError::new(ErrorType::DateType, input));
+    }
+    let dt = numeric_mode
+        .number_as_datetime(number)
+        .map_err(|error| ValError::new(ErrorType::DateFromDatetimeParsing { error }, input))?;
+    if dt.time.total_seconds() == 0 && dt.time.microsecond == 0 {
+        Ok(EitherDate::Raw(dt.date))
+    } else {
+        Err(ValError::new(ErrorType::DateFromDatetimeInexact, input))
+    }
+}
+
 #[derive(Debug, Clone)]
 struct DateConstraints {
     le: Option<Date>,
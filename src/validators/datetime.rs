@@ -1,13 +1,14 @@
+use pyo3::exceptions::PyValueError;
 use pyo3::intern;
 use pyo3::once_cell::GILOnceCell;
 use pyo3::prelude::*;
 use pyo3::types::{PyDateTime, PyDict, PyString};
-use speedate::DateTime;
+use speedate::{DateTime, ParseError};
 use std::cmp::Ordering;
 use strum::EnumMessage;
 
+use crate::build_tools::is_strict;
 use crate::build_tools::py_schema_err;
-use crate::build_tools::{is_strict, py_schema_error_type};
 use crate::errors::{py_err_string, ErrorType, ValError, ValResult};
 use crate::input::{EitherDateTime, Input};
 use crate::recursion_guard::RecursionGuard;
@@ -18,6 +19,7 @@ use super::{BuildValidator, CombinedValidator, Definitions, DefinitionsBuilder,
 #[derive(Debug, Clone)]
 pub struct DateTimeValidator {
     strict: bool,
+    numeric_mode: Option<NumericMode>,
     constraints: Option<DateTimeConstraints>,
 }
 
@@ -31,6 +33,7 @@ impl BuildValidator for DateTimeValidator {
     ) -> PyResult<CombinedValidator> {
         Ok(Self {
             strict: is_strict(schema, config)?,
+            numeric_mode: NumericMode::from_py(schema)?,
             constraints: DateTimeConstraints::from_py(schema)?,
         }
         .into())
@@ -46,7 +49,20 @@ impl Validator for DateTimeValidator {
         _definitions: &'data Definitions<CombinedValidator>,
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        let datetime = input.validate_datetime(extra.strict.unwrap_or(self.strict))?;
+        let strict = extra.strict.unwrap_or(self.strict);
+        let datetime = match (self.numeric_mode, strict) {
+            (Some(numeric_mode), false) => match input.as_temporal_number() {
+                Some(_) if numeric_mode == NumericMode::Reject => {
+                    return Err(ValError::new(ErrorType::DatetimeType, input))
+                }
+                Some(number) => match numeric_mode.number_as_datetime(number) {
+                    Ok(dt) => dt.into(),
+                    Err(error) => return Err(ValError::new(ErrorType::DatetimeParsing { error: error.into() }, input)),
+                },
+                None => input.validate_datetime(false)?,
+            },
+            _ => input.validate_datetime(strict)?,
+        };
         if let Some(constraints) = &self.constraints {
             // if we get an error from as_speedate, it's probably because the input datetime was invalid
             // specifically had an invalid tzinfo, hence here we return a validation error
@@ -78,10 +94,7 @@ impl Validator for DateTimeValidator {
             check_constraint!(gt, GreaterThan);
 
             if let Some(ref now_constraint) = constraints.now {
-                let offset = now_constraint.utc_offset(py)?;
-                let now = DateTime::now(offset).map_err(|e| {
-                    py_schema_error_type!("DateTime::now() error: {}", e.get_documentation().unwrap_or("unknown"))
-                })?;
+                let now = now_constraint.now(py)?;
                 // `if let Some(c)` to match behaviour of gt/lt/le/ge
                 if let Some(c) = speedate_dt.partial_cmp(&now) {
                     let dt_compliant = now_constraint.op.compare(c);
@@ -155,6 +168,87 @@ fn py_datetime_as_datetime(schema: &PyDict, field: &PyString) -> PyResult<Option
     }
 }
 
+/// How int and float inputs to date and datetime fields should be interpreted, if `numeric_mode` is omitted,
+/// we infer seconds or milliseconds since the unix epoch from the magnitude of the number
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NumericMode {
+    UnixSeconds,
+    UnixMs,
+    Ordinal,
+    Reject,
+}
+
+// `date(1970, 1, 1).toordinal()`
+const UNIX_EPOCH_ORDINAL: i64 = 719_163;
+// speedate interprets timestamps larger than this as milliseconds
+const MS_WATERSHED: i64 = 20_000_000_000;
+
+impl NumericMode {
+    pub fn from_py(schema: &PyDict) -> PyResult<Option<Self>> {
+        let py = schema.py();
+        match schema.get_as::<&str>(intern!(py, "numeric_mode"))? {
+            Some("unix-seconds") => Ok(Some(Self::UnixSeconds)),
+            Some("unix-ms") => Ok(Some(Self::UnixMs)),
+            Some("ordinal") => Ok(Some(Self::Ordinal)),
+            Some("reject") => Ok(Some(Self::Reject)),
+            Some(s) => py_schema_err!("Invalid numeric_mode {:?}", s),
+            None => Ok(None),
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::UnixSeconds => "unix-seconds",
+            Self::UnixMs => "unix-ms",
+            Self::Ordinal => "ordinal",
+            Self::Reject => "reject",
+        }
+    }
+
+    /// Interpret `number` as a datetime according to this mode, the error is a description of why the number
+    /// isn't valid which mentions the mode used, `Reject` should be handled by the caller.
+    pub fn number_as_datetime(&self, number: f64) -> Result<DateTime, String> {
+        let error = |msg: &str| format!("{msg} (interpreted as {})", self.as_str());
+        if !number.is_finite() {
+            return Err(error("NaN and infinite values not permitted"));
+        }
+        let (seconds, microseconds) = match self {
+            Self::UnixSeconds => {
+                let seconds = number.floor();
+                (seconds as i64, ((number - seconds) * 1_000_000.0).round() as u32)
+            }
+            Self::UnixMs => {
+                let ms = number.floor();
+                let ms_int = ms as i64;
+                let microseconds = ms_int.rem_euclid(1_000) * 1_000 + ((number - ms) * 1_000.0).round() as i64;
+                (ms_int.div_euclid(1_000), microseconds as u32)
+            }
+            Self::Ordinal => {
+                if number.fract() != 0.0 {
+                    return Err(error("ordinals must be whole numbers"));
+                }
+                if number < 1.0 {
+                    return Err(error("ordinals must be greater than zero"));
+                }
+                let days = (number as i64).saturating_sub(UNIX_EPOCH_ORDINAL);
+                (days.saturating_mul(86_400), 0)
+            }
+            Self::Reject => return Err(error("numeric input is not permitted")),
+        };
+        // speedate would reinterpret large timestamps as milliseconds, so pass them as milliseconds explicitly
+        let result = if seconds.saturating_abs() > MS_WATERSHED {
+            match seconds.checked_mul(1_000) {
+                Some(ms) => DateTime::from_timestamp(ms, microseconds),
+                None if seconds < 0 => Err(ParseError::DateTooSmall),
+                None => Err(ParseError::DateTooLarge),
+            }
+        } else {
+            DateTime::from_timestamp(seconds, microseconds)
+        };
+        result.map_err(|e| error(e.get_documentation().unwrap_or_default()))
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum NowOp {
     Past,
@@ -185,10 +279,12 @@ pub struct NowConstraint {
     utc_offset: Option<i32>,
 }
 
+static TIME_TIME: GILOnceCell<PyObject> = GILOnceCell::new();
 static TIME_LOCALTIME: GILOnceCell<PyObject> = GILOnceCell::new();
 
-fn get_localtime(py: Python) -> PyResult<PyObject> {
-    Ok(py.import("time")?.getattr("localtime")?.into_py(py))
+fn call_time_function(py: Python, cell: &GILOnceCell<PyObject>, name: &str) -> PyResult<PyObject> {
+    let function = cell.get_or_try_init(py, || py.import(intern!(py, "time"))?.getattr(name).map(Into::into))?;
+    function.call0(py)
 }
 
 impl NowConstraint {
@@ -199,27 +295,54 @@ impl NowConstraint {
         if let Some(utc_offset) = self.utc_offset {
             Ok(utc_offset)
         } else {
-            let localtime = TIME_LOCALTIME.get_or_init(py, || get_localtime(py).unwrap());
-            localtime
-                .as_ref(py)
-                .call0()?
-                .getattr(intern!(py, "tm_gmtoff"))?
-                .extract()
+            call_time_function(py, &TIME_LOCALTIME, "localtime")?
+                .getattr(py, intern!(py, "tm_gmtoff"))?
+                .extract(py)
+        }
+    }
+
+    /// The current time in the constraint's timezone, this uses `time.time()` rather than the system clock
+    /// directly so it works (or fails with an exception) wherever python does, e.g. under emscripten
+    pub fn now(&self, py: Python) -> PyResult<DateTime> {
+        let offset = self.utc_offset(py)?;
+        let timestamp: f64 = call_time_function(py, &TIME_TIME, "time")?.extract(py)?;
+        let seconds = timestamp.floor();
+        let microseconds = ((timestamp - seconds) * 1_000_000.0) as u32;
+        let mut now = DateTime::from_timestamp(seconds as i64, microseconds).map_err(now_error)?;
+        now.time.tz_offset = Some(0);
+        if offset == 0 {
+            Ok(now)
+        } else {
+            now.in_timezone(offset).map_err(now_error)
         }
     }
 
     pub fn from_py(schema: &PyDict) -> PyResult<Option<Self>> {
         let py = schema.py();
         match schema.get_as(intern!(py, "now_op"))? {
-            Some(op) => Ok(Some(Self {
-                op: NowOp::from_str(op)?,
-                utc_offset: schema.get_as(intern!(py, "now_utc_offset"))?,
-            })),
+            Some(op) => {
+                let constraint = Self {
+                    op: NowOp::from_str(op)?,
+                    utc_offset: schema.get_as(intern!(py, "now_utc_offset"))?,
+                };
+                // fail when building the schema rather than on every validation if the current time is unavailable
+                if let Err(err) = constraint.now(py) {
+                    return py_schema_err!("Unable to get the current time for `now_op`: {}", err);
+                }
+                Ok(Some(constraint))
+            }
             None => Ok(None),
         }
     }
 }
 
+fn now_error(error: ParseError) -> PyErr {
+    PyValueError::new_err(format!(
+        "Invalid current time: {}",
+        error.get_documentation().unwrap_or("unknown")
+    ))
+}
+
 #[derive(Debug, Clone)]
 pub(super) enum TZConstraint {
     Naive,
@@ -1,9 +1,14 @@
+use std::fmt;
+use std::sync::Arc;
+
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList};
 
-use crate::errors::{ErrorType, ValError, ValResult};
+use crate::build_tools::py_schema_err;
+use crate::errors::{ErrorType, ValError, ValLineError, ValResult};
 use crate::input::Input;
+use crate::memory::{HeapSize, MemoryUsage};
 use crate::recursion_guard::RecursionGuard;
 use crate::tools::SchemaDict;
 
@@ -24,29 +29,71 @@ impl BuildValidator for DefinitionsValidatorBuilder {
 
         let schema_definitions: &PyList = schema.get_as_req(intern!(py, "definitions"))?;
 
-        for schema_definition in schema_definitions {
-            build_validator(schema_definition, config, definitions)?;
+        for (index, schema_definition) in schema_definitions.iter().enumerate() {
+            build_validator(
+                &["definitions".into(), index.into()],
+                schema_definition,
+                config,
+                definitions,
+            )?;
             // no need to store the validator here, it has already been stored in definitions if necessary
         }
 
         let inner_schema: &PyAny = schema.get_as_req(intern!(py, "schema"))?;
-        build_validator(inner_schema, config, definitions)
+        build_validator(&["schema".into()], inner_schema, config, definitions)
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct DefinitionRefValidator {
     validator_id: usize,
     inner_name: String,
-    // we have to record the answers to `Question`s as we can't access the validator when `ask()` is called
+    // the most definition references which may be nested inside each other while validating
+    recursion_limit: u16,
+    // the completed definition if it can't reference itself, in which case we can validate with it directly
+    // without looking it up in definitions or checking for recursion
+    definition: Option<Arc<CombinedValidator>>,
+}
+
+impl fmt::Debug for DefinitionRefValidator {
+    // the definition is omitted as it's already included with the other definitions
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DefinitionRefValidator")
+            .field("validator_id", &self.validator_id)
+            .field("inner_name", &self.inner_name)
+            .field("recursive", &self.definition.is_none())
+            .finish()
+    }
 }
 
 impl DefinitionRefValidator {
-    pub fn new(validator_id: usize) -> Self {
-        Self {
+    pub fn new(validator_id: usize, config: Option<&PyDict>) -> PyResult<Self> {
+        let recursion_limit = match config {
+            Some(config) => config.get_as(intern!(config.py(), "recursion_limit"))?,
+            None => None,
+        };
+        let recursion_limit = match recursion_limit {
+            Some(limit) if limit > MAX_RECURSION_LIMIT => {
+                return py_schema_err!(
+                    "`recursion_limit` must be at most {}, got {}",
+                    MAX_RECURSION_LIMIT,
+                    limit
+                )
+            }
+            Some(limit) => limit,
+            None => DEFAULT_RECURSION_LIMIT,
+        };
+        Ok(Self {
             validator_id,
             inner_name: "...".to_string(),
-        }
+            recursion_limit,
+            definition: None,
+        })
+    }
+
+    fn recursion_limit_error<'data>(&self, input: &'data impl Input<'data>) -> ValError<'data> {
+        let max_depth = self.recursion_limit as usize;
+        ValError::new(ErrorType::RecursionLimitExceeded { max_depth }, input)
     }
 }
 
@@ -55,18 +102,14 @@ impl BuildValidator for DefinitionRefValidator {
 
     fn build(
         schema: &PyDict,
-        _config: Option<&PyDict>,
+        config: Option<&PyDict>,
         definitions: &mut DefinitionsBuilder<CombinedValidator>,
     ) -> PyResult<CombinedValidator> {
         let schema_ref: String = schema.get_as_req(intern!(schema.py(), "schema_ref"))?;
 
         let validator_id = definitions.get_reference_id(&schema_ref);
 
-        Ok(Self {
-            validator_id,
-            inner_name: "...".to_string(),
-        }
-        .into())
+        Ok(Self::new(validator_id, config)?.into())
     }
 }
 
@@ -79,22 +122,41 @@ impl Validator for DefinitionRefValidator {
         definitions: &'data Definitions<CombinedValidator>,
         recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        if let Some(id) = input.identity() {
+        if let Some(ref definition) = self.definition {
+            return definition.validate(py, input, extra, definitions, recursion_guard);
+        }
+        let id = input.identity();
+        if let Some(id) = id {
             if recursion_guard.contains_or_insert(id, self.validator_id) {
                 // we don't remove id here, we leave that to the validator which originally added id to `recursion_guard`
-                Err(ValError::new(ErrorType::RecursionLoop, input))
-            } else {
-                if recursion_guard.incr_depth() > BACKUP_GUARD_LIMIT {
-                    return Err(ValError::new(ErrorType::RecursionLoop, input));
-                }
-                let output = validate(self.validator_id, py, input, extra, definitions, recursion_guard);
-                recursion_guard.remove(id, self.validator_id);
-                recursion_guard.decr_depth();
-                output
+                return Err(ValError::new(ErrorType::RecursionLoop, input));
             }
+        }
+        // the depth is counted for inputs without an identity too, e.g. JSON, so deeply nested input can't overflow
+        // the stack, it's restored whether or not validation succeeds so sibling branches start from the same depth
+        let output = if recursion_guard.incr_depth() > self.recursion_limit {
+            Err(self.recursion_limit_error(input))
         } else {
             validate(self.validator_id, py, input, extra, definitions, recursion_guard)
+        };
+        if let Some(id) = id {
+            recursion_guard.remove(id, self.validator_id);
         }
+        recursion_guard.decr_depth();
+        output
+    }
+
+    fn validate_partial<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        definitions: &'data Definitions<CombinedValidator>,
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, (PyObject, Vec<ValLineError<'data>>)> {
+        // partial validation only applies to the top level schema, so this can't be recursive
+        let validator = definitions.get(self.validator_id).unwrap();
+        validator.validate_partial(py, input, extra, definitions, recursion_guard)
     }
 
     fn validate_assignment<'s, 'data: 's>(
@@ -107,28 +169,26 @@ impl Validator for DefinitionRefValidator {
         definitions: &'data Definitions<CombinedValidator>,
         recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        if let Some(id) = obj.identity() {
+        if let Some(ref definition) = self.definition {
+            return definition.validate_assignment(
+                py,
+                obj,
+                field_name,
+                field_value,
+                extra,
+                definitions,
+                recursion_guard,
+            );
+        }
+        let id = obj.identity();
+        if let Some(id) = id {
             if recursion_guard.contains_or_insert(id, self.validator_id) {
                 // we don't remove id here, we leave that to the validator which originally added id to `recursion_guard`
-                Err(ValError::new(ErrorType::RecursionLoop, obj))
-            } else {
-                if recursion_guard.incr_depth() > BACKUP_GUARD_LIMIT {
-                    return Err(ValError::new(ErrorType::RecursionLoop, obj));
-                }
-                let output = validate_assignment(
-                    self.validator_id,
-                    py,
-                    obj,
-                    field_name,
-                    field_value,
-                    extra,
-                    definitions,
-                    recursion_guard,
-                );
-                recursion_guard.remove(id, self.validator_id);
-                recursion_guard.decr_depth();
-                output
+                return Err(ValError::new(ErrorType::RecursionLoop, obj));
             }
+        }
+        let output = if recursion_guard.incr_depth() > self.recursion_limit {
+            Err(self.recursion_limit_error(obj))
         } else {
             validate_assignment(
                 self.validator_id,
@@ -140,7 +200,12 @@ impl Validator for DefinitionRefValidator {
                 definitions,
                 recursion_guard,
             )
+        };
+        if let Some(id) = id {
+            recursion_guard.remove(id, self.validator_id);
         }
+        recursion_guard.decr_depth();
+        output
     }
 
     fn different_strict_behavior(
@@ -165,19 +230,29 @@ impl Validator for DefinitionRefValidator {
     fn complete(&mut self, definitions: &DefinitionsBuilder<CombinedValidator>) -> PyResult<()> {
         let validator = definitions.get_definition(self.validator_id)?;
         self.inner_name = validator.get_name().to_string();
+        self.definition = definitions.get_completed(self.validator_id).cloned();
         Ok(())
     }
+
+    fn memory_usage(&self, _memory: &mut MemoryUsage) -> usize {
+        // the definition itself is visited separately
+        self.inner_name.heap_size()
+    }
 }
 
-// see #143 this is a backup in case the identity check recursion guard fails
-// if a single validator "depth" (how many times it's called inside itself) exceeds the limit,
-// we raise a recursion error.
-const BACKUP_GUARD_LIMIT: u16 = if cfg!(PyPy) || cfg!(target_family = "wasm") {
+// see #143 this is also a backup in case the identity check recursion guard fails
+// if definition references are nested more deeply than the limit (set with the `recursion_limit` config key),
+// we raise a recursion error rather than overflowing the stack.
+const DEFAULT_RECURSION_LIMIT: u16 = if cfg!(PyPy) || cfg!(target_family = "wasm") {
     123
 } else {
     255
 };
 
+// each level of nesting can take several stack frames, which are large in debug builds, so nesting much deeper than
+// the default can overflow the stack regardless of the config, the limit can only be lowered
+const MAX_RECURSION_LIMIT: u16 = DEFAULT_RECURSION_LIMIT;
+
 fn validate<'s, 'data>(
     validator_id: usize,
     py: Python<'data>,
@@ -1,18 +1,21 @@
-use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyMapping};
+use pyo3::{intern, PyTraverseError, PyVisit};
 
 use crate::build_tools::is_strict;
 use crate::errors::{ValError, ValLineError, ValResult};
 use crate::input::{
     DictGenericIterator, GenericMapping, Input, JsonObject, JsonObjectGenericIterator, MappingGenericIterator,
 };
+use crate::memory::{HeapSize, MemoryUsage};
 use crate::recursion_guard::RecursionGuard;
 use crate::tools::SchemaDict;
 
 use super::any::AnyValidator;
 use super::list::length_check;
-use super::{build_validator, BuildValidator, CombinedValidator, Definitions, DefinitionsBuilder, Extra, Validator};
+use super::{
+    build_validator, capped_name, BuildValidator, CombinedValidator, Definitions, DefinitionsBuilder, Extra, Validator,
+};
 
 #[derive(Debug, Clone)]
 pub struct DictValidator {
@@ -34,18 +37,18 @@ impl BuildValidator for DictValidator {
     ) -> PyResult<CombinedValidator> {
         let py = schema.py();
         let key_validator = match schema.get_item(intern!(py, "keys_schema")) {
-            Some(schema) => Box::new(build_validator(schema, config, definitions)?),
+            Some(schema) => Box::new(build_validator(&["keys_schema".into()], schema, config, definitions)?),
             None => Box::new(AnyValidator::build(schema, config, definitions)?),
         };
         let value_validator = match schema.get_item(intern!(py, "values_schema")) {
-            Some(d) => Box::new(build_validator(d, config, definitions)?),
+            Some(d) => Box::new(build_validator(&["values_schema".into()], d, config, definitions)?),
             None => Box::new(AnyValidator::build(schema, config, definitions)?),
         };
         let name = format!(
             "{}[{},{}]",
             Self::EXPECTED_TYPE,
-            key_validator.get_name(),
-            value_validator.get_name()
+            capped_name(key_validator.get_name()),
+            capped_name(value_validator.get_name())
         );
         Ok(Self {
             strict: is_strict(schema, config)?,
@@ -60,6 +63,12 @@ impl BuildValidator for DictValidator {
 }
 
 impl Validator for DictValidator {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        self.key_validator.py_gc_traverse(visit)?;
+        self.value_validator.py_gc_traverse(visit)?;
+        Ok(())
+    }
+
     fn validate<'s, 'data>(
         &'s self,
         py: Python<'data>,
@@ -70,6 +79,11 @@ impl Validator for DictValidator {
     ) -> ValResult<'data, PyObject> {
         let dict = input.validate_dict(extra.strict.unwrap_or(self.strict))?;
         match dict {
+            GenericMapping::PyDict(py_dict) if self.any_keys_values() => {
+                let output = py_dict.copy()?;
+                length_check!(input, "Dictionary", self.min_length, self.max_length, output);
+                Ok(output.into())
+            }
             GenericMapping::PyDict(py_dict) => {
                 self.validate_dict(py, input, py_dict, extra, definitions, recursion_guard)
             }
@@ -104,6 +118,12 @@ impl Validator for DictValidator {
         self.key_validator.complete(definitions)?;
         self.value_validator.complete(definitions)
     }
+
+    fn memory_usage(&self, memory: &mut MemoryUsage) -> usize {
+        memory.visit_boxed(&["keys_schema".into()], self.key_validator.as_ref())
+            + memory.visit_boxed(&["values_schema".into()], self.value_validator.as_ref())
+            + self.name.heap_size()
+    }
 }
 
 macro_rules! build_validate {
@@ -119,12 +139,23 @@ macro_rules! build_validate {
         ) -> ValResult<'data, PyObject> {
             let output = PyDict::new(py);
             let mut errors: Vec<ValLineError> = Vec::new();
+            let extra = &extra.with_index(None);
 
             let key_validator = self.key_validator.as_ref();
             let value_validator = self.value_validator.as_ref();
+            // `AnyValidator` would just return the key or value, so we can skip calling it
+            let validate_keys = !matches!(key_validator.inner(), CombinedValidator::Any(_));
+            let validate_values = !matches!(value_validator.inner(), CombinedValidator::Any(_));
             for item_result in <$iter>::new(dict)? {
+                if extra.fail_fast && !errors.is_empty() {
+                    break;
+                }
                 let (key, value) = item_result?;
-                let output_key = match key_validator.validate(py, key, extra, definitions, recursion_guard) {
+                let key_result = match validate_keys {
+                    true => key_validator.validate(py, key, extra, definitions, recursion_guard),
+                    false => Ok(key.to_object(py)),
+                };
+                let output_key = match key_result {
                     Ok(value) => Some(value),
                     Err(ValError::LineErrors(line_errors)) => {
                         for err in line_errors {
@@ -139,7 +170,11 @@ macro_rules! build_validate {
                     Err(ValError::Omit) => continue,
                     Err(err) => return Err(err),
                 };
-                let output_value = match value_validator.validate(py, value, extra, definitions, recursion_guard) {
+                let value_result = match validate_values {
+                    true => value_validator.validate(py, value, extra, definitions, recursion_guard),
+                    false => Ok(value.to_object(py)),
+                };
+                let output_value = match value_result {
                     Ok(value) => Some(value),
                     Err(ValError::LineErrors(line_errors)) => {
                         for err in line_errors {
@@ -166,6 +201,13 @@ macro_rules! build_validate {
 }
 
 impl DictValidator {
+    fn any_keys_values(&self) -> bool {
+        matches!(
+            (self.key_validator.inner(), self.value_validator.inner()),
+            (CombinedValidator::Any(_), CombinedValidator::Any(_))
+        )
+    }
+
     build_validate!(validate_dict, PyDict, DictGenericIterator);
     build_validate!(validate_mapping, PyMapping, MappingGenericIterator);
     build_validate!(validate_json_object, JsonObject, JsonObjectGenericIterator);
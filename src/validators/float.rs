@@ -3,8 +3,8 @@ use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
 use crate::build_tools::{is_strict, schema_or_config_same};
-use crate::errors::{ErrorType, ValError, ValResult};
-use crate::input::Input;
+use crate::errors::{ErrorType, ValError, ValLineError, ValResult};
+use crate::input::{EitherFloat, Input};
 use crate::recursion_guard::RecursionGuard;
 use crate::tools::SchemaDict;
 
@@ -31,16 +31,45 @@ impl BuildValidator for FloatBuilder {
             Ok(FloatValidator {
                 strict: is_strict(schema, config)?,
                 allow_inf_nan: schema_or_config_same(schema, config, intern!(py, "allow_inf_nan"))?.unwrap_or(true),
+                allow_number_protocols: config.get_as(intern!(py, "allow_number_protocols"))?.unwrap_or(false),
             }
             .into())
         }
     }
 }
 
+/// Like `input.validate_float(...)`, but if `allow_number_protocols` is set and we're in lax mode, inputs which
+/// would otherwise fail with `float_type` are converted with `__int__` if they define it
+/// (`__float__` is already respected in lax mode)
+fn validate_float_or_protocol<'data>(
+    input: &'data impl Input<'data>,
+    strict: bool,
+    ultra_strict: bool,
+    allow_number_protocols: bool,
+) -> ValResult<'data, EitherFloat<'data>> {
+    match input.validate_float(strict, ultra_strict) {
+        Err(ValError::LineErrors(line_errors))
+            if allow_number_protocols
+                && !strict
+                && matches!(
+                    line_errors.as_slice(),
+                    [ValLineError {
+                        error_type: ErrorType::FloatType,
+                        ..
+                    }]
+                ) =>
+        {
+            input.float_from_protocol().ok_or(ValError::LineErrors(line_errors))
+        }
+        result => result,
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct FloatValidator {
     strict: bool,
     allow_inf_nan: bool,
+    allow_number_protocols: bool,
 }
 
 impl BuildValidator for FloatValidator {
@@ -55,11 +84,18 @@ impl BuildValidator for FloatValidator {
         Ok(Self {
             strict: is_strict(schema, config)?,
             allow_inf_nan: schema_or_config_same(schema, config, intern!(py, "allow_inf_nan"))?.unwrap_or(true),
+            allow_number_protocols: config.get_as(intern!(py, "allow_number_protocols"))?.unwrap_or(false),
         }
         .into())
     }
 }
 
+impl FloatValidator {
+    pub fn allow_inf_nan(&self) -> bool {
+        self.allow_inf_nan
+    }
+}
+
 impl Validator for FloatValidator {
     fn validate<'s, 'data>(
         &'s self,
@@ -69,7 +105,8 @@ impl Validator for FloatValidator {
         _definitions: &'data Definitions<CombinedValidator>,
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        let either_float = input.validate_float(extra.strict.unwrap_or(self.strict), extra.ultra_strict)?;
+        let strict = extra.strict.unwrap_or(self.strict);
+        let either_float = validate_float_or_protocol(input, strict, extra.ultra_strict, self.allow_number_protocols)?;
         let float: f64 = either_float.try_into()?;
         if !self.allow_inf_nan && !float.is_finite() {
             return Err(ValError::new(ErrorType::FiniteNumber, input));
@@ -98,6 +135,7 @@ impl Validator for FloatValidator {
 pub struct ConstrainedFloatValidator {
     strict: bool,
     allow_inf_nan: bool,
+    allow_number_protocols: bool,
     multiple_of: Option<f64>,
     le: Option<f64>,
     lt: Option<f64>,
@@ -114,7 +152,8 @@ impl Validator for ConstrainedFloatValidator {
         _definitions: &'data Definitions<CombinedValidator>,
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        let either_float = input.validate_float(extra.strict.unwrap_or(self.strict), extra.ultra_strict)?;
+        let strict = extra.strict.unwrap_or(self.strict);
+        let either_float = validate_float_or_protocol(input, strict, extra.ultra_strict, self.allow_number_protocols)?;
         let float: f64 = either_float.try_into()?;
         if !self.allow_inf_nan && !float.is_finite() {
             return Err(ValError::new(ErrorType::FiniteNumber, input));
@@ -182,6 +221,7 @@ impl BuildValidator for ConstrainedFloatValidator {
         Ok(Self {
             strict: is_strict(schema, config)?,
             allow_inf_nan: schema_or_config_same(schema, config, intern!(py, "allow_inf_nan"))?.unwrap_or(true),
+            allow_number_protocols: config.get_as(intern!(py, "allow_number_protocols"))?.unwrap_or(false),
             multiple_of: schema.get_as(intern!(py, "multiple_of"))?,
             le: schema.get_as(intern!(py, "le"))?,
             lt: schema.get_as(intern!(py, "lt"))?,
@@ -1,21 +1,29 @@
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyFrozenSet};
+use pyo3::{PyTraverseError, PyVisit};
 
-use crate::errors::ValResult;
+use crate::errors::{ValLineError, ValResult};
 use crate::input::Input;
+use crate::memory::{HeapSize, MemoryUsage};
 use crate::recursion_guard::RecursionGuard;
 use crate::tools::SchemaDict;
 
+use super::iterated::{IterableValidationChecks, IteratedOutputType};
 use super::list::min_length_check;
-use super::set::set_build;
+use super::set::{duplicate_items_check, partial_min_length_error, set_build};
 use super::{BuildValidator, CombinedValidator, Definitions, DefinitionsBuilder, Extra, Validator};
 
 #[derive(Debug, Clone)]
 pub struct FrozenSetValidator {
     strict: bool,
     item_validator: Box<CombinedValidator>,
+    items_strict: Option<bool>,
     min_length: Option<usize>,
     max_length: Option<usize>,
+    unique_items_error: bool,
+    fail_fast: bool,
+    max_errors: Option<usize>,
+    hash_key: Option<PyObject>,
     name: String,
 }
 
@@ -25,6 +33,13 @@ impl BuildValidator for FrozenSetValidator {
 }
 
 impl Validator for FrozenSetValidator {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        if let Some(hash_key) = &self.hash_key {
+            visit.call(hash_key)?;
+        }
+        self.item_validator.py_gc_traverse(visit)
+    }
+
     fn validate<'s, 'data>(
         &'s self,
         py: Python<'data>,
@@ -35,21 +50,82 @@ impl Validator for FrozenSetValidator {
     ) -> ValResult<'data, PyObject> {
         let collection = input.validate_frozenset(extra.strict.unwrap_or(self.strict))?;
         let f_set = PyFrozenSet::empty(py)?;
-        collection.validate_to_set(
+        let items_added = collection.validate_to_set(
             py,
             f_set,
             input,
             self.max_length,
+            self.fail_fast,
+            self.max_errors,
+            self.hash_key.as_ref(),
             "Frozenset",
             &self.item_validator,
-            extra,
+            &extra.with_items_strict(self.items_strict),
             definitions,
             recursion_guard,
+            None,
         )?;
+        if self.unique_items_error {
+            duplicate_items_check(input, "Frozenset", self.min_length, items_added, f_set.len())?;
+        }
         min_length_check!(input, "Frozenset", self.min_length, f_set);
         Ok(f_set.into_py(py))
     }
 
+    fn validate_partial<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        definitions: &'data Definitions<CombinedValidator>,
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, (PyObject, Vec<ValLineError<'data>>)> {
+        let collection = input.validate_frozenset(extra.strict.unwrap_or(self.strict))?;
+        let f_set = PyFrozenSet::empty(py)?;
+        let mut errors = Vec::new();
+        let items_added = collection.validate_to_set(
+            py,
+            f_set,
+            input,
+            self.max_length,
+            self.fail_fast,
+            self.max_errors,
+            self.hash_key.as_ref(),
+            "Frozenset",
+            &self.item_validator,
+            &extra.with_items_strict(self.items_strict),
+            definitions,
+            recursion_guard,
+            Some(&mut errors),
+        )?;
+        errors.extend(partial_min_length_error(
+            input,
+            "Frozenset",
+            self.min_length,
+            self.unique_items_error,
+            items_added,
+            f_set.len(),
+        ));
+        Ok((f_set.into_py(py), errors))
+    }
+
+    fn iterable_checks(&self) -> Option<(Option<&CombinedValidator>, IterableValidationChecks)> {
+        if self.hash_key.is_some() {
+            // items are deduplicated by their key, which `validate_iterated` doesn't do
+            return None;
+        }
+        let checks = IterableValidationChecks {
+            output_type: IteratedOutputType::FrozenSet,
+            min_length: self.min_length,
+            max_length: self.max_length,
+            fail_fast: self.fail_fast,
+            max_errors: self.max_errors,
+            items_strict: self.items_strict,
+            unique_items_error: self.unique_items_error,
+        };
+        Some((Some(self.item_validator.as_ref()), checks))
+    }
+
     fn different_strict_behavior(
         &self,
         definitions: Option<&DefinitionsBuilder<CombinedValidator>>,
@@ -69,4 +145,8 @@ impl Validator for FrozenSetValidator {
     fn complete(&mut self, definitions: &DefinitionsBuilder<CombinedValidator>) -> PyResult<()> {
         self.item_validator.complete(definitions)
     }
+
+    fn memory_usage(&self, memory: &mut MemoryUsage) -> usize {
+        memory.visit_boxed(&["items_schema".into()], self.item_validator.as_ref()) + self.name.heap_size()
+    }
 }
@@ -1,19 +1,21 @@
 use pyo3::exceptions::{PyAssertionError, PyAttributeError, PyRuntimeError, PyTypeError, PyValueError};
-use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyAny, PyDict, PyString};
+use pyo3::{intern, PyTraverseError, PyVisit};
 
 use crate::errors::{
     ErrorType, LocItem, PydanticCustomError, PydanticKnownError, PydanticOmit, ValError, ValResult, ValidationError,
 };
 use crate::input::Input;
+use crate::memory::{HeapSize, MemoryUsage};
 use crate::recursion_guard::RecursionGuard;
 use crate::tools::{function_name, py_err, SchemaDict};
 use crate::PydanticUseDefault;
 
 use super::generator::InternalValidator;
 use super::{
-    build_validator, BuildValidator, CombinedValidator, Definitions, DefinitionsBuilder, Extra, InputType, Validator,
+    build_validator, capped_name, BuildValidator, CombinedValidator, Definitions, DefinitionsBuilder, Extra, InputType,
+    Validator,
 };
 
 fn destructure_function_schema(schema: &PyDict) -> PyResult<(bool, bool, &PyAny)> {
@@ -39,13 +41,18 @@ macro_rules! impl_build {
                 definitions: &mut DefinitionsBuilder<CombinedValidator>,
             ) -> PyResult<CombinedValidator> {
                 let py = schema.py();
-                let validator = build_validator(schema.get_as_req(intern!(py, "schema"))?, config, definitions)?;
+                let validator = build_validator(
+                    &["schema".into()],
+                    schema.get_as_req(intern!(py, "schema"))?,
+                    config,
+                    definitions,
+                )?;
                 let (is_field_validator, info_arg, function) = destructure_function_schema(schema)?;
                 let name = format!(
                     "{}[{}(), {}]",
                     $name,
                     function_name(function)?,
-                    validator.get_name()
+                    capped_name(validator.get_name())
                 );
                 Ok(Self {
                     validator: Box::new(validator),
@@ -67,6 +74,12 @@ macro_rules! impl_build {
 macro_rules! impl_validator {
     ($name:ident) => {
         impl Validator for $name {
+            fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+                visit.call(&self.func)?;
+                visit.call(&self.config)?;
+                self.validator.py_gc_traverse(visit)
+            }
+
             fn validate<'s, 'data>(
                 &'s self,
                 py: Python<'data>,
@@ -116,6 +129,10 @@ macro_rules! impl_validator {
             fn complete(&mut self, definitions: &DefinitionsBuilder<CombinedValidator>) -> PyResult<()> {
                 self.validator.complete(definitions)
             }
+
+            fn memory_usage(&self, memory: &mut MemoryUsage) -> usize {
+                memory.visit_boxed(&["schema".into()], self.validator.as_ref()) + self.name.heap_size()
+            }
         }
     };
 }
@@ -220,6 +237,12 @@ impl BuildValidator for FunctionPlainValidator {
 }
 
 impl Validator for FunctionPlainValidator {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        visit.call(&self.func)?;
+        visit.call(&self.config)?;
+        Ok(())
+    }
+
     fn validate<'s, 'data>(
         &'s self,
         py: Python<'data>,
@@ -253,6 +276,10 @@ impl Validator for FunctionPlainValidator {
     fn complete(&mut self, _definitions: &DefinitionsBuilder<CombinedValidator>) -> PyResult<()> {
         Ok(())
     }
+
+    fn memory_usage(&self, _memory: &mut MemoryUsage) -> usize {
+        self.name.heap_size()
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -275,7 +302,12 @@ impl BuildValidator for FunctionWrapValidator {
         definitions: &mut DefinitionsBuilder<CombinedValidator>,
     ) -> PyResult<CombinedValidator> {
         let py = schema.py();
-        let validator = build_validator(schema.get_as_req(intern!(py, "schema"))?, config, definitions)?;
+        let validator = build_validator(
+            &["schema".into()],
+            schema.get_as_req(intern!(py, "schema"))?,
+            config,
+            definitions,
+        )?;
         let (is_field_validator, info_arg, function) = destructure_function_schema(schema)?;
         let hide_input_in_errors: bool = config.get_as(intern!(py, "hide_input_in_errors"))?.unwrap_or(false);
         Ok(Self {
@@ -313,6 +345,13 @@ impl FunctionWrapValidator {
 }
 
 impl Validator for FunctionWrapValidator {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        visit.call(&self.func)?;
+        visit.call(&self.config)?;
+        self.validator.py_gc_traverse(visit)?;
+        Ok(())
+    }
+
     fn validate<'s, 'data>(
         &'s self,
         py: Python<'data>,
@@ -385,6 +424,10 @@ impl Validator for FunctionWrapValidator {
     fn complete(&mut self, definitions: &DefinitionsBuilder<CombinedValidator>) -> PyResult<()> {
         self.validator.complete(definitions)
     }
+
+    fn memory_usage(&self, memory: &mut MemoryUsage) -> usize {
+        memory.visit_boxed(&["schema".into()], self.validator.as_ref()) + self.name.heap_size()
+    }
 }
 
 #[pyclass(module = "pydantic_core._pydantic_core")]
@@ -503,10 +546,12 @@ pub struct ValidationInfo {
     field_name: Option<String>,
     #[pyo3(get)]
     mode: InputType,
+    #[pyo3(get)]
+    index: Option<usize>,
 }
 
 impl ValidationInfo {
-    fn new(py: Python, extra: &Extra, config: &PyObject, is_field_validator: bool) -> PyResult<Self> {
+    pub fn new(py: Python, extra: &Extra, config: &PyObject, is_field_validator: bool) -> PyResult<Self> {
         if is_field_validator {
             match extra.field_name {
                 Some(field_name) => Ok(
@@ -516,6 +561,7 @@ impl ValidationInfo {
                         field_name: Some(field_name.to_string()),
                         data: extra.data.map(Into::into),
                         mode: extra.mode,
+                        index: extra.index,
                     }
                 ),
                 _ => Err(PyRuntimeError::new_err("This validator expected to be run inside the context of a model field but no model field was found")),
@@ -527,6 +573,7 @@ impl ValidationInfo {
                 field_name: None,
                 data: None,
                 mode: extra.mode,
+                index: extra.index,
             })
         }
     }
@@ -563,6 +610,9 @@ impl ValidationInfo {
         if let Some(ref field_name) = self.field_name {
             s += &format!(", field_name='{field_name}'");
         }
+        if let Some(index) = self.index {
+            s += &format!(", index={index}");
+        }
         s += ")";
         Ok(s)
     }
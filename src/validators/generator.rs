@@ -1,16 +1,21 @@
 use std::fmt;
+use std::sync::Arc;
 
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
+use pyo3::{PyTraverseError, PyVisit};
 
 use crate::errors::{ErrorMode, ErrorType, LocItem, ValError, ValResult};
 use crate::input::{GenericIterator, Input};
+use crate::memory::{HeapSize, MemoryUsage};
 use crate::recursion_guard::RecursionGuard;
 use crate::tools::SchemaDict;
 use crate::ValidationError;
 
 use super::list::get_items_schema;
-use super::{BuildValidator, CombinedValidator, Definitions, DefinitionsBuilder, Extra, InputType, Validator};
+use super::{
+    capped_name, BuildValidator, CombinedValidator, Definitions, DefinitionsBuilder, Extra, InputType, Validator,
+};
 
 #[derive(Debug, Clone)]
 pub struct GeneratorValidator {
@@ -31,7 +36,7 @@ impl BuildValidator for GeneratorValidator {
     ) -> PyResult<CombinedValidator> {
         let item_validator = get_items_schema(schema, config, definitions)?;
         let name = match item_validator {
-            Some(ref v) => format!("{}[{}]", Self::EXPECTED_TYPE, v.get_name()),
+            Some(ref v) => format!("{}[{}]", Self::EXPECTED_TYPE, capped_name(v.get_name())),
             None => format!("{}[any]", Self::EXPECTED_TYPE),
         };
         let hide_input_in_errors: bool = config
@@ -49,6 +54,13 @@ impl BuildValidator for GeneratorValidator {
 }
 
 impl Validator for GeneratorValidator {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        if let Some(ref validator) = self.item_validator {
+            validator.py_gc_traverse(visit)?;
+        }
+        Ok(())
+    }
+
     fn validate<'s, 'data>(
         &'s self,
         py: Python<'data>,
@@ -102,6 +114,14 @@ impl Validator for GeneratorValidator {
             None => Ok(()),
         }
     }
+
+    fn memory_usage(&self, memory: &mut MemoryUsage) -> usize {
+        let items = match self.item_validator {
+            Some(ref validator) => memory.visit_boxed(&["items_schema".into()], validator.as_ref()),
+            None => 0,
+        };
+        items + self.name.heap_size()
+    }
 }
 
 #[pyclass(module = "pydantic_core._pydantic_core")]
@@ -152,7 +172,7 @@ impl ValidatorIterator {
                                     ));
                                 }
                             }
-                            validator.validate(py, next, Some(index.into())).map(Some)
+                            validator.validate_item(py, next, index).map(Some)
                         }
                         None => Ok(Some(next.to_object(py))),
                     },
@@ -212,12 +232,13 @@ impl ValidatorIterator {
 pub struct InternalValidator {
     name: String,
     validator: CombinedValidator,
-    definitions: Vec<CombinedValidator>,
+    definitions: Vec<Arc<CombinedValidator>>,
     // TODO, do we need data?
     data: Option<Py<PyDict>>,
     strict: Option<bool>,
     from_attributes: Option<bool>,
     context: Option<PyObject>,
+    index: Option<usize>,
     self_instance: Option<PyObject>,
     recursion_guard: RecursionGuard,
     validation_mode: InputType,
@@ -235,7 +256,7 @@ impl InternalValidator {
         py: Python,
         name: &str,
         validator: &CombinedValidator,
-        definitions: &[CombinedValidator],
+        definitions: &Definitions<CombinedValidator>,
         extra: &Extra,
         recursion_guard: &RecursionGuard,
         hide_input_in_errors: bool,
@@ -248,6 +269,7 @@ impl InternalValidator {
             strict: extra.strict,
             from_attributes: extra.from_attributes,
             context: extra.context.map(|d| d.into_py(py)),
+            index: extra.index,
             self_instance: extra.self_instance.map(|d| d.into_py(py)),
             recursion_guard: recursion_guard.clone(),
             validation_mode: extra.mode,
@@ -268,8 +290,10 @@ impl InternalValidator {
             data: self.data.as_ref().map(|data| data.as_ref(py)),
             strict: self.strict,
             ultra_strict: false,
+            fail_fast: false,
             from_attributes: self.from_attributes,
             context: self.context.as_ref().map(|data| data.as_ref(py)),
+            index: self.index,
             field_name: None,
             self_instance: self.self_instance.as_ref().map(|data| data.as_ref(py)),
         };
@@ -295,6 +319,20 @@ impl InternalValidator {
             })
     }
 
+    /// Validate an item of a generator, the index is available to validators as `info.index`
+    pub fn validate_item<'s, 'data>(
+        &'s mut self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        index: usize,
+    ) -> PyResult<PyObject>
+    where
+        's: 'data,
+    {
+        self.index = Some(index);
+        self.validate(py, input, Some(index.into()))
+    }
+
     pub fn validate<'s, 'data>(
         &'s mut self,
         py: Python<'data>,
@@ -309,8 +347,10 @@ impl InternalValidator {
             data: self.data.as_ref().map(|data| data.as_ref(py)),
             strict: self.strict,
             ultra_strict: false,
+            fail_fast: false,
             from_attributes: self.from_attributes,
             context: self.context.as_ref().map(|data| data.as_ref(py)),
+            index: self.index,
             field_name: None,
             self_instance: self.self_instance.as_ref().map(|data| data.as_ref(py)),
         };
@@ -4,8 +4,9 @@ use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
 use crate::build_tools::is_strict;
-use crate::errors::{ErrorType, ValError, ValResult};
-use crate::input::{Input, Int};
+use crate::errors::{ErrorType, ValError, ValLineError, ValResult};
+use crate::input::{EitherInt, Input, Int};
+use crate::memory::{HeapSize, MemoryUsage};
 use crate::recursion_guard::RecursionGuard;
 use crate::tools::SchemaDict;
 
@@ -14,6 +15,33 @@ use super::{BuildValidator, CombinedValidator, Definitions, DefinitionsBuilder,
 #[derive(Debug, Clone)]
 pub struct IntValidator {
     strict: bool,
+    allow_number_protocols: bool,
+}
+
+/// Like `input.validate_int(strict)`, but if `allow_number_protocols` is set and we're in lax mode, inputs which
+/// would otherwise fail with `int_type` are converted with `__int__` if they define it.
+/// Note that `__int__` generally truncates, e.g. a numpy float of `2.9` becomes `2`
+pub(super) fn validate_int_or_protocol<'data>(
+    input: &'data impl Input<'data>,
+    strict: bool,
+    allow_number_protocols: bool,
+) -> ValResult<'data, EitherInt<'data>> {
+    match input.validate_int(strict) {
+        Err(ValError::LineErrors(line_errors))
+            if allow_number_protocols
+                && !strict
+                && matches!(
+                    line_errors.as_slice(),
+                    [ValLineError {
+                        error_type: ErrorType::IntType,
+                        ..
+                    }]
+                ) =>
+        {
+            input.int_from_protocol().ok_or(ValError::LineErrors(line_errors))
+        }
+        result => result,
+    }
 }
 
 impl BuildValidator for IntValidator {
@@ -35,6 +63,7 @@ impl BuildValidator for IntValidator {
         } else {
             Ok(Self {
                 strict: is_strict(schema, config)?,
+                allow_number_protocols: config.get_as(intern!(py, "allow_number_protocols"))?.unwrap_or(false),
             }
             .into())
         }
@@ -50,7 +79,8 @@ impl Validator for IntValidator {
         _definitions: &'data Definitions<CombinedValidator>,
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        Ok(input.validate_int(extra.strict.unwrap_or(self.strict))?.into_py(py))
+        let strict = extra.strict.unwrap_or(self.strict);
+        Ok(validate_int_or_protocol(input, strict, self.allow_number_protocols)?.into_py(py))
     }
 
     fn different_strict_behavior(
@@ -73,6 +103,7 @@ impl Validator for IntValidator {
 #[derive(Debug, Clone)]
 pub struct ConstrainedIntValidator {
     strict: bool,
+    allow_number_protocols: bool,
     multiple_of: Option<Int>,
     le: Option<Int>,
     lt: Option<Int>,
@@ -89,7 +120,8 @@ impl Validator for ConstrainedIntValidator {
         _definitions: &'data Definitions<CombinedValidator>,
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        let either_int = input.validate_int(extra.strict.unwrap_or(self.strict))?;
+        let strict = extra.strict.unwrap_or(self.strict);
+        let either_int = validate_int_or_protocol(input, strict, self.allow_number_protocols)?;
         let int_value = either_int.as_int()?;
 
         if let Some(ref multiple_of) = self.multiple_of {
@@ -143,6 +175,14 @@ impl Validator for ConstrainedIntValidator {
     fn complete(&mut self, _definitions: &DefinitionsBuilder<CombinedValidator>) -> PyResult<()> {
         Ok(())
     }
+
+    fn memory_usage(&self, _memory: &mut MemoryUsage) -> usize {
+        self.multiple_of.heap_size()
+            + self.le.heap_size()
+            + self.lt.heap_size()
+            + self.ge.heap_size()
+            + self.gt.heap_size()
+    }
 }
 
 impl ConstrainedIntValidator {
@@ -150,6 +190,7 @@ impl ConstrainedIntValidator {
         let py = schema.py();
         Ok(Self {
             strict: is_strict(schema, config)?,
+            allow_number_protocols: config.get_as(intern!(py, "allow_number_protocols"))?.unwrap_or(false),
             multiple_of: schema.get_as(intern!(py, "multiple_of"))?,
             le: schema.get_as(intern!(py, "le"))?,
             lt: schema.get_as(intern!(py, "lt"))?,
@@ -1,11 +1,12 @@
 use pyo3::exceptions::PyNotImplementedError;
-use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyType};
+use pyo3::{intern, PyTraverseError, PyVisit};
 
 use crate::build_tools::py_schema_err;
 use crate::errors::{ErrorType, ValError, ValResult};
 use crate::input::Input;
+use crate::memory::{HeapSize, MemoryUsage};
 use crate::recursion_guard::RecursionGuard;
 use crate::tools::SchemaDict;
 
@@ -56,6 +57,10 @@ impl BuildValidator for IsInstanceValidator {
 }
 
 impl Validator for IsInstanceValidator {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        visit.call(&self.class)
+    }
+
     fn validate<'s, 'data>(
         &'s self,
         py: Python<'data>,
@@ -71,7 +76,9 @@ impl Validator for IsInstanceValidator {
             ))),
             InputType::Python => {
                 let ob = input.to_object(py);
-                match ob.as_ref(py).is_instance(self.class.as_ref(py))? {
+                let class = self.class.as_ref(py);
+                // exact type check first, then the isinstance protocol, e.g. for subclasses and `__instancecheck__`
+                match ob.as_ref(py).get_type().is(class) || ob.as_ref(py).is_instance(class)? {
                     true => Ok(ob),
                     false => Err(ValError::new(
                         ErrorType::IsInstanceOf {
@@ -99,4 +106,8 @@ impl Validator for IsInstanceValidator {
     fn complete(&mut self, _definitions: &DefinitionsBuilder<CombinedValidator>) -> PyResult<()> {
         Ok(())
     }
+
+    fn memory_usage(&self, _memory: &mut MemoryUsage) -> usize {
+        self.class_repr.heap_size() + self.name.heap_size()
+    }
 }
@@ -1,9 +1,10 @@
-use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyType};
+use pyo3::{intern, PyTraverseError, PyVisit};
 
 use crate::errors::{ErrorType, ValError, ValResult};
 use crate::input::Input;
+use crate::memory::{HeapSize, MemoryUsage};
 use crate::recursion_guard::RecursionGuard;
 use crate::tools::SchemaDict;
 
@@ -42,6 +43,10 @@ impl BuildValidator for IsSubclassValidator {
 }
 
 impl Validator for IsSubclassValidator {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        visit.call(&self.class)
+    }
+
     fn validate<'s, 'data>(
         &'s self,
         py: Python<'data>,
@@ -76,4 +81,8 @@ impl Validator for IsSubclassValidator {
     fn complete(&mut self, _definitions: &DefinitionsBuilder<CombinedValidator>) -> PyResult<()> {
         Ok(())
     }
+
+    fn memory_usage(&self, _memory: &mut MemoryUsage) -> usize {
+        self.class_repr.heap_size() + self.name.heap_size()
+    }
 }
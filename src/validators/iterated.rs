@@ -0,0 +1,319 @@
+use pyo3::exceptions::{PyRuntimeError, PyTypeError};
+use pyo3::prelude::*;
+use pyo3::types::{PyFrozenSet, PyList, PySet, PyTuple};
+use pyo3::{PyTraverseError, PyVisit};
+
+use crate::errors::{py_err_string, ErrorMode, ErrorType, PyLineError, ValError, ValLineError, ValResult};
+use crate::input::InputType;
+use crate::recursion_guard::RecursionGuard;
+
+use super::set::partial_min_length_error;
+use super::{Extra, SchemaValidator, Validator};
+
+/// The collection built by `validate_iterated`
+#[derive(Debug, Clone, Copy)]
+pub enum IteratedOutputType {
+    List,
+    Tuple,
+    Set,
+    FrozenSet,
+}
+
+impl IteratedOutputType {
+    fn field_type(self) -> &'static str {
+        match self {
+            Self::List => "List",
+            Self::Tuple => "Tuple",
+            Self::Set => "Set",
+            Self::FrozenSet => "Frozenset",
+        }
+    }
+}
+
+/// The constraints of a list, set, frozenset or variable length tuple which `validate_iterated` checks as items
+/// are fed to it, `max_length` and errors over `max_errors` are raised by `feed`, `min_length` by `finish`
+#[derive(Debug, Clone, Copy)]
+pub struct IterableValidationChecks {
+    pub output_type: IteratedOutputType,
+    pub min_length: Option<usize>,
+    pub max_length: Option<usize>,
+    pub fail_fast: bool,
+    pub max_errors: Option<usize>,
+    pub items_strict: Option<bool>,
+    pub unique_items_error: bool,
+}
+
+#[derive(Debug)]
+enum IteratedOutput {
+    Items(Vec<PyObject>),
+    Set(Py<PySet>),
+}
+
+/// Validation of a collection whose items are passed in batches, returned by `SchemaValidator.validate_iterated`.
+/// Valid items and item errors are kept between calls to `feed`, errors own their input so they outlive the batch
+/// they came from
+#[pyclass(module = "pydantic_core._pydantic_core")]
+#[cfg_attr(debug_assertions, derive(Debug))]
+pub struct IteratedValidation {
+    validator: Py<SchemaValidator>,
+    checks: IterableValidationChecks,
+    strict: Option<bool>,
+    from_attributes: Option<bool>,
+    context: Option<PyObject>,
+    output: IteratedOutput,
+    errors: Vec<PyLineError>,
+    // index of the next item, including omitted items
+    next_index: usize,
+    // items counted towards `max_length` for lists and tuples, items added for sets including duplicates
+    length: usize,
+    finished: bool,
+}
+
+impl IteratedValidation {
+    pub fn new(
+        py: Python,
+        validator: Py<SchemaValidator>,
+        checks: IterableValidationChecks,
+        strict: Option<bool>,
+        from_attributes: Option<bool>,
+        context: Option<&PyAny>,
+    ) -> PyResult<Self> {
+        let output = match checks.output_type {
+            IteratedOutputType::List | IteratedOutputType::Tuple => IteratedOutput::Items(Vec::new()),
+            IteratedOutputType::Set | IteratedOutputType::FrozenSet => IteratedOutput::Set(PySet::empty(py)?.into()),
+        };
+        Ok(Self {
+            validator,
+            checks,
+            strict,
+            from_attributes,
+            context: context.map(Into::into),
+            output,
+            errors: Vec::new(),
+            next_index: 0,
+            length: 0,
+            finished: false,
+        })
+    }
+
+    fn check_not_finished(&self) -> PyResult<()> {
+        if self.finished {
+            Err(PyRuntimeError::new_err("Iterated validation has already finished"))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn feed_items<'py>(
+        &mut self,
+        py: Python<'py>,
+        schema_validator: &'py SchemaValidator,
+        items: &'py PyAny,
+    ) -> ValResult<'py, ()> {
+        // `validate_iterated` checked the validator supports this
+        let (item_validator, _) = schema_validator.validator.iterable_checks().unwrap();
+        let context = self.context.as_ref().map(|context| context.clone_ref(py).into_ref(py));
+        let extra = Extra::new(self.strict, self.from_attributes, context, None, InputType::Python)
+            .with_items_strict(self.checks.items_strict);
+        let recursion_guard = &mut RecursionGuard::default();
+        let iter = items
+            .iter()
+            .map_err(|_| ValError::new(ErrorType::IterableType, items))?;
+        let field_type = self.checks.output_type.field_type();
+
+        for item_result in iter {
+            let index = self.next_index;
+            // the limit only stops validation if there's another item, which may be in a later batch
+            if let Some(max_errors) = self.checks.max_errors {
+                if self.errors.len() >= max_errors {
+                    self.errors.truncate(max_errors);
+                    let error_type = ErrorType::TooManyErrors {
+                        max_errors,
+                        unchecked_items: None,
+                    };
+                    self.errors.push(ValLineError::new(error_type, items).into_py(py));
+                    return Err(self.take_errors(py));
+                }
+            }
+            let item = item_result.map_err(|e| {
+                ValError::new_with_loc(
+                    ErrorType::IterationError {
+                        error: py_err_string(py, e),
+                    },
+                    items,
+                    index,
+                )
+            })?;
+            self.next_index += 1;
+            let item_extra = extra.with_index(Some(index));
+            let item_result = match item_validator {
+                Some(validator) => {
+                    validator.validate(py, item, &item_extra, &schema_validator.definitions, recursion_guard)
+                }
+                None => Ok(item.to_object(py)),
+            };
+            let actual_length = match item_result {
+                Ok(output) => self.add_item(py, output, item, index)?,
+                Err(ValError::LineErrors(line_errors)) => {
+                    self.errors.extend(
+                        line_errors
+                            .into_iter()
+                            .map(|err| err.with_outer_location(index.into()).into_py(py)),
+                    );
+                    self.items_length(py)
+                }
+                Err(ValError::Omit) => continue,
+                Err(err) => return Err(err),
+            };
+            if let Some(max_length) = self.checks.max_length {
+                if actual_length > max_length {
+                    return Err(ValError::new(
+                        ErrorType::TooLong {
+                            field_type: field_type.to_string(),
+                            max_length,
+                            actual_length,
+                        },
+                        items,
+                    ));
+                }
+            }
+            if self.checks.fail_fast && !self.errors.is_empty() {
+                return Err(self.take_errors(py));
+            }
+        }
+        Ok(())
+    }
+
+    /// Add a valid item to the output, returns the length to check against `max_length`
+    fn add_item<'py>(&mut self, py: Python<'py>, output: PyObject, item: &'py PyAny, index: usize) -> PyResult<usize> {
+        match &mut self.output {
+            IteratedOutput::Items(items) => {
+                items.push(output);
+                self.length += 1;
+                Ok(self.length)
+            }
+            IteratedOutput::Set(set) => {
+                let set = set.as_ref(py);
+                match set.add(output) {
+                    Ok(()) => self.length += 1,
+                    Err(err) if err.is_instance_of::<PyTypeError>(py) => {
+                        let err = ValLineError::new_with_loc(ErrorType::UnhashableItem, item, index);
+                        self.errors.push(err.into_py(py));
+                    }
+                    Err(err) => return Err(err),
+                }
+                Ok(set.len())
+            }
+        }
+    }
+
+    /// The length to check against `max_length` after an invalid item
+    fn items_length(&mut self, py: Python) -> usize {
+        match &self.output {
+            IteratedOutput::Items(_) => {
+                // invalid items count towards `max_length` of lists and tuples, as with `validate_python`
+                self.length += 1;
+                self.length
+            }
+            IteratedOutput::Set(set) => set.as_ref(py).len(),
+        }
+    }
+
+    fn take_errors<'py>(&mut self, py: Python<'py>) -> ValError<'py> {
+        let errors = std::mem::take(&mut self.errors);
+        ValError::LineErrors(errors.into_iter().map(|err| err.into_py(py)).collect())
+    }
+
+    fn build_output<'py>(&mut self, py: Python<'py>) -> PyResult<(&'py PyAny, usize)> {
+        let output: &PyAny = match (&mut self.output, self.checks.output_type) {
+            (IteratedOutput::Items(items), IteratedOutputType::Tuple) => PyTuple::new(py, items.drain(..)),
+            (IteratedOutput::Items(items), _) => PyList::new(py, items.drain(..)),
+            (IteratedOutput::Set(set), IteratedOutputType::FrozenSet) => PyFrozenSet::new(py, set.as_ref(py))?,
+            (IteratedOutput::Set(set), _) => set.clone_ref(py).into_ref(py),
+        };
+        Ok((output, output.len()?))
+    }
+}
+
+#[pymethods]
+impl IteratedValidation {
+    /// Validate each item of `items`, item errors are collected and raised by `finish` unless `fail_fast`
+    /// or `max_errors` stop validation early, exceeding `max_length` is raised straight away
+    pub fn feed(&mut self, py: Python, items: &PyAny) -> PyResult<()> {
+        self.check_not_finished()?;
+        let validator = self.validator.clone_ref(py);
+        let schema_validator = validator.borrow(py);
+        match self.feed_items(py, &schema_validator, items) {
+            Ok(()) => Ok(()),
+            Err(err) => {
+                self.finished = true;
+                Err(schema_validator.prepare_validation_err(py, err, ErrorMode::Python))
+            }
+        }
+    }
+
+    /// Check the collection as a whole and return it, or raise the errors from validating its items
+    pub fn finish(&mut self, py: Python) -> PyResult<PyObject> {
+        self.check_not_finished()?;
+        self.finished = true;
+        let validator = self.validator.clone_ref(py);
+        let schema_validator = validator.borrow(py);
+        if !self.errors.is_empty() {
+            let err = self.take_errors(py);
+            return Err(schema_validator.prepare_validation_err(py, err, ErrorMode::Python));
+        }
+        let (output, actual_length) = self.build_output(py)?;
+        let min_length_error = partial_min_length_error(
+            output,
+            self.checks.output_type.field_type(),
+            self.checks.min_length,
+            self.checks.unique_items_error,
+            self.length,
+            actual_length,
+        );
+        match min_length_error {
+            Some(err) => {
+                Err(schema_validator.prepare_validation_err(py, ValError::LineErrors(vec![err]), ErrorMode::Python))
+            }
+            None => Ok(output.into_py(py)),
+        }
+    }
+
+    fn __repr__(&self, py: Python) -> String {
+        format!(
+            "IteratedValidation(validator={}, items={}, finished={})",
+            self.validator.borrow(py).validator.get_name(),
+            self.next_index,
+            self.finished
+        )
+    }
+
+    fn __traverse__(&self, visit: PyVisit<'_>) -> Result<(), PyTraverseError> {
+        visit.call(&self.validator)?;
+        if let Some(ref context) = self.context {
+            visit.call(context)?;
+        }
+        match &self.output {
+            IteratedOutput::Items(items) => {
+                for item in items {
+                    visit.call(item)?;
+                }
+            }
+            IteratedOutput::Set(set) => visit.call(set)?,
+        }
+        for error in &self.errors {
+            error.py_gc_traverse(&visit)?;
+        }
+        Ok(())
+    }
+
+    /// Drop the held items and errors, either can reference this object, e.g. an invalid item holding it
+    fn __clear__(&mut self) {
+        self.context = None;
+        match &mut self.output {
+            IteratedOutput::Items(items) => items.clear(),
+            IteratedOutput::Set(set) => Python::with_gil(|py| set.as_ref(py).clear()),
+        }
+        self.errors.clear();
+    }
+}
@@ -1,17 +1,23 @@
-use pyo3::intern;
+use std::borrow::Cow;
+
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
+use pyo3::{intern, PyTraverseError, PyVisit};
 
 use crate::errors::ValResult;
 use crate::input::Input;
+use crate::memory::{HeapSize, MemoryUsage};
 use crate::recursion_guard::RecursionGuard;
 use crate::tools::SchemaDict;
 
-use super::{build_validator, BuildValidator, CombinedValidator, Definitions, DefinitionsBuilder, Extra, Validator};
+use super::{
+    build_validator, capped_name, BuildValidator, CombinedValidator, Definitions, DefinitionsBuilder, Extra, Validator,
+};
 
 #[derive(Debug, Clone)]
 pub struct JsonValidator {
     validator: Option<Box<CombinedValidator>>,
+    lenient_surrogates: bool,
     name: String,
 }
 
@@ -25,8 +31,8 @@ impl BuildValidator for JsonValidator {
     ) -> PyResult<CombinedValidator> {
         let validator = match schema.get_as(intern!(schema.py(), "schema"))? {
             Some(schema) => {
-                let validator = build_validator(schema, config, definitions)?;
-                match validator {
+                let validator = build_validator(&["schema".into()], schema, config, definitions)?;
+                match validator.inner() {
                     CombinedValidator::Any(_) => None,
                     _ => Some(Box::new(validator)),
                 }
@@ -36,13 +42,29 @@ impl BuildValidator for JsonValidator {
         let name = format!(
             "{}[{}]",
             Self::EXPECTED_TYPE,
-            validator.as_ref().map_or("any", |v| v.get_name())
+            validator
+                .as_ref()
+                .map_or(Cow::Borrowed("any"), |v| capped_name(v.get_name()))
         );
-        Ok(Self { validator, name }.into())
+        Ok(Self {
+            validator,
+            lenient_surrogates: config
+                .get_as(intern!(schema.py(), "lenient_surrogates"))?
+                .unwrap_or(false),
+            name,
+        }
+        .into())
     }
 }
 
 impl Validator for JsonValidator {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        if let Some(ref validator) = self.validator {
+            validator.py_gc_traverse(visit)?;
+        }
+        Ok(())
+    }
+
     fn validate<'s, 'data>(
         &'s self,
         py: Python<'data>,
@@ -51,7 +73,7 @@ impl Validator for JsonValidator {
         definitions: &'data Definitions<CombinedValidator>,
         recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        let json_value = input.parse_json()?;
+        let json_value = input.parse_json(self.lenient_surrogates)?;
         match self.validator {
             Some(ref validator) => match validator.validate(py, &json_value, extra, definitions, recursion_guard) {
                 Ok(v) => Ok(v),
@@ -83,4 +105,12 @@ impl Validator for JsonValidator {
             None => Ok(()),
         }
     }
+
+    fn memory_usage(&self, memory: &mut MemoryUsage) -> usize {
+        let inner = match self.validator {
+            Some(ref validator) => memory.visit_boxed(&["schema".into()], validator.as_ref()),
+            None => 0,
+        };
+        inner + self.name.heap_size()
+    }
 }
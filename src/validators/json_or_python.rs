@@ -1,15 +1,16 @@
-use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
+use pyo3::{intern, PyTraverseError, PyVisit};
 
 use crate::definitions::DefinitionsBuilder;
 use crate::errors::ValResult;
 use crate::input::Input;
+use crate::memory::{HeapSize, MemoryUsage};
 use crate::recursion_guard::RecursionGuard;
 use crate::tools::SchemaDict;
 
 use super::InputType;
-use super::{build_validator, BuildValidator, CombinedValidator, Definitions, Extra, Validator};
+use super::{build_validator, capped_name, BuildValidator, CombinedValidator, Definitions, Extra, Validator};
 
 #[derive(Debug, Clone)]
 pub struct JsonOrPython {
@@ -30,14 +31,14 @@ impl BuildValidator for JsonOrPython {
         let json_schema: &PyDict = schema.get_as_req(intern!(py, "json_schema"))?;
         let python_schema: &PyDict = schema.get_as_req(intern!(py, "python_schema"))?;
 
-        let json = build_validator(json_schema, config, definitions)?;
-        let python = build_validator(python_schema, config, definitions)?;
+        let json = build_validator(&["json_schema".into()], json_schema, config, definitions)?;
+        let python = build_validator(&["python_schema".into()], python_schema, config, definitions)?;
 
         let name = format!(
             "{}[json={},python={}]",
             Self::EXPECTED_TYPE,
-            json.get_name(),
-            python.get_name(),
+            capped_name(json.get_name()),
+            capped_name(python.get_name()),
         );
         Ok(Self {
             json: Box::new(json),
@@ -49,6 +50,12 @@ impl BuildValidator for JsonOrPython {
 }
 
 impl Validator for JsonOrPython {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        self.json.py_gc_traverse(visit)?;
+        self.python.py_gc_traverse(visit)?;
+        Ok(())
+    }
+
     fn validate<'s, 'data>(
         &'s self,
         py: Python<'data>,
@@ -80,4 +87,10 @@ impl Validator for JsonOrPython {
         self.json.complete(definitions)?;
         self.python.complete(definitions)
     }
+
+    fn memory_usage(&self, memory: &mut MemoryUsage) -> usize {
+        memory.visit_boxed(&["json_schema".into()], self.json.as_ref())
+            + memory.visit_boxed(&["python_schema".into()], self.python.as_ref())
+            + self.name.heap_size()
+    }
 }
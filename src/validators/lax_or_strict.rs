@@ -1,14 +1,17 @@
-use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
+use pyo3::{intern, PyTraverseError, PyVisit};
 
 use crate::build_tools::is_strict;
 use crate::errors::ValResult;
 use crate::input::Input;
+use crate::memory::{HeapSize, MemoryUsage};
 use crate::recursion_guard::RecursionGuard;
 use crate::tools::SchemaDict;
 
-use super::{build_validator, BuildValidator, CombinedValidator, Definitions, DefinitionsBuilder, Extra, Validator};
+use super::{
+    build_validator, capped_name, BuildValidator, CombinedValidator, Definitions, DefinitionsBuilder, Extra, Validator,
+};
 
 #[derive(Debug, Clone)]
 pub struct LaxOrStrictValidator {
@@ -28,16 +31,26 @@ impl BuildValidator for LaxOrStrictValidator {
     ) -> PyResult<CombinedValidator> {
         let py = schema.py();
         let lax_schema = schema.get_as_req(intern!(py, "lax_schema"))?;
-        let lax_validator = Box::new(build_validator(lax_schema, config, definitions)?);
+        let lax_validator = Box::new(build_validator(
+            &["lax_schema".into()],
+            lax_schema,
+            config,
+            definitions,
+        )?);
 
         let strict_schema = schema.get_as_req(intern!(py, "strict_schema"))?;
-        let strict_validator = Box::new(build_validator(strict_schema, config, definitions)?);
+        let strict_validator = Box::new(build_validator(
+            &["strict_schema".into()],
+            strict_schema,
+            config,
+            definitions,
+        )?);
 
         let name = format!(
             "{}[lax={},strict={}]",
             Self::EXPECTED_TYPE,
-            lax_validator.get_name(),
-            strict_validator.get_name()
+            capped_name(lax_validator.get_name()),
+            capped_name(strict_validator.get_name())
         );
         Ok(Self {
             strict: is_strict(schema, config)?,
@@ -50,6 +63,12 @@ impl BuildValidator for LaxOrStrictValidator {
 }
 
 impl Validator for LaxOrStrictValidator {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        self.lax_validator.py_gc_traverse(visit)?;
+        self.strict_validator.py_gc_traverse(visit)?;
+        Ok(())
+    }
+
     fn validate<'s, 'data>(
         &'s self,
         py: Python<'data>,
@@ -87,4 +106,10 @@ impl Validator for LaxOrStrictValidator {
         self.lax_validator.complete(definitions)?;
         self.strict_validator.complete(definitions)
     }
+
+    fn memory_usage(&self, memory: &mut MemoryUsage) -> usize {
+        memory.visit_boxed(&["lax_schema".into()], self.lax_validator.as_ref())
+            + memory.visit_boxed(&["strict_schema".into()], self.strict_validator.as_ref())
+            + self.name.heap_size()
+    }
 }
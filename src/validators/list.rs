@@ -1,22 +1,69 @@
+use std::borrow::Cow;
+
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyBool, PyDict, PyFloat, PyList, PyLong, PyString};
+use pyo3::PyTypeInfo;
+use pyo3::{PyTraverseError, PyVisit};
 
-use crate::errors::ValResult;
+use crate::errors::{ErrorType, ValLineError, ValResult};
 use crate::input::{GenericIterable, Input};
+use crate::memory::{HeapSize, MemoryUsage};
 use crate::recursion_guard::RecursionGuard;
 use crate::tools::SchemaDict;
 
-use super::{build_validator, BuildValidator, CombinedValidator, Definitions, DefinitionsBuilder, Extra, Validator};
+use super::iterated::{IterableValidationChecks, IteratedOutputType};
+use super::{
+    build_validator, capped_name, BuildValidator, CombinedValidator, Definitions, DefinitionsBuilder, Extra, Validator,
+};
 
 #[derive(Debug, Clone)]
 pub struct ListValidator {
     strict: bool,
     item_validator: Option<Box<CombinedValidator>>,
+    items_strict: Option<bool>,
+    exact_item_type: Option<ExactItemType>,
     min_length: Option<usize>,
     max_length: Option<usize>,
+    fail_fast: bool,
+    max_errors: Option<usize>,
     name: String,
 }
 
+/// Builtin types where the item validator returns exact instances unchanged in both strict and lax mode,
+/// so a list containing only exact instances can be copied without validating each item
+#[derive(Debug, Clone, Copy)]
+enum ExactItemType {
+    Int,
+    Str,
+    Float,
+    Bool,
+    None,
+}
+
+impl ExactItemType {
+    fn from_validator(validator: &CombinedValidator) -> Option<Self> {
+        match validator.inner() {
+            CombinedValidator::Int(_) => Some(Self::Int),
+            CombinedValidator::Str(_) => Some(Self::Str),
+            CombinedValidator::Float(v) if v.allow_inf_nan() => Some(Self::Float),
+            CombinedValidator::Bool(_) => Some(Self::Bool),
+            CombinedValidator::None(_) => Some(Self::None),
+            _ => None,
+        }
+    }
+
+    fn all_exact(self, list: &PyList) -> bool {
+        match self {
+            Self::Int => list.iter().all(PyLong::is_exact_type_of),
+            Self::Str => list.iter().all(PyString::is_exact_type_of),
+            Self::Float => list.iter().all(PyFloat::is_exact_type_of),
+            // bool can't be subclassed
+            Self::Bool => list.iter().all(PyBool::is_type_of),
+            Self::None => list.iter().all(PyAny::is_none),
+        }
+    }
+}
+
 pub fn get_items_schema(
     schema: &PyDict,
     config: Option<&PyDict>,
@@ -24,8 +71,8 @@ pub fn get_items_schema(
 ) -> PyResult<Option<Box<CombinedValidator>>> {
     match schema.get_item(pyo3::intern!(schema.py(), "items_schema")) {
         Some(d) => {
-            let validator = build_validator(d, config, definitions)?;
-            match validator {
+            let validator = build_validator(&["items_schema".into()], d, config, definitions)?;
+            match validator.inner() {
                 CombinedValidator::Any(_) => Ok(None),
                 _ => Ok(Some(Box::new(validator))),
             }
@@ -87,6 +134,26 @@ macro_rules! min_length_check {
 }
 pub(crate) use min_length_check;
 
+/// The error from `min_length_check!` for partial validation, where it's returned alongside the output
+pub(crate) fn min_length_error<'data>(
+    input: &'data impl Input<'data>,
+    field_type: &str,
+    min_length: Option<usize>,
+    actual_length: usize,
+) -> Option<ValLineError<'data>> {
+    match min_length {
+        Some(min_length) if actual_length < min_length => Some(ValLineError::new(
+            ErrorType::TooShort {
+                field_type: field_type.to_string(),
+                min_length,
+                actual_length,
+            },
+            input,
+        )),
+        _ => None,
+    }
+}
+
 impl BuildValidator for ListValidator {
     const EXPECTED_TYPE: &'static str = "list";
 
@@ -97,13 +164,20 @@ impl BuildValidator for ListValidator {
     ) -> PyResult<CombinedValidator> {
         let py = schema.py();
         let item_validator = get_items_schema(schema, config, definitions)?;
-        let inner_name = item_validator.as_ref().map_or("any", |v| v.get_name());
+        let inner_name = item_validator
+            .as_ref()
+            .map_or(Cow::Borrowed("any"), |v| capped_name(v.get_name()));
         let name = format!("{}[{inner_name}]", Self::EXPECTED_TYPE);
+        let exact_item_type = item_validator.as_deref().and_then(ExactItemType::from_validator);
         Ok(Self {
             strict: crate::build_tools::is_strict(schema, config)?,
             item_validator,
+            items_strict: schema.get_as(pyo3::intern!(py, "items_strict"))?,
+            exact_item_type,
             min_length: schema.get_as(pyo3::intern!(py, "min_length"))?,
             max_length: schema.get_as(pyo3::intern!(py, "max_length"))?,
+            fail_fast: crate::build_tools::is_fail_fast(schema, config)?,
+            max_errors: crate::build_tools::get_max_errors(schema, config)?,
             name,
         }
         .into())
@@ -111,6 +185,13 @@ impl BuildValidator for ListValidator {
 }
 
 impl Validator for ListValidator {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        if let Some(ref validator) = self.item_validator {
+            validator.py_gc_traverse(visit)?;
+        }
+        Ok(())
+    }
+
     fn validate<'s, 'data>(
         &'s self,
         py: Python<'data>,
@@ -121,16 +202,30 @@ impl Validator for ListValidator {
     ) -> ValResult<'data, PyObject> {
         let seq = input.validate_list(extra.strict.unwrap_or(self.strict))?;
 
+        if let (Some(exact_item_type), GenericIterable::List(list)) = (self.exact_item_type, &seq) {
+            // if the list is too long, fall through so the error matches the one from validating each item
+            if !matches!(self.max_length, Some(max_length) if list.len() > max_length)
+                && exact_item_type.all_exact(list)
+            {
+                min_length_check!(input, "List", self.min_length, list);
+                let list_copy = list.get_slice(0, usize::MAX);
+                return Ok(list_copy.into_py(py));
+            }
+        }
+
         let output = match self.item_validator {
             Some(ref v) => seq.validate_to_vec(
                 py,
                 input,
                 self.max_length,
+                self.fail_fast,
+                self.max_errors,
                 "List",
                 v,
-                extra,
+                &extra.with_items_strict(self.items_strict),
                 definitions,
                 recursion_guard,
+                None,
             )?,
             None => match seq {
                 GenericIterable::List(list) => {
@@ -145,6 +240,49 @@ impl Validator for ListValidator {
         Ok(output.into_py(py))
     }
 
+    fn validate_partial<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        definitions: &'data Definitions<CombinedValidator>,
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, (PyObject, Vec<ValLineError<'data>>)> {
+        let seq = input.validate_list(extra.strict.unwrap_or(self.strict))?;
+        let mut errors = Vec::new();
+        let output = match self.item_validator {
+            Some(ref v) => seq.validate_to_vec(
+                py,
+                input,
+                self.max_length,
+                self.fail_fast,
+                self.max_errors,
+                "List",
+                v,
+                &extra.with_items_strict(self.items_strict),
+                definitions,
+                recursion_guard,
+                Some(&mut errors),
+            )?,
+            None => seq.to_vec(py, input, "List", self.max_length)?,
+        };
+        errors.extend(min_length_error(input, "List", self.min_length, output.len()));
+        Ok((output.into_py(py), errors))
+    }
+
+    fn iterable_checks(&self) -> Option<(Option<&CombinedValidator>, IterableValidationChecks)> {
+        let checks = IterableValidationChecks {
+            output_type: IteratedOutputType::List,
+            min_length: self.min_length,
+            max_length: self.max_length,
+            fail_fast: self.fail_fast,
+            max_errors: self.max_errors,
+            items_strict: self.items_strict,
+            unique_items_error: false,
+        };
+        Some((self.item_validator.as_deref(), checks))
+    }
+
     fn different_strict_behavior(
         &self,
         definitions: Option<&DefinitionsBuilder<CombinedValidator>>,
@@ -167,9 +305,17 @@ impl Validator for ListValidator {
     fn complete(&mut self, definitions: &DefinitionsBuilder<CombinedValidator>) -> PyResult<()> {
         if let Some(ref mut v) = self.item_validator {
             v.complete(definitions)?;
-            let inner_name = v.get_name();
+            let inner_name = capped_name(v.get_name());
             self.name = format!("{}[{inner_name}]", Self::EXPECTED_TYPE);
         }
         Ok(())
     }
+
+    fn memory_usage(&self, memory: &mut MemoryUsage) -> usize {
+        let items = match self.item_validator {
+            Some(ref validator) => memory.visit_boxed(&["items_schema".into()], validator.as_ref()),
+            None => 0,
+        };
+        items + self.name.heap_size()
+    }
 }
@@ -1,22 +1,32 @@
 // Validator for things inside of a typing.Literal[]
 // which can be an int, a string, bytes or an Enum value (including `class Foo(str, Enum)` type enums)
 use core::fmt::Debug;
+use std::mem::size_of;
 
 use ahash::AHashMap;
-use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList};
+use pyo3::{intern, PyTraverseError, PyVisit};
 
 use crate::build_tools::{py_schema_err, py_schema_error_type};
-use crate::errors::{ErrorType, ValError, ValResult};
+use crate::errors::{ErrorType, LocItem, ValError, ValResult};
 use crate::input::Input;
+use crate::memory::{HeapSize, MemoryUsage};
 use crate::recursion_guard::RecursionGuard;
 use crate::tools::SchemaDict;
 
 use super::{BuildValidator, CombinedValidator, Definitions, DefinitionsBuilder, Extra, Validator};
 
+#[derive(Debug, Clone)]
+struct BoolLiteral {
+    true_id: Option<usize>,
+    false_id: Option<usize>,
+}
+
 #[derive(Debug, Clone)]
 pub struct LiteralLookup<T: Clone + Debug> {
+    // Bools are kept separate so they're never confused with the ints `1` and `0` which hash and compare equal
+    expected_bool: Option<BoolLiteral>,
     // Specialized lookups for ints and strings because they
     // (1) are easy to convert between Rust and Python
     // (2) hashing them in Rust is very fast
@@ -30,6 +40,10 @@ pub struct LiteralLookup<T: Clone + Debug> {
 
 impl<T: Clone + Debug> LiteralLookup<T> {
     pub fn new<'py>(py: Python<'py>, expected: impl Iterator<Item = (&'py PyAny, T)>) -> PyResult<Self> {
+        let mut expected_bool = BoolLiteral {
+            true_id: None,
+            false_id: None,
+        };
         let mut expected_int = AHashMap::new();
         let mut expected_str = AHashMap::new();
         let expected_py = PyDict::new(py);
@@ -37,7 +51,13 @@ impl<T: Clone + Debug> LiteralLookup<T> {
         for (k, v) in expected {
             let id = values.len();
             values.push(v);
-            if let Ok(either_int) = k.exact_int() {
+            if let Ok(bool) = k.strict_bool() {
+                if bool {
+                    expected_bool.true_id = Some(id);
+                } else {
+                    expected_bool.false_id = Some(id);
+                }
+            } else if let Ok(either_int) = k.exact_int() {
                 let int = either_int
                     .into_i64(py)
                     .map_err(|_| py_schema_error_type!("error extracting int {:?}", k))?;
@@ -53,6 +73,10 @@ impl<T: Clone + Debug> LiteralLookup<T> {
         }
 
         Ok(Self {
+            expected_bool: match expected_bool.true_id.is_some() || expected_bool.false_id.is_some() {
+                true => Some(expected_bool),
+                false => None,
+            },
             expected_int: match expected_int.is_empty() {
                 true => None,
                 false => Some(expected_int),
@@ -69,12 +93,47 @@ impl<T: Clone + Debug> LiteralLookup<T> {
         })
     }
 
+    /// Visit the python objects used to lookup values, but not `values` themselves
+    pub fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        if let Some(ref expected_py) = self.expected_py {
+            visit.call(expected_py)?;
+        }
+        Ok(())
+    }
+
+    /// Estimated heap memory of the lookup tables and the storage for `values`, but not memory owned by the values
+    pub fn lookup_heap_size(&self) -> usize {
+        self.expected_int.heap_size() + self.expected_str.heap_size() + self.values.capacity() * size_of::<T>()
+    }
+
+    /// The expected value for each of `values` as a location item, bools, bytes and enums use their index
+    pub fn tags(&self) -> Vec<LocItem> {
+        let mut tags: Vec<LocItem> = (0..self.values.len()).map(Into::into).collect();
+        for (int, id) in self.expected_int.iter().flatten() {
+            tags[*id] = (*int).into();
+        }
+        for (str, id) in self.expected_str.iter().flatten() {
+            tags[*id] = str.as_str().into();
+        }
+        tags
+    }
+
     pub fn validate<'data, I: Input<'data>>(
         &self,
         py: Python<'data>,
         input: &'data I,
     ) -> ValResult<'data, Option<(&'data I, &T)>> {
-        // dbg!(input.to_object(py).as_ref(py).repr().unwrap());
+        if let Some(expected_bool) = &self.expected_bool {
+            if let Ok(bool_value) = input.strict_bool() {
+                let id = match bool_value {
+                    true => expected_bool.true_id,
+                    false => expected_bool.false_id,
+                };
+                if let Some(id) = id {
+                    return Ok(Some((input, &self.values[id])));
+                }
+            }
+        }
         if let Some(expected_ints) = &self.expected_int {
             if let Ok(either_int) = input.exact_int() {
                 let int = either_int.into_i64(py)?;
@@ -84,7 +143,6 @@ impl<T: Clone + Debug> LiteralLookup<T> {
             }
         }
         if let Some(expected_strings) = &self.expected_str {
-            // dbg!(expected_strings);
             if let Ok(either_str) = input.exact_str() {
                 let cow = either_str.as_cow()?;
                 if let Some(id) = expected_strings.get(cow.as_ref()) {
@@ -99,6 +157,18 @@ impl<T: Clone + Debug> LiteralLookup<T> {
                 return Ok(Some((input, &self.values[id])));
             }
         };
+        // str subclasses (e.g. `class Foo(str, Enum)` members) which aren't expected themselves are
+        // looked up by their value
+        if let Some(expected_strings) = &self.expected_str {
+            if input.is_python() {
+                if let Ok(either_str) = input.strict_str() {
+                    let cow = either_str.as_cow()?;
+                    if let Some(id) = expected_strings.get(cow.as_ref()) {
+                        return Ok(Some((input, &self.values[*id])));
+                    }
+                }
+            }
+        }
         Ok(None)
     }
 }
@@ -138,6 +208,14 @@ impl BuildValidator for LiteralValidator {
 }
 
 impl Validator for LiteralValidator {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        self.lookup.py_gc_traverse(visit)?;
+        for value in &self.lookup.values {
+            visit.call(value)?;
+        }
+        Ok(())
+    }
+
     fn validate<'s, 'data>(
         &'s self,
         py: Python<'data>,
@@ -147,7 +225,7 @@ impl Validator for LiteralValidator {
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
         match self.lookup.validate(py, input)? {
-            Some((_, v)) => Ok(v.clone()),
+            Some((_, v)) => Ok(v.clone_ref(py)),
             None => Err(ValError::new(
                 ErrorType::LiteralError {
                     expected: self.expected_repr.clone(),
@@ -172,6 +250,10 @@ impl Validator for LiteralValidator {
     fn complete(&mut self, _definitions: &DefinitionsBuilder<CombinedValidator>) -> PyResult<()> {
         Ok(())
     }
+
+    fn memory_usage(&self, _memory: &mut MemoryUsage) -> usize {
+        self.lookup.lookup_heap_size() + self.expected_repr.heap_size() + self.name.heap_size()
+    }
 }
 
 pub fn expected_repr_name(mut repr_args: Vec<String>, base_name: &'static str) -> (String, String) {
@@ -1,19 +1,21 @@
+use std::borrow::Cow;
 use std::fmt::Debug;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
 
 use enum_dispatch::enum_dispatch;
 
-use pyo3::exceptions::PyTypeError;
-use pyo3::once_cell::GILOnceCell;
+use pyo3::exceptions::{PyRuntimeError, PyTypeError, PyValueError};
 use pyo3::prelude::*;
-use pyo3::types::{PyAny, PyDict, PyTuple, PyType};
+use pyo3::types::{IntoPyDict, PyAny, PyDict, PyList, PyTuple, PyType};
 use pyo3::{intern, PyTraverseError, PyVisit};
 
-use crate::build_tools::{py_schema_err, py_schema_error_type, SchemaError};
+use crate::build_tools::{did_you_mean, py_schema_err, py_schema_error_type, SchemaError};
 use crate::definitions::{Definitions, DefinitionsBuilder};
-use crate::errors::{ErrorMode, LocItem, ValError, ValResult, ValidationError};
+use crate::errors::{ErrorMode, LocItem, ValError, ValLineError, ValResult, ValidationError};
 use crate::input::{Input, InputType};
+use crate::memory::{definitions_size, MemoryNode, MemoryUsage};
 use crate::recursion_guard::RecursionGuard;
-use crate::tools::SchemaDict;
+use crate::tools::{deepcopy, py_err, safe_repr, SchemaDict};
 
 mod any;
 mod arguments;
@@ -22,6 +24,8 @@ mod bytes;
 mod call;
 mod callable;
 mod chain;
+mod counter;
+mod custom;
 mod custom_error;
 mod dataclass;
 mod date;
@@ -35,6 +39,7 @@ mod generator;
 mod int;
 mod is_instance;
 mod is_subclass;
+mod iterated;
 mod json;
 mod json_or_python;
 mod lax_or_strict;
@@ -45,6 +50,7 @@ mod model_fields;
 mod none;
 mod nullable;
 mod set;
+mod stats;
 mod string;
 mod time;
 mod timedelta;
@@ -54,6 +60,11 @@ mod union;
 mod url;
 mod with_default;
 
+pub use custom::{register_validator, registered_builder};
+pub use function::convert_err;
+use iterated::IterableValidationChecks;
+pub use iterated::IteratedValidation;
+pub use stats::{NodeStats, StatsCollector};
 pub use with_default::DefaultType;
 
 use self::definitions::DefinitionRefValidator;
@@ -93,32 +104,43 @@ impl PySome {
     }
 }
 
+/// Built validators are immutable and all per-call state (`Extra`, the recursion guard) lives on the stack, so one
+/// `SchemaValidator` can be used from many threads at once without locking. The only shared mutable state is the
+/// `collect_stats` counters, which are relaxed atomics, and global caches, which are either immutable once
+/// initialised or protected by a mutex.
 #[pyclass(module = "pydantic_core._pydantic_core")]
 #[derive(Debug, Clone)]
 pub struct SchemaValidator {
     validator: CombinedValidator,
-    definitions: Vec<CombinedValidator>,
+    definitions: Vec<Arc<CombinedValidator>>,
+    // the schema and config the validator was built from, used for pickling
     schema: PyObject,
+    config: Option<Py<PyDict>>,
     #[pyo3(get)]
     title: PyObject,
     hide_input_in_errors: bool,
+    lenient_surrogates: bool,
+    // counters for each schema node, only set with `collect_stats=True`
+    stats: Option<Vec<Arc<NodeStats>>>,
 }
 
 #[pymethods]
 impl SchemaValidator {
     #[new]
-    pub fn py_new(py: Python, schema: &PyAny, config: Option<&PyDict>) -> PyResult<Self> {
+    #[pyo3(signature = (schema, config=None, *, collect_stats=false))]
+    pub fn py_new(py: Python, schema: &PyAny, config: Option<&PyDict>, collect_stats: bool) -> PyResult<Self> {
         let self_validator = SelfValidator::new(py)?;
         let schema = self_validator.validate_schema(py, schema)?;
 
         let mut definitions_builder = DefinitionsBuilder::new();
-
-        let mut validator = build_validator(schema, config, &mut definitions_builder)?;
-        validator.complete(&definitions_builder)?;
-        let mut definitions = definitions_builder.clone().finish()?;
-        for val in &mut definitions {
-            val.complete(&definitions_builder)?;
+        if collect_stats {
+            definitions_builder.collect_stats();
         }
+
+        let mut validator = build_validator(&[], schema, config, &mut definitions_builder)
+            .map_err(|err| definitions_builder.schemas().locate_error(py, err))?;
+        let stats = definitions_builder.take_stats();
+        let definitions = complete_validators(&mut validator, definitions_builder)?;
         let config_title = match config {
             Some(c) => c.get_item("title"),
             None => None,
@@ -128,19 +150,59 @@ impl SchemaValidator {
             None => validator.get_name().into_py(py),
         };
         let hide_input_in_errors: bool = config.get_as(intern!(py, "hide_input_in_errors"))?.unwrap_or(false);
+        let lenient_surrogates: bool = config.get_as(intern!(py, "lenient_surrogates"))?.unwrap_or(false);
         Ok(Self {
             validator,
             definitions,
             schema: schema.into_py(py),
+            config: config.map(|c| c.into_py(py)),
             title,
             hide_input_in_errors,
+            lenient_surrogates,
+            stats,
         })
     }
 
-    pub fn __reduce__(&self, py: Python) -> PyResult<PyObject> {
-        let args = (self.schema.as_ref(py),);
-        let cls = Py::new(py, self.clone())?.getattr(py, "__class__")?;
-        Ok((cls, args).into_py(py))
+    pub fn __reduce__(slf: &PyCell<Self>) -> PyResult<PyObject> {
+        let py = slf.py();
+        let this = slf.try_borrow()?;
+        let args = (this.schema.as_ref(py), this.config.as_ref().map(|c| c.as_ref(py)));
+        Ok((slf.get_type(), args).into_py(py))
+    }
+
+    /// Rebuild the validator from the same schema and config, objects within the schema (e.g. functions and
+    /// classes) are shared with the original rather than copied
+    pub fn __copy__(&self, py: Python) -> PyResult<Self> {
+        let config = self.config.as_ref().map(|c| c.as_ref(py));
+        Self::py_new(py, self.schema.as_ref(py), config, self.stats.is_some())
+    }
+
+    /// Rebuild the validator from a deep copy of the schema and config, so objects within the schema
+    /// (e.g. functions and classes) follow `memo`
+    #[pyo3(signature = (memo, /))]
+    pub fn __deepcopy__(&self, py: Python, memo: &PyAny) -> PyResult<Self> {
+        let schema = deepcopy(self.schema.as_ref(py), memo)?;
+        let config = match self.config {
+            Some(ref config) => Some(deepcopy(config.as_ref(py), memo)?.downcast()?),
+            None => None,
+        };
+        Self::py_new(py, schema, config, self.stats.is_some())
+    }
+
+    /// The schema as given after validation against the self-schema, it isn't normalized: defaults aren't filled in,
+    /// the config isn't merged and references aren't resolved. Dicts and lists are copied so the validator's own
+    /// schema can't be modified, other values (e.g. functions) are returned by reference
+    #[getter]
+    pub fn input_schema(&self, py: Python) -> PyResult<PyObject> {
+        copy_schema(self.schema.as_ref(py))
+    }
+
+    #[getter]
+    pub fn config(&self, py: Python) -> PyResult<PyObject> {
+        match self.config {
+            Some(ref config) => copy_schema(config.as_ref(py)),
+            None => Ok(py.None()),
+        }
     }
 
     #[pyo3(signature = (input, *, strict=None, from_attributes=None, context=None, self_instance=None))]
@@ -175,7 +237,7 @@ impl SchemaValidator {
         context: Option<&PyAny>,
         self_instance: Option<&PyAny>,
     ) -> PyResult<bool> {
-        match self._validate(
+        let r = self._isinstance(
             py,
             input,
             InputType::Python,
@@ -183,12 +245,25 @@ impl SchemaValidator {
             from_attributes,
             context,
             self_instance,
-        ) {
-            Ok(_) => Ok(true),
-            Err(ValError::InternalErr(err)) => Err(err),
-            Err(ValError::Omit) => Err(ValidationError::omit_error()),
-            Err(ValError::UseDefault) => Err(ValidationError::use_default_error()),
-            Err(ValError::LineErrors(_)) => Ok(false),
+        );
+        Self::isinstance_result(r)
+    }
+
+    #[pyo3(signature = (input, *, strict=None, context=None, self_instance=None))]
+    pub fn isinstance_json(
+        &self,
+        py: Python,
+        input: &PyAny,
+        strict: Option<bool>,
+        context: Option<&PyAny>,
+        self_instance: Option<&PyAny>,
+    ) -> PyResult<bool> {
+        match input.parse_json(self.lenient_surrogates) {
+            Ok(input) => {
+                let r = self._isinstance(py, &input, InputType::Json, strict, None, context, self_instance);
+                Self::isinstance_result(r)
+            }
+            Err(err) => Self::isinstance_result(Err(err)),
         }
     }
 
@@ -201,7 +276,7 @@ impl SchemaValidator {
         context: Option<&PyAny>,
         self_instance: Option<&PyAny>,
     ) -> PyResult<PyObject> {
-        match input.parse_json() {
+        match input.parse_json(self.lenient_surrogates) {
             Ok(input) => {
                 let r = self._validate(py, &input, InputType::Json, strict, None, context, self_instance);
                 r.map_err(|e| self.prepare_validation_err(py, e, ErrorMode::Json))
@@ -210,6 +285,108 @@ impl SchemaValidator {
         }
     }
 
+    /// Validate a list, set, frozenset or variable length tuple keeping whichever items are valid, returns
+    /// `(output, error)` where `error` is a `ValidationError` for the invalid items, or `None` if there were none
+    #[pyo3(signature = (input, *, strict=None, from_attributes=None, context=None))]
+    pub fn validate_partial(
+        &self,
+        py: Python,
+        input: &PyAny,
+        strict: Option<bool>,
+        from_attributes: Option<bool>,
+        context: Option<&PyAny>,
+    ) -> PyResult<(PyObject, PyObject)> {
+        let extra = Extra::new(strict, from_attributes, context, None, InputType::Python);
+        let recursion_guard = &mut RecursionGuard::default();
+        match self
+            .validator
+            .validate_partial(py, input, &extra, &self.definitions, recursion_guard)
+        {
+            Ok((output, errors)) if errors.is_empty() => Ok((output, py.None())),
+            Ok((output, errors)) => {
+                let err = self.prepare_validation_err(py, ValError::LineErrors(errors), ErrorMode::Python);
+                Ok((output, err.value(py).into_py(py)))
+            }
+            Err(err) => Err(self.prepare_validation_err(py, err, ErrorMode::Python)),
+        }
+    }
+
+    /// Start validating a list, set, frozenset or variable length tuple whose items are passed in batches to
+    /// `feed` on the returned object, `finish` then checks the whole collection and returns it
+    #[pyo3(signature = (*, strict=None, from_attributes=None, context=None))]
+    pub fn validate_iterated(
+        slf: &PyCell<Self>,
+        strict: Option<bool>,
+        from_attributes: Option<bool>,
+        context: Option<&PyAny>,
+    ) -> PyResult<IteratedValidation> {
+        let py = slf.py();
+        let checks = match slf.borrow().validator.iterable_checks() {
+            Some((_, checks)) => checks,
+            None => {
+                let name = slf.borrow().validator.get_name().to_string();
+                return py_err!(PyTypeError; "validate_iterated is not supported for {}", name);
+            }
+        };
+        IteratedValidation::new(py, slf.into(), checks, strict, from_attributes, context)
+    }
+
+    /// Validate each item of `inputs` in turn, `on_error='raise'` returns a list of outputs and raises the first
+    /// error, `on_error='collect'` returns `(index, output_or_validation_error)` for every item.
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (inputs, *, mode="python", on_error="raise", strict=None, from_attributes=None, context=None))]
+    pub fn validate_many(
+        &self,
+        py: Python,
+        inputs: &PyAny,
+        mode: &str,
+        on_error: &str,
+        strict: Option<bool>,
+        from_attributes: Option<bool>,
+        context: Option<&PyAny>,
+    ) -> PyResult<PyObject> {
+        let (input_type, error_mode) = match mode {
+            "python" => (InputType::Python, ErrorMode::Python),
+            "json" => (InputType::Json, ErrorMode::Json),
+            s => return py_err!(PyValueError; "Invalid mode: `{}`, expected `python` or `json`", s),
+        };
+        let collect = match on_error {
+            "raise" => false,
+            "collect" => true,
+            s => return py_err!(PyValueError; "Invalid on_error: `{}`, expected `raise` or `collect`", s),
+        };
+        // the same state is used for every item
+        let extra = Extra::new(strict, from_attributes, context, None, input_type);
+        let recursion_guard = &mut RecursionGuard::default();
+
+        let output = PyList::empty(py);
+        for (index, item) in inputs.iter()?.enumerate() {
+            let item = item?;
+            let result = match input_type {
+                InputType::Python => self
+                    .validator
+                    .validate(py, item, &extra, &self.definitions, recursion_guard)
+                    .map_err(|e| self.prepare_validation_err(py, e, error_mode.clone())),
+                InputType::Json => match item.parse_json(self.lenient_surrogates) {
+                    Ok(json_input) => self
+                        .validator
+                        .validate(py, &json_input, &extra, &self.definitions, recursion_guard)
+                        .map_err(|e| self.prepare_validation_err(py, e, error_mode.clone())),
+                    Err(err) => Err(self.prepare_validation_err(py, err, error_mode.clone())),
+                },
+            };
+            match result {
+                Ok(value) if collect => output.append((index, value))?,
+                Ok(value) => output.append(value)?,
+                Err(err) if collect && err.is_instance_of::<ValidationError>(py) => {
+                    output.append((index, err.value(py)))?;
+                }
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(output.into_py(py))
+    }
+
     #[allow(clippy::too_many_arguments)]
     #[pyo3(signature = (obj, field_name, field_value, *, strict=None, from_attributes=None, context=None))]
     pub fn validate_assignment(
@@ -228,7 +405,9 @@ impl SchemaValidator {
             strict,
             from_attributes,
             ultra_strict: false,
+            fail_fast: false,
             context,
+            index: None,
             field_name: None,
             self_instance: None,
         };
@@ -247,7 +426,9 @@ impl SchemaValidator {
             strict,
             from_attributes: None,
             ultra_strict: false,
+            fail_fast: false,
             context,
+            index: None,
             field_name: None,
             self_instance: None,
         };
@@ -264,10 +445,39 @@ impl SchemaValidator {
         }
     }
 
+    /// Statistics for each schema node, empty unless the validator was created with `collect_stats=True`
+    pub fn stats<'py>(&self, py: Python<'py>) -> PyResult<&'py PyList> {
+        let output = PyList::empty(py);
+        for node in self.stats.iter().flatten() {
+            output.append(node.to_dict(py)?)?;
+        }
+        Ok(output)
+    }
+
+    pub fn reset_stats(&self) {
+        for node in self.stats.iter().flatten() {
+            node.reset();
+        }
+    }
+
+    /// Estimated memory used by each node of the built validator, definitions are listed separately
+    pub fn memory_breakdown<'py>(&self, py: Python<'py>) -> PyResult<&'py PyList> {
+        self.memory_usage().to_list(py)
+    }
+
+    pub fn __sizeof__(&self) -> usize {
+        std::mem::size_of::<PyCell<Self>>() + definitions_size(&self.definitions) + self.memory_usage().total()
+    }
+
     pub fn __repr__(&self, py: Python) -> String {
+        format!("SchemaValidator({})", self.repr_summary(py))
+    }
+
+    /// `repr` including the whole built validator and its definitions, for debugging
+    pub fn debug_repr(&self, py: Python) -> String {
         format!(
-            "SchemaValidator(title={:?}, validator={:#?}, definitions={:#?})",
-            self.title.extract::<&str>(py).unwrap(),
+            "SchemaValidator({}, validator={:#?}, definitions={:#?})",
+            self.repr_summary(py),
             self.validator,
             self.definitions,
         )
@@ -276,21 +486,56 @@ impl SchemaValidator {
     fn __traverse__(&self, visit: PyVisit<'_>) -> Result<(), PyTraverseError> {
         self.validator.py_gc_traverse(&visit)?;
         visit.call(&self.schema)?;
+        if let Some(ref config) = self.config {
+            visit.call(config)?;
+        }
         for slot in &self.definitions {
             slot.py_gc_traverse(&visit)?;
         }
         Ok(())
     }
 
+    /// No validator releases anything in `py_gc_clear`, cycles through a validator are broken by the garbage collector
+    /// clearing the Python objects in them instead, e.g. the `__dict__` of a model class whose validator refers back
+    /// to it. Definitions which are also held by the definition-ref validators using them, or by a generator still
+    /// being validated, can't be borrowed mutably and are skipped, which loses nothing for the same reason.
     fn __clear__(&mut self) {
         self.validator.py_gc_clear();
         for slot in &mut self.definitions {
-            slot.py_gc_clear();
+            if let Some(slot) = Arc::get_mut(slot) {
+                slot.py_gc_clear();
+            }
         }
     }
 }
 
 impl SchemaValidator {
+    fn memory_usage(&self) -> MemoryUsage {
+        let mut memory = MemoryUsage::default();
+        memory.visit(&[], &self.validator);
+        for (index, definition) in self.definitions.iter().enumerate() {
+            memory.visit(&["definitions".into(), index.into()], definition.as_ref());
+        }
+        memory
+    }
+
+    /// The title, schema type and config shown by `repr`
+    fn repr_summary(&self, py: Python) -> String {
+        let schema_type = self
+            .schema
+            .as_ref(py)
+            .get_item(intern!(py, "type"))
+            .and_then(PyAny::extract::<&str>)
+            .map_or_else(|_| "None".to_string(), |t| format!("{t:?}"));
+        // the title is usually a string, but comes from the config as-is
+        let title = match self.title.extract::<&str>(py) {
+            Ok(title) => format!("{title:?}"),
+            Err(_) => safe_repr(self.title.as_ref(py)).into_owned(),
+        };
+        let config = self.config.as_ref().map_or("None".into(), |c| safe_repr(c.as_ref(py)));
+        format!("title={title}, schema_type={schema_type}, config={config}")
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn _validate<'s, 'data>(
         &'data self,
@@ -314,6 +559,39 @@ impl SchemaValidator {
         )
     }
 
+    /// Like `_validate` but stops at the first error, since the errors themselves are never used
+    #[allow(clippy::too_many_arguments)]
+    fn _isinstance<'s, 'data>(
+        &'data self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        mode: InputType,
+        strict: Option<bool>,
+        from_attributes: Option<bool>,
+        context: Option<&'data PyAny>,
+        self_instance: Option<&PyAny>,
+    ) -> ValResult<'data, PyObject>
+    where
+        's: 'data,
+    {
+        let extra = Extra {
+            fail_fast: true,
+            ..Extra::new(strict, from_attributes, context, self_instance, mode)
+        };
+        self.validator
+            .validate(py, input, &extra, &self.definitions, &mut RecursionGuard::default())
+    }
+
+    fn isinstance_result(result: ValResult<PyObject>) -> PyResult<bool> {
+        match result {
+            Ok(_) => Ok(true),
+            Err(ValError::InternalErr(err)) => Err(err),
+            Err(ValError::Omit) => Err(ValidationError::omit_error()),
+            Err(ValError::UseDefault) => Err(ValidationError::use_default_error()),
+            Err(ValError::LineErrors(_)) => Ok(false),
+        }
+    }
+
     fn prepare_validation_err(&self, py: Python, error: ValError, error_mode: ErrorMode) -> PyErr {
         ValidationError::from_val_error(
             py,
@@ -326,20 +604,70 @@ impl SchemaValidator {
     }
 }
 
-static SCHEMA_DEFINITION: GILOnceCell<SchemaValidator> = GILOnceCell::new();
+fn copy_schema(value: &PyAny) -> PyResult<PyObject> {
+    let py = value.py();
+    if let Ok(dict) = value.downcast::<PyDict>() {
+        let copy = PyDict::new(py);
+        for (key, value) in dict {
+            copy.set_item(key, copy_schema(value)?)?;
+        }
+        Ok(copy.into_py(py))
+    } else if let Ok(list) = value.downcast::<PyList>() {
+        let items = list.iter().map(copy_schema).collect::<PyResult<Vec<_>>>()?;
+        Ok(PyList::new(py, items).into_py(py))
+    } else {
+        Ok(value.into_py(py))
+    }
+}
 
-#[derive(Debug, Clone)]
+// built on first use, and again after schema types are registered with `register_validator`, a mutex rather than
+// relying on the GIL so this is also safe on free-threaded python, it's only locked to get or replace the cached
+// validator, never while calling python code
+static SCHEMA_DEFINITION: Mutex<Option<Py<SchemaValidator>>> = Mutex::new(None);
+
+fn self_schema_cache() -> MutexGuard<'static, Option<Py<SchemaValidator>>> {
+    SCHEMA_DEFINITION.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+pub(crate) fn reset_self_schema() {
+    // dropped after the lock is released since dropping can run arbitrary python code
+    let previous = self_schema_cache().take();
+    drop(previous);
+}
+
+#[derive(Debug)]
 pub struct SelfValidator<'py> {
-    validator: &'py SchemaValidator,
+    validator: PyRef<'py, SchemaValidator>,
 }
 
 impl<'py> SelfValidator<'py> {
     pub fn new(py: Python<'py>) -> PyResult<Self> {
-        let validator = SCHEMA_DEFINITION.get_or_init(py, || match Self::build(py) {
-            Ok(schema) => schema,
-            Err(e) => panic!("Error building schema validator:\n  {e}"),
-        });
-        Ok(Self { validator })
+        let cached = self_schema_cache().as_ref().map(|v| v.clone_ref(py));
+        let validator = match cached {
+            Some(validator) => validator,
+            None => {
+                let registered = custom::registered_schema_count();
+                // an error rather than a panic, panics abort rather than raising on targets which can't unwind
+                let validator = match Self::build(py) {
+                    Ok(schema) => Py::new(py, schema)?,
+                    Err(e) => return py_err!(PyRuntimeError; "Error building schema validator:\n  {}", e),
+                };
+                // not cached if another thread registered a schema type while this one was building, checked with
+                // the cache locked so it can't race with `reset_self_schema` in `register_validator`
+                let mut cache = self_schema_cache();
+                let previous = if custom::registered_schema_count() == registered {
+                    cache.replace(validator.clone_ref(py))
+                } else {
+                    None
+                };
+                drop(cache);
+                drop(previous);
+                validator
+            }
+        };
+        Ok(Self {
+            validator: validator.into_ref(py).borrow(),
+        })
     }
 
     pub fn validate_schema(&self, py: Python<'py>, schema: &'py PyAny) -> PyResult<&'py PyAny> {
@@ -352,7 +680,7 @@ impl<'py> SelfValidator<'py> {
             &mut RecursionGuard::default(),
         ) {
             Ok(schema_obj) => Ok(schema_obj.into_ref(py)),
-            Err(e) => Err(SchemaError::from_val_error(py, e)),
+            Err(e) => Err(SchemaError::from_val_error(py, e, schema)),
         }
     }
 
@@ -361,28 +689,98 @@ impl<'py> SelfValidator<'py> {
         let locals = PyDict::new(py);
         py.run(code, None, Some(locals))?;
         let self_schema: &PyDict = locals.get_as_req(intern!(py, "self_schema"))?;
+        let choices: &PyDict = self_schema.get_as_req(intern!(py, "choices"))?;
+        for schema_type in custom::registered_schema_types() {
+            // only the type is checked, the rest of the schema is up to the registered builder
+            let choice = PyDict::new(py);
+            choice.set_item("type", "typed-dict")?;
+            let type_schema = PyDict::new(py);
+            type_schema.set_item("type", "literal")?;
+            type_schema.set_item("expected", vec![&schema_type])?;
+            let type_field = PyDict::new(py);
+            type_field.set_item("schema", type_schema)?;
+            choice.set_item("fields", [("type", type_field)].into_py_dict(py))?;
+            choice.set_item("extra_behavior", "allow")?;
+            choices.set_item(schema_type, choice)?;
+        }
 
         let mut definitions_builder = DefinitionsBuilder::new();
 
-        let mut validator = match build_validator(self_schema, None, &mut definitions_builder) {
+        let mut validator = match build_validator(&[], self_schema, None, &mut definitions_builder) {
             Ok(v) => v,
             Err(err) => return py_schema_err!("Error building self-schema:\n  {}", err),
         };
-        validator.complete(&definitions_builder)?;
-        let mut definitions = definitions_builder.clone().finish()?;
-        for val in &mut definitions {
-            val.complete(&definitions_builder)?;
-        }
+        let definitions = complete_validators(&mut validator, definitions_builder)?;
         Ok(SchemaValidator {
             validator,
             definitions,
             schema: py.None(),
+            config: None,
             title: "Self Schema".into_py(py),
             hide_input_in_errors: false,
+            lenient_surrogates: false,
+            stats: None,
         })
     }
 }
 
+/// Complete the definitions, then the validator. Definitions are completed in an order which lets references to
+/// definitions which aren't recursive share the completed definition, see `DefinitionRefValidator::complete`
+fn complete_validators(
+    validator: &mut CombinedValidator,
+    mut definitions_builder: DefinitionsBuilder<CombinedValidator>,
+) -> PyResult<Vec<Arc<CombinedValidator>>> {
+    let definitions = definitions_builder.complete_definitions(|val, definitions| val.complete(definitions))?;
+    validator.complete(&definitions_builder)?;
+    Ok(definitions)
+}
+
+/// Names of validators which wrap other validators show at most this many levels of nesting,
+/// deeper names are replaced with `...`, e.g. `list[list[list[list[...]]]]`
+const MAX_NAME_DEPTH: usize = 4;
+
+/// The name of a validator as it should be shown within the name of a validator wrapping it
+pub(crate) fn capped_name(name: &str) -> Cow<'_, str> {
+    // the wrapping validator adds one level of nesting
+    let max_depth = MAX_NAME_DEPTH - 1;
+    let nesting = name.chars().scan(0usize, |depth, c| {
+        match c {
+            '[' => *depth += 1,
+            ']' => *depth = depth.saturating_sub(1),
+            _ => (),
+        }
+        Some(*depth)
+    });
+    if nesting.max().unwrap_or(0) <= max_depth {
+        return Cow::Borrowed(name);
+    }
+
+    let mut capped = String::with_capacity(name.len());
+    let mut depth: usize = 0;
+    for c in name.chars() {
+        match c {
+            '[' => {
+                depth += 1;
+                if depth < max_depth {
+                    capped.push(c);
+                } else if depth == max_depth {
+                    // the contents of the deepest brackets are replaced
+                    capped.push_str("[...");
+                }
+            }
+            ']' => {
+                if depth <= max_depth {
+                    capped.push(c);
+                }
+                depth = depth.saturating_sub(1);
+            }
+            _ if depth < max_depth => capped.push(c),
+            _ => (),
+        }
+    }
+    Cow::Owned(capped)
+}
+
 pub trait BuildValidator: Sized {
     const EXPECTED_TYPE: &'static str;
 
@@ -404,123 +802,174 @@ fn build_specific_validator<'a, T: BuildValidator>(
 ) -> PyResult<CombinedValidator> {
     let py = schema_dict.py();
     if let Some(schema_ref) = schema_dict.get_as::<String>(intern!(py, "ref"))? {
-        let inner_val = T::build(schema_dict, config, definitions)?;
-        let validator_id = definitions.add_definition(schema_ref, inner_val)?;
-        return Ok(DefinitionRefValidator::new(validator_id).into());
+        let validator_id = definitions.build_definition(schema_ref, |definitions| {
+            let validator = T::build(schema_dict, config, definitions)?;
+            Ok(match definitions.stats_mut() {
+                Some(stats) => stats.wrap(validator),
+                None => validator,
+            })
+        })?;
+        return Ok(DefinitionRefValidator::new(validator_id, config)?.into());
     }
 
     T::build(schema_dict, config, definitions)
         .map_err(|err| py_schema_error_type!("Error building \"{}\" validator:\n  {}", val_type, err))
 }
 
-// macro to build the match statement for validator selection
+// macro to build the match statement for validator selection and the list of schema types it knows about
 macro_rules! validator_match {
-    ($type:ident, $dict:ident, $config:ident, $definitions:ident, $($validator:path,)+) => {
-        match $type {
-            $(
-                <$validator>::EXPECTED_TYPE => build_specific_validator::<$validator>($type, $dict, $config, $definitions),
-            )+
-            _ => return py_schema_err!(r#"Unknown schema type: "{}""#, $type),
+    ($($validator:path,)+) => {
+        /// All schema types which can be built, used to suggest alternatives for unknown types
+        pub(crate) const SCHEMA_TYPES: &[&str] = &[$(<$validator>::EXPECTED_TYPE,)+];
+
+        fn build_validator_inner<'a>(
+            type_: &str,
+            dict: &'a PyDict,
+            config: Option<&'a PyDict>,
+            definitions: &mut DefinitionsBuilder<CombinedValidator>,
+        ) -> PyResult<CombinedValidator> {
+            match type_ {
+                $(
+                    <$validator>::EXPECTED_TYPE => {
+                        build_specific_validator::<$validator>(type_, dict, config, definitions)
+                    }
+                )+
+                _ => {
+                    if let Some(builder) = custom::registered_builder(dict.py(), type_) {
+                        return custom::CustomValidator::build(type_, builder, dict, config, definitions);
+                    }
+                    match did_you_mean(type_, SCHEMA_TYPES).as_str() {
+                    "" => py_schema_err!(r#"Unknown schema type: "{}""#, type_),
+                    suggestion => py_schema_err!(r#"Unknown schema type: "{}", {}"#, type_, suggestion),
+                    }
+                }
+            }
         }
     };
 }
 
+/// Build the validator for `schema`, `path` is where it is in the schema being built which contains it, e.g.
+/// `["items_schema"]`, so build errors can report exactly which schema they came from
 pub fn build_validator<'a>(
+    path: &[LocItem],
     schema: &'a PyAny,
     config: Option<&'a PyDict>,
     definitions: &mut DefinitionsBuilder<CombinedValidator>,
 ) -> PyResult<CombinedValidator> {
     let dict: &PyDict = schema.downcast()?;
-    let type_: &str = dict.get_as_req(intern!(schema.py(), "type"))?;
-    validator_match!(
-        type_,
-        dict,
-        config,
-        definitions,
-        // typed dict e.g. heterogeneous dicts or simply a model
-        typed_dict::TypedDictValidator,
-        // unions
-        union::UnionValidator,
-        union::TaggedUnionValidator,
-        // nullables
-        nullable::NullableValidator,
-        // model classes
-        model::ModelValidator,
-        model_fields::ModelFieldsValidator,
-        // dataclasses
-        dataclass::DataclassArgsValidator,
-        dataclass::DataclassValidator,
-        // strings
-        string::StrValidator,
-        // integers
-        int::IntValidator,
-        // boolean
-        bool::BoolValidator,
-        // floats
-        float::FloatBuilder,
-        // tuples
-        tuple::TuplePositionalValidator,
-        tuple::TupleVariableValidator,
-        // list/arrays
-        list::ListValidator,
-        // sets - unique lists
-        set::SetValidator,
-        // dicts/objects (recursive)
-        dict::DictValidator,
-        // None/null
-        none::NoneValidator,
-        // functions - before, after, plain & wrap
-        function::FunctionAfterValidator,
-        function::FunctionBeforeValidator,
-        function::FunctionPlainValidator,
-        function::FunctionWrapValidator,
-        // function call - validation around a function call
-        call::CallValidator,
-        // literals
-        literal::LiteralValidator,
-        // any
-        any::AnyValidator,
-        // bytes
-        bytes::BytesValidator,
-        // dates
-        date::DateValidator,
-        // times
-        time::TimeValidator,
-        // datetimes
-        datetime::DateTimeValidator,
-        // frozensets
-        frozenset::FrozenSetValidator,
-        // timedelta
-        timedelta::TimeDeltaValidator,
-        // introspection types
-        is_instance::IsInstanceValidator,
-        is_subclass::IsSubclassValidator,
-        callable::CallableValidator,
-        // arguments
-        arguments::ArgumentsValidator,
-        // default value
-        with_default::WithDefaultValidator,
-        // chain validators
-        chain::ChainValidator,
-        // lax or strict
-        lax_or_strict::LaxOrStrictValidator,
-        // json or python
-        json_or_python::JsonOrPython,
-        // generator validators
-        generator::GeneratorValidator,
-        // custom error
-        custom_error::CustomErrorValidator,
-        // json data
-        json::JsonValidator,
-        // url types
-        url::UrlValidator,
-        url::MultiHostUrlValidator,
-        // recursive (self-referencing) models
-        definitions::DefinitionRefValidator,
-        definitions::DefinitionsValidatorBuilder,
-    )
+    definitions.schemas_mut().push(path, dict);
+    let validator = build_validator_dict(dict, config, definitions);
+    definitions.schemas_mut().pop(validator.is_ok());
+    validator
+}
+
+fn build_validator_dict<'a>(
+    dict: &'a PyDict,
+    config: Option<&'a PyDict>,
+    definitions: &mut DefinitionsBuilder<CombinedValidator>,
+) -> PyResult<CombinedValidator> {
+    let type_: &str = dict.get_as_req(intern!(dict.py(), "type"))?;
+    match definitions.stats_mut() {
+        Some(stats) => stats.enter(type_),
+        None => return build_validator_inner(type_, dict, config, definitions),
+    }
+    let validator = build_validator_inner(type_, dict, config, definitions);
+    match definitions.stats_mut() {
+        Some(stats) => {
+            let validator = validator.map(|v| stats.wrap(v));
+            stats.exit();
+            validator
+        }
+        None => validator,
+    }
 }
 
+validator_match!(
+    // typed dict e.g. heterogeneous dicts or simply a model
+    typed_dict::TypedDictValidator,
+    // unions
+    union::UnionValidator,
+    union::TaggedUnionValidator,
+    // nullables
+    nullable::NullableValidator,
+    // model classes
+    model::ModelValidator,
+    model_fields::ModelFieldsValidator,
+    // dataclasses
+    dataclass::DataclassArgsValidator,
+    dataclass::DataclassValidator,
+    // strings
+    string::StrValidator,
+    // integers
+    int::IntValidator,
+    // boolean
+    bool::BoolValidator,
+    // floats
+    float::FloatBuilder,
+    // tuples
+    tuple::TuplePositionalValidator,
+    tuple::TupleVariableValidator,
+    // list/arrays
+    list::ListValidator,
+    // sets - unique lists
+    set::SetValidator,
+    // dicts/objects (recursive)
+    dict::DictValidator,
+    // None/null
+    none::NoneValidator,
+    // functions - before, after, plain & wrap
+    function::FunctionAfterValidator,
+    function::FunctionBeforeValidator,
+    function::FunctionPlainValidator,
+    function::FunctionWrapValidator,
+    // function call - validation around a function call
+    call::CallValidator,
+    // literals
+    literal::LiteralValidator,
+    // any
+    any::AnyValidator,
+    // bytes
+    bytes::BytesValidator,
+    // dates
+    date::DateValidator,
+    // times
+    time::TimeValidator,
+    // datetimes
+    datetime::DateTimeValidator,
+    // frozensets
+    frozenset::FrozenSetValidator,
+    // counters
+    counter::CounterValidator,
+    // timedelta
+    timedelta::TimeDeltaValidator,
+    // introspection types
+    is_instance::IsInstanceValidator,
+    is_subclass::IsSubclassValidator,
+    callable::CallableValidator,
+    // arguments
+    arguments::ArgumentsValidator,
+    // default value
+    with_default::WithDefaultValidator,
+    // chain validators
+    chain::ChainValidator,
+    // lax or strict
+    lax_or_strict::LaxOrStrictValidator,
+    // json or python
+    json_or_python::JsonOrPython,
+    // generator validators
+    generator::GeneratorValidator,
+    // custom error
+    custom_error::CustomErrorValidator,
+    // json data
+    json::JsonValidator,
+    // url types
+    url::UrlValidator,
+    url::MultiHostUrlValidator,
+    // recursive (self-referencing) models
+    definitions::DefinitionRefValidator,
+    definitions::DefinitionsValidatorBuilder,
+);
+
 /// More (mostly immutable) data to pass between validators, should probably be class `Context`,
 /// but that would confuse it with context as per pydantic/pydantic#1549
 #[derive(Debug)]
@@ -536,10 +985,15 @@ pub struct Extra<'a> {
     pub strict: Option<bool>,
     /// whether we're in ultra-strict mode, only used occasionally in unions
     pub ultra_strict: bool,
+    /// stop at the first error instead of collecting all errors, used by `isinstance_*` where errors are discarded
+    pub fail_fast: bool,
     /// Validation time setting of `from_attributes`
     pub from_attributes: Option<bool>,
     /// context used in validator functions
     pub context: Option<&'a PyAny>,
+    /// Index of the item being validated within a list, tuple, set, frozenset or generator, set by the collection
+    /// for each item and reset by validators of mappings and fields so it doesn't leak into nested values
+    pub index: Option<usize>,
     /// This is an instance of the model or dataclass being validated, when validation is performed from `__init__`
     self_instance: Option<&'a PyAny>,
 }
@@ -558,8 +1012,10 @@ impl<'a> Extra<'a> {
             field_name: None,
             strict,
             ultra_strict: false,
+            fail_fast: false,
             from_attributes,
             context,
+            index: None,
             self_instance,
         }
     }
@@ -572,12 +1028,28 @@ impl<'a> Extra<'a> {
             data: self.data,
             strict: Some(true),
             ultra_strict,
+            fail_fast: self.fail_fast,
             from_attributes: self.from_attributes,
             context: self.context,
+            index: self.index,
             field_name: self.field_name,
             self_instance: self.self_instance,
         }
     }
+
+    /// For validating the item at `index` of a collection, `index` is `None` for values which aren't items
+    pub fn with_index(&self, index: Option<usize>) -> Self {
+        Self { index, ..*self }
+    }
+
+    /// For validating the items of a collection, `items_strict` overrides the strictness the collection
+    /// was validated with when it's set
+    pub fn with_items_strict(&self, items_strict: Option<bool>) -> Self {
+        Self {
+            strict: items_strict.or(self.strict),
+            ..*self
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -640,6 +1112,8 @@ pub enum CombinedValidator {
     Datetime(datetime::DateTimeValidator),
     // frozensets
     FrozenSet(frozenset::FrozenSetValidator),
+    // counters
+    Counter(counter::CounterValidator),
     // timedelta
     Timedelta(timedelta::TimeDeltaValidator),
     // introspection types
@@ -667,6 +1141,30 @@ pub enum CombinedValidator {
     DefinitionRef(definitions::DefinitionRefValidator),
     // input dependent
     JsonOrPython(json_or_python::JsonOrPython),
+    // statistics, only used with `collect_stats=True`
+    Stats(stats::StatsValidator),
+    // schema types registered with `register_validator`
+    Custom(custom::CustomValidator),
+}
+
+impl CombinedValidator {
+    /// The validator wrapped by a `Stats` validator, so checks of which kind of validator a child is give the
+    /// same answer with `collect_stats=True`
+    pub fn inner(&self) -> &Self {
+        match self {
+            Self::Stats(validator) => validator.inner(),
+            validator => validator,
+        }
+    }
+
+    /// Like `inner`, but unwrapping, for parents which replace a child with its parts, the child's stats node
+    /// is discarded as it would never be called
+    pub fn into_inner(self) -> Self {
+        match self {
+            Self::Stats(validator) => validator.into_inner(),
+            validator => validator,
+        }
+    }
 }
 
 /// This trait must be implemented by all validators, it allows various validators to be accessed consistently,
@@ -688,6 +1186,26 @@ pub trait Validator: Send + Sync + Clone + Debug {
         recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject>;
 
+    /// Validate a collection keeping whichever items are valid, errors for the other items are returned alongside
+    /// the output rather than failing validation, see `SchemaValidator.validate_partial`
+    fn validate_partial<'s, 'data>(
+        &'s self,
+        _py: Python<'data>,
+        _input: &'data impl Input<'data>,
+        _extra: &Extra,
+        _definitions: &'data Definitions<CombinedValidator>,
+        _recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, (PyObject, Vec<ValLineError<'data>>)> {
+        let py_err = PyTypeError::new_err(format!("validate_partial is not supported for {}", self.get_name()));
+        Err(py_err.into())
+    }
+
+    /// The item validator and constraints used by `SchemaValidator.validate_iterated`, `None` if the validator
+    /// can't be fed its items incrementally
+    fn iterable_checks(&self) -> Option<(Option<&CombinedValidator>, IterableValidationChecks)> {
+        None
+    }
+
     /// Get a default value, currently only used by `WithDefaultValidator`
     fn default_value<'s, 'data>(
         &'s self,
@@ -731,4 +1249,19 @@ pub trait Validator: Send + Sync + Clone + Debug {
     /// this method must be implemented for any validator which holds references to other validators,
     /// it is used by `DefinitionRefValidator` to set its name
     fn complete(&mut self, _definitions: &DefinitionsBuilder<CombinedValidator>) -> PyResult<()>;
+
+    /// Estimated heap memory owned by this validator in bytes, see `MemoryNode::memory_usage`
+    fn memory_usage(&self, _memory: &mut MemoryUsage) -> usize {
+        0
+    }
+}
+
+impl MemoryNode for CombinedValidator {
+    fn node_name(&self) -> &str {
+        self.get_name()
+    }
+
+    fn memory_usage(&self, memory: &mut MemoryUsage) -> usize {
+        Validator::memory_usage(self, memory)
+    }
 }
@@ -9,9 +9,10 @@ use pyo3::{ffi, intern};
 use super::function::convert_err;
 use super::{build_validator, BuildValidator, CombinedValidator, Definitions, DefinitionsBuilder, Extra, Validator};
 use crate::build_tools::py_schema_err;
-use crate::build_tools::schema_or_config_same;
+use crate::build_tools::{merge_config, schema_or_config_same};
 use crate::errors::{ErrorType, ValError, ValResult};
 use crate::input::{py_error_on_minusone, Input};
+use crate::memory::{HeapSize, MemoryUsage};
 use crate::recursion_guard::RecursionGuard;
 use crate::tools::{py_err, SchemaDict};
 use crate::PydanticUndefinedType;
@@ -66,16 +67,16 @@ impl BuildValidator for ModelValidator {
 
     fn build(
         schema: &PyDict,
-        _config: Option<&PyDict>,
+        parent_config: Option<&PyDict>,
         definitions: &mut DefinitionsBuilder<CombinedValidator>,
     ) -> PyResult<CombinedValidator> {
         let py = schema.py();
-        // models ignore the parent config and always use the config from this model
-        let config = schema.get_as(intern!(py, "config"))?;
+        // the config from this model is merged over the parent config
+        let config = merge_config(parent_config, schema.get_as(intern!(py, "config"))?)?;
 
         let class: &PyType = schema.get_as_req(intern!(py, "cls"))?;
         let sub_schema: &PyAny = schema.get_as_req(intern!(py, "schema"))?;
-        let validator = build_validator(sub_schema, config, definitions)?;
+        let validator = build_validator(&["schema".into()], sub_schema, config, definitions)?;
 
         Ok(Self {
             revalidate: Revalidate::from_str(schema_or_config_same(
@@ -234,6 +235,10 @@ impl Validator for ModelValidator {
     fn complete(&mut self, definitions: &DefinitionsBuilder<CombinedValidator>) -> PyResult<()> {
         self.validator.complete(definitions)
     }
+
+    fn memory_usage(&self, memory: &mut MemoryUsage) -> usize {
+        memory.visit_boxed(&["schema".into()], self.validator.as_ref()) + self.name.heap_size()
+    }
 }
 
 impl ModelValidator {
@@ -259,7 +264,7 @@ impl ModelValidator {
             .validate(py, input, &new_extra, definitions, recursion_guard)?;
 
         if self.root_model {
-            let fields_set = if input.to_object(py).is(&PydanticUndefinedType::py_undefined()) {
+            let fields_set = if PydanticUndefinedType::is_undefined_identity(py, input.identity()) {
                 PySet::empty(py)?
             } else {
                 PySet::new(py, [&String::from(ROOT_FIELD)])?
@@ -309,7 +314,7 @@ impl ModelValidator {
         let instance_ref = instance.as_ref(py);
 
         if self.root_model {
-            let fields_set = if input.to_object(py).is(&PydanticUndefinedType::py_undefined()) {
+            let fields_set = if PydanticUndefinedType::is_undefined_identity(py, input.identity()) {
                 PySet::empty(py)?
             } else {
                 PySet::new(py, [&String::from(ROOT_FIELD)])?
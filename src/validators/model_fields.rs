@@ -1,7 +1,9 @@
+use std::mem::size_of;
+
 use pyo3::exceptions::PyKeyError;
-use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PySet, PyString, PyType};
+use pyo3::types::{PyDict, PyMapping, PySet, PyString, PyType};
+use pyo3::{intern, PyTraverseError, PyVisit};
 
 use ahash::AHashSet;
 
@@ -12,7 +14,8 @@ use crate::input::{
     AttributesGenericIterator, DictGenericIterator, GenericMapping, Input, JsonObjectGenericIterator,
     MappingGenericIterator,
 };
-use crate::lookup_key::LookupKey;
+use crate::lookup_key::{LookupKey, LookupMode};
+use crate::memory::{HeapSize, MemoryUsage};
 use crate::recursion_guard::RecursionGuard;
 use crate::tools::SchemaDict;
 
@@ -35,6 +38,7 @@ pub struct ModelFieldsValidator {
     extra_validator: Option<Box<CombinedValidator>>,
     strict: bool,
     from_attributes: bool,
+    lookup_mode: Option<LookupMode>,
     loc_by_alias: bool,
 }
 
@@ -55,7 +59,12 @@ impl BuildValidator for ModelFieldsValidator {
         let extra_behavior = ExtraBehavior::from_schema_or_config(py, schema, config, ExtraBehavior::Ignore)?;
 
         let extra_validator = match (schema.get_item(intern!(py, "extra_validator")), &extra_behavior) {
-            (Some(v), ExtraBehavior::Allow) => Some(Box::new(build_validator(v, config, definitions)?)),
+            (Some(v), ExtraBehavior::Allow) => Some(Box::new(build_validator(
+                &["extra_validator".into()],
+                v,
+                config,
+                definitions,
+            )?)),
             (Some(_), _) => return py_schema_err!("extra_validator can only be used if extra_behavior=allow"),
             (_, _) => None,
         };
@@ -72,7 +81,8 @@ impl BuildValidator for ModelFieldsValidator {
 
             let schema = field_info.get_as_req(intern!(py, "schema"))?;
 
-            let validator = match build_validator(schema, config, definitions) {
+            let path = ["fields".into(), field_name.into(), "schema".into()];
+            let validator = match build_validator(&path, schema, config, definitions) {
                 Ok(v) => v,
                 Err(err) => return py_schema_err!("Field \"{}\":\n  {}", field_name, err),
             };
@@ -101,6 +111,7 @@ impl BuildValidator for ModelFieldsValidator {
             extra_validator,
             strict,
             from_attributes,
+            lookup_mode: LookupMode::from_py(schema)?,
             loc_by_alias: config.get_as(intern!(py, "loc_by_alias"))?.unwrap_or(true),
         }
         .into())
@@ -108,6 +119,16 @@ impl BuildValidator for ModelFieldsValidator {
 }
 
 impl Validator for ModelFieldsValidator {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        for field in &self.fields {
+            field.validator.py_gc_traverse(visit)?;
+        }
+        if let Some(ref validator) = self.extra_validator {
+            validator.py_gc_traverse(visit)?;
+        }
+        Ok(())
+    }
+
     fn validate<'s, 'data>(
         &'s self,
         py: Python<'data>,
@@ -117,7 +138,13 @@ impl Validator for ModelFieldsValidator {
         recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
         let strict = extra.strict.unwrap_or(self.strict);
-        let from_attributes = extra.from_attributes.unwrap_or(self.from_attributes);
+        // `lookup_mode` means we might need attribute access, so it implies `from_attributes`, unless the call
+        // sets `from_attributes=False` which also disables the attribute access of `lookup_mode`
+        let lookup_mode = match extra.from_attributes {
+            Some(false) => None,
+            _ => self.lookup_mode,
+        };
+        let from_attributes = extra.from_attributes.unwrap_or(self.from_attributes) || lookup_mode.is_some();
 
         // we convert the DictType error to a ModelType error
         let dict = match input.validate_model_fields(strict, from_attributes) {
@@ -143,7 +170,8 @@ impl Validator for ModelFieldsValidator {
 
         let model_dict = PyDict::new(py);
         let mut model_extra_dict_op: Option<&PyDict> = None;
-        let mut errors: Vec<ValLineError> = Vec::with_capacity(self.fields.len());
+        // clean data is the common case, so only allocate once there are errors
+        let mut errors: Vec<ValLineError> = Vec::new();
         let mut fields_set_vec: Vec<Py<PyString>> = Vec::with_capacity(self.fields.len());
 
         // we only care about which keys have been used if we're iterating over the object for extra after
@@ -155,14 +183,18 @@ impl Validator for ModelFieldsValidator {
         };
 
         macro_rules! process {
-            ($dict:ident, $get_method:ident, $iter:ty $(,$kwargs:ident)?) => {{
+            ($dict:ident, $get_method:ident, $iter:ty $(,$args:expr)*) => {{
                 for field in &self.fields {
+                    if extra.fail_fast && !errors.is_empty() {
+                        break;
+                    }
                     let extra = Extra {
                         data: Some(model_dict),
                         field_name: Some(&field.name),
+                        index: None,
                         ..*extra
                     };
-                    let op_key_value = match field.lookup_key.$get_method($dict $(, $kwargs )? ) {
+                    let op_key_value = match field.lookup_key.$get_method($dict $(, $args )* ) {
                         Ok(v) => v,
                         Err(err) => {
                             errors.push(ValLineError::new_with_loc(
@@ -213,6 +245,9 @@ impl Validator for ModelFieldsValidator {
                 if let Some(ref mut used_keys) = used_keys {
                     let model_extra_dict = PyDict::new(py);
                     for item_result in <$iter>::new($dict)? {
+                        if extra.fail_fast && !errors.is_empty() {
+                            break;
+                        }
                         let (raw_key, value) = item_result?;
                         let either_str = match raw_key.strict_str() {
                             Ok(k) => k,
@@ -269,11 +304,21 @@ impl Validator for ModelFieldsValidator {
                 }
             }};
         }
-        match dict {
-            GenericMapping::PyDict(d) => process!(d, py_get_dict_item, DictGenericIterator),
-            GenericMapping::PyGetAttr(d, kwargs) => process!(d, py_get_attr, AttributesGenericIterator, kwargs),
-            GenericMapping::PyMapping(d) => process!(d, py_get_mapping_item, MappingGenericIterator),
-            GenericMapping::JsonObject(d) => process!(d, json_get, JsonObjectGenericIterator),
+        match (dict, lookup_mode) {
+            // attributes are never read from dicts or other mappings, even with `lookup_mode`
+            (GenericMapping::PyDict(d), _) => process!(d, py_get_dict_item, DictGenericIterator),
+            // mappings are only validated from attributes in strict mode
+            (GenericMapping::PyGetAttr(d, _), Some(_)) if d.downcast::<PyMapping>().is_ok() => {
+                process!(d, py_get_mapping_item, AttributesGenericIterator)
+            }
+            (GenericMapping::PyGetAttr(d, kwargs), Some(mode)) => {
+                process!(d, py_get_item_or_attr, AttributesGenericIterator, kwargs, mode)
+            }
+            (GenericMapping::PyGetAttr(d, kwargs), None) => {
+                process!(d, py_get_attr, AttributesGenericIterator, kwargs)
+            }
+            (GenericMapping::PyMapping(d), _) => process!(d, py_get_mapping_item, MappingGenericIterator),
+            (GenericMapping::JsonObject(d), _) => process!(d, json_get, JsonObjectGenericIterator),
         }
 
         if !errors.is_empty() {
@@ -401,4 +446,19 @@ impl Validator for ModelFieldsValidator {
             None => Ok(()),
         }
     }
+
+    fn memory_usage(&self, memory: &mut MemoryUsage) -> usize {
+        let mut bytes = self.fields.capacity() * size_of::<Field>();
+        for field in &self.fields {
+            bytes += field.name.heap_size() + field.lookup_key.heap_size();
+            memory.visit(
+                &["fields".into(), field.name.as_str().into(), "schema".into()],
+                &field.validator,
+            );
+        }
+        if let Some(ref validator) = self.extra_validator {
+            bytes += memory.visit_boxed(&["extra_validator".into()], validator.as_ref());
+        }
+        bytes + self.model_name.heap_size()
+    }
 }
@@ -1,20 +1,28 @@
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PySet};
+use pyo3::{PyTraverseError, PyVisit};
 
-use crate::errors::ValResult;
+use crate::errors::{ErrorType, ValError, ValLineError, ValResult};
 use crate::input::Input;
+use crate::memory::{HeapSize, MemoryUsage};
 use crate::recursion_guard::RecursionGuard;
 use crate::tools::SchemaDict;
 
-use super::list::min_length_check;
+use super::iterated::{IterableValidationChecks, IteratedOutputType};
+use super::list::{min_length_check, min_length_error};
 use super::{BuildValidator, CombinedValidator, Definitions, DefinitionsBuilder, Extra, Validator};
 
 #[derive(Debug, Clone)]
 pub struct SetValidator {
     strict: bool,
     item_validator: Box<CombinedValidator>,
+    items_strict: Option<bool>,
     min_length: Option<usize>,
     max_length: Option<usize>,
+    unique_items_error: bool,
+    fail_fast: bool,
+    max_errors: Option<usize>,
+    hash_key: Option<PyObject>,
     name: String,
 }
 
@@ -27,21 +35,33 @@ macro_rules! set_build {
         ) -> PyResult<CombinedValidator> {
             let py = schema.py();
             let item_validator = match schema.get_item(pyo3::intern!(schema.py(), "items_schema")) {
-                Some(d) => Box::new(crate::validators::build_validator(d, config, definitions)?),
+                Some(d) => Box::new(crate::validators::build_validator(
+                    &["items_schema".into()],
+                    d,
+                    config,
+                    definitions,
+                )?),
                 None => Box::new(crate::validators::any::AnyValidator::build(
                     schema,
                     config,
                     definitions,
                 )?),
             };
-            let inner_name = item_validator.get_name();
+            let inner_name = crate::validators::capped_name(item_validator.get_name());
             let max_length = schema.get_as(pyo3::intern!(py, "max_length"))?;
             let name = format!("{}[{}]", Self::EXPECTED_TYPE, inner_name);
             Ok(Self {
                 strict: crate::build_tools::is_strict(schema, config)?,
                 item_validator,
+                items_strict: schema.get_as(pyo3::intern!(py, "items_strict"))?,
                 min_length: schema.get_as(pyo3::intern!(py, "min_length"))?,
                 max_length,
+                unique_items_error: schema
+                    .get_as(pyo3::intern!(py, "unique_items_error"))?
+                    .unwrap_or(false),
+                fail_fast: crate::build_tools::is_fail_fast(schema, config)?,
+                max_errors: crate::build_tools::get_max_errors(schema, config)?,
+                hash_key: schema.get_as(pyo3::intern!(py, "hash_key"))?,
                 name,
             }
             .into())
@@ -50,12 +70,61 @@ macro_rules! set_build {
 }
 pub(crate) use set_build;
 
+/// With `unique_items_error`, sets which are only too short because duplicates were removed get a
+/// `duplicate_items` error instead of `too_short`
+pub(crate) fn duplicate_items_check<'data>(
+    input: &'data impl Input<'data>,
+    field_type: &str,
+    min_length: Option<usize>,
+    items_added: usize,
+    actual_length: usize,
+) -> ValResult<'data, ()> {
+    match min_length {
+        Some(min_length) if actual_length < min_length && items_added >= min_length => Err(ValError::new(
+            ErrorType::DuplicateItems {
+                field_type: field_type.to_string(),
+                min_length,
+                actual_length,
+            },
+            input,
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// The error from `duplicate_items_check` or `min_length_check!` for partial validation, where it's returned
+/// alongside the output
+pub(crate) fn partial_min_length_error<'data>(
+    input: &'data impl Input<'data>,
+    field_type: &str,
+    min_length: Option<usize>,
+    unique_items_error: bool,
+    items_added: usize,
+    actual_length: usize,
+) -> Option<ValLineError<'data>> {
+    if unique_items_error {
+        if let Err(ValError::LineErrors(mut errors)) =
+            duplicate_items_check(input, field_type, min_length, items_added, actual_length)
+        {
+            return errors.pop();
+        }
+    }
+    min_length_error(input, field_type, min_length, actual_length)
+}
+
 impl BuildValidator for SetValidator {
     const EXPECTED_TYPE: &'static str = "set";
     set_build!();
 }
 
 impl Validator for SetValidator {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        if let Some(hash_key) = &self.hash_key {
+            visit.call(hash_key)?;
+        }
+        self.item_validator.py_gc_traverse(visit)
+    }
+
     fn validate<'s, 'data>(
         &'s self,
         py: Python<'data>,
@@ -66,21 +135,82 @@ impl Validator for SetValidator {
     ) -> ValResult<'data, PyObject> {
         let collection = input.validate_set(extra.strict.unwrap_or(self.strict))?;
         let set = PySet::empty(py)?;
-        collection.validate_to_set(
+        let items_added = collection.validate_to_set(
             py,
             set,
             input,
             self.max_length,
+            self.fail_fast,
+            self.max_errors,
+            self.hash_key.as_ref(),
             "Set",
             &self.item_validator,
-            extra,
+            &extra.with_items_strict(self.items_strict),
             definitions,
             recursion_guard,
+            None,
         )?;
+        if self.unique_items_error {
+            duplicate_items_check(input, "Set", self.min_length, items_added, set.len())?;
+        }
         min_length_check!(input, "Set", self.min_length, set);
         Ok(set.into_py(py))
     }
 
+    fn validate_partial<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        definitions: &'data Definitions<CombinedValidator>,
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, (PyObject, Vec<ValLineError<'data>>)> {
+        let collection = input.validate_set(extra.strict.unwrap_or(self.strict))?;
+        let set = PySet::empty(py)?;
+        let mut errors = Vec::new();
+        let items_added = collection.validate_to_set(
+            py,
+            set,
+            input,
+            self.max_length,
+            self.fail_fast,
+            self.max_errors,
+            self.hash_key.as_ref(),
+            "Set",
+            &self.item_validator,
+            &extra.with_items_strict(self.items_strict),
+            definitions,
+            recursion_guard,
+            Some(&mut errors),
+        )?;
+        errors.extend(partial_min_length_error(
+            input,
+            "Set",
+            self.min_length,
+            self.unique_items_error,
+            items_added,
+            set.len(),
+        ));
+        Ok((set.into_py(py), errors))
+    }
+
+    fn iterable_checks(&self) -> Option<(Option<&CombinedValidator>, IterableValidationChecks)> {
+        if self.hash_key.is_some() {
+            // items are deduplicated by their key, which `validate_iterated` doesn't do
+            return None;
+        }
+        let checks = IterableValidationChecks {
+            output_type: IteratedOutputType::Set,
+            min_length: self.min_length,
+            max_length: self.max_length,
+            fail_fast: self.fail_fast,
+            max_errors: self.max_errors,
+            items_strict: self.items_strict,
+            unique_items_error: self.unique_items_error,
+        };
+        Some((Some(self.item_validator.as_ref()), checks))
+    }
+
     fn different_strict_behavior(
         &self,
         definitions: Option<&DefinitionsBuilder<CombinedValidator>>,
@@ -100,4 +230,8 @@ impl Validator for SetValidator {
     fn complete(&mut self, definitions: &DefinitionsBuilder<CombinedValidator>) -> PyResult<()> {
         self.item_validator.complete(definitions)
     }
+
+    fn memory_usage(&self, memory: &mut MemoryUsage) -> usize {
+        memory.visit_boxed(&["items_schema".into()], self.item_validator.as_ref()) + self.name.heap_size()
+    }
 }
@@ -0,0 +1,228 @@
+use std::cell::Cell;
+use std::mem::size_of;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Instant;
+
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use pyo3::{PyTraverseError, PyVisit};
+
+use crate::errors::{ValLineError, ValResult};
+use crate::input::Input;
+use crate::memory::MemoryUsage;
+use crate::recursion_guard::RecursionGuard;
+
+use super::iterated::IterableValidationChecks;
+use super::{CombinedValidator, Definitions, DefinitionsBuilder, Extra, Validator};
+
+/// Counters for one schema node, shared between its `StatsValidator` and the `SchemaValidator`, atomics mean
+/// threads validating concurrently never wait on each other, at the cost of contended increments for hot nodes
+#[derive(Debug)]
+pub struct NodeStats {
+    path: String,
+    name: String,
+    calls: AtomicU64,
+    failures: AtomicU64,
+    time_ns: AtomicU64,
+}
+
+impl NodeStats {
+    pub fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<&'py PyDict> {
+        let dict = PyDict::new(py);
+        dict.set_item(intern!(py, "path"), &self.path)?;
+        dict.set_item(intern!(py, "name"), &self.name)?;
+        dict.set_item(intern!(py, "calls"), self.calls.load(Ordering::Relaxed))?;
+        dict.set_item(intern!(py, "failures"), self.failures.load(Ordering::Relaxed))?;
+        dict.set_item(
+            intern!(py, "time"),
+            self.time_ns.load(Ordering::Relaxed) as f64 / 1_000_000_000.0,
+        )?;
+        Ok(dict)
+    }
+
+    pub fn reset(&self) {
+        self.calls.store(0, Ordering::Relaxed);
+        self.failures.store(0, Ordering::Relaxed);
+        self.time_ns.store(0, Ordering::Relaxed);
+    }
+}
+
+/// Used while building a schema with `collect_stats=True` to wrap validators in `StatsValidator`
+#[derive(Debug, Clone, Default)]
+pub struct StatsCollector {
+    // schema types of the validators currently being built, outermost first
+    path: Vec<String>,
+    nodes: Vec<Arc<NodeStats>>,
+}
+
+impl StatsCollector {
+    pub fn enter(&mut self, schema_type: &str) {
+        self.path.push(schema_type.to_string());
+    }
+
+    pub fn exit(&mut self) {
+        self.path.pop();
+    }
+
+    /// Wrap a validator built for the schema passed to the innermost `enter`
+    pub fn wrap(&mut self, validator: CombinedValidator) -> CombinedValidator {
+        match validator {
+            // validators which check for `WithDefault` validators need to see them directly, references are
+            // skipped so the time is recorded against the definition itself, `Stats` validators are already
+            // wrapped, e.g. the inner schema of `definitions` schemas
+            CombinedValidator::WithDefault(_) | CombinedValidator::DefinitionRef(_) | CombinedValidator::Stats(_) => {
+                validator
+            }
+            _ => {
+                let stats = Arc::new(NodeStats {
+                    path: self.path.join("."),
+                    name: validator.get_name().to_string(),
+                    calls: AtomicU64::new(0),
+                    failures: AtomicU64::new(0),
+                    time_ns: AtomicU64::new(0),
+                });
+                self.nodes.push(stats.clone());
+                StatsValidator {
+                    validator: Box::new(validator),
+                    stats,
+                }
+                .into()
+            }
+        }
+    }
+
+    /// Nodes whose validators were discarded while building, e.g. `any` item schemas which are skipped,
+    /// are left out since they'd never be called
+    pub fn finish(self) -> Vec<Arc<NodeStats>> {
+        self.nodes.into_iter().filter(|n| Arc::strong_count(n) > 1).collect()
+    }
+}
+
+thread_local! {
+    // time spent in nested `StatsValidator`s, so each node only records its own time
+    static CHILD_TIME_NS: Cell<u64> = const { Cell::new(0) };
+}
+
+#[derive(Debug, Clone)]
+pub struct StatsValidator {
+    validator: Box<CombinedValidator>,
+    stats: Arc<NodeStats>,
+}
+
+impl StatsValidator {
+    pub fn inner(&self) -> &CombinedValidator {
+        &self.validator
+    }
+
+    pub fn into_inner(self) -> CombinedValidator {
+        *self.validator
+    }
+
+    fn record<'data, T>(&self, call: impl FnOnce() -> ValResult<'data, T>) -> ValResult<'data, T> {
+        let outer_child_time = CHILD_TIME_NS.with(|t| t.replace(0));
+        let start = Instant::now();
+        let result = call();
+        let elapsed = u64::try_from(start.elapsed().as_nanos()).unwrap_or(u64::MAX);
+        let child_time = CHILD_TIME_NS.with(|t| t.replace(outer_child_time.saturating_add(elapsed)));
+
+        self.stats.calls.fetch_add(1, Ordering::Relaxed);
+        if result.is_err() {
+            self.stats.failures.fetch_add(1, Ordering::Relaxed);
+        }
+        self.stats
+            .time_ns
+            .fetch_add(elapsed.saturating_sub(child_time), Ordering::Relaxed);
+        result
+    }
+}
+
+impl Validator for StatsValidator {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        self.validator.py_gc_traverse(visit)
+    }
+
+    fn py_gc_clear(&mut self) {
+        self.validator.py_gc_clear();
+    }
+
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        definitions: &'data Definitions<CombinedValidator>,
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        self.record(|| self.validator.validate(py, input, extra, definitions, recursion_guard))
+    }
+
+    fn validate_partial<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        definitions: &'data Definitions<CombinedValidator>,
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, (PyObject, Vec<ValLineError<'data>>)> {
+        self.record(|| {
+            self.validator
+                .validate_partial(py, input, extra, definitions, recursion_guard)
+        })
+    }
+
+    fn iterable_checks(&self) -> Option<(Option<&CombinedValidator>, IterableValidationChecks)> {
+        // the container itself isn't called when validating iteratively, only the item validator is recorded
+        self.validator.iterable_checks()
+    }
+
+    fn default_value<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        outer_loc: Option<impl Into<crate::errors::LocItem>>,
+        extra: &Extra,
+        definitions: &'data Definitions<CombinedValidator>,
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, Option<PyObject>> {
+        self.validator
+            .default_value(py, outer_loc, extra, definitions, recursion_guard)
+    }
+
+    fn validate_assignment<'s, 'data: 's>(
+        &'s self,
+        py: Python<'data>,
+        obj: &'data PyAny,
+        field_name: &'data str,
+        field_value: &'data PyAny,
+        extra: &Extra,
+        definitions: &'data Definitions<CombinedValidator>,
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        self.record(|| {
+            self.validator
+                .validate_assignment(py, obj, field_name, field_value, extra, definitions, recursion_guard)
+        })
+    }
+
+    fn different_strict_behavior(
+        &self,
+        definitions: Option<&DefinitionsBuilder<CombinedValidator>>,
+        ultra_strict: bool,
+    ) -> bool {
+        self.validator.different_strict_behavior(definitions, ultra_strict)
+    }
+
+    fn get_name(&self) -> &str {
+        self.validator.get_name()
+    }
+
+    fn complete(&mut self, definitions: &DefinitionsBuilder<CombinedValidator>) -> PyResult<()> {
+        self.validator.complete(definitions)
+    }
+
+    fn memory_usage(&self, memory: &mut MemoryUsage) -> usize {
+        // stats validators are transparent, the wrapped validator's memory is recorded against the same node
+        size_of::<CombinedValidator>() + Validator::memory_usage(self.validator.as_ref(), memory)
+    }
+}
@@ -1,11 +1,15 @@
+use std::sync::Arc;
+
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyString};
 use regex::Regex;
+use unicode_segmentation::UnicodeSegmentation;
 
-use crate::build_tools::{is_strict, py_schema_error_type, schema_or_config};
+use crate::build_tools::{is_strict, py_schema_err, py_schema_error_type, schema_or_config};
 use crate::errors::{ErrorType, ValError, ValResult};
 use crate::input::Input;
+use crate::memory::MemoryUsage;
 use crate::recursion_guard::RecursionGuard;
 use crate::tools::SchemaDict;
 
@@ -22,9 +26,9 @@ impl BuildValidator for StrValidator {
     fn build(
         schema: &PyDict,
         config: Option<&PyDict>,
-        _definitions: &mut DefinitionsBuilder<CombinedValidator>,
+        definitions: &mut DefinitionsBuilder<CombinedValidator>,
     ) -> PyResult<CombinedValidator> {
-        let con_str_validator = StrConstrainedValidator::build(schema, config)?;
+        let con_str_validator = StrConstrainedValidator::build(schema, config, definitions)?;
 
         if con_str_validator.has_constraints_set() {
             Ok(con_str_validator.into())
@@ -70,9 +74,12 @@ impl Validator for StrValidator {
 #[derive(Debug, Clone, Default)]
 pub struct StrConstrainedValidator {
     strict: bool,
-    pattern: Option<Regex>,
+    pattern: Option<Arc<Regex>>,
     max_length: Option<usize>,
     min_length: Option<usize>,
+    // `None` if the schema doesn't set `length_unit`, lengths are then measured in code points but not reported
+    // in errors, for compatibility with errors from before `length_unit` existed
+    length_unit: Option<LengthUnit>,
     strip_whitespace: bool,
     to_lower: bool,
     to_upper: bool,
@@ -93,15 +100,30 @@ impl Validator for StrConstrainedValidator {
         if self.strip_whitespace {
             str = str.trim();
         }
-        if let Some(min_length) = self.min_length {
-            if str.len() < min_length {
-                // return py_schema_err!("{} is shorter than {}", str, min_length);
-                return Err(ValError::new(ErrorType::StringTooShort { min_length }, input));
+        if self.min_length.is_some() || self.max_length.is_some() {
+            let length = self.length_unit.unwrap_or_default().measure(str);
+            let actual_length = self.length_unit.map(|_| length);
+            if let Some(min_length) = self.min_length {
+                if length < min_length {
+                    return Err(ValError::new(
+                        ErrorType::StringTooShort {
+                            min_length,
+                            actual_length,
+                        },
+                        input,
+                    ));
+                }
             }
-        }
-        if let Some(max_length) = self.max_length {
-            if str.len() > max_length {
-                return Err(ValError::new(ErrorType::StringTooLong { max_length }, input));
+            if let Some(max_length) = self.max_length {
+                if length > max_length {
+                    return Err(ValError::new(
+                        ErrorType::StringTooLong {
+                            max_length,
+                            actual_length,
+                        },
+                        input,
+                    ));
+                }
             }
         }
         if let Some(pattern) = &self.pattern {
@@ -143,13 +165,21 @@ impl Validator for StrConstrainedValidator {
     fn complete(&mut self, _definitions: &DefinitionsBuilder<CombinedValidator>) -> PyResult<()> {
         Ok(())
     }
+
+    fn memory_usage(&self, memory: &mut MemoryUsage) -> usize {
+        self.pattern.as_ref().map_or(0, |pattern| memory.visit_shared(pattern))
+    }
 }
 
 impl StrConstrainedValidator {
-    fn build(schema: &PyDict, config: Option<&PyDict>) -> PyResult<Self> {
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        definitions: &mut DefinitionsBuilder<CombinedValidator>,
+    ) -> PyResult<Self> {
         let py = schema.py();
         let pattern = match schema.get_as(intern!(py, "pattern"))? {
-            Some(s) => Some(Regex::new(s).map_err(|e| py_schema_error_type!("{}", e))?),
+            Some(s) => Some(definitions.get_regex(s).map_err(|e| py_schema_error_type!("{}", e))?),
             None => None,
         };
         let min_length: Option<usize> =
@@ -174,6 +204,7 @@ impl StrConstrainedValidator {
             pattern,
             min_length,
             max_length,
+            length_unit: LengthUnit::from_py(schema)?,
             strip_whitespace,
             to_lower,
             to_upper,
@@ -191,3 +222,33 @@ impl StrConstrainedValidator {
             || self.to_upper
     }
 }
+
+/// The unit `min_length` and `max_length` are measured in
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LengthUnit {
+    #[default]
+    Codepoint,
+    Byte,
+    /// Extended grapheme clusters, roughly what a user sees as a single character
+    Grapheme,
+}
+
+impl LengthUnit {
+    fn from_py(schema: &PyDict) -> PyResult<Option<Self>> {
+        match schema.get_as::<&str>(intern!(schema.py(), "length_unit"))? {
+            None => Ok(None),
+            Some("codepoint") => Ok(Some(Self::Codepoint)),
+            Some("byte") => Ok(Some(Self::Byte)),
+            Some("grapheme") => Ok(Some(Self::Grapheme)),
+            Some(s) => py_schema_err!("Invalid length_unit {:?}", s),
+        }
+    }
+
+    fn measure(self, s: &str) -> usize {
+        match self {
+            Self::Codepoint => s.chars().count(),
+            Self::Byte => s.len(),
+            Self::Grapheme => s.graphemes(true).count(),
+        }
+    }
+}
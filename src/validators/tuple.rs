@@ -1,22 +1,31 @@
-use pyo3::intern;
+use std::borrow::Cow;
+
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyList, PyTuple};
+use pyo3::types::{PyDict, PyTuple};
+use pyo3::{intern, PyTraverseError, PyVisit};
 
-use crate::build_tools::is_strict;
+use crate::build_tools::{get_max_errors, is_fail_fast, is_strict, py_schema_err, tuple_positional_items};
 use crate::errors::{ErrorType, ValError, ValLineError, ValResult};
 use crate::input::{GenericIterable, Input};
+use crate::memory::{HeapSize, MemoryUsage};
 use crate::recursion_guard::RecursionGuard;
 use crate::tools::SchemaDict;
 
-use super::list::{get_items_schema, min_length_check};
-use super::{build_validator, BuildValidator, CombinedValidator, Definitions, DefinitionsBuilder, Extra, Validator};
+use super::iterated::{IterableValidationChecks, IteratedOutputType};
+use super::list::{get_items_schema, min_length_check, min_length_error};
+use super::{
+    build_validator, capped_name, BuildValidator, CombinedValidator, Definitions, DefinitionsBuilder, Extra, Validator,
+};
 
 #[derive(Debug, Clone)]
 pub struct TupleVariableValidator {
     strict: bool,
     item_validator: Option<Box<CombinedValidator>>,
+    items_strict: Option<bool>,
     min_length: Option<usize>,
     max_length: Option<usize>,
+    fail_fast: bool,
+    max_errors: Option<usize>,
     name: String,
 }
 
@@ -29,13 +38,18 @@ impl BuildValidator for TupleVariableValidator {
     ) -> PyResult<CombinedValidator> {
         let py = schema.py();
         let item_validator = get_items_schema(schema, config, definitions)?;
-        let inner_name = item_validator.as_ref().map_or("any", |v| v.get_name());
+        let inner_name = item_validator
+            .as_ref()
+            .map_or(Cow::Borrowed("any"), |v| capped_name(v.get_name()));
         let name = format!("tuple[{inner_name}, ...]");
         Ok(Self {
             strict: is_strict(schema, config)?,
             item_validator,
+            items_strict: schema.get_as(intern!(py, "items_strict"))?,
             min_length: schema.get_as(intern!(py, "min_length"))?,
             max_length: schema.get_as(intern!(py, "max_length"))?,
+            fail_fast: is_fail_fast(schema, config)?,
+            max_errors: get_max_errors(schema, config)?,
             name,
         }
         .into())
@@ -43,6 +57,13 @@ impl BuildValidator for TupleVariableValidator {
 }
 
 impl Validator for TupleVariableValidator {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        if let Some(ref validator) = self.item_validator {
+            validator.py_gc_traverse(visit)?;
+        }
+        Ok(())
+    }
+
     fn validate<'s, 'data>(
         &'s self,
         py: Python<'data>,
@@ -58,11 +79,14 @@ impl Validator for TupleVariableValidator {
                 py,
                 input,
                 self.max_length,
+                self.fail_fast,
+                self.max_errors,
                 "Tuple",
                 v,
-                extra,
+                &extra.with_items_strict(self.items_strict),
                 definitions,
                 recursion_guard,
+                None,
             )?,
             None => seq.to_vec(py, input, "Tuple", self.max_length)?,
         };
@@ -70,6 +94,49 @@ impl Validator for TupleVariableValidator {
         Ok(PyTuple::new(py, &output).into_py(py))
     }
 
+    fn validate_partial<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        definitions: &'data Definitions<CombinedValidator>,
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, (PyObject, Vec<ValLineError<'data>>)> {
+        let seq = input.validate_tuple(extra.strict.unwrap_or(self.strict))?;
+        let mut errors = Vec::new();
+        let output = match self.item_validator {
+            Some(ref v) => seq.validate_to_vec(
+                py,
+                input,
+                self.max_length,
+                self.fail_fast,
+                self.max_errors,
+                "Tuple",
+                v,
+                &extra.with_items_strict(self.items_strict),
+                definitions,
+                recursion_guard,
+                Some(&mut errors),
+            )?,
+            None => seq.to_vec(py, input, "Tuple", self.max_length)?,
+        };
+        errors.extend(min_length_error(input, "Tuple", self.min_length, output.len()));
+        Ok((PyTuple::new(py, &output).into_py(py), errors))
+    }
+
+    fn iterable_checks(&self) -> Option<(Option<&CombinedValidator>, IterableValidationChecks)> {
+        let checks = IterableValidationChecks {
+            output_type: IteratedOutputType::Tuple,
+            min_length: self.min_length,
+            max_length: self.max_length,
+            fail_fast: self.fail_fast,
+            max_errors: self.max_errors,
+            items_strict: self.items_strict,
+            unique_items_error: false,
+        };
+        Some((self.item_validator.as_deref(), checks))
+    }
+
     fn different_strict_behavior(
         &self,
         definitions: Option<&DefinitionsBuilder<CombinedValidator>>,
@@ -95,6 +162,14 @@ impl Validator for TupleVariableValidator {
             None => Ok(()),
         }
     }
+
+    fn memory_usage(&self, memory: &mut MemoryUsage) -> usize {
+        let items = match self.item_validator {
+            Some(ref validator) => memory.visit_boxed(&["items_schema".into()], validator.as_ref()),
+            None => 0,
+        };
+        items + self.name.heap_size()
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -102,6 +177,9 @@ pub struct TuplePositionalValidator {
     strict: bool,
     items_validators: Vec<CombinedValidator>,
     extra_validator: Option<Box<CombinedValidator>>,
+    // the length constraints apply to the items validated by `extra_validator`
+    min_length: Option<usize>,
+    max_length: Option<usize>,
     name: String,
 }
 
@@ -113,24 +191,46 @@ impl BuildValidator for TuplePositionalValidator {
         definitions: &mut DefinitionsBuilder<CombinedValidator>,
     ) -> PyResult<CombinedValidator> {
         let py = schema.py();
-        let items: &PyList = schema.get_as_req(intern!(py, "items_schema"))?;
+        let (items, extra_schema) = tuple_positional_items(schema)?;
         let validators: Vec<CombinedValidator> = items
-            .iter()
-            .map(|item| build_validator(item, config, definitions))
+            .into_iter()
+            .enumerate()
+            .map(|(index, item)| build_validator(&["items_schema".into(), index.into()], item, config, definitions))
             .collect::<PyResult<_>>()?;
+        let extra_validator = match extra_schema {
+            Some(v) => {
+                // with `variadic_item_index`, the extra schema is the last item of `items_schema`
+                let path = match schema.contains(intern!(py, "variadic_item_index"))? {
+                    true => ["items_schema".into(), validators.len().into()].to_vec(),
+                    false => ["extra_schema".into()].to_vec(),
+                };
+                Some(Box::new(build_validator(&path, v, config, definitions)?))
+            }
+            None => None,
+        };
+        let min_length: Option<usize> = schema.get_as(intern!(py, "min_length"))?;
+        let max_length: Option<usize> = schema.get_as(intern!(py, "max_length"))?;
+        if extra_validator.is_none() && (min_length.is_some() || max_length.is_some()) {
+            return py_schema_err!("`min_length` and `max_length` require `extra_schema` or `variadic_item_index`");
+        }
 
-        let descr = validators
+        let mut descr = validators
             .iter()
             .map(Validator::get_name)
             .collect::<Vec<_>>()
             .join(", ");
+        if let (Some(_), Some(ref v)) = (schema.get_item(intern!(py, "variadic_item_index")), &extra_validator) {
+            if !descr.is_empty() {
+                descr.push_str(", ");
+            }
+            descr.push_str(&format!("{}, ...", v.get_name()));
+        }
         Ok(Self {
             strict: is_strict(schema, config)?,
             items_validators: validators,
-            extra_validator: match schema.get_item(intern!(py, "extra_schema")) {
-                Some(v) => Some(Box::new(build_validator(v, config, definitions)?)),
-                None => None,
-            },
+            extra_validator,
+            min_length,
+            max_length,
             name: format!("tuple[{descr}]"),
         }
         .into())
@@ -151,10 +251,17 @@ fn validate_tuple_positional<'s, 'data, T: Iterator<Item = PyResult<&'data I>>,
     collection_iter: &mut T,
     collection_len: Option<usize>,
     expected_length: usize,
+    min_length: Option<usize>,
+    max_length: Option<usize>,
 ) -> ValResult<'data, ()> {
+    let mut items_missing = false;
     for (index, validator) in items_validators.iter().enumerate() {
+        if extra.fail_fast && !errors.is_empty() {
+            break;
+        }
+        let item_extra = extra.with_index(Some(index));
         match collection_iter.next() {
-            Some(result) => match validator.validate(py, result?, extra, definitions, recursion_guard) {
+            Some(result) => match validator.validate(py, result?, &item_extra, definitions, recursion_guard) {
                 Ok(item) => output.push(item),
                 Err(ValError::LineErrors(line_errors)) => {
                     errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index.into())));
@@ -166,15 +273,35 @@ fn validate_tuple_positional<'s, 'data, T: Iterator<Item = PyResult<&'data I>>,
                     output.push(value);
                 } else {
                     errors.push(ValLineError::new_with_loc(ErrorType::Missing, input, index));
+                    items_missing = true;
                 }
             }
         }
     }
+    let mut extra_length = 0;
     for (index, result) in collection_iter.enumerate() {
+        if extra.fail_fast && !errors.is_empty() {
+            break;
+        }
         let item = result?;
         match extra_validator {
             Some(ref extra_validator) => {
-                match extra_validator.validate(py, item, extra, definitions, recursion_guard) {
+                extra_length += 1;
+                if let Some(max_length) = max_length {
+                    if extra_length > max_length {
+                        errors.push(ValLineError::new(
+                            ErrorType::TooLong {
+                                field_type: "Tuple".to_string(),
+                                max_length: expected_length + max_length,
+                                actual_length: collection_len.unwrap_or(expected_length + extra_length),
+                            },
+                            input,
+                        ));
+                        break;
+                    }
+                }
+                let item_extra = extra.with_index(Some(index + expected_length));
+                match extra_validator.validate(py, item, &item_extra, definitions, recursion_guard) {
                     Ok(item) => output.push(item),
                     Err(ValError::LineErrors(line_errors)) => {
                         errors.extend(
@@ -201,10 +328,33 @@ fn validate_tuple_positional<'s, 'data, T: Iterator<Item = PyResult<&'data I>>,
             }
         }
     }
+    // missing positional items have their own errors
+    if let Some(min_length) = min_length {
+        if extra_length < min_length && !items_missing {
+            errors.push(ValLineError::new(
+                ErrorType::TooShort {
+                    field_type: "Tuple".to_string(),
+                    min_length: expected_length + min_length,
+                    actual_length: expected_length + extra_length,
+                },
+                input,
+            ));
+        }
+    }
     Ok(())
 }
 
 impl Validator for TuplePositionalValidator {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        for validator in &self.items_validators {
+            validator.py_gc_traverse(visit)?;
+        }
+        if let Some(ref validator) = self.extra_validator {
+            validator.py_gc_traverse(visit)?;
+        }
+        Ok(())
+    }
+
     fn validate<'s, 'data>(
         &'s self,
         py: Python<'data>,
@@ -235,6 +385,8 @@ impl Validator for TuplePositionalValidator {
                     &mut $collection_iter,
                     collection_len,
                     expected_length,
+                    self.min_length,
+                    self.max_length,
                 )?
             }};
         }
@@ -287,4 +439,12 @@ impl Validator for TuplePositionalValidator {
             None => Ok(()),
         }
     }
+
+    fn memory_usage(&self, memory: &mut MemoryUsage) -> usize {
+        let extra = match self.extra_validator {
+            Some(ref validator) => memory.visit_boxed(&["extra_schema".into()], validator.as_ref()),
+            None => 0,
+        };
+        memory.visit_vec("items_schema", &self.items_validators) + extra + self.name.heap_size()
+    }
 }
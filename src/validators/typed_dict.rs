@@ -1,17 +1,20 @@
-use pyo3::intern;
+use std::mem::size_of;
+
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyString};
+use pyo3::{intern, PyTraverseError, PyVisit};
 
 use ahash::AHashSet;
 
 use crate::build_tools::py_schema_err;
-use crate::build_tools::{is_strict, schema_or_config, schema_or_config_same, ExtraBehavior};
+use crate::build_tools::{is_strict, merge_config, schema_or_config, schema_or_config_same, ExtraBehavior};
 use crate::errors::{py_err_string, ErrorType, ValError, ValLineError, ValResult};
 use crate::input::{
     AttributesGenericIterator, DictGenericIterator, GenericMapping, Input, JsonObjectGenericIterator,
     MappingGenericIterator,
 };
 use crate::lookup_key::LookupKey;
+use crate::memory::{HeapSize, MemoryUsage};
 use crate::recursion_guard::RecursionGuard;
 use crate::tools::SchemaDict;
 
@@ -40,13 +43,13 @@ impl BuildValidator for TypedDictValidator {
 
     fn build(
         schema: &PyDict,
-        _config: Option<&PyDict>,
+        parent_config: Option<&PyDict>,
         definitions: &mut DefinitionsBuilder<CombinedValidator>,
     ) -> PyResult<CombinedValidator> {
         let py = schema.py();
 
-        // typed dicts ignore the parent config and always use the config from this TypedDict
-        let config = schema.get_as(intern!(py, "config"))?;
+        // the config from this TypedDict is merged over the parent config
+        let config = merge_config(parent_config, schema.get_as(intern!(py, "config"))?)?;
 
         let strict = is_strict(schema, config)?;
 
@@ -57,7 +60,12 @@ impl BuildValidator for TypedDictValidator {
         let extra_behavior = ExtraBehavior::from_schema_or_config(py, schema, config, ExtraBehavior::Ignore)?;
 
         let extra_validator = match (schema.get_item(intern!(py, "extra_validator")), &extra_behavior) {
-            (Some(v), ExtraBehavior::Allow) => Some(Box::new(build_validator(v, config, definitions)?)),
+            (Some(v), ExtraBehavior::Allow) => Some(Box::new(build_validator(
+                &["extra_validator".into()],
+                v,
+                config,
+                definitions,
+            )?)),
             (Some(_), _) => return py_schema_err!("extra_validator can only be used if extra_behavior=allow"),
             (_, _) => None,
         };
@@ -71,7 +79,8 @@ impl BuildValidator for TypedDictValidator {
 
             let schema = field_info.get_as_req(intern!(py, "schema"))?;
 
-            let validator = match build_validator(schema, config, definitions) {
+            let path = ["fields".into(), field_name.into(), "schema".into()];
+            let validator = match build_validator(&path, schema, config, definitions) {
                 Ok(v) => v,
                 Err(err) => return py_schema_err!("Field \"{}\":\n  {}", field_name, err),
             };
@@ -133,6 +142,16 @@ impl BuildValidator for TypedDictValidator {
 }
 
 impl Validator for TypedDictValidator {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        for field in &self.fields {
+            field.validator.py_gc_traverse(visit)?;
+        }
+        if let Some(ref validator) = self.extra_validator {
+            validator.py_gc_traverse(visit)?;
+        }
+        Ok(())
+    }
+
     fn validate<'s, 'data>(
         &'s self,
         py: Python<'data>,
@@ -142,10 +161,11 @@ impl Validator for TypedDictValidator {
         recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
         let strict = extra.strict.unwrap_or(self.strict);
-        let dict = input.validate_dict(strict)?;
+        let dict = input.validate_model_fields(strict, extra.from_attributes.unwrap_or(false))?;
 
         let output_dict = PyDict::new(py);
-        let mut errors: Vec<ValLineError> = Vec::with_capacity(self.fields.len());
+        // clean data is the common case, so only allocate once there are errors
+        let mut errors: Vec<ValLineError> = Vec::new();
 
         // we only care about which keys have been used if we're iterating over the object for extra after
         // the first pass
@@ -158,9 +178,13 @@ impl Validator for TypedDictValidator {
         macro_rules! process {
             ($dict:ident, $get_method:ident, $iter:ty $(,$kwargs:ident)?) => {{
                 for field in &self.fields {
+                    if extra.fail_fast && !errors.is_empty() {
+                        break;
+                    }
                     let extra = Extra {
                         data: Some(output_dict),
                         field_name: Some(&field.name),
+                        index: None,
                         ..*extra
                     };
                     let op_key_value = match field.lookup_key.$get_method($dict $(, $kwargs )? ) {
@@ -212,6 +236,9 @@ impl Validator for TypedDictValidator {
 
                 if let Some(ref mut used_keys) = used_keys {
                     for item_result in <$iter>::new($dict)? {
+                        if extra.fail_fast && !errors.is_empty() {
+                            break;
+                        }
                         let (raw_key, value) = item_result?;
                         let either_str = match raw_key.strict_str() {
                             Ok(k) => k,
@@ -300,4 +327,19 @@ impl Validator for TypedDictValidator {
             None => Ok(()),
         }
     }
+
+    fn memory_usage(&self, memory: &mut MemoryUsage) -> usize {
+        let mut bytes = self.fields.capacity() * size_of::<TypedDictField>();
+        for field in &self.fields {
+            bytes += field.name.heap_size() + field.lookup_key.heap_size();
+            memory.visit(
+                &["fields".into(), field.name.as_str().into(), "schema".into()],
+                &field.validator,
+            );
+        }
+        if let Some(ref validator) = self.extra_validator {
+            bytes += memory.visit_boxed(&["extra_validator".into()], validator.as_ref());
+        }
+        bytes
+    }
 }
@@ -1,20 +1,23 @@
 use std::fmt::Write;
 
-use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList, PyString};
+use pyo3::{intern, PyTraverseError, PyVisit};
 
 use crate::build_tools::py_schema_err;
 use crate::build_tools::{is_strict, schema_or_config};
 use crate::errors::{ErrorType, LocItem, ValError, ValLineError, ValResult};
 use crate::input::{GenericMapping, Input};
 use crate::lookup_key::LookupKey;
+use crate::memory::{HeapSize, MemoryUsage};
 use crate::recursion_guard::RecursionGuard;
 use crate::tools::SchemaDict;
 
 use super::custom_error::CustomError;
 use super::literal::LiteralLookup;
-use super::{build_validator, BuildValidator, CombinedValidator, Definitions, DefinitionsBuilder, Extra, Validator};
+use super::{
+    build_validator, capped_name, BuildValidator, CombinedValidator, Definitions, DefinitionsBuilder, Extra, Validator,
+};
 
 #[derive(Debug, Clone)]
 pub struct UnionValidator {
@@ -24,6 +27,7 @@ pub struct UnionValidator {
     name: String,
     strict_required: bool,
     ultra_strict_required: bool,
+    has_container_choices: bool,
 }
 
 impl BuildValidator for UnionValidator {
@@ -38,7 +42,8 @@ impl BuildValidator for UnionValidator {
         let choices: Vec<CombinedValidator> = schema
             .get_as_req::<&PyList>(intern!(py, "choices"))?
             .iter()
-            .map(|choice| build_validator(choice, config, definitions))
+            .enumerate()
+            .map(|(index, choice)| build_validator(&["choices".into(), index.into()], choice, config, definitions))
             .collect::<PyResult<Vec<CombinedValidator>>>()?;
 
         let auto_collapse = || schema.get_as_req(intern!(py, "auto_collapse")).unwrap_or(true);
@@ -46,8 +51,13 @@ impl BuildValidator for UnionValidator {
             0 => py_schema_err!("One or more union choices required"),
             1 if auto_collapse() => Ok(choices.into_iter().next().unwrap()),
             _ => {
-                let descr = choices.iter().map(Validator::get_name).collect::<Vec<_>>().join(",");
+                let descr = choices
+                    .iter()
+                    .map(|v| capped_name(v.get_name()))
+                    .collect::<Vec<_>>()
+                    .join(",");
 
+                let has_container_choices = choices.iter().any(is_container);
                 Ok(Self {
                     choices,
                     custom_error: CustomError::build(schema, config, definitions)?,
@@ -55,6 +65,7 @@ impl BuildValidator for UnionValidator {
                     name: format!("{}[{descr}]", Self::EXPECTED_TYPE),
                     strict_required: true,
                     ultra_strict_required: false,
+                    has_container_choices,
                 }
                 .into())
             }
@@ -62,14 +73,49 @@ impl BuildValidator for UnionValidator {
     }
 }
 
+/// Whether `validator` produces a list, tuple, set or frozenset
+fn is_container(validator: &CombinedValidator) -> bool {
+    matches!(
+        validator.inner(),
+        CombinedValidator::List(_)
+            | CombinedValidator::TupleVariable(_)
+            | CombinedValidator::TuplePositional(_)
+            | CombinedValidator::Set(_)
+            | CombinedValidator::FrozenSet(_)
+    )
+}
+
+/// Whether `input` is already the container type `validator` produces, e.g. a `set` for a set schema
+fn is_native_container<'data>(validator: &CombinedValidator, input: &'data impl Input<'data>) -> bool {
+    match validator.inner() {
+        CombinedValidator::List(_) => input.strict_list().is_ok(),
+        CombinedValidator::TupleVariable(_) | CombinedValidator::TuplePositional(_) => input.strict_tuple().is_ok(),
+        CombinedValidator::Set(_) => input.strict_set().is_ok(),
+        CombinedValidator::FrozenSet(_) => input.strict_frozenset().is_ok(),
+        _ => false,
+    }
+}
+
 impl UnionValidator {
+    /// `errors` holds the line errors from each choice in order; the choice names are only added to their
+    /// locations here, once every choice has failed, since errors are discarded when a later choice succeeds
     fn or_custom_error<'s, 'data>(
         &'s self,
-        errors: Option<Vec<ValLineError<'data>>>,
+        errors: Option<Vec<Vec<ValLineError<'data>>>>,
         input: &'data impl Input<'data>,
     ) -> ValError<'data> {
         if let Some(errors) = errors {
-            ValError::LineErrors(errors)
+            ValError::LineErrors(
+                self.choices
+                    .iter()
+                    .zip(errors)
+                    .flat_map(|(validator, line_errors)| {
+                        line_errors
+                            .into_iter()
+                            .map(|err| err.with_outer_location(validator.get_name().into()))
+                    })
+                    .collect(),
+            )
         } else {
             self.custom_error.as_ref().unwrap().as_val_error(input)
         }
@@ -77,6 +123,13 @@ impl UnionValidator {
 }
 
 impl Validator for UnionValidator {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        for choice in &self.choices {
+            choice.py_gc_traverse(visit)?;
+        }
+        Ok(())
+    }
+
     fn validate<'s, 'data>(
         &'s self,
         py: Python<'data>,
@@ -99,7 +152,7 @@ impl Validator for UnionValidator {
         }
 
         if extra.strict.unwrap_or(self.strict) {
-            let mut errors: Option<Vec<ValLineError>> = match self.custom_error {
+            let mut errors: Option<Vec<Vec<ValLineError>>> = match self.custom_error {
                 None => Some(Vec::with_capacity(self.choices.len())),
                 _ => None,
             };
@@ -112,11 +165,7 @@ impl Validator for UnionValidator {
                 };
 
                 if let Some(ref mut errors) = errors {
-                    errors.extend(
-                        line_errors
-                            .into_iter()
-                            .map(|err| err.with_outer_location(validator.get_name().into())),
-                    );
+                    errors.push(line_errors);
                 }
             }
 
@@ -136,24 +185,39 @@ impl Validator for UnionValidator {
                 }
             }
 
-            let mut errors: Option<Vec<ValLineError>> = match self.custom_error {
+            // 2nd pass: if the input is a python list, tuple, set or frozenset, try the first choice for that
+            // container type before other choices, so e.g. a set with `list[int] | set[int]` isn't coerced to a
+            // list just because an item needed coercing; JSON arrays have no preferred container type
+            let mut native_choice = None;
+            if self.has_container_choices && input.is_python() {
+                if let Some(index) = self.choices.iter().position(|v| is_native_container(v, input)) {
+                    match self.choices[index].validate(py, input, extra, definitions, recursion_guard) {
+                        Err(ValError::LineErrors(line_errors)) => native_choice = Some((index, line_errors)),
+                        otherwise => return otherwise,
+                    }
+                }
+            }
+
+            let mut errors: Option<Vec<Vec<ValLineError>>> = match self.custom_error {
                 None => Some(Vec::with_capacity(self.choices.len())),
                 _ => None,
             };
 
-            // 2nd pass: check if the value can be coerced into one of the Union types, e.g. use validate
-            for validator in &self.choices {
-                let line_errors = match validator.validate(py, input, extra, definitions, recursion_guard) {
-                    Err(ValError::LineErrors(line_errors)) => line_errors,
-                    success => return success,
+            // 3rd pass: check if the value can be coerced into one of the Union types, e.g. use validate
+            for (index, validator) in self.choices.iter().enumerate() {
+                let line_errors = match native_choice {
+                    // this choice has already been tried in the 2nd pass
+                    Some((native_index, ref mut native_errors)) if native_index == index => {
+                        std::mem::take(native_errors)
+                    }
+                    _ => match validator.validate(py, input, extra, definitions, recursion_guard) {
+                        Err(ValError::LineErrors(line_errors)) => line_errors,
+                        success => return success,
+                    },
                 };
 
                 if let Some(ref mut errors) = errors {
-                    errors.extend(
-                        line_errors
-                            .into_iter()
-                            .map(|err| err.with_outer_location(validator.get_name().into())),
-                    );
+                    errors.push(line_errors);
                 }
             }
 
@@ -181,6 +245,10 @@ impl Validator for UnionValidator {
         self.ultra_strict_required = self.different_strict_behavior(Some(definitions), true);
         Ok(())
     }
+
+    fn memory_usage(&self, memory: &mut MemoryUsage) -> usize {
+        memory.visit_vec("choices", &self.choices) + self.name.heap_size()
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -249,16 +317,19 @@ impl BuildValidator for TaggedUnionValidator {
         let mut lookup_map = Vec::with_capacity(choices.len());
         for (choice_key, choice_schema) in schema_choices.iter() {
             discriminators.push(choice_key);
-            let validator = build_validator(choice_schema, config, definitions)?;
+            // discriminators which aren't strings or ints (e.g. enum members) are reported by their repr
+            let key_loc = LocItem::try_from(choice_key).or_else(|_| choice_key.repr().map(|r| r.to_string().into()))?;
+            let path = ["choices".into(), key_loc];
+            let validator = build_validator(&path, choice_schema, config, definitions)?;
             let tag_repr = choice_key.repr()?.to_string();
             if first {
                 first = false;
                 write!(tags_repr, "{tag_repr}").unwrap();
-                descr.push_str(validator.get_name());
+                descr.push_str(&capped_name(validator.get_name()));
             } else {
                 write!(tags_repr, ", {tag_repr}").unwrap();
                 // no spaces in get_name() output to make loc easy to read
-                write!(descr, ",{}", validator.get_name()).unwrap();
+                write!(descr, ",{}", capped_name(validator.get_name())).unwrap();
             }
             lookup_map.push((choice_key, validator));
         }
@@ -288,6 +359,17 @@ impl BuildValidator for TaggedUnionValidator {
 }
 
 impl Validator for TaggedUnionValidator {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        if let Discriminator::Function(ref function) = self.discriminator {
+            visit.call(function)?;
+        }
+        self.lookup.py_gc_traverse(visit)?;
+        for validator in &self.lookup.values {
+            validator.py_gc_traverse(visit)?;
+        }
+        Ok(())
+    }
+
     fn validate<'s, 'data>(
         &'s self,
         py: Python<'data>,
@@ -310,8 +392,9 @@ impl Validator for TaggedUnionValidator {
                         }
                     }};
                 }
+                let strict = extra.strict.unwrap_or(self.strict);
                 let from_attributes = extra.from_attributes.unwrap_or(self.from_attributes);
-                let dict = input.validate_model_fields(self.strict, from_attributes)?;
+                let dict = input.validate_model_fields(strict, from_attributes)?;
                 let tag = match dict {
                     GenericMapping::PyDict(dict) => find_validator!(py_get_dict_item, dict),
                     GenericMapping::PyGetAttr(obj, kwargs) => find_validator!(py_get_attr, obj, kwargs),
@@ -360,6 +443,18 @@ impl Validator for TaggedUnionValidator {
             .iter_mut()
             .try_for_each(|validator| validator.complete(definitions))
     }
+
+    fn memory_usage(&self, memory: &mut MemoryUsage) -> usize {
+        let mut bytes = self.lookup.lookup_heap_size();
+        for (tag, validator) in self.lookup.tags().into_iter().zip(&self.lookup.values) {
+            memory.visit(&["choices".into(), tag], validator);
+        }
+        bytes += match self.discriminator {
+            Discriminator::LookupKey(ref lookup_key) => lookup_key.heap_size(),
+            Discriminator::Function(_) | Discriminator::SelfSchema => 0,
+        };
+        bytes + self.tags_repr.heap_size() + self.discriminator_repr.heap_size() + self.name.heap_size()
+    }
 }
 
 impl TaggedUnionValidator {
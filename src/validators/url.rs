@@ -12,6 +12,7 @@ use url::{ParseError, SyntaxViolation, Url};
 use crate::build_tools::{is_strict, py_schema_err};
 use crate::errors::{ErrorType, ValError, ValResult};
 use crate::input::Input;
+use crate::memory::{HeapSize, MemoryUsage};
 use crate::recursion_guard::RecursionGuard;
 use crate::tools::SchemaDict;
 use crate::url::{schema_is_special, PyMultiHostUrl, PyUrl};
@@ -102,6 +103,13 @@ impl Validator for UrlValidator {
     fn complete(&mut self, _definitions: &DefinitionsBuilder<CombinedValidator>) -> PyResult<()> {
         Ok(())
     }
+
+    fn memory_usage(&self, _memory: &mut MemoryUsage) -> usize {
+        self.allowed_schemes.heap_size()
+            + self.default_host.heap_size()
+            + self.default_path.heap_size()
+            + self.name.heap_size()
+    }
 }
 
 impl UrlValidator {
@@ -230,6 +238,13 @@ impl Validator for MultiHostUrlValidator {
     fn complete(&mut self, _definitions: &DefinitionsBuilder<CombinedValidator>) -> PyResult<()> {
         Ok(())
     }
+
+    fn memory_usage(&self, _memory: &mut MemoryUsage) -> usize {
+        self.allowed_schemes.heap_size()
+            + self.default_host.heap_size()
+            + self.default_path.heap_size()
+            + self.name.heap_size()
+    }
 }
 
 impl MultiHostUrlValidator {
@@ -1,13 +1,16 @@
-use pyo3::intern;
 use pyo3::once_cell::GILOnceCell;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
+use pyo3::{intern, PyTraverseError, PyVisit};
 
-use super::{build_validator, BuildValidator, CombinedValidator, Definitions, DefinitionsBuilder, Extra, Validator};
+use super::{
+    build_validator, capped_name, BuildValidator, CombinedValidator, Definitions, DefinitionsBuilder, Extra, Validator,
+};
 use crate::build_tools::py_schema_err;
 use crate::build_tools::schema_or_config_same;
 use crate::errors::{LocItem, ValError, ValResult};
 use crate::input::Input;
+use crate::memory::{HeapSize, MemoryUsage};
 use crate::recursion_guard::RecursionGuard;
 use crate::tools::SchemaDict;
 use crate::PydanticUndefinedType;
@@ -26,6 +29,13 @@ pub enum DefaultType {
 }
 
 impl DefaultType {
+    pub fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        match self {
+            Self::Default(object) | Self::DefaultFactory(object) => visit.call(object),
+            Self::None => Ok(()),
+        }
+    }
+
     pub fn new(schema: &PyDict) -> PyResult<Self> {
         let py = schema.py();
         match (
@@ -90,7 +100,7 @@ impl BuildValidator for WithDefaultValidator {
         };
 
         let sub_schema: &PyAny = schema.get_as_req(intern!(schema.py(), "schema"))?;
-        let validator = Box::new(build_validator(sub_schema, config, definitions)?);
+        let validator = Box::new(build_validator(&["schema".into()], sub_schema, config, definitions)?);
 
         let copy_default = if let DefaultType::Default(default_obj) = &default {
             default_obj.as_ref(py).hash().is_err()
@@ -98,7 +108,7 @@ impl BuildValidator for WithDefaultValidator {
             false
         };
 
-        let name = format!("{}[{}]", Self::EXPECTED_TYPE, validator.get_name());
+        let name = format!("{}[{}]", Self::EXPECTED_TYPE, capped_name(validator.get_name()));
 
         Ok(Self {
             default,
@@ -113,6 +123,12 @@ impl BuildValidator for WithDefaultValidator {
 }
 
 impl Validator for WithDefaultValidator {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        self.default.py_gc_traverse(visit)?;
+        self.validator.py_gc_traverse(visit)?;
+        Ok(())
+    }
+
     fn validate<'s, 'data>(
         &'s self,
         py: Python<'data>,
@@ -121,7 +137,7 @@ impl Validator for WithDefaultValidator {
         definitions: &'data Definitions<CombinedValidator>,
         recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        if input.to_object(py).is(&PydanticUndefinedType::py_undefined()) {
+        if PydanticUndefinedType::is_undefined_identity(py, input.identity()) {
             Ok(self
                 .default_value(py, None::<usize>, extra, definitions, recursion_guard)?
                 .unwrap())
@@ -194,6 +210,10 @@ impl Validator for WithDefaultValidator {
     fn complete(&mut self, definitions: &DefinitionsBuilder<CombinedValidator>) -> PyResult<()> {
         self.validator.complete(definitions)
     }
+
+    fn memory_usage(&self, memory: &mut MemoryUsage) -> usize {
+        memory.visit_boxed(&["schema".into()], self.validator.as_ref()) + self.name.heap_size()
+    }
 }
 
 impl WithDefaultValidator {
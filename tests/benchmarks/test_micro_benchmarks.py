@@ -138,6 +138,37 @@ class TestModelLarge:
         benchmark(core_model_validator.validate_json, json_data)
 
 
+@pytest.mark.benchmark(group='large typed dict - python')
+def test_large_typed_dict_core_python(benchmark):
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {f'field_{i}': core_schema.typed_dict_field(core_schema.int_schema()) for i in range(100)}
+        )
+    )
+    data = {f'field_{i}': i for i in range(100)}
+    assert v.validate_python(data) == data
+
+    benchmark(v.validate_python, data)
+
+
+@pytest.mark.benchmark(group='wide model aliases - python')
+def test_wide_model_aliases_core_python(benchmark):
+    class CoreModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    fields = {
+        f'field_{i}': core_schema.model_field(core_schema.int_schema(), validation_alias=f'fieldAlias{i}')
+        for i in range(50)
+    }
+    v = SchemaValidator(core_schema.model_schema(CoreModel, core_schema.model_fields_schema(fields)))
+    # keys built at runtime so they aren't interned, like data parsed from a request
+    data = {''.join(['fieldAlias', str(i)]): i for i in range(50)}
+    m = v.validate_python(data)
+    assert m.field_0 == 0
+    assert m.field_49 == 49
+    benchmark(v.validate_python, data)
+
+
 bool_cases = [True, False, 0, 1, '0', '1', 'true', 'false', 'True', 'False']
 
 
@@ -373,6 +404,32 @@ def test_definition_model_core(definition_model_data, benchmark):
     benchmark(v.validate_python, definition_model_data)
 
 
+@pytest.mark.benchmark(group='shared refs')
+def test_shared_refs_core(benchmark):
+    address_schema = core_schema.typed_dict_schema(
+        {
+            'street': core_schema.typed_dict_field(core_schema.str_schema()),
+            'city': core_schema.typed_dict_field(core_schema.str_schema()),
+            'postcode': core_schema.typed_dict_field(core_schema.str_schema()),
+        },
+        ref='Address',
+    )
+    v = SchemaValidator(
+        core_schema.definitions_schema(
+            core_schema.typed_dict_schema(
+                {
+                    f'address_{i}': core_schema.typed_dict_field(core_schema.definition_reference_schema('Address'))
+                    for i in range(20)
+                }
+            ),
+            [address_schema],
+        )
+    )
+    data = {f'address_{i}': {'street': f'{i} Main St', 'city': 'London', 'postcode': 'N1'} for i in range(20)}
+    assert v.validate_python(data) == data
+    benchmark(v.validate_python, data)
+
+
 @skip_pydantic
 @pytest.mark.benchmark(group='List[TypedDict]')
 def test_list_of_dict_models_pyd(benchmark):
@@ -402,6 +459,19 @@ def test_list_of_dict_models_core(benchmark):
     benchmark(v.validate_python, data)
 
 
+@pytest.mark.benchmark(group='List[TypedDict] nested')
+def test_nested_list_of_dict_models_core(benchmark):
+    leaf_schema = core_schema.typed_dict_schema({'width': core_schema.typed_dict_field(core_schema.int_schema())})
+    branch_schema = core_schema.typed_dict_schema(
+        {'leaves': core_schema.typed_dict_field(core_schema.list_schema(leaf_schema))}
+    )
+    v = SchemaValidator(core_schema.list_schema(branch_schema))
+
+    data = [{'leaves': [{'width': i} for i in range(10)]} for _ in range(100)]
+    assert v.validate_python(data) == data
+    benchmark(v.validate_python, data)
+
+
 list_of_ints_data = ([i for i in range(1000)], [str(i) for i in range(1000)])
 
 
@@ -427,6 +497,55 @@ def test_list_of_ints_core_py(benchmark):
         v.validate_python(list_of_ints_data[1])
 
 
+@pytest.mark.benchmark(group='List[int] exact')
+def test_list_of_exact_ints_core_py(benchmark):
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    input_data = list(range(1_000_000))
+
+    benchmark(v.validate_python, input_data)
+
+
+@pytest.mark.benchmark(group='List[int] exact')
+def test_list_of_exact_ints_constrained_core_py(benchmark):
+    # constraints disable the exact type fast path
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema(ge=0)))
+    input_data = list(range(1_000_000))
+
+    benchmark(v.validate_python, input_data)
+
+
+@pytest.mark.benchmark(group='List[int] iterator')
+def test_list_of_ints_hinted_iterator_core_py(benchmark):
+    # list iterators have a `__length_hint__` used to presize the output
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    input_data = list(range(1_000_000))
+
+    @benchmark
+    def t():
+        v.validate_python(iter(input_data))
+
+
+@pytest.mark.benchmark(group='List[int] iterator')
+def test_list_of_ints_generator_core_py(benchmark):
+    # generators (and `map()`) have no length hint
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    input_data = list(range(1_000_000))
+
+    @benchmark
+    def t():
+        v.validate_python(x for x in input_data)
+
+
+@pytest.mark.benchmark(group='List[int] small')
+def test_many_small_lists_core_py(benchmark):
+    # many small lists, where the per-list overhead dominates
+    v = SchemaValidator(core_schema.list_schema(core_schema.list_schema(core_schema.int_schema())))
+    input_data = [[str(i) for i in range(5)] for _ in range(10_000)]
+    assert v.validate_python(input_data) == [list(range(5))] * 10_000
+
+    benchmark(v.validate_python, input_data)
+
+
 @skip_pydantic
 @pytest.mark.benchmark(group='List[int] JSON')
 def test_list_of_ints_pyd_json(benchmark):
@@ -884,6 +1003,17 @@ class TestBenchmarkUnion:
 
         benchmark(PydanticModel.parse_obj, 1)  # will be True
 
+    @pytest.mark.benchmark(group='smart-union-last-choice')
+    def test_smart_union_last_choice_core(self, benchmark):
+        choices = [
+            {'type': 'typed-dict', 'fields': {f'{c}_{i}': {'type': 'typed-dict-field', 'schema': {'type': 'int'}}}}
+            for i, c in enumerate('abcde')
+        ]
+        v = SchemaValidator({'type': 'union', 'choices': choices})
+        assert v.validate_python({'e_4': '1'}) == {'e_4': 1}
+
+        benchmark(v.validate_python, {'e_4': '1'})
+
     @pytest.mark.benchmark(group='strict-union')
     def test_strict_union_core(self, benchmark):
         v = SchemaValidator(
@@ -1185,6 +1315,37 @@ def test_isinstance_json(benchmark):
         validator.validate_json('"foo"')
 
 
+@pytest.mark.benchmark(group='isinstance-list-false')
+def test_isinstance_list_false_validate(benchmark):
+    validator = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    input_value = ['x'] * 100
+
+    @benchmark
+    def t():
+        try:
+            validator.validate_python(input_value)
+        except ValidationError:
+            pass
+
+
+@pytest.mark.benchmark(group='isinstance-list-false')
+def test_isinstance_list_false_isinstance(benchmark):
+    validator = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    input_value = ['x'] * 100
+    assert validator.isinstance_python(input_value) is False
+
+    benchmark(validator.isinstance_python, input_value)
+
+
+@pytest.mark.benchmark(group='isinstance-list-false')
+def test_isinstance_list_false_isinstance_json(benchmark):
+    validator = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    input_value = json.dumps(['x'] * 100)
+    assert validator.isinstance_json(input_value) is False
+
+    benchmark(validator.isinstance_json, input_value)
+
+
 @pytest.mark.benchmark(group='error')
 def test_int_error(benchmark):
     validator = SchemaValidator(core_schema.int_schema())
@@ -1212,6 +1373,24 @@ def test_int_error(benchmark):
             e.errors()
 
 
+@pytest.mark.benchmark(group='error')
+def test_list_of_ints_many_errors(benchmark):
+    item_schema = core_schema.typed_dict_schema({'x': core_schema.typed_dict_field(core_schema.int_schema())})
+    validator = SchemaValidator(core_schema.list_schema(item_schema))
+    input_data = [{'x': 'foobar'}] * 100_000
+    with pytest.raises(ValidationError) as exc_info:
+        validator.validate_python(input_data)
+    assert exc_info.value.error_count() == 100_000
+    assert exc_info.value.errors()[-1]['loc'] == (99_999, 'x')
+
+    @benchmark
+    def t():
+        try:
+            validator.validate_python(input_data)
+        except ValidationError:
+            pass
+
+
 @pytest.mark.benchmark(group='definition')
 def test_definition_in_tree(benchmark):
     validator = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
@@ -1325,6 +1504,7 @@ LARGE_STR_PREFIX = 'a' * 50
         (list(range(100)), 5, 5),
         ([f'abc{i}' for i in range(100)], 'abc99', 'abc99'),
         ([LARGE_STR_PREFIX + f'{i}' for i in range(100)], f'{LARGE_STR_PREFIX}99', f'{LARGE_STR_PREFIX}99'),
+        ([f'abc{i}' for i in range(500)], 'abc499', 'abc499'),
         (['null', None, -1, SomeStrEnum.baz], None, None),
     ],
     ids=[
@@ -1335,6 +1515,7 @@ LARGE_STR_PREFIX = 'a' * 50
         'many_ints',
         'many_small_strings',
         'many_large_strings',
+        'very_many_small_strings',
         'few_mixed',
     ],
 )
@@ -1450,3 +1631,52 @@ def test_tagged_union_int_keys_json(benchmark):
         v.validate_json('{"x": 1001, "y": "1"}')
 
     benchmark(v.validate_json, payload)
+
+
+@pytest.mark.benchmark(group='large JSON threaded')
+def test_large_json_threaded(benchmark):
+    from concurrent.futures import ThreadPoolExecutor
+
+    v = SchemaValidator(core_schema.list_schema(core_schema.any_schema()))
+    # parsing JSON this large releases the GIL, so other threads can run at the same time
+    payload = json.dumps([{'a': i, 'b': str(i), 'c': [i, i + 1]} for i in range(50_000)])
+    assert len(v.validate_json(payload)) == 50_000
+
+    with ThreadPoolExecutor(max_workers=4) as executor:
+
+        @benchmark
+        def t():
+            list(executor.map(v.validate_json, [payload] * 4))
+
+
+validate_many_schema = core_schema.typed_dict_schema(
+    {
+        'id': core_schema.typed_dict_field(core_schema.int_schema()),
+        'name': core_schema.typed_dict_field(core_schema.str_schema()),
+    }
+)
+validate_many_data = [{'id': i, 'name': f'name {i}'} for i in range(10_000)]
+
+
+@pytest.mark.benchmark(group='validate many')
+def test_validate_many_loop(benchmark):
+    v = SchemaValidator(validate_many_schema)
+
+    @benchmark
+    def t():
+        [v.validate_python(item) for item in validate_many_data]
+
+
+@pytest.mark.benchmark(group='validate many')
+def test_validate_many(benchmark):
+    v = SchemaValidator(validate_many_schema)
+    assert v.validate_many(validate_many_data) == validate_many_data
+
+    benchmark(v.validate_many, validate_many_data)
+
+
+@pytest.mark.benchmark(group='validate many')
+def test_validate_many_collect(benchmark):
+    v = SchemaValidator(validate_many_schema)
+
+    benchmark(v.validate_many, validate_many_data, on_error='collect')
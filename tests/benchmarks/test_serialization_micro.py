@@ -436,6 +436,38 @@ def test_model_list_core_json(benchmark):
         s.to_json(m_big)
 
 
+@pytest.fixture(scope='module', name='wide_model_list')
+def wide_model_list_fixture():
+    fields = {f'field_{i}': core_schema.model_field(core_schema.int_schema()) for i in range(20)}
+    fields['field_0'] = core_schema.model_field(core_schema.int_schema(), serialization_alias='alias_0')
+    model_schema = core_schema.model_schema(BasicModel, core_schema.model_fields_schema(fields))
+    s = SchemaSerializer(core_schema.list_schema(model_schema))
+    models = [BasicModel(**{f'field_{i}': n for i in range(20)}) for n in range(10_000)]
+    return s, models
+
+
+@pytest.mark.benchmark(group='model-list-wide')
+def test_model_list_wide_core_py(benchmark, wide_model_list):
+    s, models = wide_model_list
+    output = s.to_python(models, by_alias=True)
+    assert len(output) == 10_000
+    assert output[1] == {'alias_0': 1, **{f'field_{i}': 1 for i in range(1, 20)}}
+
+    @benchmark
+    def r():
+        s.to_python(models, by_alias=True)
+
+
+@pytest.mark.benchmark(group='model-list-wide')
+def test_model_list_wide_core_json(benchmark, wide_model_list):
+    s, models = wide_model_list
+    assert json.loads(s.to_json(models[:2])) == s.to_python(models[:2], by_alias=True)
+
+    @benchmark
+    def r():
+        s.to_json(models)
+
+
 @pytest.mark.benchmark(group='model-list-json')
 def test_datetime(benchmark):
     v = SchemaSerializer(core_schema.datetime_schema())
@@ -470,3 +502,60 @@ def test_filter(benchmark):
     @benchmark
     def t():
         v.to_python(['a', 'b', 'c', 'd', 'e'], include={-1, -2})
+
+
+@pytest.fixture(scope='module', name='large_document')
+def large_document_fixture():
+    doc = [{'id': i, 'name': f'item {i}', 'tags': ['a', 'b', 'c'], 'price': i * 1.5} for i in range(1500)]
+    serializer = SchemaSerializer(core_schema.any_schema())
+    assert 90_000 < len(serializer.to_json(doc)) < 120_000
+    return serializer, doc
+
+
+@pytest.mark.benchmark(group='to-json-100KB')
+def test_to_json_large_document_repeated(benchmark, large_document):
+    serializer, doc = large_document
+
+    @benchmark
+    def r():
+        serializer.to_json(doc)
+
+
+@pytest.mark.benchmark(group='to-json-100KB')
+def test_to_json_large_document_capacity(benchmark, large_document):
+    serializer, doc = large_document
+
+    @benchmark
+    def r():
+        serializer.to_json(doc, capacity=128 * 1024)
+
+
+@pytest.fixture(scope='module', name='raw_json_model')
+def raw_json_model_fixture():
+    serializer = SchemaSerializer(
+        core_schema.model_schema(
+            BasicModel,
+            core_schema.model_fields_schema(
+                {
+                    'id': core_schema.model_field(core_schema.int_schema()),
+                    'raw': core_schema.model_field(core_schema.any_schema()),
+                }
+            ),
+        )
+    )
+    raw = {
+        f'key_{i}': {'values': list(range(10)), 'name': f'name {i}', 'nested': {'ok': True, 'x': 1.5}}
+        for i in range(12_000)
+    }
+    m = BasicModel(id=1, raw=raw)
+    assert 900_000 < len(serializer.to_json(m)) < 1_200_000
+    return serializer, m
+
+
+@pytest.mark.benchmark(group='to-json-raw-1MB')
+def test_to_json_raw_json_field(benchmark, raw_json_model):
+    serializer, m = raw_json_model
+
+    @benchmark
+    def r():
+        serializer.to_json(m)
@@ -13,7 +13,7 @@ import hypothesis
 import pytest
 from typing_extensions import Literal
 
-from pydantic_core import ArgsKwargs, SchemaValidator, ValidationError
+from pydantic_core import ArgsKwargs, SchemaSerializer, SchemaValidator, ValidationError
 from pydantic_core.core_schema import CoreConfig
 
 __all__ = 'Err', 'PyAndJson', 'plain_repr', 'infinite_generator'
@@ -24,7 +24,7 @@ hypothesis.settings.load_profile(os.getenv('HYPOTHESIS_PROFILE', 'fast'))
 
 
 def plain_repr(obj):
-    r = repr(obj)
+    r = obj.debug_repr() if isinstance(obj, (SchemaValidator, SchemaSerializer)) else repr(obj)
     r = re.sub(r',\s*([)}])', r'\1', r)
     r = re.sub(r'\s+', '', r)
     return r
@@ -24,7 +24,9 @@ def any_serializer():
 
 
 def test_repr(any_serializer):
-    assert plain_repr(any_serializer) == 'SchemaSerializer(serializer=Any(AnySerializer),definitions=[])'
+    assert plain_repr(any_serializer) == (
+        'SchemaSerializer(title="any",schema_type="any",config=None,serializer=Any(AnySerializer),definitions=[])'
+    )
 
 
 @dataclasses.dataclass(frozen=True)
@@ -123,7 +125,9 @@ def test_any_json(any_serializer, value, expected_json):
 def test_other_type():
     """Types with no serializer, fall back to any serializer"""
     v = SchemaSerializer(core_schema.is_instance_schema(int))
-    assert plain_repr(v) == 'SchemaSerializer(serializer=Any(AnySerializer),definitions=[])'
+    assert plain_repr(v) == (
+        'SchemaSerializer(title="any",schema_type="is-instance",config=None,serializer=Any(AnySerializer),definitions=[])'  # noqa: E501
+    )
     assert v.to_json('foobar') == b'"foobar"'
 
 
@@ -202,6 +206,37 @@ def test_recursion(any_serializer):
         any_serializer.to_json(v)
 
 
+def test_recursion_deep(any_serializer):
+    v = {'a': [{'b': 1}]}
+    v['a'][0]['c'] = [[[v]]]
+    with pytest.raises(ValueError, match=r'Circular reference detected \(id repeated\)'):
+        any_serializer.to_json(v)
+
+
+def test_plain_json_nested(any_serializer):
+    class MyStr(str):
+        pass
+
+    value = {
+        'a': [1, 2.5, True, None, 'x', MyStr('sub')],
+        'b': {1: date(2022, 1, 1), None: b'bytes'},
+        'c': [[[[{'d': (1, 2)}]]]],
+    }
+    assert json.loads(any_serializer.to_json(value)) == {
+        'a': [1, 2.5, True, None, 'x', 'sub'],
+        'b': {'1': '2022-01-01', 'None': 'bytes'},
+        'c': [[[[{'d': [1, 2]}]]]],
+    }
+    assert any_serializer.to_json([float('inf'), float('nan')]) == b'[null,null]'
+
+
+def test_plain_json_very_deep(any_serializer):
+    value = 'x'
+    for _ in range(100):
+        value = {'a': [value]}
+    assert json.loads(any_serializer.to_json(value)) == value
+
+
 @pytest.mark.parametrize('seq_f', [as_list, as_tuple])
 def test_include_list_tuple(any_serializer, seq_f):
     assert any_serializer.to_python(seq_f(0, 1, 2, 3)) == seq_f(0, 1, 2, 3)
@@ -36,8 +36,8 @@ def test_format_when_used_unless_none():
     s = SchemaSerializer(
         core_schema.any_schema(serialization=core_schema.format_ser_schema('0.1f', when_used='unless-none'))
     )
-    assert 'FormatSerializer' in repr(s)
-    assert 'ToStringSerializer' not in repr(s)
+    assert 'FormatSerializer' in s.debug_repr()
+    assert 'ToStringSerializer' not in s.debug_repr()
     assert s.to_python(42.12345) == '42.1'
     assert s.to_python(42.12345, mode='json') == '42.1'
     assert s.to_json(42.12345) == b'"42.1"'
@@ -68,8 +68,8 @@ def test_to_string_when_used_unless_none():
     s = SchemaSerializer(
         core_schema.any_schema(serialization=core_schema.format_ser_schema('', when_used='unless-none'))
     )
-    assert 'ToStringSerializer' in repr(s)
-    assert 'FormatSerializer' not in repr(s)
+    assert 'ToStringSerializer' in s.debug_repr()
+    assert 'FormatSerializer' not in s.debug_repr()
     assert s.to_python(42) == '42'
     assert s.to_python(42, mode='json') == '42'
     assert s.to_json(42) == b'"42"'
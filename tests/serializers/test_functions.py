@@ -628,8 +628,7 @@ def test_recursive_call():
     # insert_assert(str(exc_info.value))
     assert str(exc_info.value) == (
         'Error serializing to JSON: '
-        'PydanticSerializationError: Error calling function `bad_recursive`: '
-        'RuntimeError: Already mutably borrowed'
+        'PydanticSerializationError: Error calling function `bad_recursive`: RecursionError'
     )
 
 
@@ -9,7 +9,7 @@ def test_chain():
     s = SchemaSerializer(core_schema.chain_schema([core_schema.str_schema(), core_schema.int_schema()]))
 
     # insert_assert(plain_repr(s))
-    assert plain_repr(s) == 'SchemaSerializer(serializer=Int(IntSerializer),definitions=[])'
+    assert plain_repr(s) == 'SchemaSerializer(title="int",schema_type="chain",config=None,serializer=Int(IntSerializer),definitions=[])'  # noqa: E501
 
     assert s.to_python(1) == 1
     assert s.to_json(1) == b'1'
@@ -19,25 +19,25 @@ def test_function_plain():
     s = SchemaSerializer(core_schema.general_plain_validator_function(lambda v, info: v + 1))
     # can't infer the type from plain function validators
     # insert_assert(plain_repr(s))
-    assert plain_repr(s) == 'SchemaSerializer(serializer=Any(AnySerializer),definitions=[])'
+    assert plain_repr(s) == 'SchemaSerializer(title="any",schema_type="function-plain",config=None,serializer=Any(AnySerializer),definitions=[])'  # noqa: E501
 
 
 def test_function_before():
     s = SchemaSerializer(core_schema.general_before_validator_function(lambda v, info: v + 1, core_schema.int_schema()))
     # insert_assert(plain_repr(s))
-    assert plain_repr(s) == 'SchemaSerializer(serializer=Int(IntSerializer),definitions=[])'
+    assert plain_repr(s) == 'SchemaSerializer(title="int",schema_type="function-before",config=None,serializer=Int(IntSerializer),definitions=[])'  # noqa: E501
 
 
 def test_function_after():
     s = SchemaSerializer(core_schema.general_after_validator_function(lambda v, info: v + 1, core_schema.int_schema()))
     # insert_assert(plain_repr(s))
-    assert plain_repr(s) == 'SchemaSerializer(serializer=Int(IntSerializer),definitions=[])'
+    assert plain_repr(s) == 'SchemaSerializer(title="int",schema_type="function-after",config=None,serializer=Int(IntSerializer),definitions=[])'  # noqa: E501
 
 
 def test_lax_or_strict():
     s = SchemaSerializer(core_schema.lax_or_strict_schema(core_schema.int_schema(), core_schema.str_schema()))
     # insert_assert(plain_repr(s))
-    assert plain_repr(s) == 'SchemaSerializer(serializer=Str(StrSerializer),definitions=[])'
+    assert plain_repr(s) == 'SchemaSerializer(title="str",schema_type="lax-or-strict",config=None,serializer=Str(StrSerializer),definitions=[])'  # noqa: E501
 
     assert s.to_json('abc') == b'"abc"'
     with pytest.warns(UserWarning, match='Expected `str` but got `int` - serialized value may not be as expected'):
@@ -0,0 +1,133 @@
+import copy
+import gc
+import pickle
+import sys
+from datetime import date, timedelta
+
+import pytest
+
+from pydantic_core import SchemaSerializer, core_schema
+
+
+class Branch:
+    name: str
+    created: date
+    sub: 'Branch | None'
+
+    def __init__(self, name, created, sub=None):
+        self.name = name
+        self.created = created
+        self.sub = sub
+
+
+def repr_name(value):
+    return f'<{value}>'
+
+
+def branch_serializer():
+    return SchemaSerializer(
+        core_schema.definitions_schema(
+            core_schema.definition_reference_schema('branch'),
+            [
+                core_schema.model_schema(
+                    Branch,
+                    core_schema.model_fields_schema(
+                        {
+                            'name': core_schema.model_field(
+                                core_schema.str_schema(
+                                    serialization=core_schema.plain_serializer_function_ser_schema(repr_name)
+                                ),
+                                serialization_alias='Name',
+                            ),
+                            'created': core_schema.model_field(core_schema.date_schema()),
+                            'sub': core_schema.model_field(
+                                core_schema.nullable_schema(core_schema.definition_reference_schema('branch'))
+                            ),
+                        }
+                    ),
+                    ref='branch',
+                )
+            ],
+        )
+    )
+
+
+def branch():
+    return Branch('a', date(2022, 1, 1), Branch('b', date(2023, 6, 30)))
+
+
+@pytest.mark.parametrize('pickle_protocol', range(1, pickle.HIGHEST_PROTOCOL + 1))
+def test_pickle(pickle_protocol: int):
+    s1 = branch_serializer()
+    s2 = pickle.loads(pickle.dumps(s1, protocol=pickle_protocol))
+    assert isinstance(s2, SchemaSerializer)
+    assert s2.to_json(branch()) == s1.to_json(branch())
+    assert s2.to_json(branch()) == (
+        b'{"Name":"<a>","created":"2022-01-01","sub":{"Name":"<b>","created":"2023-06-30","sub":null}}'
+    )
+    assert s2.to_python(branch(), mode='json') == s1.to_python(branch(), mode='json')
+
+
+def test_pickle_config():
+    s1 = SchemaSerializer(core_schema.timedelta_schema(), {'ser_json_timedelta': 'float'})
+    s2 = pickle.loads(pickle.dumps(s1))
+    assert s2.to_json(timedelta(seconds=1.5)) == b'1.5'
+
+
+def test_pickle_unpicklable_function():
+    s = SchemaSerializer(
+        core_schema.any_schema(serialization=core_schema.plain_serializer_function_ser_schema(lambda v: v))
+    )
+    with pytest.raises((pickle.PicklingError, AttributeError)):
+        pickle.dumps(s)
+
+
+def test_copy():
+    s = branch_serializer()
+    s2 = copy.copy(s)
+    assert isinstance(s2, SchemaSerializer)
+    assert s2 is not s
+    assert s2.to_json(branch()) == s.to_json(branch())
+
+
+def test_copy_references():
+    def f(value):
+        return value
+
+    schema = core_schema.definitions_schema(
+        core_schema.definition_reference_schema('f'),
+        [core_schema.any_schema(serialization=core_schema.plain_serializer_function_ser_schema(f), ref='f')],
+    )
+    s = SchemaSerializer(schema)
+    before = sys.getrefcount(f)
+    s2 = copy.copy(s)
+    # the copy must own every reference it reports to the garbage collector, including those in definitions
+    assert sys.getrefcount(f) - before >= sum(referent is f for referent in gc.get_referents(s2))
+    del s
+    gc.collect()
+    assert s2.to_python(1) == 1
+
+
+def test_deepcopy():
+    s = branch_serializer()
+    s2 = copy.deepcopy(s)
+    assert isinstance(s2, SchemaSerializer)
+    assert s2 is not s
+    assert s2.to_json(branch()) == s.to_json(branch())
+    assert s2.to_python(branch()) == s.to_python(branch())
+
+    ss = copy.deepcopy({'a': s, 'b': s})
+    assert ss['a'] is ss['b']
+
+
+def test_deepcopy_memo():
+    class SubBranch(Branch):
+        pass
+
+    s = branch_serializer()
+    # like pydantic's generics, classes in the schema can be replaced via the memo
+    s2 = copy.deepcopy(s, {id(Branch): SubBranch})
+    schema, _ = s2.__reduce__()[1]
+    assert schema['definitions'][0]['cls'] is SubBranch
+    sub_branch = SubBranch('a', date(2022, 1, 1))
+    assert s2.to_python(sub_branch) == {'Name': '<a>', 'created': date(2022, 1, 1), 'sub': None}
@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod tests {
-    use _pydantic_core::SchemaSerializer;
+    use _pydantic_core::{SchemaSerializer, SchemaValidator};
     use pyo3::prelude::*;
     use pyo3::types::PyDict;
 
@@ -58,11 +58,22 @@ a = A()
             let schema: &PyDict = locals.get_item("schema").unwrap().extract().unwrap();
             let serialized: Vec<u8> = SchemaSerializer::py_new(py, schema, None)
                 .unwrap()
-                .to_json(py, a, None, None, None, true, false, false, false, false, true, None)
+                .to_json(
+                    py, a, None, None, None, true, false, false, false, false, true, None, None,
+                )
                 .unwrap()
                 .extract(py)
                 .unwrap();
             assert_eq!(serialized, b"{\"b\":\"b\"}");
         });
     }
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn test_send_sync() {
+        // validators and serializers are shared between threads, all per-call state lives on the stack
+        assert_send_sync::<SchemaValidator>();
+        assert_send_sync::<SchemaSerializer>();
+    }
 }
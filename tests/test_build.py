@@ -1,8 +1,11 @@
+import copy
+import gc
 import pickle
+import sys
 
 import pytest
 
-from pydantic_core import SchemaError, SchemaValidator, __version__
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, __version__
 from pydantic_core import core_schema as cs
 
 
@@ -27,6 +30,149 @@ def test_build_error_deep():
         )
 
 
+
+def test_build_error_path_unknown_type():
+    schema = cs.typed_dict_schema({'address': cs.typed_dict_field(cs.list_schema({'type': 'intt'}))})
+    with pytest.raises(SchemaError) as exc_info:
+        SchemaValidator(schema)
+    assert exc_info.value.path == ('fields', 'address', 'schema', 'items_schema')
+    assert exc_info.value.kind == 'intt'
+    assert str(exc_info.value).endswith("\n  Did you mean 'int'?")
+
+
+def test_build_error_suggestions():
+    with pytest.raises(SchemaError) as exc_info:
+        SchemaValidator({'type': 'tuple'})
+    assert exc_info.value.path == ()
+    assert exc_info.value.kind == 'tuple'
+    assert str(exc_info.value).endswith("\n  Did you mean 'tuple-variable' or 'tuple-positional'?")
+
+    with pytest.raises(SchemaError) as exc_info:
+        SchemaValidator({'type': 'not-a-type'})
+    assert 'Did you mean' not in str(exc_info.value)
+
+
+def test_build_error_path_invalid_value():
+    with pytest.raises(SchemaError) as exc_info:
+        SchemaValidator(cs.union_schema([cs.int_schema(), cs.int_schema(gt='x')]))
+    assert exc_info.value.path == ('choices', 1, 'gt')
+    assert exc_info.value.kind == 'int'
+
+
+def test_build_error_path_build():
+    schema = cs.typed_dict_schema(
+        {'address': cs.typed_dict_field(cs.list_schema(cs.union_schema([cs.int_schema(), cs.str_schema(pattern='(')])))}
+    )
+    with pytest.raises(SchemaError) as exc_info:
+        SchemaValidator(schema)
+    assert exc_info.value.path == ('fields', 'address', 'schema', 'items_schema', 'choices', 1)
+    assert exc_info.value.kind == 'str'
+    assert str(exc_info.value).endswith('\n  [path=fields.address.schema.items_schema.choices.1, type=str]')
+
+
+def test_build_error_path_repeated_schema():
+    # the same schema object also appears in metadata, the path must be where it's actually built from
+    bad_schema = cs.str_schema(pattern='(')
+    schema = {'type': 'list', 'metadata': {'original': bad_schema}, 'items_schema': bad_schema}
+    with pytest.raises(SchemaError) as exc_info:
+        SchemaValidator(cs.tagged_union_schema({'a': cs.int_schema(), 'b': schema}, discriminator='kind'))
+    assert exc_info.value.path == ('choices', 'b', 'items_schema')
+    assert exc_info.value.kind == 'str'
+
+
+def test_schema_error_no_path():
+    e = SchemaError('testing')
+    assert e.path == ()
+    assert e.kind is None
+
+
+def test_copy():
+    v = SchemaValidator(cs.list_schema(cs.int_schema()), {'str_max_length': 3})
+    v2 = copy.copy(v)
+    assert isinstance(v2, SchemaValidator)
+    assert v2 is not v
+    assert v2.validate_python(['1', 2]) == [1, 2]
+    assert v2.title == v.title
+
+
+def test_copy_references():
+    def f(value):
+        return value
+
+    schema = cs.definitions_schema(
+        cs.definition_reference_schema('f'), [cs.no_info_plain_validator_function(f, ref='f')]
+    )
+    v = SchemaValidator(schema)
+    before = sys.getrefcount(f)
+    v2 = copy.copy(v)
+    # the copy must own every reference it reports to the garbage collector, including those in definitions
+    assert sys.getrefcount(f) - before >= sum(referent is f for referent in gc.get_referents(v2))
+    del v
+    gc.collect()
+    assert v2.validate_python(1) == 1
+
+
+def test_deepcopy():
+    default = [1]
+    schema = cs.typed_dict_schema(
+        {
+            'a': cs.typed_dict_field(cs.with_default_schema(cs.list_schema(cs.int_schema()), default=default)),
+            'b': cs.typed_dict_field(cs.no_info_after_validator_function(lambda x: x * 2, cs.int_schema())),
+        }
+    )
+    v = SchemaValidator(schema, {'title': 'Foo'})
+    v2 = copy.deepcopy(v)
+    assert v2 is not v
+    assert v2.title == 'Foo'
+    assert v2.validate_python({'b': '2'}) == v.validate_python({'b': '2'}) == {'a': [1], 'b': 4}
+    assert v2.validate_json('{"a": [3], "b": 1}') == {'a': [3], 'b': 2}
+
+    # the copy has its own schema, so changes to the original schema don't affect it
+    assert v2.input_schema['fields']['a']['schema']['default'] is not default
+    default.append(2)
+    assert v.validate_python({'b': 1}) == {'a': [1, 2], 'b': 2}
+    assert v2.validate_python({'b': 1}) == {'a': [1], 'b': 2}
+
+
+def test_deepcopy_model_class():
+    class MyModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    class MySubModel(MyModel):
+        pass
+
+    def double(value):
+        return value * 2
+
+    MyModel.__pydantic_validator__ = SchemaValidator(
+        cs.model_schema(
+            MyModel,
+            cs.model_fields_schema({'x': cs.model_field(cs.no_info_after_validator_function(double, cs.int_schema()))}),
+        )
+    )
+    # pydantic's generics substitute classes when copying the parent's validator
+    MySubModel.__pydantic_validator__ = copy.deepcopy(MyModel.__pydantic_validator__, {id(MyModel): MySubModel})
+
+    m = MySubModel.__pydantic_validator__.validate_python({'x': 2})
+    assert type(m) is MySubModel
+    assert m.x == 4
+    m = MyModel.__pydantic_validator__.validate_python({'x': 2})
+    assert type(m) is MyModel
+    assert m.x == 4
+
+    # functions are shared, as with `copy.deepcopy`
+    schema = MySubModel.__pydantic_validator__.input_schema
+    assert schema['schema']['fields']['x']['schema']['function']['function'] is double
+
+
+def test_deepcopy_stats():
+    v = SchemaValidator(cs.int_schema(), collect_stats=True)
+    v.validate_python(1)
+    v2 = copy.deepcopy(v)
+    assert [s['calls'] for s in v2.stats()] == [0]
+    v2.validate_python(1)
+    assert [s['calls'] for s in v.stats()] == [1]
+
 def test_schema_as_string():
     v = SchemaValidator({'type': 'bool'})
     assert v.validate_python('tRuE') is True
@@ -58,7 +204,89 @@ def test_pickle(pickle_protocol: int) -> None:
     p = pickle.dumps(v1, protocol=pickle_protocol)
     v2 = pickle.loads(p)
     assert v2.validate_python('tRuE') is True
-    assert repr(v1) == repr(v2)
+    assert v1.debug_repr() == v2.debug_repr()
+
+
+def double(value):
+    return value * 2
+
+
+@pytest.mark.parametrize('pickle_protocol', range(1, pickle.HIGHEST_PROTOCOL + 1))
+def test_pickle_config(pickle_protocol: int) -> None:
+    v1 = SchemaValidator(cs.list_schema(cs.str_schema()), {'str_to_upper': True, 'title': 'Upper'})
+    v2 = pickle.loads(pickle.dumps(v1, protocol=pickle_protocol))
+    assert v2.validate_python(['a', 'b']) == ['A', 'B']
+    assert v2.title == 'Upper'
+    assert v1.debug_repr() == v2.debug_repr()
+
+    with pytest.raises(ValidationError) as exc_info:
+        v2.validate_python([1])
+    assert exc_info.value.title == 'Upper'
+
+
+@pytest.mark.parametrize('pickle_protocol', range(1, pickle.HIGHEST_PROTOCOL + 1))
+def test_pickle_definitions_functions(pickle_protocol: int) -> None:
+    schema = cs.definitions_schema(
+        cs.definition_reference_schema('branch'),
+        [
+            cs.typed_dict_schema(
+                {
+                    'name': cs.typed_dict_field(cs.str_schema()),
+                    'size': cs.typed_dict_field(cs.no_info_after_validator_function(double, cs.int_schema())),
+                    'sub': cs.typed_dict_field(cs.nullable_schema(cs.definition_reference_schema('branch'))),
+                },
+                ref='branch',
+            )
+        ],
+    )
+    v1 = SchemaValidator(schema)
+    v2 = pickle.loads(pickle.dumps(v1, protocol=pickle_protocol))
+    input_value = {'name': 'a', 'size': 1, 'sub': {'name': 'b', 'size': '2', 'sub': None}}
+    expected = {'name': 'a', 'size': 2, 'sub': {'name': 'b', 'size': 4, 'sub': None}}
+    assert v1.validate_python(input_value) == expected
+    assert v2.validate_python(input_value) == expected
+    assert v1.debug_repr() == v2.debug_repr()
+
+
+def test_pickle_unpicklable_function() -> None:
+    v = SchemaValidator(cs.no_info_after_validator_function(lambda x: x, cs.int_schema()))
+    with pytest.raises((pickle.PicklingError, AttributeError)):
+        pickle.dumps(v)
+
+
+def test_schema_round_trip() -> None:
+    def f(value):
+        return value * 2
+
+    schema = cs.definitions_schema(
+        cs.list_schema(cs.definition_reference_schema('int-ref')),
+        [cs.no_info_after_validator_function(f, cs.int_schema(), ref='int-ref')],
+    )
+    v = SchemaValidator(schema, {'str_strip_whitespace': True})
+    assert v.input_schema == schema
+    assert v.config == {'str_strip_whitespace': True}
+    assert v.input_schema['definitions'][0]['function']['function'] is f
+
+    v2 = SchemaValidator(v.input_schema, v.config)
+    assert v2.validate_python([1, '2']) == [2, 4]
+    assert (v2.input_schema, v2.config) == (v.input_schema, v.config)
+
+
+def test_schema_copy() -> None:
+    schema = cs.list_schema(cs.int_schema())
+    v = SchemaValidator(schema)
+    dumped = v.input_schema
+    assert dumped is not schema
+    dumped['items_schema']['type'] = 'str'
+    assert v.input_schema == {'type': 'list', 'items_schema': {'type': 'int'}}
+    assert v.config is None
+
+
+def test_input_schema_not_normalized() -> None:
+    # the schema is returned as given, the config isn't merged into it and defaults aren't filled in
+    v = SchemaValidator(cs.list_schema(cs.int_schema()), {'strict': True})
+    assert v.input_schema == {'type': 'list', 'items_schema': {'type': 'int'}}
+    assert v.config == {'strict': True}
 
 
 def test_schema_definition_error():
@@ -77,7 +305,7 @@ def test_not_schema_definition_error():
         },
     }
     v = SchemaValidator(schema)
-    assert repr(v).count('TypedDictField') == 101
+    assert v.debug_repr().count('TypedDictField') == 101
 
 
 def test_no_type():
@@ -98,7 +326,7 @@ def test_function_no_mode():
 def test_try_self_schema_discriminator():
     """Trying to use self-schema when it shouldn't be used"""
     v = SchemaValidator({'type': 'tagged-union', 'choices': {'int': {'type': 'int'}}, 'discriminator': 'self-schema'})
-    assert 'discriminator: LookupKey' in repr(v)
+    assert 'discriminator: LookupKey' in v.debug_repr()
 
 
 def test_build_recursive_schema_from_defs() -> None:
@@ -4,7 +4,7 @@ import re
 import pytest
 from dirty_equals import FunctionCheck, HasAttributes, IsInstance
 
-from pydantic_core import CoreConfig, SchemaValidator, ValidationError
+from pydantic_core import CoreConfig, SchemaError, SchemaValidator, ValidationError, core_schema
 
 from .conftest import Err, plain_repr
 
@@ -137,3 +137,74 @@ def test_hide_input_in_errors(config, input_str):
 
     with pytest.raises(ValidationError, match=re.escape(f'Input should be a valid string [{input_str}]')):
         assert v.validate_python({'f': 123})
+
+
+def nested_model_schema(config, field_schema):
+    return core_schema.model_schema(
+        MyModel, core_schema.model_fields_schema({'f': core_schema.model_field(field_schema)}), config=config
+    )
+
+
+def test_config_propagation():
+    # outer model -> typed dict -> inner model, each with its own config
+    inner = nested_model_schema(
+        {'str_to_upper': True, 'str_to_lower': False}, core_schema.tuple_variable_schema(core_schema.str_schema())
+    )
+    middle = core_schema.typed_dict_schema(
+        {
+            'inner': core_schema.typed_dict_field(inner),
+            'x': core_schema.typed_dict_field(core_schema.float_schema()),
+            's': core_schema.typed_dict_field(core_schema.str_schema()),
+        },
+        config={'str_to_upper': False, 'str_to_lower': True},
+    )
+    v = SchemaValidator(nested_model_schema({'str_strip_whitespace': True, 'allow_inf_nan': False}, middle))
+
+    m = v.validate_python({'f': {'inner': {'f': [' Abc ']}, 'x': 1, 's': ' Abc '}})
+    # the middle config overrides `str_to_upper`, then the inner config overrides both case settings
+    assert m.f['inner'].f == ('ABC',)
+    assert m.f['s'] == 'abc'
+    with pytest.raises(ValidationError, match='Input should be a finite number'):
+        v.validate_python({'f': {'inner': {'f': []}, 'x': 'inf', 's': ''}})
+
+
+def test_config_propagation_top_level():
+    v = SchemaValidator(nested_model_schema({'str_to_lower': True}, core_schema.str_schema()), {'str_max_length': 3})
+    assert v.validate_python({'f': 'ABC'}).f == 'abc'
+    assert v.isinstance_python({'f': 'ABCD'}) is False
+
+
+def test_config_propagation_none():
+    inner = nested_model_schema({'config_propagation': 'none'}, core_schema.str_schema())
+    v = SchemaValidator(nested_model_schema({'str_strip_whitespace': True}, inner))
+    assert v.validate_python({'f': {'f': ' abc '}}).f.f == ' abc '
+
+    # grand-children merge with the isolated config as normal
+    inner = nested_model_schema(
+        {'config_propagation': 'none', 'str_to_upper': True},
+        nested_model_schema({'str_strip_whitespace': True}, core_schema.str_schema()),
+    )
+    v = SchemaValidator(nested_model_schema({'str_max_length': 1}, inner))
+    assert v.validate_python({'f': {'f': {'f': ' abc '}}}).f.f.f == 'ABC'
+
+
+def test_config_propagation_invalid():
+    inner = nested_model_schema({'config_propagation': 'bad'}, core_schema.str_schema())
+    with pytest.raises(SchemaError, match="Input should be 'merge' or 'none'"):
+        SchemaValidator(nested_model_schema({'str_strip_whitespace': True}, inner), {'title': 'x'})
+
+
+def test_config_propagation_per_model_keys():
+    # keys describing the model itself aren't inherited by nested models
+    inner = nested_model_schema(None, core_schema.int_schema())
+    v = SchemaValidator(
+        nested_model_schema({'extra_fields_behavior': 'forbid', 'title': 'Outer', 'str_to_upper': True}, inner)
+    )
+    m = v.validate_python({'f': {'f': 1, 'extra': 2}})
+    assert m.f.f == 1
+    with pytest.raises(ValidationError, match=r'extra\n  Extra inputs are not permitted'):
+        v.validate_python({'f': {'f': 1}, 'extra': 2})
+
+    # nor from the top level config
+    v = SchemaValidator(inner, {'extra_fields_behavior': 'forbid'})
+    assert v.validate_python({'f': 1, 'extra': 2}).f == 1
@@ -180,6 +180,11 @@ all_errors = [
     ('json_invalid', 'Invalid JSON: foobar', {'error': 'foobar'}),
     ('json_type', 'JSON input should be string, bytes or bytearray', None),
     ('recursion_loop', 'Recursion error - cyclic reference detected', None),
+    (
+        'recursion_limit_exceeded',
+        'Recursion error - input is nested more than 42 levels deep',
+        {'max_depth': 42},
+    ),
     ('model_type', 'Input should be a valid dictionary or instance of Foobar', {'class_name': 'Foobar'}),
     ('model_attributes_type', 'Input should be a valid dictionary or object to extract fields from', None),
     ('dataclass_exact_type', 'Input should be an instance of Foobar', {'class_name': 'Foobar'}),
@@ -218,9 +223,26 @@ all_errors = [
     ('mapping_type', 'Input should be a valid mapping, error: foobar', {'error': 'foobar'}),
     ('iterable_type', 'Input should be iterable', None),
     ('iteration_error', 'Error iterating over object, error: foobar', {'error': 'foobar'}),
+    (
+        'too_many_errors',
+        'Validation stopped after 42 errors, remaining items were not checked',
+        {'max_errors': 42},
+    ),
+    (
+        'too_many_errors',
+        'Validation stopped after 1 error, remaining items were not checked',
+        {'max_errors': 1, 'unchecked_items': 3},
+    ),
     ('list_type', 'Input should be a valid list', None),
     ('tuple_type', 'Input should be a valid tuple', None),
     ('set_type', 'Input should be a valid set', None),
+    ('unhashable_item', 'Set items should be hashable', None),
+    ('hash_key_error', 'Error calling hash_key on set item, error: foobar', {'error': 'foobar'}),
+    (
+        'duplicate_items',
+        'Foobar should have at least 42 unique items after validation, not 40',
+        {'field_type': 'Foobar', 'min_length': 42, 'actual_length': 40},
+    ),
     ('bool_type', 'Input should be a valid boolean', None),
     ('bool_parsing', 'Input should be a valid boolean, unable to interpret input', None),
     ('int_type', 'Input should be a valid integer', None),
@@ -260,6 +282,13 @@ all_errors = [
     ('time_delta_type', 'Input should be a valid timedelta', None),
     ('time_delta_parsing', 'Input should be a valid timedelta, foobar', {'error': 'foobar'}),
     ('frozen_set_type', 'Input should be a valid frozenset', None),
+    ('counter_type', 'Input should be a valid counter, mapping of counts or iterable', None),
+    (
+        'count_too_small',
+        'Item should occur at least 2 times, not 1',
+        {'min_count': 2, 'actual_count': 1},
+    ),
+    ('count_too_large', 'Item should occur at most 1 time, not 3', {'max_count': 1, 'actual_count': 3}),
     ('is_instance_of', 'Input should be an instance of Foo', {'class': 'Foo'}),
     ('is_subclass_of', 'Input should be a subclass of Foo', {'class': 'Foo'}),
     ('callable_type', 'Input should be callable', None),
@@ -298,6 +327,16 @@ def test_all_errors_covered():
     assert actual_types == listed_types
 
 
+def test_string_length_context():
+    # `actual_length` is optional context, only included by str schemas which set `length_unit`
+    e = PydanticKnownError('string_too_short', {'min_length': 3, 'actual_length': 1})
+    assert e.message() == 'String should have at least 3 characters'
+    assert e.context == {'min_length': 3, 'actual_length': 1}
+
+    with pytest.raises(TypeError, match="StringTooLong: 'actual_length' context value must be a usize"):
+        PydanticKnownError('string_too_long', {'max_length': 3, 'actual_length': 'x'})
+
+
 def test_error_decimal():
     e = PydanticKnownError('greater_than', {'gt': Decimal('42.1')})
     assert e.message() == 'Input should be greater than 42.1'
@@ -650,3 +689,87 @@ def test_loc_with_dots():
         "[type=int_parsing, input_value='x', input_type=str]\n"
         f'    For further information visit https://errors.pydantic.dev/{__version__}/v/int_parsing'
     )
+
+
+def test_input_type_python():
+    v = SchemaValidator(core_schema.set_schema(core_schema.int_schema()))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([1, 'x', 2.5, None])
+    assert [(e['loc'], e['input_type']) for e in exc_info.value.errors(include_input_type=True)] == [
+        ((1,), {'source': 'python', 'type': 'str'}),
+        ((2,), {'source': 'python', 'type': 'float'}),
+        ((3,), {'source': 'python', 'type': 'NoneType'}),
+    ]
+    # not included by default
+    assert all('input_type' not in e for e in exc_info.value.errors())
+
+
+def test_input_type_json():
+    v = SchemaValidator(core_schema.set_schema(core_schema.int_schema()))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('[1, "x", 2.5, null]')
+    assert [(e['loc'], e['input_type']) for e in exc_info.value.errors(include_input_type=True)] == [
+        ((1,), {'source': 'json', 'type': 'str'}),
+        ((2,), {'source': 'json', 'type': 'float'}),
+        ((3,), {'source': 'json', 'type': 'NoneType'}),
+    ]
+    assert exc_info.value.json(include_url=False, include_input_type=True) == IsJson(
+        [
+            {
+                'type': 'int_parsing',
+                'loc': [1],
+                'msg': 'Input should be a valid integer, unable to parse string as an integer',
+                'input': 'x',
+                'input_type': {'source': 'json', 'type': 'str'},
+            },
+            {
+                'type': 'int_from_float',
+                'loc': [2],
+                'msg': 'Input should be a valid integer, got a number with a fractional part',
+                'input': 2.5,
+                'input_type': {'source': 'json', 'type': 'float'},
+            },
+            {
+                'type': 'int_type',
+                'loc': [3],
+                'msg': 'Input should be a valid integer',
+                'input': None,
+                'input_type': {'source': 'json', 'type': 'NoneType'},
+            },
+        ]
+    )
+
+
+@pytest.mark.parametrize('source', ['python', 'json'])
+def test_input_type_nested(source):
+    v = SchemaValidator(
+        core_schema.dict_schema(core_schema.int_schema(), core_schema.list_schema(core_schema.int_schema()))
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        if source == 'python':
+            v.validate_python({'a': [1], '2': [3, 'x']})
+        else:
+            v.validate_json('{"a": [1], "2": [3, "x"]}')
+    assert [(e['loc'], e['input_type']) for e in exc_info.value.errors(include_input_type=True)] == [
+        (('a', '[key]'), {'source': source, 'type': 'str'}),
+        (('2', 1), {'source': source, 'type': 'str'}),
+    ]
+
+
+def test_input_type_wrap():
+    def wrap(input_value, handler):
+        return handler(input_value)
+
+    # wrap functions are called with the python version of JSON input, so the handler validates python input
+    v = SchemaValidator(
+        core_schema.no_info_wrap_validator_function(wrap, core_schema.list_schema(core_schema.int_schema()))
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('[1, "x"]')
+    assert [e['input_type'] for e in exc_info.value.errors(include_input_type=True)] == [
+        {'source': 'python', 'type': 'str'}
+    ]
+
+    # the source of errors created from python isn't known
+    error = ValidationError.from_exception_data('Foobar', [{'type': 'int_type', 'input': 'x'}])
+    assert error.errors(include_input_type=True)[0]['input_type'] == {'source': None, 'type': 'str'}
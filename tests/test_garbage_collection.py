@@ -80,3 +80,139 @@ def test_gc_schema_validator() -> None:
     gc.collect(2)
 
     assert len(cache) == 0
+
+
+def build_validator(cls):
+    def check(value):
+        # closes over the class, making a cycle through the class's validator
+        assert cls is not None
+        return value
+
+    return SchemaValidator(
+        core_schema.model_schema(
+            cls,
+            core_schema.model_fields_schema(
+                {
+                    'a': core_schema.model_field(
+                        core_schema.no_info_after_validator_function(check, core_schema.int_schema())
+                    ),
+                    'b': core_schema.model_field(
+                        core_schema.with_default_schema(core_schema.any_schema(), default_factory=lambda: cls)
+                    ),
+                    'c': core_schema.model_field(
+                        core_schema.list_schema(core_schema.is_instance_schema(cls)),
+                    ),
+                }
+            ),
+        )
+    )
+
+
+def build_serializer(cls):
+    return SchemaSerializer(
+        core_schema.model_schema(
+            cls,
+            core_schema.model_fields_schema(
+                {
+                    'a': core_schema.model_field(
+                        core_schema.int_schema(
+                            serialization=core_schema.plain_serializer_function_ser_schema(lambda v: (cls, v))
+                        )
+                    ),
+                    'b': core_schema.model_field(
+                        core_schema.with_default_schema(core_schema.any_schema(), default_factory=lambda: cls)
+                    ),
+                },
+                computed_fields=[
+                    core_schema.computed_field(
+                        'c',
+                        core_schema.any_schema(
+                            serialization=core_schema.wrap_serializer_function_ser_schema(lambda v, h: (cls, h(v)))
+                        ),
+                    )
+                ],
+            ),
+        )
+    )
+
+
+@pytest.mark.xfail(
+    condition=platform.python_implementation() == 'PyPy', reason='https://foss.heptapod.net/pypy/pypy/-/issues/3899'
+)
+@pytest.mark.parametrize('attr,build', [('__validator__', build_validator), ('__serializer__', build_serializer)])
+def test_gc_function_cycles(attr, build) -> None:
+    cache: 'WeakValueDictionary[int, Any]' = WeakValueDictionary()
+
+    def make_models(count):
+        for _ in range(count):
+
+            class MyModel:
+                pass
+
+            setattr(MyModel, attr, build(MyModel))
+            cache[id(MyModel)] = MyModel
+            del MyModel
+        gc.collect()
+
+    make_models(100)
+    assert len(cache) == 0
+    before = len(gc.get_objects())
+    make_models(1_000)
+    assert len(cache) == 0
+    assert len(gc.get_objects()) <= before + 10
+
+
+@pytest.mark.xfail(
+    condition=platform.python_implementation() == 'PyPy', reason='https://foss.heptapod.net/pypy/pypy/-/issues/3899'
+)
+@pytest.mark.parametrize('schema_cls', [SchemaValidator, SchemaSerializer])
+def test_gc_shared_definition(schema_cls) -> None:
+    cache: 'WeakValueDictionary[int, Any]' = WeakValueDictionary()
+
+    for _ in range(100):
+
+        class MyModel:
+            pass
+
+        # the model isn't recursive, so its definition is shared with both references to it
+        MyModel.__schema__ = schema_cls(
+            core_schema.definitions_schema(
+                core_schema.tuple_positional_schema([core_schema.definition_reference_schema('model')] * 2),
+                [
+                    core_schema.model_schema(
+                        MyModel,
+                        core_schema.model_fields_schema({'a': core_schema.model_field(core_schema.int_schema())}),
+                        ref='model',
+                    )
+                ],
+            )
+        )
+        cache[id(MyModel)] = MyModel
+        del MyModel
+
+    gc.collect()
+    assert len(cache) == 0
+
+
+@pytest.mark.xfail(
+    condition=platform.python_implementation() == 'PyPy', reason='https://foss.heptapod.net/pypy/pypy/-/issues/3899'
+)
+@pytest.mark.parametrize('schema_func', [core_schema.list_schema, core_schema.set_schema])
+def test_gc_iterated_validation_errors(schema_func) -> None:
+    cache: 'WeakValueDictionary[int, Any]' = WeakValueDictionary()
+    v = SchemaValidator(schema_func(core_schema.int_schema()))
+
+    for _ in range(10):
+
+        class Owner:
+            pass
+
+        owner = Owner()
+        # the invalid item is kept as the input of its error, and references the iterated validation
+        owner.iterated = v.validate_iterated()
+        owner.iterated.feed([owner])
+        cache[id(owner)] = owner
+        del owner
+
+    gc.collect()
+    assert len(cache) == 0
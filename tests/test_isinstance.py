@@ -1,6 +1,6 @@
 import pytest
 
-from pydantic_core import PydanticOmit, SchemaError, SchemaValidator, ValidationError, core_schema
+from pydantic_core import ArgsKwargs, PydanticOmit, SchemaError, SchemaValidator, ValidationError, core_schema
 
 from .conftest import PyAndJson
 
@@ -65,3 +65,121 @@ def test_omit(py_and_json: PyAndJson):
         assert v.isinstance_test('error') is False
     with pytest.raises(SchemaError, match='Uncaught Omit error, please check your usage of `default` validators.'):
         v.validate_test('omit')
+
+
+def test_isinstance_python():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    assert v.isinstance_python([1, '2']) is True
+    assert v.isinstance_python([1, 'x']) is False
+    assert v.isinstance_python('x') is False
+    assert v.isinstance_python(['1'], strict=True) is False
+
+
+def test_isinstance_json():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    assert v.isinstance_json('[1, 2]') is True
+    assert v.isinstance_json(b'[1, "x"]') is False
+    assert v.isinstance_json('[1, "2"]', strict=True) is False
+    assert v.isinstance_json('[1, 2') is False
+
+
+def test_isinstance_json_context():
+    def f(input_value, info):
+        return input_value < info.context['limit']
+
+    v = SchemaValidator(
+        core_schema.chain_schema(
+            [
+                core_schema.int_schema(),
+                core_schema.general_plain_validator_function(f),
+                core_schema.literal_schema([True]),
+            ]
+        )
+    )
+    assert v.isinstance_json('1', context={'limit': 2}) is True
+    assert v.isinstance_json('3', context={'limit': 2}) is False
+
+
+def test_isinstance_fail_fast_skips_calls():
+    calls = []
+
+    def f(input_value):
+        calls.append(input_value)
+        return input_value
+
+    schema = core_schema.typed_dict_schema(
+        {
+            'a': core_schema.typed_dict_field(core_schema.int_schema()),
+            'b': core_schema.typed_dict_field(
+                core_schema.list_schema(core_schema.no_info_after_validator_function(f, core_schema.int_schema()))
+            ),
+        }
+    )
+    v = SchemaValidator(schema)
+    assert v.isinstance_python({'a': 'x', 'b': [1, 2]}) is False
+    assert calls == []
+
+    assert v.isinstance_python({'a': 1, 'b': ['x', 2, 3]}) is False
+    assert calls == []
+
+    assert v.isinstance_python({'a': 1, 'b': [1, 2]}) is True
+    assert calls == [1, 2]
+
+
+def check(input_value):
+    if input_value == 'x':
+        raise ValueError('invalid')
+    elif input_value == 'boom':
+        raise RuntimeError('internal')
+    return input_value
+
+
+check_schema = core_schema.no_info_plain_validator_function(check)
+
+
+@pytest.mark.parametrize(
+    'schema,input_value',
+    [
+        (core_schema.list_schema(check_schema), ['x', 'boom']),
+        (core_schema.tuple_positional_schema([check_schema, check_schema]), ('x', 'boom')),
+        (core_schema.tuple_variable_schema(check_schema), ('x', 'boom')),
+        (core_schema.set_schema(check_schema), ['x', 'boom']),
+        (core_schema.dict_schema(check_schema, check_schema), {'x': 1, 2: 'boom'}),
+        (
+            core_schema.typed_dict_schema(
+                {'a': core_schema.typed_dict_field(check_schema), 'b': core_schema.typed_dict_field(check_schema)}
+            ),
+            {'a': 'x', 'b': 'boom'},
+        ),
+        (
+            core_schema.model_fields_schema(
+                {'a': core_schema.model_field(check_schema), 'b': core_schema.model_field(check_schema)}
+            ),
+            {'a': 'x', 'b': 'boom'},
+        ),
+        (
+            core_schema.arguments_schema(
+                [core_schema.arguments_parameter('a', check_schema), core_schema.arguments_parameter('b', check_schema)]
+            ),
+            ArgsKwargs(('x', 'boom')),
+        ),
+    ],
+    ids=['list', 'tuple-positional', 'tuple-variable', 'set', 'dict', 'typed-dict', 'model-fields', 'arguments'],
+)
+def test_isinstance_fail_fast(schema, input_value):
+    v = SchemaValidator(schema)
+    # validation stops at the first error, so later items are never validated
+    assert v.isinstance_python(input_value) is False
+    with pytest.raises(RuntimeError, match='internal'):
+        v.validate_python(input_value)
+
+
+def test_isinstance_internal_error():
+    def f(input_value):
+        raise RuntimeError('internal')
+
+    v = SchemaValidator(core_schema.no_info_plain_validator_function(f))
+    with pytest.raises(RuntimeError, match='internal'):
+        v.isinstance_python(1)
+    with pytest.raises(RuntimeError, match='internal'):
+        v.isinstance_json('1')
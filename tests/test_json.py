@@ -194,6 +194,96 @@ def test_json_invalid():
     ]
 
 
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (r'"\ud83d\ude00"', '\U0001f600'),
+        (r'"\uD83D\uDE00"', '\U0001f600'),
+        (r'"a\u0000b"', 'a\x00b'),
+        (r'"\\ud83d"', r'\ud83d'),
+        (r'"\ud83d"', Err(r'lone surrogate \ud83d at line 1 column 2 (offset 1) [type=json_invalid')),
+        (r'"abc\ude00"', Err(r'lone surrogate \ude00 at line 1 column 5 (offset 4) [type=json_invalid')),
+        (r'"\ud83d\ud83d\ude00"', Err(r'lone surrogate \ud83d at line 1 column 2 (offset 1) [type=json_invalid')),
+        ('{"a":\n"\\ud83dx"}', Err(r'lone surrogate \ud83d at line 2 column 2 (offset 7) [type=json_invalid')),
+        # the earlier error is reported
+        (r'[1,,"\ud83d"]', Err('expected value at line 1 column 4 [type=json_invalid')),
+    ],
+)
+def test_json_surrogates(input_value, expected):
+    v = SchemaValidator(core_schema.any_schema())
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_json(input_value)
+    else:
+        assert v.validate_json(input_value) == expected
+        assert v.validate_json(input_value.encode()) == expected
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (r'"\ud83d"', '\ufffd'),
+        (r'"a\ude00b"', 'a\ufffdb'),
+        (r'"\ud83d\ud83d\ude00"', '\ufffd\U0001f600'),
+        (r'{"\ud83d": [1, "\udbff"]}', {'\ufffd': [1, '\ufffd']}),
+        (r'"\ud83d\ude00"', '\U0001f600'),
+        (r'"\\ud83d"', r'\ud83d'),
+    ],
+)
+def test_json_lenient_surrogates(input_value, expected):
+    v = SchemaValidator(core_schema.any_schema(), {'lenient_surrogates': True})
+    assert v.validate_json(input_value) == expected
+    # JSON strings inside JSON use the same policy
+    v = SchemaValidator(core_schema.json_schema(), {'lenient_surrogates': True})
+    assert v.validate_json(json.dumps(input_value)) == expected
+
+
+def test_json_surrogates_round_trip():
+    v = SchemaValidator(core_schema.any_schema(), {'lenient_surrogates': True})
+    value = v.validate_json(r'["\ud83d", "\ud83d\ude00", "a\u0000b", "\"\\"]')
+    assert value == ['\ufffd', '\U0001f600', 'a\x00b', '"\\']
+    # only quotes, backslashes and control characters are escaped
+    assert to_json(value) == '["\ufffd","\U0001f600","a\\u0000b","\\"\\\\"]'.encode()
+    assert v.validate_json(to_json(value)) == value
+    # other errors are unchanged
+    with pytest.raises(ValidationError, match=re.escape('trailing comma at line 1 column 11 [type=json_invalid')):
+        v.validate_json(r'["\ud83d",]')
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (b'\xef\xbb\xbf{"a": 1}', {'a': 1}),
+        (b'\xef\xbb\xbf \n [1, 2]', [1, 2]),
+        (b'\xef\xbb\xbf', Err('EOF while parsing a value at line 1 column 0 [type=json_invalid')),
+        (b'\xef\xbb\xbf\xef\xbb\xbf1', Err('expected value at line 1 column 1 [type=json_invalid')),
+        ('"a"'.encode('utf-16'), Err('input starts with a UTF-16LE byte order mark, JSON bytes must be UTF-8 encoded')),
+        (b'\xfe\xff\x00"\x00a\x00"', Err('input starts with a UTF-16BE byte order mark')),
+        ('"a"'.encode('utf-32'), Err('input starts with a UTF-32LE byte order mark')),
+        (b'\x00\x00\xfe\xff\x00\x00\x00"', Err('input starts with a UTF-32BE byte order mark')),
+        (b'"ab\xffc"', Err('invalid UTF-8 sequence at byte offset 3 [type=json_invalid')),
+        (b'\xef\xbb\xbf"ab\xffc"', Err('invalid UTF-8 sequence at byte offset 6 [type=json_invalid')),
+        (b'"ab\xe2\x82', Err('incomplete UTF-8 sequence at end of input, byte offset 3 [type=json_invalid')),
+        (b'"ab\xe2\x82\xac"', 'ab\u20ac'),
+    ],
+)
+def test_json_bytes_encoding(input_value, expected):
+    v = SchemaValidator(core_schema.any_schema())
+    for value in (input_value, bytearray(input_value)):
+        if isinstance(expected, Err):
+            with pytest.raises(ValidationError, match=re.escape(expected.message)):
+                v.validate_json(value)
+        else:
+            assert v.validate_json(value) == expected
+
+
+def test_json_str_bom():
+    v = SchemaValidator(core_schema.any_schema())
+    # BOMs are only stripped from bytes input
+    with pytest.raises(ValidationError, match=re.escape('expected value at line 1 column 1 [type=json_invalid')):
+        v.validate_json('\ufeff[1]')
+
+
 class Foobar:
     def __str__(self):
         return 'Foobar.__str__'
@@ -222,6 +312,34 @@ def test_to_json_fallback():
     assert to_json(Foobar(), fallback=fallback_func) == b'"fallback:Foobar"'
 
 
+def test_to_json_capacity():
+    assert to_json([1, 2], capacity=0) == b'[1,2]'
+    assert to_json([1, 2], capacity=1_000_000) == b'[1,2]'
+    # the capacity is only a hint, it's capped rather than allocated
+    assert to_json([1], capacity=2**62) == b'[1]'
+    s = SchemaSerializer(core_schema.list_schema(core_schema.int_schema()))
+    assert s.to_json([1, 2], capacity=0) == b'[1,2]'
+    assert s.to_json([1, 2], capacity=2**62) == b'[1,2]'
+    long_list = list(range(10_000))
+    assert s.to_json(long_list, capacity=100) == json.dumps(long_list, separators=(',', ':')).encode()
+
+
+def test_to_json_buffer_reuse():
+    s = SchemaSerializer(core_schema.list_schema(core_schema.str_schema()))
+    big = s.to_json(['x' * 1000] * 100)
+    small = s.to_json(['a'])
+    # each call returns new bytes, reusing the buffer mustn't leak output from previous calls
+    assert small == b'["a"]'
+    assert big == json.dumps(['x' * 1000] * 100, separators=(',', ':')).encode()
+
+
+def test_to_json_nested():
+    def fallback(value):
+        return to_json([value.__class__.__name__, to_json([1, 2]).decode()]).decode()
+
+    assert to_json([Foobar(), 3], fallback=fallback) == b'["[\\"Foobar\\",\\"[1,2]\\"]",3]'
+
+
 def test_to_jsonable_python():
     assert to_jsonable_python([1, 2]) == [1, 2]
     assert to_jsonable_python({1, 2}) == IsList(1, 2, check_order=False)
@@ -0,0 +1,114 @@
+import sys
+
+from pydantic_core import SchemaSerializer, SchemaValidator, core_schema
+
+
+def fields_schema(count):
+    return core_schema.typed_dict_schema(
+        {f'field_{i}': core_schema.typed_dict_field(core_schema.str_schema()) for i in range(count)}
+    )
+
+
+def test_sizeof():
+    small = SchemaValidator(fields_schema(1))
+    large = SchemaValidator(fields_schema(100))
+    assert sys.getsizeof(small) > 0
+    assert sys.getsizeof(large) > sys.getsizeof(small) + 100 * len('field_00')
+
+
+def test_breakdown():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(core_schema.list_schema(core_schema.str_schema())),
+            }
+        )
+    )
+    breakdown = v.memory_breakdown()
+    assert [(node['path'], node['name']) for node in breakdown] == [
+        ((), 'typed-dict'),
+        (('fields', 'a', 'schema'), 'int'),
+        (('fields', 'b', 'schema'), 'list[str]'),
+        (('fields', 'b', 'schema', 'items_schema'), 'str'),
+    ]
+    assert all(node['bytes'] >= 0 for node in breakdown)
+    assert breakdown[0]['bytes'] > 0
+    assert v.__sizeof__() > sum(node['bytes'] for node in breakdown)
+
+
+def test_large_literal():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(core_schema.literal_schema([f'value_{i}' for i in range(10_000)])),
+            }
+        )
+    )
+    breakdown = v.memory_breakdown()
+    largest = max(breakdown, key=lambda node: node['bytes'])
+    assert largest['path'] == ('fields', 'b', 'schema')
+    assert largest['bytes'] > 10_000 * len('value_0000')
+    assert largest['bytes'] > sum(node['bytes'] for node in breakdown) / 2
+
+
+def test_pattern():
+    v = SchemaValidator(core_schema.str_schema(pattern=r'^[a-z]{1,200}$'))
+    assert v.memory_breakdown()[0]['bytes'] > SchemaValidator(core_schema.str_schema()).memory_breakdown()[0]['bytes']
+
+
+def test_shared_pattern():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.str_schema(pattern='^[a-z]+$')),
+                'b': core_schema.typed_dict_field(core_schema.str_schema(pattern='^[a-z]+$')),
+            }
+        )
+    )
+    # both fields use the same compiled regex, so it's only counted against the first
+    _, a, b = v.memory_breakdown()
+    assert a['path'] == ('fields', 'a', 'schema')
+    assert a['bytes'] > b['bytes']
+    assert b['bytes'] == SchemaValidator(core_schema.str_schema(min_length=1)).memory_breakdown()[0]['bytes']
+
+
+def test_definitions():
+    v = SchemaValidator(
+        core_schema.definitions_schema(
+            core_schema.list_schema(core_schema.definition_reference_schema('node')),
+            [
+                core_schema.typed_dict_schema(
+                    {
+                        'children': core_schema.typed_dict_field(
+                            core_schema.list_schema(core_schema.definition_reference_schema('node'))
+                        )
+                    },
+                    ref='node',
+                )
+            ],
+        )
+    )
+    # each definition is listed once, however many times it's referenced
+    assert [(node['path'], node['name']) for node in v.memory_breakdown()] == [
+        ((), 'list[typed-dict]'),
+        (('items_schema',), 'typed-dict'),
+        (('definitions', 0), 'typed-dict'),
+        (('definitions', 0, 'fields', 'children', 'schema'), 'list[typed-dict]'),
+        (('definitions', 0, 'fields', 'children', 'schema', 'items_schema'), 'typed-dict'),
+    ]
+
+
+def test_serializer():
+    small = SchemaSerializer(fields_schema(1))
+    large = SchemaSerializer(fields_schema(100))
+    assert sys.getsizeof(large) > sys.getsizeof(small) + 100 * len('field_00')
+
+    s = SchemaSerializer(core_schema.dict_schema(core_schema.str_schema(), core_schema.list_schema()))
+    assert [(node['path'], node['name']) for node in s.memory_breakdown()] == [
+        ((), 'dict[str, list[any]]'),
+        (('keys_schema',), 'str'),
+        (('values_schema',), 'list[any]'),
+        (('values_schema', 'items_schema'), 'any'),
+    ]
@@ -5,8 +5,15 @@ import re
 import pytest
 from typing_extensions import get_args
 
-from pydantic_core import CoreSchema, CoreSchemaType, PydanticUndefined
-from pydantic_core._pydantic_core import SchemaError, SchemaValidator, ValidationError, __version__, build_profile
+from pydantic_core import CoreSchema, CoreSchemaType, PydanticUndefined, core_schema
+from pydantic_core._pydantic_core import (
+    SchemaError,
+    SchemaSerializer,
+    SchemaValidator,
+    ValidationError,
+    __version__,
+    build_profile,
+)
 
 
 @pytest.mark.parametrize('obj', [ValidationError, SchemaValidator, SchemaError])
@@ -97,6 +104,48 @@ def test_custom_title():
     assert exc_info.value.title == 'MyInt'
 
 
+def test_nested_title_capped():
+    schema = core_schema.int_schema()
+    for _ in range(6):
+        schema = core_schema.list_schema(schema)
+    v = SchemaValidator(schema)
+    assert v.title == 'list[list[list[list[...]]]]'
+
+    v = SchemaValidator(core_schema.list_schema(core_schema.list_schema(core_schema.int_schema())))
+    assert v.title == 'list[list[int]]'
+
+
+def test_repr_summary():
+    v = SchemaValidator(core_schema.int_schema(), {'title': 'MyInt'})
+    assert repr(v) == 'SchemaValidator(title="MyInt", schema_type="int", config={\'title\': \'MyInt\'})'
+    s = SchemaSerializer(core_schema.int_schema())
+    assert repr(s) == 'SchemaSerializer(title="int", schema_type="int", config=None)'
+
+
+def test_debug_repr():
+    # the built validator and serializer are only shown by `debug_repr`
+    v = SchemaValidator(core_schema.int_schema(), {'title': 'MyInt'})
+    assert v.debug_repr().startswith(
+        'SchemaValidator(title="MyInt", schema_type="int", config={\'title\': \'MyInt\'}, validator=Int('
+    )
+    assert v.debug_repr().endswith(', definitions=[])')
+    s = SchemaSerializer(core_schema.int_schema())
+    assert s.debug_repr().startswith('SchemaSerializer(title="int", schema_type="int", config=None, serializer=Int(')
+
+
+def test_repr_non_str_title():
+    # the config title isn't validated, it's still shown rather than panicking
+    v = SchemaValidator(core_schema.int_schema(), {'title': 123})
+    assert repr(v) == 'SchemaValidator(title=123, schema_type="int", config={\'title\': 123})'
+    s = SchemaSerializer(core_schema.int_schema(), {'title': ['x']})
+    assert repr(s) == "SchemaSerializer(title=['x'], schema_type=\"int\", config={'title': ['x']})"
+
+
+def test_serializer_title():
+    assert SchemaSerializer(core_schema.list_schema(core_schema.int_schema())).title == 'list[int]'
+    assert SchemaSerializer(core_schema.int_schema(), {'title': 'MyInt'}).title == 'MyInt'
+
+
 def test_validation_error_multiple():
     class MyModel:
         # this is not required, but it avoids `__pydantic_fields_set__` being included in `__dict__`
@@ -85,6 +85,11 @@ all_schema_functions = [
         args({'type': 'int'}, max_length=5),
         {'type': 'frozenset', 'items_schema': {'type': 'int'}, 'max_length': 5},
     ),
+    (
+        core_schema.counter_schema,
+        args({'type': 'str'}, max_count=3),
+        {'type': 'counter', 'items_schema': {'type': 'str'}, 'max_count': 3},
+    ),
     (core_schema.generator_schema, args({'type': 'int'}), {'type': 'generator', 'items_schema': {'type': 'int'}}),
     (core_schema.dict_schema, args(), {'type': 'dict'}),
     (
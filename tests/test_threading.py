@@ -0,0 +1,242 @@
+import json
+import sys
+import threading
+import time
+from concurrent.futures import ThreadPoolExecutor
+from datetime import datetime, timedelta, timezone
+
+import pytest
+
+from pydantic_core import SchemaSerializer, SchemaValidator, ValidationError, core_schema, register_validator
+
+pytestmark = pytest.mark.skipif(sys.platform == 'emscripten', reason='threads are not supported on emscripten')
+
+THREADS = 16
+
+
+def gil_enabled():
+    return getattr(sys, '_is_gil_enabled', lambda: True)()
+
+
+@pytest.fixture(autouse=True)
+def switch_often():
+    # switch between threads as often as possible to shake out any shared state
+    interval = sys.getswitchinterval()
+    sys.setswitchinterval(1e-6)
+    yield
+    sys.setswitchinterval(interval)
+
+
+def run_threads(func):
+    barrier = threading.Barrier(THREADS)
+
+    def run(index):
+        barrier.wait()
+        return func(index)
+
+    with ThreadPoolExecutor(max_workers=THREADS) as executor:
+        return list(executor.map(run, range(THREADS)))
+
+
+def double(input_value, info):
+    # make sure each call sees its own context
+    assert info.context['thread'] == threading.get_ident()
+    return input_value * 2
+
+
+def wrap(input_value, handler):
+    return handler(input_value)
+
+
+tree_schema = core_schema.definitions_schema(
+    core_schema.definition_reference_schema('node'),
+    [
+        core_schema.typed_dict_schema(
+            {
+                'value': core_schema.typed_dict_field(
+                    core_schema.general_after_validator_function(double, core_schema.int_schema())
+                ),
+                'children': core_schema.typed_dict_field(
+                    core_schema.list_schema(
+                        core_schema.no_info_wrap_validator_function(
+                            wrap, core_schema.definition_reference_schema('node')
+                        )
+                    )
+                ),
+                'when': core_schema.typed_dict_field(core_schema.nullable_schema(core_schema.datetime_schema())),
+            },
+            ref='node',
+        )
+    ],
+)
+
+
+def make_tree(depth, index):
+    return {
+        'value': index,
+        'children': [make_tree(depth - 1, index) for _ in range(2)] if depth else [],
+        'when': f'2032-01-01T00:00:00+{index:02d}:00',
+    }
+
+
+def check_tree(tree, depth, index):
+    assert tree['value'] == index * 2
+    assert tree['when'] == datetime(2032, 1, 1, tzinfo=timezone(timedelta(hours=index)))
+    assert len(tree['children']) == (2 if depth else 0)
+    for child in tree['children']:
+        check_tree(child, depth - 1, index)
+
+
+def test_recursive_functions():
+    v = SchemaValidator(tree_schema)
+
+    def validate(index):
+        results = []
+        for _ in range(10):
+            context = {'thread': threading.get_ident()}
+            results.append(v.validate_python(make_tree(4, index), context=context))
+            results.append(v.validate_json(json.dumps(make_tree(4, index)), context=context))
+        return results
+
+    for index, results in enumerate(run_threads(validate)):
+        for tree in results:
+            check_tree(tree, 4, index)
+
+
+def test_errors():
+    v = SchemaValidator(tree_schema)
+
+    def validate(index):
+        tree = make_tree(3, index)
+        tree['children'][index % 2]['value'] = 'x'
+        with pytest.raises(ValidationError) as exc_info:
+            v.validate_python(tree, context={'thread': threading.get_ident()})
+        return exc_info.value.errors()
+
+    for index, errors in enumerate(run_threads(validate)):
+        assert [(e['type'], e['loc']) for e in errors] == [('int_parsing', ('children', index % 2, 'value'))]
+
+
+def test_cyclic_input():
+    v = SchemaValidator(tree_schema)
+
+    def validate(index):
+        tree = make_tree(1, index)
+        tree['children'][0]['children'].append(tree)
+        with pytest.raises(ValidationError) as exc_info:
+            v.validate_python(tree, context={'thread': threading.get_ident()})
+        return exc_info.value.errors()
+
+    # the recursion guard is per-call, so every thread detects its own cycle
+    for errors in run_threads(validate):
+        assert [e['type'] for e in errors] == ['recursion_loop']
+
+
+def test_refcounts():
+    v = SchemaValidator(core_schema.list_schema(core_schema.no_info_plain_validator_function(lambda x: x)))
+    s = SchemaSerializer(core_schema.list_schema(core_schema.any_schema()))
+    item = object()
+    before = sys.getrefcount(item)
+
+    def validate(index):
+        for _ in range(100):
+            assert s.to_python(v.validate_python([item] * 10)) == [item] * 10
+
+    run_threads(validate)
+    assert sys.getrefcount(item) == before
+
+
+def test_large_json():
+    # large JSON inputs are parsed without holding the GIL
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    payload = json.dumps(list(range(50_000)))
+
+    def validate(index):
+        return v.validate_json(payload)
+
+    assert all(r == list(range(50_000)) for r in run_threads(validate))
+
+
+def to_upper(value, info):
+    return value.upper()
+
+
+serializer_schema = core_schema.typed_dict_schema(
+    {
+        'name': core_schema.typed_dict_field(
+            core_schema.str_schema(
+                serialization=core_schema.plain_serializer_function_ser_schema(to_upper, info_arg=True)
+            )
+        ),
+        'when': core_schema.typed_dict_field(core_schema.datetime_schema()),
+        'tags': core_schema.typed_dict_field(core_schema.list_schema(core_schema.any_schema())),
+    }
+)
+
+
+def test_serializer():
+    s = SchemaSerializer(serializer_schema)
+
+    def serialize(index):
+        value = {'name': f'thread-{index}', 'when': datetime(2032, 1, 1, tzinfo=timezone.utc), 'tags': [index] * 10}
+        results = []
+        for _ in range(50):
+            results.append(s.to_python(value, mode='json'))
+            # `to_json` reuses a buffer between calls, it must be per-thread
+            results.append(json.loads(s.to_json(value)))
+        return results
+
+    for index, results in enumerate(run_threads(serialize)):
+        expected = {'name': f'THREAD-{index}', 'when': '2032-01-01T00:00:00Z', 'tags': [index] * 10}
+        assert all(r == expected for r in results)
+
+
+def test_serializer_overlapping_calls():
+    def sleepy(value):
+        # give up the GIL mid-call so calls on the same serializer overlap
+        time.sleep(0)
+        return value
+
+    s = SchemaSerializer(
+        core_schema.list_schema(
+            core_schema.int_schema(serialization=core_schema.plain_serializer_function_ser_schema(sleepy))
+        )
+    )
+
+    def serialize(index):
+        return [s.to_json([index] * (index + 1)) for _ in range(20)]
+
+    for index, results in enumerate(run_threads(serialize)):
+        assert all(json.loads(r) == [index] * (index + 1) for r in results)
+
+
+class Passthrough:
+    def validate(self, input_value, strict, info):
+        return input_value
+
+
+def test_register_validator():
+    def register_and_build(index):
+        schema_type = f'threading-{index}'
+        register_validator(schema_type, lambda schema, config: Passthrough())
+        # the cached self-schema must include the new type, even if another thread rebuilt it meanwhile
+        return SchemaValidator({'type': schema_type}).validate_python(index)
+
+    assert run_threads(register_and_build) == list(range(THREADS))
+    for index in range(THREADS):
+        assert SchemaValidator({'type': f'threading-{index}'}).validate_python(index) == index
+
+
+@pytest.mark.skipif(gil_enabled(), reason='requires a free-threaded build of python')
+def test_free_threaded():
+    # without the GIL calls really do run in parallel, so hammer one validator and one serializer for longer
+    v = SchemaValidator(tree_schema)
+    s = SchemaSerializer(serializer_schema)
+
+    def run(index):
+        for _ in range(500):
+            tree = v.validate_python(make_tree(3, index), context={'thread': threading.get_ident()})
+            check_tree(tree, 3, index)
+            assert s.to_python({'name': 'x', 'when': tree['when'], 'tags': []})['name'] == 'X'
+
+    run_threads(run)
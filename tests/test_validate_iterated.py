@@ -0,0 +1,214 @@
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+
+@pytest.mark.parametrize(
+    'schema_func,output_type',
+    [
+        (core_schema.list_schema, list),
+        (core_schema.tuple_variable_schema, tuple),
+        (core_schema.set_schema, set),
+        (core_schema.frozenset_schema, frozenset),
+    ],
+)
+def test_batches(schema_func, output_type):
+    v = SchemaValidator(schema_func(core_schema.int_schema()))
+    iterated = v.validate_iterated()
+    iterated.feed([1, '2'])
+    iterated.feed(x for x in ['3', 4])
+    iterated.feed([])
+    output = iterated.finish()
+    assert output == output_type([1, 2, 3, 4])
+    assert type(output) is output_type
+
+
+def test_error_in_second_batch():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    iterated = v.validate_iterated()
+    iterated.feed([1, 2, 3])
+    # errors are collected until `finish`, with their index in the whole collection
+    iterated.feed([4, 'x', 6, 'y'])
+    with pytest.raises(ValidationError) as exc_info:
+        iterated.finish()
+    assert exc_info.value.title == v.title
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': (4,),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        },
+        {
+            'type': 'int_parsing',
+            'loc': (6,),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'y',
+        },
+    ]
+
+
+def test_nested_error():
+    v = SchemaValidator(
+        core_schema.list_schema(
+            core_schema.typed_dict_schema({'a': core_schema.typed_dict_field(core_schema.int_schema())})
+        )
+    )
+    iterated = v.validate_iterated()
+    iterated.feed([{'a': 1}])
+    iterated.feed([{'a': 2}, {'a': 'x'}])
+    with pytest.raises(ValidationError) as exc_info:
+        iterated.finish()
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('int_parsing', (2, 'a'))]
+
+
+def test_min_length():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema(), min_length=3))
+    iterated = v.validate_iterated()
+    iterated.feed([1])
+    iterated.feed([2])
+    with pytest.raises(ValidationError) as exc_info:
+        iterated.finish()
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'too_short',
+            'loc': (),
+            'msg': 'List should have at least 3 items after validation, not 2',
+            'input': [1, 2],
+            'ctx': {'field_type': 'List', 'min_length': 3, 'actual_length': 2},
+        }
+    ]
+
+    v = SchemaValidator(core_schema.set_schema(core_schema.int_schema(), min_length=2, unique_items_error=True))
+    iterated = v.validate_iterated()
+    iterated.feed([1])
+    iterated.feed([1])
+    with pytest.raises(ValidationError) as exc_info:
+        iterated.finish()
+    assert [e['type'] for e in exc_info.value.errors()] == ['duplicate_items']
+
+
+def test_max_length():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema(), max_length=3))
+    iterated = v.validate_iterated()
+    iterated.feed([1, 2])
+
+    consumed = []
+
+    def gen():
+        for i in range(100):
+            consumed.append(i)
+            yield i
+
+    # exceeding `max_length` is raised by `feed` rather than waiting for `finish`
+    with pytest.raises(ValidationError) as exc_info:
+        iterated.feed(gen())
+    assert [(e['type'], e['msg'], e['ctx']) for e in exc_info.value.errors()] == [
+        (
+            'too_long',
+            'List should have at most 3 items after validation, not 4',
+            {'field_type': 'List', 'max_length': 3, 'actual_length': 4},
+        )
+    ]
+    assert consumed == [0, 1]
+
+    with pytest.raises(RuntimeError, match='Iterated validation has already finished'):
+        iterated.feed([1])
+    with pytest.raises(RuntimeError, match='Iterated validation has already finished'):
+        iterated.finish()
+
+
+def test_set_max_length():
+    v = SchemaValidator(core_schema.set_schema(core_schema.int_schema(), max_length=2))
+    iterated = v.validate_iterated()
+    # duplicates don't count towards `max_length`
+    iterated.feed([1, 1, 2])
+    iterated.feed([2, 1])
+    assert iterated.finish() == {1, 2}
+
+    iterated = v.validate_iterated()
+    iterated.feed([1, 2])
+    with pytest.raises(ValidationError, match=r'Set should have at most 2 items after validation, not 3'):
+        iterated.feed([3])
+
+
+def test_fail_fast():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema(), fail_fast=True))
+    iterated = v.validate_iterated()
+    iterated.feed([1, 2])
+    with pytest.raises(ValidationError) as exc_info:
+        iterated.feed([3, 'x', 'y'])
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('int_parsing', (3,))]
+
+
+def test_max_errors():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema(), max_errors=2))
+    iterated = v.validate_iterated()
+    iterated.feed(['a'])
+    with pytest.raises(ValidationError) as exc_info:
+        iterated.feed([1, 'b', 'c'])
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [
+        ('int_parsing', (0,)),
+        ('int_parsing', (2,)),
+        ('too_many_errors', ()),
+    ]
+    # later batches aren't known, so the unchecked items aren't counted
+    assert exc_info.value.errors()[-1]['ctx'] == {'max_errors': 2}
+
+    # reaching the limit at the end of a batch only stops validation if another item is fed
+    iterated = v.validate_iterated()
+    iterated.feed(['a', 'b'])
+    iterated.feed([])
+    with pytest.raises(ValidationError) as exc_info:
+        iterated.finish()
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('int_parsing', (0,)), ('int_parsing', (1,))]
+
+    iterated = v.validate_iterated()
+    iterated.feed(['a', 'b'])
+    with pytest.raises(ValidationError) as exc_info:
+        iterated.feed([1])
+    assert [e['type'] for e in exc_info.value.errors()] == ['int_parsing', 'int_parsing', 'too_many_errors']
+
+
+def test_options():
+    def check_index(value, info):
+        assert info.context == {'offset': 10}
+        return value + info.context['offset'] + info.index
+
+    v = SchemaValidator(
+        core_schema.list_schema(core_schema.general_after_validator_function(check_index, core_schema.int_schema()))
+    )
+    iterated = v.validate_iterated(context={'offset': 10})
+    iterated.feed([0, 0])
+    iterated.feed([0])
+    assert iterated.finish() == [10, 11, 12]
+
+    iterated = v.validate_iterated(strict=True, context={'offset': 10})
+    iterated.feed([0])
+    iterated.feed(['1'])
+    with pytest.raises(ValidationError) as exc_info:
+        iterated.finish()
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('int_type', (1,))]
+
+
+def test_invalid_batch():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    iterated = v.validate_iterated()
+    with pytest.raises(ValidationError, match=r'Input should be iterable \[type=iterable_type'):
+        iterated.feed(1)
+
+
+def test_not_supported():
+    v = SchemaValidator(core_schema.int_schema())
+    with pytest.raises(TypeError, match='validate_iterated is not supported for int'):
+        v.validate_iterated()
+
+
+def test_collect_stats():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()), collect_stats=True)
+    iterated = v.validate_iterated()
+    iterated.feed([1, '2'])
+    iterated.feed(['3'])
+    assert iterated.finish() == [1, 2, 3]
+    stats = {s['path']: s['calls'] for s in v.stats()}
+    assert stats == {'list': 0, 'list.int': 3}
@@ -0,0 +1,86 @@
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+
+def test_validate_many_python():
+    v = SchemaValidator(core_schema.int_schema())
+    assert v.validate_many([1, '2', 3.0]) == [1, 2, 3]
+    assert v.validate_many([]) == []
+    assert v.validate_many(str(i) for i in range(3)) == [0, 1, 2]
+
+
+def test_validate_many_json():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    assert v.validate_many(['[1, 2]', b'[3]', bytearray(b'[]')], mode='json') == [[1, 2], [3], []]
+
+
+def test_validate_many_raise():
+    v = SchemaValidator(core_schema.int_schema())
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_many([1, 'x', 'y'])
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': (),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        }
+    ]
+
+
+def test_validate_many_collect():
+    v = SchemaValidator(core_schema.int_schema())
+    results = v.validate_many([1, 'x', '3'], on_error='collect')
+    assert [index for index, _ in results] == [0, 1, 2]
+    assert results[0] == (0, 1)
+    assert results[2] == (2, 3)
+    error = results[1][1]
+    assert isinstance(error, ValidationError)
+    assert error.title == 'int'
+    assert error.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': (),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        }
+    ]
+
+
+def test_validate_many_collect_json():
+    v = SchemaValidator(core_schema.int_schema())
+    results = v.validate_many(['1', '"x"', '{'], mode='json', on_error='collect')
+    assert results[0] == (0, 1)
+    assert [e['type'] for e in results[1][1].errors()] == ['int_parsing']
+    assert [e['type'] for e in results[2][1].errors()] == ['json_invalid']
+
+
+def test_validate_many_strict_context():
+    def f(input_value, info):
+        return input_value * info.context['multiplier']
+
+    v = SchemaValidator(core_schema.general_after_validator_function(f, core_schema.int_schema()))
+    assert v.validate_many([1, 2], context={'multiplier': 10}) == [10, 20]
+    results = v.validate_many([1, '2'], strict=True, context={'multiplier': 10}, on_error='collect')
+    assert results[0] == (0, 10)
+    assert isinstance(results[1][1], ValidationError)
+
+
+def test_validate_many_internal_error():
+    def f(input_value):
+        raise RuntimeError('internal')
+
+    v = SchemaValidator(core_schema.no_info_plain_validator_function(f))
+    with pytest.raises(RuntimeError, match='internal'):
+        v.validate_many([1], on_error='collect')
+
+
+def test_validate_many_invalid_args():
+    v = SchemaValidator(core_schema.int_schema())
+    with pytest.raises(ValueError, match='Invalid mode: `xml`, expected `python` or `json`'):
+        v.validate_many([1], mode='xml')
+    with pytest.raises(ValueError, match='Invalid on_error: `ignore`, expected `raise` or `collect`'):
+        v.validate_many([1], on_error='ignore')
+    with pytest.raises(TypeError, match="'int' object is not iterable"):
+        v.validate_many(1)
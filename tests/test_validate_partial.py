@@ -0,0 +1,124 @@
+import pytest
+
+from pydantic_core import PydanticOmit, SchemaValidator, ValidationError, core_schema
+
+
+@pytest.mark.parametrize(
+    'schema_func,output_type',
+    [
+        (core_schema.list_schema, list),
+        (core_schema.tuple_variable_schema, tuple),
+        (core_schema.set_schema, set),
+        (core_schema.frozenset_schema, frozenset),
+    ],
+)
+def test_validate_partial(schema_func, output_type):
+    v = SchemaValidator(schema_func(core_schema.int_schema()))
+    output, error = v.validate_partial([1, 'x', '3', 'y'])
+    assert output == output_type([1, 3])
+    assert type(output) is output_type
+    assert isinstance(error, ValidationError)
+    assert error.title == v.title
+    assert [(e['type'], e['loc'], e['input']) for e in error.errors()] == [
+        ('int_parsing', (1,), 'x'),
+        ('int_parsing', (3,), 'y'),
+    ]
+
+    output, error = v.validate_partial(['1', 2])
+    assert output == output_type([1, 2])
+    assert error is None
+
+
+def test_large_input():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    records = [str(i) if i != 5_000 else 'bad' for i in range(10_000)]
+    output, error = v.validate_partial(records)
+    assert len(output) == 9_999
+    assert 5_000 not in output
+    assert [e['loc'] for e in error.errors()] == [(5_000,)]
+
+
+def test_min_length():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema(), min_length=3))
+    # too few valid items is reported as an error, but the valid items are still returned
+    output, error = v.validate_partial([1, 'x', 2])
+    assert output == [1, 2]
+    assert [(e['type'], e['loc']) for e in error.errors()] == [('int_parsing', (1,)), ('too_short', ())]
+    assert error.errors()[1]['ctx'] == {'field_type': 'List', 'min_length': 3, 'actual_length': 2}
+
+    v = SchemaValidator(core_schema.set_schema(core_schema.int_schema(), min_length=2, unique_items_error=True))
+    output, error = v.validate_partial([1, 1, 'x'])
+    assert output == {1}
+    assert [(e['type'], e['loc']) for e in error.errors()] == [('int_parsing', (2,)), ('duplicate_items', ())]
+
+
+def test_omit():
+    def omit_negative(value: int) -> int:
+        if value < 0:
+            raise PydanticOmit
+        return value
+
+    v = SchemaValidator(
+        core_schema.list_schema(core_schema.no_info_after_validator_function(omit_negative, core_schema.int_schema()))
+    )
+    # omitted items are in neither the output nor the errors
+    output, error = v.validate_partial([1, -1, 'x', 2])
+    assert output == [1, 2]
+    assert [(e['type'], e['loc']) for e in error.errors()] == [('int_parsing', (2,))]
+
+    output, error = v.validate_partial([-1, 3])
+    assert output == [3]
+    assert error is None
+
+
+def test_whole_input_errors():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema(), max_length=2))
+    # errors which apply to the whole input are still raised
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_partial('not a list')
+    assert [e['type'] for e in exc_info.value.errors()] == ['list_type']
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_partial([1, 'x', 3])
+    assert [e['type'] for e in exc_info.value.errors()] == ['too_long']
+
+
+def test_nested():
+    v = SchemaValidator(core_schema.list_schema(core_schema.list_schema(core_schema.int_schema())))
+    # only the top level collection is partial, an item with any invalid items is invalid
+    output, error = v.validate_partial([[1, 2], [3, 'x'], [4]])
+    assert output == [[1, 2], [4]]
+    assert [(e['type'], e['loc']) for e in error.errors()] == [('int_parsing', (1, 1))]
+
+
+def test_options():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema(strict=True), max_errors=1))
+    output, error = v.validate_partial([1, 'x', 2, 'y'], context={})
+    assert output == [1]
+    assert [e['type'] for e in error.errors()] == ['int_type', 'too_many_errors']
+
+    output, error = v.validate_partial([1, 'x', 2, 'y'], strict=False)
+    assert output == [1]
+    assert [e['type'] for e in error.errors()] == ['int_parsing', 'too_many_errors']
+
+
+def test_ref():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema(), ref='ints'))
+    output, error = v.validate_partial([1, 'x'])
+    assert output == [1]
+    assert [e['loc'] for e in error.errors()] == [(1,)]
+
+
+def test_not_supported():
+    v = SchemaValidator(core_schema.int_schema())
+    with pytest.raises(TypeError, match='validate_partial is not supported for int'):
+        v.validate_partial(1)
+
+
+def test_collect_stats():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()), collect_stats=True)
+    output, error = v.validate_partial([1, 'x', '3'])
+    assert output == [1, 3]
+    assert [e['loc'] for e in error.errors()] == [(1,)]
+    stats = {s['path']: (s['calls'], s['failures']) for s in v.stats()}
+    assert stats == {'list': (1, 0), 'list.int': (3, 1)}
@@ -0,0 +1,178 @@
+import time
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+
+def stats_by_path(v):
+    return {s['path']: s for s in v.stats()}
+
+
+def test_stats_disabled():
+    v = SchemaValidator(core_schema.int_schema())
+    assert v.validate_python('1') == 1
+    assert v.stats() == []
+    v.reset_stats()
+
+
+def test_stats_calls_failures():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(core_schema.list_schema(core_schema.str_schema())),
+            }
+        ),
+        collect_stats=True,
+    )
+    # bytes so the list isn't just copied, see `test_stats_exact_items`
+    assert v.validate_python({'a': 1, 'b': ['x', 'y', b'z']}) == {'a': 1, 'b': ['x', 'y', 'z']}
+    with pytest.raises(ValidationError):
+        v.validate_python({'a': 'x', 'b': []})
+
+    stats = stats_by_path(v)
+    assert set(stats) == {'typed-dict', 'typed-dict.int', 'typed-dict.list', 'typed-dict.list.str'}
+    assert {path: (s['name'], s['calls'], s['failures']) for path, s in stats.items()} == {
+        'typed-dict': ('typed-dict', 2, 1),
+        'typed-dict.int': ('int', 2, 1),
+        'typed-dict.list': ('list[str]', 2, 0),
+        'typed-dict.list.str': ('str', 3, 0),
+    }
+    for s in stats.values():
+        assert isinstance(s['time'], float)
+        assert s['time'] >= 0
+
+
+def test_stats_reset():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()), collect_stats=True)
+    v.validate_python([1, '2'])
+    assert [(s['path'], s['calls']) for s in v.stats()] == [('list.int', 2), ('list', 1)]
+
+    v.reset_stats()
+    assert [(s['path'], s['calls'], s['failures'], s['time']) for s in v.stats()] == [
+        ('list.int', 0, 0, 0.0),
+        ('list', 0, 0, 0.0),
+    ]
+    v.validate_python(['1'])
+    assert [(s['path'], s['calls']) for s in v.stats()] == [('list.int', 1), ('list', 1)]
+
+
+def test_stats_json():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()), collect_stats=True)
+    assert v.validate_json('[1, 2, 3]') == [1, 2, 3]
+    assert stats_by_path(v)['list.int']['calls'] == 3
+
+
+def test_stats_defaults():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {'a': core_schema.typed_dict_field(core_schema.with_default_schema(core_schema.int_schema(), default=1))}
+        ),
+        collect_stats=True,
+    )
+    assert v.validate_python({}) == {'a': 1}
+    assert v.validate_python({'a': 2}) == {'a': 2}
+    stats = stats_by_path(v)
+    assert set(stats) == {'typed-dict', 'typed-dict.default.int'}
+    assert stats['typed-dict.default.int']['calls'] == 1
+
+
+def test_stats_recursive():
+    v = SchemaValidator(
+        core_schema.definitions_schema(
+            core_schema.definition_reference_schema('branch'),
+            [
+                core_schema.typed_dict_schema(
+                    {
+                        'sub': core_schema.typed_dict_field(
+                            core_schema.nullable_schema(core_schema.definition_reference_schema('branch'))
+                        )
+                    },
+                    ref='branch',
+                )
+            ],
+        ),
+        collect_stats=True,
+    )
+    assert v.validate_python({'sub': {'sub': {'sub': None}}}) == {'sub': {'sub': {'sub': None}}}
+    stats = stats_by_path(v)
+    assert {path: s['calls'] for path, s in stats.items()} == {
+        'definitions.typed-dict': 3,
+        'definitions.typed-dict.nullable': 3,
+    }
+
+
+def test_stats_validate_assignment():
+    v = SchemaValidator(
+        core_schema.model_fields_schema({'a': core_schema.model_field(core_schema.int_schema())}),
+        collect_stats=True,
+    )
+    assert v.validate_assignment({'a': 1}, 'a', '2') == ({'a': 2}, None, {'a'})
+    with pytest.raises(ValidationError):
+        v.validate_assignment({'a': 1}, 'a', 'x')
+    stats = stats_by_path(v)
+    assert (stats['model-fields']['calls'], stats['model-fields']['failures']) == (2, 1)
+    assert (stats['model-fields.int']['calls'], stats['model-fields.int']['failures']) == (2, 1)
+
+
+def test_stats_time_innermost():
+    def slow(input_value):
+        time.sleep(0.02)
+        return input_value
+
+    v = SchemaValidator(
+        core_schema.list_schema(core_schema.no_info_after_validator_function(slow, core_schema.int_schema())),
+        collect_stats=True,
+    )
+    assert v.validate_python([1, 2]) == [1, 2]
+    stats = stats_by_path(v)
+    assert stats['list.function-after']['time'] >= 0.04
+    assert stats['list']['time'] < 0.02
+    assert stats['list.function-after.int']['time'] < 0.02
+
+
+def test_stats_same_output():
+    # validators which check the kind of their children see through the stats wrappers
+    schema = core_schema.union_schema(
+        [core_schema.list_schema(core_schema.int_schema()), core_schema.set_schema(core_schema.int_schema())]
+    )
+    output = SchemaValidator(schema, collect_stats=True).validate_python({'1', 2})
+    assert output == SchemaValidator(schema).validate_python({'1', 2}) == {1, 2}
+    assert type(output) is set
+
+
+def test_stats_skipped_any():
+    v = SchemaValidator(
+        core_schema.dict_schema(core_schema.str_schema(), core_schema.list_schema(core_schema.any_schema())),
+        collect_stats=True,
+    )
+    assert v.validate_python({'a': [1, 'x']}) == {'a': [1, 'x']}
+    # `any` item schemas are never called, so they aren't listed
+    assert {path: s['calls'] for path, s in stats_by_path(v).items()} == {'dict': 1, 'dict.str': 1, 'dict.list': 1}
+
+
+def test_stats_nested_chain():
+    v = SchemaValidator(
+        core_schema.chain_schema(
+            [
+                core_schema.str_schema(),
+                core_schema.chain_schema(
+                    [core_schema.int_schema(), core_schema.no_info_plain_validator_function(lambda x: x * 2)]
+                ),
+            ]
+        ),
+        collect_stats=True,
+    )
+    assert v.validate_python('2') == 4
+    # the nested chain is flattened into the outer one
+    stats = stats_by_path(v)
+    assert set(stats) == {'chain', 'chain.str', 'chain.chain.int', 'chain.chain.function-plain'}
+    assert all(s['calls'] == 1 for s in stats.values())
+
+
+def test_stats_exact_items():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()), collect_stats=True)
+    # lists of exact ints are copied without calling the item validator, as without `collect_stats`
+    assert v.validate_python([1, 2]) == [1, 2]
+    assert [(s['path'], s['calls']) for s in v.stats()] == [('list.int', 0), ('list', 1)]
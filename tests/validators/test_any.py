@@ -0,0 +1,28 @@
+import pytest
+
+from pydantic_core import SchemaValidator, core_schema
+
+
+class Foo:
+    pass
+
+
+@pytest.mark.parametrize('input_value', [1, 'a', None, True, 1.5, [1, 2], {'a': 1}, {1, 2}, Foo(), Foo])
+def test_any_identity(input_value):
+    v = SchemaValidator(core_schema.any_schema())
+    assert v.validate_python(input_value) is input_value
+
+
+def test_any_items_identity():
+    items = [Foo(), [1], {'a': 1}, None]
+    v = SchemaValidator(core_schema.list_schema(core_schema.any_schema()))
+    output = v.validate_python(items)
+    assert output == items
+    assert all(a is b for a, b in zip(output, items))
+
+    v = SchemaValidator(core_schema.dict_schema(core_schema.any_schema(), core_schema.any_schema()))
+    key, value = (1, 2), Foo()
+    output = v.validate_python({key: value})
+    ((output_key, output_value),) = output.items()
+    assert output_key is key
+    assert output_value is value
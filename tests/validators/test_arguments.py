@@ -1,3 +1,4 @@
+import pickle
 import re
 import sys
 from functools import wraps
@@ -13,16 +14,16 @@ from ..conftest import Err, PyAndJson, plain_repr
 
 def test_args_kwargs():
     ak = ArgsKwargs(('hello', True))
-    assert str(ak) == "ArgsKwargs(('hello', True))"
-    assert repr(ak) == "ArgsKwargs(('hello', True))"
+    assert str(ak) == "ArgsKwargs('hello', True)"
+    assert repr(ak) == "ArgsKwargs('hello', True)"
     assert ak.args == ('hello', True)
     assert ak.kwargs is None
     ak2 = ArgsKwargs((), {'a': 123})
-    assert repr(ak2) == "ArgsKwargs((), {'a': 123})"
+    assert repr(ak2) == 'ArgsKwargs(a=123)'
     assert ak2.args == ()
     assert ak2.kwargs == {'a': 123}
     ak3 = ArgsKwargs(('hello', True), {'a': 123, 'b': b'bytes'})
-    assert repr(ak3) == "ArgsKwargs(('hello', True), {'a': 123, 'b': b'bytes'})"
+    assert repr(ak3) == "ArgsKwargs('hello', True, a=123, b=b'bytes')"
 
     assert ak != ak2
 
@@ -31,7 +32,45 @@ def test_args_kwargs():
     assert ak3 != ArgsKwargs(('hello', True), {'a': 123, 'b': b'different'})
     assert ArgsKwargs((1,), {}) == ArgsKwargs((1,), None) == ArgsKwargs((1,))
 
-    assert repr(ArgsKwargs((1,))) == 'ArgsKwargs((1,))'
+    assert repr(ArgsKwargs((1,))) == 'ArgsKwargs(1)'
+    assert repr(ArgsKwargs(())) == 'ArgsKwargs()'
+    assert repr(ArgsKwargs((), {1: 2})) == 'ArgsKwargs(**{1: 2})'
+    assert ArgsKwargs((1,), {'a': 2, 'b': 3}) == ArgsKwargs((1,), {'b': 3, 'a': 2})
+
+
+def test_args_kwargs_hash():
+    assert hash(ArgsKwargs((1,), {'a': 2, 'b': 3})) == hash(ArgsKwargs((1,), {'b': 3, 'a': 2}))
+    assert hash(ArgsKwargs((1,), {})) == hash(ArgsKwargs((1,)))
+    assert {ArgsKwargs((1,)): 1, ArgsKwargs((), {'a': 1}): 2}[ArgsKwargs((), {'a': 1})] == 2
+    with pytest.raises(TypeError, match="unhashable type: 'list'"):
+        hash(ArgsKwargs(([],)))
+    with pytest.raises(TypeError, match="unhashable type: 'list'"):
+        hash(ArgsKwargs((), {'a': []}))
+
+
+@pytest.mark.parametrize('pickle_protocol', range(1, pickle.HIGHEST_PROTOCOL + 1))
+def test_args_kwargs_pickle(pickle_protocol):
+    ak = ArgsKwargs((1, 'a'), {'b': [2]})
+    ak2 = pickle.loads(pickle.dumps(ak, protocol=pickle_protocol))
+    assert ak2 == ak
+    assert ak2.args == (1, 'a')
+    assert ak2.kwargs == {'b': [2]}
+    assert pickle.loads(pickle.dumps(ArgsKwargs((1,)), protocol=pickle_protocol)).kwargs is None
+
+
+def test_args_kwargs_normalize():
+    v = SchemaValidator(
+        core_schema.arguments_schema(
+            [
+                core_schema.arguments_parameter('a', core_schema.int_schema()),
+                core_schema.arguments_parameter('b', core_schema.int_schema(), mode='keyword_only'),
+            ]
+        )
+    )
+    assert v.validate_python(ArgsKwargs((1,), {'b': 2})) == ((1,), {'b': 2})
+    assert v.validate_python({'a': 1, 'b': 2}) == ((), {'a': 1, 'b': 2})
+    with pytest.raises(ValidationError, match='b\n  Missing required keyword only argument'):
+        v.validate_python((1,))
 
 
 @pytest.mark.parametrize(
@@ -1012,11 +1051,11 @@ def test_error_display():
         "1 validation error for arguments\n"
         "b\n"
         "  Missing required argument [type=missing_argument, "
-        "input_value=ArgsKwargs((), {'a': 1}), input_type=ArgsKwargs]\n"
+        "input_value=ArgsKwargs(a=1), input_type=ArgsKwargs]\n"
         f"    For further information visit https://errors.pydantic.dev/{__version__}/v/missing_argument"
     )
     # insert_assert(exc_info.value.json(include_url=False))
     assert exc_info.value.json(include_url=False) == (
         '[{"type":"missing_argument","loc":["b"],"msg":"Missing required argument",'
-        '"input":"ArgsKwargs((), {\'a\': 1})"}]'
+        '"input":"ArgsKwargs(a=1)"}]'
     )
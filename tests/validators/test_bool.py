@@ -77,9 +77,9 @@ def test_bool_error():
 
 def test_bool_repr():
     v = SchemaValidator({'type': 'bool'})
-    assert plain_repr(v) == 'SchemaValidator(title="bool",validator=Bool(BoolValidator{strict:false}),definitions=[])'
+    assert plain_repr(v) == 'SchemaValidator(title="bool",schema_type="bool",config=None,validator=Bool(BoolValidator{strict:false}),definitions=[])'  # noqa: E501
     v = SchemaValidator({'type': 'bool', 'strict': True})
-    assert plain_repr(v) == 'SchemaValidator(title="bool",validator=Bool(BoolValidator{strict:true}),definitions=[])'
+    assert plain_repr(v) == 'SchemaValidator(title="bool",schema_type="bool",config=None,validator=Bool(BoolValidator{strict:true}),definitions=[])'  # noqa: E501
 
 
 def test_bool_key(py_and_json: PyAndJson):
@@ -1,7 +1,10 @@
+import array
+import mmap
 import re
 from typing import Any, Dict
 
 import pytest
+from dirty_equals import IsInstance
 
 from pydantic_core import SchemaValidator, ValidationError
 
@@ -121,3 +124,53 @@ def test_length_ctx():
             'ctx': {'max_length': 3},
         }
     ]
+
+
+def test_lax_bytes_buffer_protocol():
+    v = SchemaValidator({'type': 'bytes'})
+
+    assert v.validate_python(memoryview(b'foo')) == b'foo'
+    assert v.validate_python(memoryview(b'')) == b''
+    assert v.validate_python(array.array('B', [1, 2, 3])) == b'\x01\x02\x03'
+    # the raw memory is copied, not the items
+    assert v.validate_python(array.array('i', [1])) == array.array('i', [1]).tobytes()
+
+    with mmap.mmap(-1, 8) as m:
+        m.write(b'01234567')
+        output = v.validate_python(m)
+    assert output == b'01234567'
+    assert type(output) is bytes
+
+
+def test_bytes_buffer_not_contiguous():
+    v = SchemaValidator({'type': 'bytes'})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(memoryview(b'foobar')[::2])
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'bytes_type', 'loc': (), 'msg': 'Input should be a valid bytes', 'input': IsInstance(memoryview)}
+    ]
+
+
+def test_strict_bytes_buffer_protocol():
+    v = SchemaValidator({'type': 'bytes', 'strict': True})
+    with pytest.raises(ValidationError, match='Input should be a valid bytes'):
+        v.validate_python(memoryview(b'foo'))
+
+
+def test_constrained_bytes_buffer_protocol():
+    v = SchemaValidator({'type': 'bytes', 'max_length': 64})
+    assert v.validate_python(memoryview(b'foo')) == b'foo'
+
+    # the length is checked against the buffer, before any data is copied
+    with mmap.mmap(-1, 1024 * 1024) as m:
+        with pytest.raises(ValidationError) as exc_info:
+            v.validate_python(m)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'bytes_too_long',
+            'loc': (),
+            'msg': 'Data should have at most 64 bytes',
+            'input': IsInstance(mmap.mmap),
+            'ctx': {'max_length': 64},
+        }
+    ]
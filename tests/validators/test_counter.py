@@ -0,0 +1,236 @@
+import re
+from collections import Counter
+from itertools import count
+
+import pytest
+
+from pydantic_core import SchemaSerializer, SchemaValidator, ValidationError, core_schema
+
+from ..conftest import Err, PyAndJson
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ({}, Counter()),
+        ({'a': 2, 'b': 1}, Counter({'a': 2, 'b': 1})),
+        ({'a': '2'}, Counter({'a': 2})),
+        (['a', 'b', 'a'], Counter({'a': 2, 'b': 1})),
+        ([], Counter()),
+        ('abc', Err('[type=counter_type,')),
+        (1, Err('[type=counter_type,')),
+    ],
+)
+def test_counter(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(core_schema.counter_schema(core_schema.str_schema()))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        output = v.validate_test(input_value)
+        assert output == expected
+        assert type(output) is Counter
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (Counter('aab'), Counter({'a': 2, 'b': 1})),
+        ({'a': 1}, Counter({'a': 1})),
+        (('a', 'b', 'a'), Counter({'a': 2, 'b': 1})),
+        ({'a', 'b'}, Counter({'a': 1, 'b': 1})),
+        ((c for c in 'aba'), Counter({'a': 2, 'b': 1})),
+    ],
+)
+def test_counter_python_inputs(input_value, expected):
+    v = SchemaValidator(core_schema.counter_schema(core_schema.str_schema()))
+    assert v.validate_python(input_value) == expected
+
+
+def test_validated_keys():
+    v = SchemaValidator(core_schema.counter_schema(core_schema.int_schema()))
+    assert v.validate_python(['1', 1, 2]) == Counter({1: 2, 2: 1})
+    # counts of keys which are equal after validation are summed
+    assert v.validate_python({'1': 2, 1: 3}) == Counter({1: 5})
+    assert v.validate_json('{"1": 2, "2": 3}') == Counter({1: 2, 2: 3})
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'x': 1, 2: 'y'})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': ('x', '[key]'),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        },
+        {
+            'type': 'int_parsing',
+            'loc': (2,),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'y',
+        },
+    ]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([1, 'x'])
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('int_parsing', (1,))]
+
+
+def test_negative_count():
+    v = SchemaValidator(core_schema.counter_schema(core_schema.str_schema()))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 1, 'b': -1})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'greater_than_equal',
+            'loc': ('b',),
+            'msg': 'Input should be greater than or equal to 0',
+            'input': -1,
+            'ctx': {'ge': 0},
+        }
+    ]
+
+
+def test_json_object():
+    v = SchemaValidator(core_schema.counter_schema(core_schema.str_schema(), max_count=3))
+    assert v.validate_json('{"a": 3, "b": 1}') == Counter({'a': 3, 'b': 1})
+    assert v.validate_json('["a", "b", "a"]') == Counter({'a': 2, 'b': 1})
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('{"a": 4, "b": -1, "c": 1.5}')
+    assert [(e['type'], e['loc'], e['input']) for e in exc_info.value.errors()] == [
+        ('greater_than_equal', ('b',), -1),
+        ('int_from_float', ('c',), 1.5),
+    ]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('{"a": 4}')
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('count_too_large', ('a',))]
+
+
+def test_min_max_count():
+    v = SchemaValidator(core_schema.counter_schema(core_schema.str_schema(), min_count=2, max_count=3))
+    assert v.validate_python(list('aabbb')) == Counter({'a': 2, 'b': 3})
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(['a', 'b', 'b', 'b', 'b', 'c', 'c'])
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'count_too_small',
+            'loc': ('a',),
+            'msg': 'Item should occur at least 2 times, not 1',
+            'input': 'a',
+            'ctx': {'min_count': 2, 'actual_count': 1},
+        },
+        {
+            'type': 'count_too_large',
+            'loc': ('b',),
+            'msg': 'Item should occur at most 3 times, not 4',
+            'input': 'b',
+            'ctx': {'max_count': 3, 'actual_count': 4},
+        },
+    ]
+
+    with pytest.raises(ValidationError, match=r'Item should occur at least 2 times, not 0 \[type=count_too_small'):
+        v.validate_python({'a': 0})
+
+
+def test_max_keys():
+    v = SchemaValidator(core_schema.counter_schema(core_schema.int_schema(), max_keys=2))
+    assert v.validate_python(i % 2 for i in range(10)) == Counter({0: 5, 1: 5})
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(i for i in range(3))
+    assert [(e['type'], e['msg'], e['ctx']) for e in exc_info.value.errors()] == [
+        (
+            'too_long',
+            'Counter should have at most 2 items after validation, not 3',
+            {'field_type': 'Counter', 'max_length': 2, 'actual_length': 3},
+        )
+    ]
+
+
+def test_max_keys_lazy():
+    v = SchemaValidator(core_schema.counter_schema(core_schema.int_schema(), max_keys=2))
+    consumed = []
+
+    def gen():
+        for i in range(1000):
+            consumed.append(i)
+            yield i
+
+    # the generator is consumed lazily, stopping at the first key over the limit
+    with pytest.raises(ValidationError, match=r'Counter should have at most 2 items after validation, not 3'):
+        v.validate_python(gen())
+    assert consumed == [0, 1, 2]
+
+    with pytest.raises(ValidationError, match=r'\[type=too_long,'):
+        v.validate_python(count())
+
+
+def test_count_overflow():
+    v = SchemaValidator(core_schema.counter_schema(core_schema.int_schema()))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({1: 2**62, '1': 2**62})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'less_than_equal',
+            'loc': ('1',),
+            'msg': 'Input should be less than or equal to 9223372036854775807',
+            'input': 2**62,
+            'ctx': {'le': 9223372036854775807},
+        }
+    ]
+
+
+def test_max_input_length():
+    v = SchemaValidator(core_schema.counter_schema(core_schema.int_schema(), max_input_length=3))
+    assert v.validate_python([1, 1, 1]) == Counter({1: 3})
+    # mappings aren't limited by `max_input_length`
+    assert v.validate_python({1: 100}) == Counter({1: 100})
+
+    consumed = []
+
+    def gen():
+        for i in range(100):
+            consumed.append(i)
+            yield i
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(gen())
+    assert [e['type'] for e in exc_info.value.errors()] == ['too_long']
+    # the generator is consumed lazily, stopping at the first item over the limit
+    assert len(consumed) == 4
+
+
+def test_strict():
+    v = SchemaValidator(core_schema.counter_schema(core_schema.str_schema(), strict=True))
+    assert v.validate_python(Counter('aab')) == Counter({'a': 2, 'b': 1})
+    assert v.validate_json('{"a": 2}') == Counter({'a': 2})
+
+    for input_value in ({'a': 1}, ['a']):
+        with pytest.raises(ValidationError, match=r'\[type=counter_type,'):
+            v.validate_python(input_value)
+    with pytest.raises(ValidationError, match=r'\[type=counter_type,'):
+        v.validate_json('["a"]')
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('{"a": "2"}')
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('int_type', ('a',))]
+
+    v = SchemaValidator(core_schema.counter_schema(core_schema.str_schema()))
+    with pytest.raises(ValidationError, match=r'\[type=counter_type,'):
+        v.validate_python(['a'], strict=True)
+
+
+def test_unhashable():
+    v = SchemaValidator(core_schema.counter_schema())
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([1, [2]])
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('unhashable_item', (1,))]
+
+
+def test_serialization():
+    schema = core_schema.counter_schema(core_schema.str_schema())
+    s = SchemaSerializer(schema)
+    assert s.to_python(Counter('aab')) == {'a': 2, 'b': 1}
+    assert s.to_json(Counter('aab')) == b'{"a":2,"b":1}'
@@ -0,0 +1,146 @@
+import re
+from decimal import Decimal
+
+import pytest
+
+from pydantic_core import (
+    PydanticCustomError,
+    SchemaError,
+    SchemaSerializer,
+    SchemaValidator,
+    ValidationError,
+    core_schema,
+    register_validator,
+)
+
+
+class MoneyValidator:
+    def __init__(self, currency):
+        self.currency = currency
+
+    def validate(self, input_value, strict, info):
+        if isinstance(input_value, Decimal):
+            return input_value, self.currency
+        if strict:
+            raise PydanticCustomError('money_type', 'Input should be a Decimal')
+        try:
+            return Decimal(str(input_value)), self.currency
+        except ArithmeticError:
+            raise PydanticCustomError('money_parsing', 'Input should be an amount of {currency}', {'currency': 'USD'})
+
+
+def build_money(schema, config):
+    return MoneyValidator(schema.get('currency', 'USD'))
+
+
+register_validator('money', build_money)
+
+
+def test_custom():
+    v = SchemaValidator({'type': 'money', 'currency': 'EUR'})
+    assert v.title == 'money'
+    assert v.validate_python('1.5') == (Decimal('1.5'), 'EUR')
+    assert v.validate_json('2') == (Decimal('2'), 'EUR')
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('x')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'money_parsing',
+            'loc': (),
+            'msg': 'Input should be an amount of USD',
+            'input': 'x',
+            'ctx': {'currency': 'USD'},
+        }
+    ]
+
+
+def test_custom_strict():
+    v = SchemaValidator({'type': 'money', 'strict': True})
+    assert v.validate_python(Decimal('1')) == (Decimal('1'), 'USD')
+    with pytest.raises(ValidationError, match='Input should be a Decimal'):
+        v.validate_python('1')
+
+    v = SchemaValidator({'type': 'money'}, {'strict': True})
+    with pytest.raises(ValidationError, match='Input should be a Decimal'):
+        v.validate_python('1')
+
+    v = SchemaValidator({'type': 'money'})
+    assert v.validate_python('1') == (Decimal('1'), 'USD')
+    with pytest.raises(ValidationError, match='Input should be a Decimal'):
+        v.validate_python('1', strict=True)
+
+
+def test_custom_composes():
+    discount_schema = core_schema.union_schema([core_schema.none_schema(), {'type': 'money'}])
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'prices': core_schema.typed_dict_field(core_schema.list_schema({'type': 'money'})),
+                'discount': core_schema.typed_dict_field(discount_schema),
+            }
+        )
+    )
+    assert v.validate_python({'prices': ['1', 2], 'discount': None}) == {
+        'prices': [(Decimal('1'), 'USD'), (Decimal('2'), 'USD')],
+        'discount': None,
+    }
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'prices': ['1', 'x'], 'discount': 'y'})
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [
+        ('money_parsing', ('prices', 1)),
+        ('none_required', ('discount', 'none')),
+        ('money_parsing', ('discount', 'money')),
+    ]
+
+    assert SchemaValidator(core_schema.list_schema({'type': 'money'})).title == 'list[money]'
+
+
+def test_custom_info():
+    calls = []
+
+    class Validator:
+        def validate(self, input_value, strict, info):
+            calls.append((input_value, strict, info.context, info.config['title'], info.mode))
+            return input_value
+
+    def build(schema, config):
+        calls.append((schema['type'], config))
+        return Validator()
+
+    register_validator('test-info', build)
+    v = SchemaValidator({'type': 'test-info'}, {'title': 'Test'})
+    assert calls == [('test-info', {'title': 'Test'})]
+    assert v.validate_json('1', context={'a': 1}) == 1
+    assert calls[1:] == [(1, False, {'a': 1}, 'Test', 'json')]
+
+
+def test_custom_serialization():
+    s = SchemaSerializer({'type': 'money'})
+    assert s.to_python(Decimal('1.5')) == Decimal('1.5')
+    assert s.to_json(Decimal('1.5')) == b'"1.5"'
+
+
+def test_custom_register_invalid():
+    with pytest.raises(ValueError, match=re.escape('Cannot register a validator for the built-in schema type "int"')):
+        register_validator('int', build_money)
+    with pytest.raises(TypeError, match='builder must be callable'):
+        register_validator('test-invalid', 1)
+
+
+def test_custom_build_errors():
+    def build_error(schema, config):
+        raise TypeError('bad schema')
+
+    register_validator('test-build-error', build_error)
+    with pytest.raises(SchemaError, match='Error building "test-build-error" validator:\n  TypeError: bad schema'):
+        SchemaValidator({'type': 'test-build-error'})
+
+    register_validator('test-no-validate', lambda schema, config: object())
+    with pytest.raises(SchemaError, match='validator must have a `validate` method'):
+        SchemaValidator({'type': 'test-no-validate'})
+
+
+def test_unregistered():
+    with pytest.raises(SchemaError, match="Input tag 'test-unregistered' found using 'type' does not match"):
+        SchemaValidator({'type': 'test-unregistered'})
@@ -4,7 +4,7 @@ import sys
 from typing import Any, ClassVar, Dict, List, Optional, Union
 
 import pytest
-from dirty_equals import IsListOrTuple, IsStr
+from dirty_equals import HasRepr, IsListOrTuple, IsStr
 
 from pydantic_core import ArgsKwargs, SchemaValidator, ValidationError, core_schema
 
@@ -1488,3 +1488,54 @@ def test_dataclass_json():
             'type': 'dataclass_type',
         }
     ]
+
+
+def test_dataclass_from_attributes_override():
+    class OrmFoo:
+        def __init__(self, **attributes):
+            self.__dict__.update(attributes)
+
+    class OrmBar:
+        @property
+        def a(self):
+            raise RuntimeError('intentional error')
+
+    schema = core_schema.dataclass_schema(
+        FooDataclass,
+        core_schema.dataclass_args_schema(
+            'FooDataclass',
+            [
+                core_schema.dataclass_field(name='a', schema=core_schema.str_schema()),
+                core_schema.dataclass_field(name='b', schema=core_schema.bool_schema()),
+            ],
+        ),
+        ['a', 'b'],
+    )
+    v = SchemaValidator(schema)
+
+    with pytest.raises(ValidationError, match='Input should be a dictionary or an instance of FooDataclass'):
+        v.validate_python(OrmFoo(a='hello', b=True))
+    foo = v.validate_python(OrmFoo(a='hello', b='true', c='ignored'), from_attributes=True)
+    assert dataclasses.asdict(foo) == {'a': 'hello', 'b': True}
+    assert v.validate_python({'a': 'hello', 'b': True}, from_attributes=True) == FooDataclass(a='hello', b=True)
+    assert v.validate_python(ArgsKwargs((), {'a': 'hello', 'b': True}), from_attributes=True) == FooDataclass(
+        a='hello', b=True
+    )
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(OrmFoo(a='hello'), from_attributes=True)
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'missing', 'loc': ('b',), 'msg': 'Field required', 'input': {'a': 'hello'}}
+    ]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(OrmBar(), from_attributes=True)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'get_attribute_error',
+            'loc': ('a',),
+            'msg': 'Error extracting attribute: RuntimeError: intentional error',
+            'input': HasRepr(IsStr(regex='.+OrmBar object at.+')),
+            'ctx': {'error': 'RuntimeError: intentional error'},
+        }
+    ]
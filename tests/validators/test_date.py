@@ -289,3 +289,68 @@ def test_date_past_future_today():
 def test_offset_too_large():
     with pytest.raises(SchemaError, match=r'Input should be less than 86400 \[type=less_than,'):
         SchemaValidator(core_schema.date_schema(now_op='past', now_utc_offset=24 * 3600))
+
+
+@pytest.mark.parametrize(
+    'numeric_mode,input_value,expected',
+    [
+        ('unix-seconds', 1672531200, date(2023, 1, 1)),
+        ('unix-ms', 1672531200000, date(2023, 1, 1)),
+        ('ordinal', 738521, date(2023, 1, 1)),
+        ('ordinal', 1, Err('dates before 1600 are not supported as unix timestamps (interpreted as ordinal)')),
+        (
+            'unix-seconds',
+            1672531201,
+            Err(
+                'Datetimes provided to dates should have zero time - e.g. be exact dates '
+                '[type=date_from_datetime_inexact'
+            ),
+        ),
+        (
+            'unix-ms',
+            1672531200001,
+            Err(
+                'Datetimes provided to dates should have zero time - e.g. be exact dates '
+                '[type=date_from_datetime_inexact'
+            ),
+        ),
+        (
+            'unix-ms',
+            10**18,
+            Err(
+                'Input should be a valid date or datetime, dates after 9999 are not supported as unix timestamps '
+                '(interpreted as unix-ms) [type=date_from_datetime_parsing'
+            ),
+        ),
+        ('reject', 1672531200, Err('Input should be a valid date [type=date_type')),
+        ('reject', '2023-01-01', date(2023, 1, 1)),
+        ('unix-seconds', '1672531200', date(2023, 1, 1)),
+        ('ordinal', '738521', date(2023, 1, 1)),
+        ('reject', '1672531200', Err('Input should be a valid date [type=date_type')),
+    ],
+)
+def test_date_numeric_mode(py_and_json: PyAndJson, numeric_mode, input_value, expected):
+    v = py_and_json(core_schema.date_schema(numeric_mode=numeric_mode))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_date_numeric_mode_strict():
+    v = SchemaValidator(core_schema.date_schema(strict=True, numeric_mode='unix-seconds'))
+    with pytest.raises(ValidationError, match=re.escape('Input should be a valid date [type=date_type')):
+        v.validate_python(1672531200)
+
+
+def test_date_numeric_mode_strict_override():
+    v = SchemaValidator(core_schema.date_schema(numeric_mode='unix-seconds'))
+    assert v.validate_python(1672531200) == date(2023, 1, 1)
+    with pytest.raises(ValidationError, match=re.escape('Input should be a valid date [type=date_type')):
+        v.validate_python(1672531200, strict=True)
+    with pytest.raises(ValidationError, match=re.escape('Input should be a valid date [type=date_type')):
+        v.validate_json('1672531200', strict=True)
+
+    v = SchemaValidator(core_schema.date_schema(strict=True, numeric_mode='unix-seconds'))
+    assert v.validate_python(1672531200, strict=False) == date(2023, 1, 1)
@@ -1,5 +1,6 @@
 import copy
 import json
+import pickle
 import platform
 import re
 from datetime import date, datetime, time, timedelta, timezone, tzinfo
@@ -8,7 +9,7 @@ from decimal import Decimal
 import pytest
 import pytz
 
-from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
+from pydantic_core import SchemaError, SchemaValidator, TzInfo, ValidationError, core_schema
 
 from ..conftest import Err, PyAndJson
 
@@ -205,6 +206,73 @@ def test_tz_info_copy():
     assert c == output
 
 
+
+def test_tz_info_cached():
+    v = SchemaValidator({'type': 'datetime'})
+    a = v.validate_python('2023-01-01T12:00:00+05:30')
+    b = v.validate_json('"2023-06-01T00:00:00+05:30"')
+    assert a.tzinfo is b.tzinfo
+    assert isinstance(a.tzinfo, TzInfo)
+    assert v.validate_python('2023-01-01T12:00:00+05:00').tzinfo is not a.tzinfo
+
+
+def test_tz_info_fixed_offset():
+    tz = SchemaValidator({'type': 'datetime'}).validate_python('2023-01-01T12:00:00+05:30').tzinfo
+    assert tz == timezone(timedelta(hours=5, minutes=30))
+    assert timezone(timedelta(hours=5, minutes=30)) == tz
+    assert tz != timezone.utc
+    assert hash(tz) == hash(timezone(timedelta(hours=5, minutes=30)))
+    assert tz.tzname(None) == '+05:30'
+    assert tz.utcoffset(None) == timedelta(hours=5, minutes=30)
+    assert tz.dst(None) is None
+    assert tz.fromutc(datetime(2023, 1, 1, 12, tzinfo=tz)) == datetime(2023, 1, 1, 17, 30, tzinfo=tz)
+
+
+def test_tz_info_astimezone():
+    v = SchemaValidator({'type': 'datetime'})
+    output = v.validate_python('2023-01-01T12:00:00+05:30')
+    utc = output.astimezone(timezone.utc)
+    assert utc == datetime(2023, 1, 1, 6, 30, tzinfo=timezone.utc)
+    round_trip = utc.astimezone(output.tzinfo)
+    assert round_trip == output
+    assert round_trip.tzinfo is output.tzinfo
+    assert (round_trip.hour, round_trip.minute) == (12, 0)
+
+
+@pytest.mark.parametrize('pickle_protocol', range(1, pickle.HIGHEST_PROTOCOL + 1))
+def test_tz_info_pickle(pickle_protocol):
+    output = SchemaValidator({'type': 'datetime'}).validate_python('2023-01-01T12:00:00-08:00')
+    tz = pickle.loads(pickle.dumps(output.tzinfo, protocol=pickle_protocol))
+    assert isinstance(tz, TzInfo)
+    assert repr(tz) == 'TzInfo(-08:00)'
+    assert pickle.loads(pickle.dumps(output, protocol=pickle_protocol)) == output
+
+
+def test_tz_info_invalid():
+    assert repr(TzInfo(-3600)) == 'TzInfo(-01:00)'
+    with pytest.raises(ValueError, match='TzInfo offset must be strictly between -86400 and 86400 seconds, not 86400'):
+        TzInfo(86_400)
+
+
+@pytest.mark.parametrize(
+    'offset',
+    [
+        timedelta(minutes=-30),
+        timedelta(hours=-1, minutes=-30),
+        timedelta(minutes=30),
+        timedelta(hours=-12, minutes=-15),
+        timedelta(hours=5, minutes=30, seconds=15),
+        timedelta(seconds=-15),
+    ],
+)
+def test_tz_info_name(offset):
+    tz = TzInfo(int(offset.total_seconds()))
+    # the same as `datetime.timezone`, without its 'UTC' prefix
+    expected = timezone(offset).tzname(None)[3:]
+    assert tz.tzname(None) == expected
+    assert str(tz) == expected
+
+
 def test_custom_tz():
     class CustomTz(tzinfo):
         def utcoffset(self, _dt):
@@ -476,3 +544,95 @@ def test_tz_constraint_too_high():
 def test_tz_constraint_wrong():
     with pytest.raises(SchemaError, match="Input should be 'aware' or 'naive"):
         SchemaValidator(core_schema.datetime_schema(tz_constraint='wrong'))
+
+
+@pytest.mark.parametrize(
+    'numeric_mode,input_value,expected',
+    [
+        ('unix-seconds', 1672531200, datetime(2023, 1, 1)),
+        ('unix-seconds', 1672531200.5, datetime(2023, 1, 1, 0, 0, 0, 500000)),
+        ('unix-seconds', 30_000_000_000, datetime(1970, 1, 1) + timedelta(seconds=30_000_000_000)),
+        ('unix-seconds', -86_400, datetime(1969, 12, 31)),
+        ('unix-ms', 1672531200000, datetime(2023, 1, 1)),
+        ('unix-ms', 1672531200, datetime(1970, 1, 20, 8, 35, 31, 200000)),
+        ('unix-ms', 1672531200123.5, datetime(2023, 1, 1, 0, 0, 0, 123500)),
+        ('ordinal', 738521, datetime(2023, 1, 1)),
+        ('ordinal', 738521.0, datetime(2023, 1, 1)),
+        ('unix-seconds', '2023-01-01T00:00:00', datetime(2023, 1, 1)),
+        (
+            'unix-seconds',
+            300_000_000_000,
+            Err(
+                'Input should be a valid datetime, dates after 9999 are not supported as unix timestamps '
+                '(interpreted as unix-seconds) [type=datetime_parsing'
+            ),
+        ),
+        (
+            'unix-ms',
+            -20_000_000_000_000,
+            Err(
+                'Input should be a valid datetime, dates before 1600 are not supported as unix timestamps '
+                '(interpreted as unix-ms) [type=datetime_parsing'
+            ),
+        ),
+        (
+            'ordinal',
+            738521.5,
+            Err(
+                'Input should be a valid datetime, ordinals must be whole numbers (interpreted as ordinal) '
+                '[type=datetime_parsing'
+            ),
+        ),
+        (
+            'ordinal',
+            0,
+            Err(
+                'Input should be a valid datetime, ordinals must be greater than zero (interpreted as ordinal) '
+                '[type=datetime_parsing'
+            ),
+        ),
+        ('reject', 1672531200, Err('Input should be a valid datetime [type=datetime_type')),
+        ('reject', 1672531200.5, Err('Input should be a valid datetime [type=datetime_type')),
+        ('reject', '2023-01-01T00:00:00', datetime(2023, 1, 1)),
+        ('unix-seconds', '1672531200.5', datetime(2023, 1, 1, 0, 0, 0, 500000)),
+        ('unix-ms', '1672531200', datetime(1970, 1, 20, 8, 35, 31, 200000)),
+        ('ordinal', '738521', datetime(2023, 1, 1)),
+        ('reject', '1672531200', Err('Input should be a valid datetime [type=datetime_type')),
+        ('reject', '-1.5', Err('Input should be a valid datetime [type=datetime_type')),
+    ],
+)
+def test_datetime_numeric_mode(py_and_json: PyAndJson, numeric_mode, input_value, expected):
+    v = py_and_json(core_schema.datetime_schema(numeric_mode=numeric_mode))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_datetime_numeric_mode_strict():
+    v = SchemaValidator(core_schema.datetime_schema(strict=True, numeric_mode='unix-ms'))
+    with pytest.raises(ValidationError, match=re.escape('Input should be a valid datetime [type=datetime_type')):
+        v.validate_python(1672531200000)
+    v = SchemaValidator(core_schema.datetime_schema(numeric_mode='unix-ms'))
+    with pytest.raises(ValidationError, match=re.escape('Input should be a valid datetime [type=datetime_type')):
+        v.validate_python(1672531200000, strict=True)
+
+
+def test_datetime_numeric_mode_bytes():
+    v = SchemaValidator(core_schema.datetime_schema(numeric_mode='unix-ms'))
+    assert v.validate_python(b'1672531200000') == datetime(2023, 1, 1)
+    v = SchemaValidator(core_schema.datetime_schema(numeric_mode='reject'))
+    with pytest.raises(ValidationError, match=re.escape('Input should be a valid datetime [type=datetime_type')):
+        v.validate_python(b'1672531200')
+
+
+def test_datetime_numeric_mode_bool():
+    v = SchemaValidator(core_schema.datetime_schema(numeric_mode='unix-seconds'))
+    with pytest.raises(ValidationError, match=re.escape('Input should be a valid datetime [type=datetime_type')):
+        v.validate_python(True)
+
+
+def test_datetime_invalid_numeric_mode():
+    with pytest.raises(SchemaError, match="Input should be 'unix-seconds', 'unix-ms', 'ordinal' or 'reject'"):
+        SchemaValidator({'type': 'datetime', 'numeric_mode': 'foobar'})
@@ -1,6 +1,6 @@
 import pytest
 
-from pydantic_core import SchemaError, SchemaValidator, core_schema
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
 
 from ..conftest import plain_repr
 
@@ -136,3 +136,68 @@ def test_use_after():
         )
     )
     assert v.validate_python(['1', '2']) == (1, 2)
+
+
+def test_shared_ref_not_recursive():
+    address_schema = core_schema.typed_dict_schema(
+        {
+            'street': core_schema.typed_dict_field(core_schema.str_schema()),
+            'country': core_schema.typed_dict_field(core_schema.definition_reference_schema('Country')),
+        },
+        ref='Address',
+    )
+    country_schema = core_schema.str_schema(to_upper=True, ref='Country')
+    v = SchemaValidator(
+        core_schema.definitions_schema(
+            core_schema.typed_dict_schema(
+                {
+                    f'address_{i}': core_schema.typed_dict_field(core_schema.definition_reference_schema('Address'))
+                    for i in range(20)
+                }
+            ),
+            [address_schema, country_schema],
+        )
+    )
+    assert 'recursive:true' not in plain_repr(v)
+    assert plain_repr(v).count('DefinitionRefValidator{validator_id:0,inner_name:"typed-dict",recursive:false}') == 20
+
+    data = {f'address_{i}': {'street': f'{i} Main St', 'country': 'gb'} for i in range(20)}
+    assert v.validate_python(data) == {
+        f'address_{i}': {'street': f'{i} Main St', 'country': 'GB'} for i in range(20)
+    }
+
+
+def test_recursive_and_shared_refs():
+    v = SchemaValidator(
+        core_schema.definitions_schema(
+            core_schema.definition_reference_schema('Branch'),
+            [
+                core_schema.typed_dict_schema(
+                    {
+                        'leaf': core_schema.typed_dict_field(core_schema.definition_reference_schema('Leaf')),
+                        'branches': core_schema.typed_dict_field(
+                            core_schema.list_schema(core_schema.definition_reference_schema('Branch'))
+                        ),
+                    },
+                    ref='Branch',
+                ),
+                core_schema.int_schema(ref='Leaf'),
+            ],
+        )
+    )
+    r = plain_repr(v)
+    assert r.startswith(
+        'SchemaValidator(title="typed-dict",schema_type="definitions",config=None,'
+        'validator=DefinitionRef(DefinitionRefValidator{'
+    )
+    assert 'inner_name:"typed-dict",recursive:true' in r
+    assert 'inner_name:"int",recursive:false' in r
+
+    assert v.validate_python({'leaf': '1', 'branches': [{'leaf': 2, 'branches': []}]}) == {
+        'leaf': 1,
+        'branches': [{'leaf': 2, 'branches': []}],
+    }
+    data = {'leaf': 1, 'branches': []}
+    data['branches'].append(data)
+    with pytest.raises(ValidationError, match='Recursion error - cyclic reference detected'):
+        v.validate_python(data)
@@ -1,8 +1,11 @@
+import json
+import platform
+import sys
 from dataclasses import dataclass
-from typing import List, Optional
+from typing import Any, List, Optional
 
 import pytest
-from dirty_equals import AnyThing, HasAttributes, IsList, IsPartialDict, IsStr, IsTuple
+from dirty_equals import AnyThing, HasAttributes, IsInt, IsList, IsPartialDict, IsStr, IsTuple
 
 from pydantic_core import SchemaError, SchemaValidator, ValidationError, __version__, core_schema
 
@@ -31,7 +34,8 @@ def test_branch_nullable():
 
     assert v.validate_python({'name': 'root'}) == {'name': 'root', 'sub_branch': None}
     assert plain_repr(v).startswith(
-        'SchemaValidator(title="typed-dict",validator=DefinitionRef(DefinitionRefValidator{'
+        'SchemaValidator(title="typed-dict",schema_type="typed-dict",config=None,'
+        'validator=DefinitionRef(DefinitionRefValidator{'
     )
     assert ',definitions=[TypedDict(TypedDictValidator{' in plain_repr(v)
 
@@ -373,6 +377,47 @@ def test_recursion_branch():
     ]
 
 
+def test_recursion_branch_deep():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'ref': 'Branch',
+            'fields': {
+                'name': {'type': 'typed-dict-field', 'schema': {'type': 'str'}},
+                'branch': {
+                    'type': 'typed-dict-field',
+                    'schema': {
+                        'type': 'default',
+                        'schema': {'type': 'nullable', 'schema': {'type': 'definition-ref', 'schema_ref': 'Branch'}},
+                        'default': None,
+                    },
+                },
+            },
+        }
+    )
+    # deeper than the recursion guard's inline storage
+    root = {'name': 'b0', 'branch': None}
+    b = root
+    for i in range(1, 40):
+        b['branch'] = {'name': f'b{i}', 'branch': None}
+        b = b['branch']
+    output = v.validate_python(root)
+    assert output == root
+    assert output is not root
+
+    b['branch'] = root['branch']['branch']
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(root)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'recursion_loop',
+            'loc': ('branch',) * 40,
+            'msg': 'Recursion error - cyclic reference detected',
+            'input': IsPartialDict(name='b2'),
+        }
+    ]
+
+
 def test_recursion_branch_from_attributes():
     v = SchemaValidator(
         {
@@ -739,18 +784,20 @@ def test_function_change_id(strict: bool):
     with pytest.raises(ValidationError) as exc_info:
         v.validate_python('start-0')
 
+    # each call creates a new input so this isn't a cycle, but the recursion limit stops it
     assert exc_info.value.errors(include_url=False) == [
         {
-            'type': 'recursion_loop',
+            'type': 'recursion_limit_exceeded',
             'loc': IsTuple(length=(1, 255)),
-            'msg': 'Recursion error - cyclic reference detected',
+            'msg': IsStr(regex=r'Recursion error - input is nested more than \d+ levels deep'),
             'input': IsStr(regex=r'f-\d+'),
+            'ctx': {'max_depth': IsInt()},
         }
     ]
 
 
 def test_many_uses_of_ref():
-    # check we can safely exceed BACKUP_GUARD_LIMIT without upsetting the backup recursion guard
+    # check we can safely exceed DEFAULT_RECURSION_LIMIT without upsetting the backup recursion guard
     v = SchemaValidator(
         {
             'type': 'typed-dict',
@@ -808,7 +855,8 @@ def test_error_inside_definition_wrapper():
     assert str(exc_info.value) == (
         'Field "sub_branch":\n'
         '  SchemaError: Error building "default" validator:\n'
-        "  SchemaError: 'default' and 'default_factory' cannot be used together"
+        "  SchemaError: 'default' and 'default_factory' cannot be used together\n"
+        '  [path=fields.sub_branch.schema, type=default]'
     )
 
 
@@ -913,3 +961,101 @@ def test_validate_assignment() -> None:
             'url': f'https://errors.pydantic.dev/{__version__}/v/dataclass_type',
         }
     ]
+
+
+def nested_list_schema() -> core_schema.DefinitionsSchema:
+    return core_schema.definitions_schema(
+        core_schema.definition_reference_schema('list'),
+        [core_schema.list_schema(core_schema.definition_reference_schema('list'), ref='list')],
+    )
+
+
+def nested_lists(depth: int) -> list:
+    value: list = []
+    for _ in range(depth - 1):
+        value = [value]
+    return value
+
+
+# the default limit is lower on PyPy and wasm
+DEFAULT_RECURSION_LIMIT = 123 if platform.python_implementation() == 'PyPy' or sys.platform == 'emscripten' else 255
+
+
+def test_recursion_limit_deep_input():
+    v = SchemaValidator(nested_list_schema())
+    assert v.validate_python(nested_lists(100)) == nested_lists(100)
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(nested_lists(300))
+    max_depth = DEFAULT_RECURSION_LIMIT
+    # a normal error, with the location of the item which was too deep
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'recursion_limit_exceeded',
+            'loc': (0,) * max_depth,
+            'msg': f'Recursion error - input is nested more than {max_depth} levels deep',
+            'input': nested_lists(300 - max_depth),
+            'ctx': {'max_depth': max_depth},
+        }
+    ]
+
+
+def test_recursion_limit_config():
+    v = SchemaValidator(nested_list_schema(), {'recursion_limit': 10})
+    assert v.validate_python(nested_lists(10)) == nested_lists(10)
+    assert v.validate_json(json.dumps(nested_lists(10))) == nested_lists(10)
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(nested_lists(11))
+    assert [(e['type'], len(e['loc'])) for e in exc_info.value.errors()] == [('recursion_limit_exceeded', 10)]
+
+    # JSON input has no identity, but its depth is still counted
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json(json.dumps(nested_lists(11)))
+    assert [(e['type'], len(e['loc'])) for e in exc_info.value.errors()] == [('recursion_limit_exceeded', 10)]
+
+
+def test_recursion_limit_max():
+    # the limit can't be raised above the default, deeper nesting could overflow the stack
+    with pytest.raises(SchemaError, match=f'`recursion_limit` must be at most {DEFAULT_RECURSION_LIMIT}, got 65535'):
+        SchemaValidator(nested_list_schema(), {'recursion_limit': 65535})
+
+    v = SchemaValidator(nested_list_schema(), {'recursion_limit': DEFAULT_RECURSION_LIMIT})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(nested_lists(60_000))
+    assert [(e['type'], len(e['loc'])) for e in exc_info.value.errors()] == [
+        ('recursion_limit_exceeded', DEFAULT_RECURSION_LIMIT)
+    ]
+
+
+def test_recursion_limit_siblings():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'value': core_schema.typed_dict_field(core_schema.int_schema()),
+                'children': core_schema.typed_dict_field(
+                    core_schema.list_schema(core_schema.definition_reference_schema('node'))
+                ),
+            },
+            ref='node',
+        ),
+        {'recursion_limit': 10},
+    )
+
+    def chain(depth: int, leaf_value: Any = 1) -> dict:
+        node = {'value': leaf_value, 'children': []}
+        for _ in range(depth - 1):
+            node = {'value': 1, 'children': [node]}
+        return node
+
+    # the depth is restored after both errors and the limit being exceeded, so later siblings
+    # are validated from the same depth
+    tree = {'value': 1, 'children': [chain(9, 'x'), chain(20), chain(9), chain(9, 'y'), chain(9)]}
+    for validate in (v.validate_python, lambda value: v.validate_json(json.dumps(value))):
+        with pytest.raises(ValidationError) as exc_info:
+            validate(tree)
+        assert [(e['type'], e['loc'][:2], len(e['loc'])) for e in exc_info.value.errors()] == [
+            ('int_parsing', ('children', 0), 2 * 9 + 1),
+            ('recursion_limit_exceeded', ('children', 1), 2 * 9 + 2),
+            ('int_parsing', ('children', 3), 2 * 9 + 1),
+        ]
@@ -102,6 +102,19 @@ def test_dict_any_value():
     assert v.validate_python({'1': 1, '2': 'a', '3': None}) == {'1': 1, '2': 'a', '3': None}
 
 
+def test_dict_any_keys_values():
+    v = SchemaValidator({'type': 'dict', 'max_length': 3})
+    input_value = {'1': 1, 2: 'a', None: [1, 2]}
+    output = v.validate_python(input_value)
+    assert output == input_value
+    assert output is not input_value
+    assert v.validate_python(OrderedDict(a=1)) == {'a': 1}
+    with pytest.raises(ValidationError, match='Dictionary should have at most 3 items after validation, not 4'):
+        v.validate_python({1: 1, 2: 2, 3: 3, 4: 4})
+    with pytest.raises(ValidationError, match='Input should be a valid dictionary'):
+        v.validate_python([(1, 2)])
+
+
 def test_mapping():
     class MyMapping(Mapping):
         def __init__(self, d):
@@ -179,15 +179,15 @@ def test_float_repr():
     v = SchemaValidator({'type': 'float'})
     assert (
         plain_repr(v)
-        == 'SchemaValidator(title="float",validator=Float(FloatValidator{strict:false,allow_inf_nan:true}),definitions=[])'  # noqa: E501
+        == 'SchemaValidator(title="float",schema_type="float",config=None,validator=Float(FloatValidator{strict:false,allow_inf_nan:true,allow_number_protocols:false}),definitions=[])'  # noqa: E501
     )
     v = SchemaValidator({'type': 'float', 'strict': True})
     assert (
         plain_repr(v)
-        == 'SchemaValidator(title="float",validator=Float(FloatValidator{strict:true,allow_inf_nan:true}),definitions=[])'  # noqa: E501
+        == 'SchemaValidator(title="float",schema_type="float",config=None,validator=Float(FloatValidator{strict:true,allow_inf_nan:true,allow_number_protocols:false}),definitions=[])'  # noqa: E501
     )
     v = SchemaValidator({'type': 'float', 'multiple_of': 7})
-    assert plain_repr(v).startswith('SchemaValidator(title="constrained-float",validator=ConstrainedFloat(')
+    assert plain_repr(v).startswith('SchemaValidator(title="constrained-float",schema_type="float",config=None,validator=ConstrainedFloat(')  # noqa: E501
 
 
 @pytest.mark.parametrize('input_value,expected', [(Decimal('1.23'), 1.23), (Decimal('1'), 1.0)])
@@ -325,3 +325,37 @@ def test_non_finite_constrained_float_values(input_value, allow_inf_nan, expecte
             v.validate_python(input_value)
     else:
         assert v.validate_python(input_value) == expected
+
+
+class IntLike:
+    def __init__(self, value):
+        self.value = value
+
+    def __int__(self):
+        return self.value
+
+
+def test_float_protocol_disabled():
+    v = SchemaValidator({'type': 'float'})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(IntLike(42))
+    assert exc_info.value.errors(include_url=False)[0]['type'] == 'float_type'
+
+
+def test_float_protocol_enabled():
+    v = SchemaValidator({'type': 'float'}, {'allow_number_protocols': True})
+    assert v.validate_python(IntLike(42)) == 42.0
+    assert v.validate_python('1.5') == 1.5
+
+    v = SchemaValidator({'type': 'float', 'strict': True}, {'allow_number_protocols': True})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(IntLike(42))
+    assert exc_info.value.errors(include_url=False)[0]['type'] == 'float_type'
+
+
+def test_float_protocol_constrained():
+    v = SchemaValidator({'type': 'float', 'lt': 10}, {'allow_number_protocols': True})
+    assert v.validate_python(IntLike(5)) == 5.0
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(IntLike(11))
+    assert exc_info.value.errors(include_url=False)[0]['type'] == 'less_than'
@@ -158,6 +158,11 @@ def generate_repeats():
         # length check after set creation
         ({'max_length': 3}, [1, 1, 2, 2, 3, 3], {1, 2, 3}),
         ({'max_length': 3}, generate_repeats(), {1, 2, 3}),
+        (
+            {'max_length': 5, 'items_schema': {'type': 'int'}},
+            [1, '1', 1.0, 2, '2', 2.0, 3, '3', 3.0, 1],
+            {1, 2, 3},
+        ),
         (
             {'max_length': 3},
             infinite_generator(),
@@ -246,8 +251,11 @@ def test_repr():
     assert plain_repr(v) == (
         'SchemaValidator('
         'title="frozenset[any]",'
+        'schema_type="frozenset",'
+        'config=None,'
         'validator=FrozenSet(FrozenSetValidator{'
-        'strict:true,item_validator:Any(AnyValidator),min_length:Some(42),max_length:None,'
+        'strict:true,item_validator:Any(AnyValidator),items_strict:None,min_length:Some(42),max_length:None,'
+        'unique_items_error:false,fail_fast:false,max_errors:None,hash_key:None,'
         'name:"frozenset[any]"'
         '}),definitions=[])'
     )
@@ -294,3 +302,10 @@ def test_frozenset_from_dict_items(input_value, items_schema, expected):
     output = v.validate_python(input_value)
     assert isinstance(output, frozenset)
     assert output == expected
+
+
+def test_frozenset_any_max_length():
+    v = SchemaValidator({'type': 'frozenset', 'max_length': 3})
+    assert v.validate_python([1, 1, '1', 1.0]) == {1, '1'}
+    with pytest.raises(ValidationError, match='Frozenset should have at most 3 items after validation, not 4'):
+        v.validate_python([1, 2, 3, 4])
@@ -938,3 +938,93 @@ def test_function_validation_info_mode():
     assert v.validate_json('1') == 1
     assert calls == ['json']
     calls.clear()
+
+
+def test_function_validation_info_index():
+    calls: list[Any] = []
+
+    def f(v: Any, info: core_schema.ValidationInfo) -> Any:
+        calls.append((v, info.index))
+        return v
+
+    func_schema = core_schema.general_before_validator_function(f, core_schema.int_schema())
+    v = SchemaValidator(core_schema.list_schema(func_schema))
+    assert v.validate_python(['1', '2', '3']) == [1, 2, 3]
+    assert calls == [('1', 0), ('2', 1), ('3', 2)]
+    calls.clear()
+    assert v.validate_json('[1, 2]') == [1, 2]
+    assert calls == [(1, 0), (2, 1)]
+    calls.clear()
+    assert v.validate_python(x for x in [3, 4]) == [3, 4]
+    assert calls == [(3, 0), (4, 1)]
+    calls.clear()
+
+    # outside a collection there's no index
+    v = SchemaValidator(func_schema)
+    assert v.validate_python(1) == 1
+    assert calls == [(1, None)]
+
+
+def test_function_validation_info_index_header():
+    def row(v: Any, info: core_schema.ValidationInfo) -> Any:
+        # the first row is the header, the rest are data
+        if info.index == 0:
+            return [str(cell) for cell in v]
+        return [int(cell) for cell in v]
+
+    v = SchemaValidator(core_schema.list_schema(core_schema.general_plain_validator_function(row)))
+    assert v.validate_python([['a', 'b'], ['1', '2'], ['3', '4']]) == [['a', 'b'], [1, 2], [3, 4]]
+
+
+def test_function_validation_info_index_nested():
+    calls: list[Any] = []
+
+    def f(v: Any, info: core_schema.ValidationInfo) -> Any:
+        calls.append((v, info.index))
+        return v
+
+    func_schema = core_schema.general_after_validator_function(f, core_schema.int_schema())
+    # each level of nesting gets its own index
+    v = SchemaValidator(
+        core_schema.list_schema(core_schema.general_after_validator_function(f, core_schema.list_schema(func_schema)))
+    )
+    assert v.validate_python([[1, 2], [3]]) == [[1, 2], [3]]
+    assert calls == [(1, 0), (2, 1), ([1, 2], 0), (3, 0), ([3], 1)]
+    calls.clear()
+
+    # values of a dict or fields of a typed dict within a list don't see the list's index
+    v = SchemaValidator(core_schema.list_schema(core_schema.dict_schema(values_schema=func_schema)))
+    assert v.validate_python([{'a': 1}, {'b': 2}]) == [{'a': 1}, {'b': 2}]
+    assert calls == [(1, None), (2, None)]
+    calls.clear()
+    v = SchemaValidator(
+        core_schema.list_schema(core_schema.typed_dict_schema({'x': core_schema.typed_dict_field(func_schema)}))
+    )
+    assert v.validate_python([{'x': 1}, {'x': 2}]) == [{'x': 1}, {'x': 2}]
+    assert calls == [(1, None), (2, None)]
+    calls.clear()
+
+    # tuples, sets and generators set the index too
+    v = SchemaValidator(core_schema.tuple_positional_schema([func_schema], extra_schema=func_schema))
+    assert v.validate_json('[4, 5, 6]') == (4, 5, 6)
+    assert calls == [(4, 0), (5, 1), (6, 2)]
+    calls.clear()
+    v = SchemaValidator(core_schema.frozenset_schema(func_schema))
+    assert v.validate_python([7]) == {7}
+    assert calls == [(7, 0)]
+    calls.clear()
+    v = SchemaValidator(core_schema.generator_schema(func_schema))
+    assert list(v.validate_python(iter([8, 9]))) == [8, 9]
+    assert calls == [(8, 0), (9, 1)]
+
+
+def test_function_validation_info_index_repr():
+    reprs: list[str] = []
+
+    def f(v: Any, info: core_schema.ValidationInfo) -> Any:
+        reprs.append(repr(info))
+        return v
+
+    v = SchemaValidator(core_schema.list_schema(core_schema.general_plain_validator_function(f)))
+    v.validate_python([1])
+    assert reprs == ['ValidationInfo(config=None, context=None, index=0)']
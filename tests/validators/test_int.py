@@ -333,11 +333,11 @@ def test_union_int_simple(py_and_json: PyAndJson):
 
 def test_int_repr():
     v = SchemaValidator({'type': 'int'})
-    assert plain_repr(v) == 'SchemaValidator(title="int",validator=Int(IntValidator{strict:false}),definitions=[])'
+    assert plain_repr(v) == 'SchemaValidator(title="int",schema_type="int",config=None,validator=Int(IntValidator{strict:false,allow_number_protocols:false}),definitions=[])'  # noqa: E501
     v = SchemaValidator({'type': 'int', 'strict': True})
-    assert plain_repr(v) == 'SchemaValidator(title="int",validator=Int(IntValidator{strict:true}),definitions=[])'
+    assert plain_repr(v) == 'SchemaValidator(title="int",schema_type="int",config=None,validator=Int(IntValidator{strict:true,allow_number_protocols:false}),definitions=[])'  # noqa: E501
     v = SchemaValidator({'type': 'int', 'multiple_of': 7})
-    assert plain_repr(v).startswith('SchemaValidator(title="constrained-int",validator=ConstrainedInt(')
+    assert plain_repr(v).startswith('SchemaValidator(title="constrained-int",schema_type="int",config=None,validator=ConstrainedInt(')  # noqa: E501
 
 
 def test_too_long():
@@ -403,3 +403,59 @@ def test_int_key(py_and_json: PyAndJson):
     assert v.validate_test({'1': 1, '2': 2}) == {1: 1, 2: 2}
     with pytest.raises(ValidationError, match='Input should be a valid integer'):
         v.validate_test({'1': 1, '2': 2}, strict=True)
+
+
+class IntLike:
+    def __init__(self, value):
+        self.value = value
+
+    def __int__(self):
+        return self.value
+
+
+def test_int_protocol_disabled():
+    v = SchemaValidator({'type': 'int'})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(IntLike(42))
+    assert exc_info.value.errors(include_url=False)[0]['type'] == 'int_type'
+
+
+def test_int_protocol_enabled():
+    v = SchemaValidator({'type': 'int'}, {'allow_number_protocols': True})
+    assert v.validate_python(IntLike(42)) == 42
+    assert v.validate_python(42) == 42
+    assert v.validate_python('42') == 42
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(Decimal('42.5'))
+    assert exc_info.value.errors(include_url=False)[0]['type'] == 'int_from_float'
+    # __int__ must return an int
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(IntLike('42'))
+    assert exc_info.value.errors(include_url=False)[0]['type'] == 'int_type'
+
+
+@pytest.mark.parametrize('schema_type', ['int', 'float'])
+def test_number_protocol_bool_result(schema_type):
+    v = SchemaValidator({'type': schema_type}, {'allow_number_protocols': True})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(IntLike(True))
+    assert exc_info.value.errors(include_url=False)[0]['type'] == f'{schema_type}_type'
+
+
+def test_int_protocol_strict():
+    v = SchemaValidator({'type': 'int', 'strict': True}, {'allow_number_protocols': True})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(IntLike(42))
+    assert exc_info.value.errors(include_url=False)[0]['type'] == 'int_type'
+
+    v = SchemaValidator({'type': 'int'}, {'allow_number_protocols': True})
+    with pytest.raises(ValidationError):
+        v.validate_python(IntLike(42), strict=True)
+
+
+def test_int_protocol_constrained():
+    v = SchemaValidator({'type': 'int', 'gt': 0, 'le': 10}, {'allow_number_protocols': True})
+    assert v.validate_python(IntLike(5)) == 5
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(IntLike(11))
+    assert exc_info.value.errors(include_url=False)[0]['type'] == 'less_than_equal'
@@ -1,4 +1,5 @@
 import typing
+from abc import ABC
 
 import pytest
 
@@ -97,6 +98,34 @@ def test_instancecheck():
         v.validate_python('error')
 
 
+def test_instancecheck_exact_type():
+    # exact instances pass without calling `__instancecheck__`, just like `isinstance`
+    instance = HasIsInstance()
+    assert isinstance(instance, HasIsInstance)
+    v = SchemaValidator({'type': 'is-instance', 'cls': HasIsInstance})
+    assert v.validate_python(instance) is instance
+
+
+def test_virtual_subclass():
+    class MyABC(ABC):
+        pass
+
+    class Virtual:
+        pass
+
+    MyABC.register(Virtual)
+
+    v = SchemaValidator({'type': 'is-instance', 'cls': MyABC})
+    virtual = Virtual()
+    assert v.validate_python(virtual) is virtual
+    with pytest.raises(ValidationError, match='type=is_instance_of'):
+        v.validate_python(Spam())
+
+    v = SchemaValidator({'type': 'is-instance', 'cls': Foo})
+    bar = Bar()
+    assert v.validate_python(bar) is bar
+
+
 def test_repr():
     v = SchemaValidator({'type': 'union', 'choices': [{'type': 'int'}, {'type': 'is-instance', 'cls': Foo}]})
     assert v.isinstance_python(4) is True
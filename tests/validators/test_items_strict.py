@@ -0,0 +1,146 @@
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+from ..conftest import PyAndJson
+
+collection_schemas = pytest.mark.parametrize(
+    'schema_func,output_type',
+    [
+        (core_schema.list_schema, list),
+        (core_schema.tuple_variable_schema, tuple),
+        (core_schema.set_schema, set),
+        (core_schema.frozenset_schema, frozenset),
+    ],
+)
+
+
+@collection_schemas
+@pytest.mark.parametrize('strict', [False, True])
+def test_strict_items(schema_func, output_type, strict):
+    v = SchemaValidator(schema_func(core_schema.int_schema(), strict=strict, items_strict=True))
+    assert v.validate_python(output_type([1, 2])) == output_type([1, 2])
+    assert v.validate_json('[1, 2]') == output_type([1, 2])
+
+    int_type_error = {'type': 'int_type', 'loc': (0,), 'msg': 'Input should be a valid integer', 'input': '1'}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(output_type(['1']))
+    assert exc_info.value.errors(include_url=False) == [int_type_error]
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('["1"]')
+    assert exc_info.value.errors(include_url=False) == [int_type_error]
+
+
+@collection_schemas
+@pytest.mark.parametrize('strict', [False, True])
+def test_lax_items(schema_func, output_type, strict):
+    v = SchemaValidator(schema_func(core_schema.int_schema(), strict=strict, items_strict=False))
+    assert v.validate_python(output_type(['1'])) == output_type([1])
+    assert v.validate_json('["1"]') == output_type([1])
+
+    int_parsing_error = {
+        'type': 'int_parsing',
+        'loc': (0,),
+        'msg': 'Input should be a valid integer, unable to parse string as an integer',
+        'input': 'x',
+    }
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(output_type(['x']))
+    assert exc_info.value.errors(include_url=False) == [int_parsing_error]
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('["x"]')
+    assert exc_info.value.errors(include_url=False) == [int_parsing_error]
+
+
+def test_container_strictness():
+    # `items_strict` doesn't change the strictness of the list itself
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema(), items_strict=True))
+    assert v.validate_python((1, 2)) == [1, 2]
+
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema(), strict=True, items_strict=False))
+    assert v.validate_python(['1']) == [1]
+    with pytest.raises(ValidationError, match=r'Input should be a valid list \[type=list_type'):
+        v.validate_python(('1',))
+
+
+def test_overrides_validation_strictness(py_and_json: PyAndJson):
+    v = py_and_json(core_schema.list_schema(core_schema.int_schema(), items_strict=False))
+    assert v.validate_test(['1'], strict=True) == [1]
+
+    v = py_and_json(core_schema.list_schema(core_schema.int_schema(), items_strict=True))
+    with pytest.raises(ValidationError, match=r'\[type=int_type,'):
+        v.validate_test(['1'], strict=False)
+
+
+def test_overrides_item_schema_strictness():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema(strict=True), items_strict=False))
+    assert v.validate_python(['1']) == [1]
+
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema(strict=False), items_strict=True))
+    with pytest.raises(ValidationError, match=r'\[type=int_type,'):
+        v.validate_python(['1'])
+
+
+def test_nested():
+    # `items_strict` applies to the whole of each item, including nested collections
+    v = SchemaValidator(core_schema.list_schema(core_schema.list_schema(core_schema.int_schema()), items_strict=True))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([[1], ('2',)])
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('list_type', (1,))]
+
+    # an inner `items_strict` takes precedence over an outer one
+    v = SchemaValidator(
+        core_schema.list_schema(
+            core_schema.list_schema(core_schema.int_schema(), items_strict=False), items_strict=True
+        )
+    )
+    assert v.validate_python([['1']]) == [[1]]
+
+
+def test_strict_union():
+    v = SchemaValidator(
+        core_schema.union_schema(
+            [core_schema.list_schema(core_schema.int_schema(), items_strict=False), core_schema.str_schema()],
+            strict=True,
+        )
+    )
+    assert v.validate_python(['1']) == [1]
+    assert v.validate_json('["1"]') == [1]
+
+
+def test_smart_union():
+    v = SchemaValidator(
+        core_schema.union_schema(
+            [
+                core_schema.list_schema(core_schema.int_schema(), items_strict=True),
+                core_schema.list_schema(core_schema.float_schema()),
+            ]
+        )
+    )
+    assert v.validate_python([1]) == [1]
+    # the first choice only accepts strict items, even in the lax pass
+    output = v.validate_python(['1'])
+    assert output == [1.0]
+    assert type(output[0]) is float
+
+    v = SchemaValidator(
+        core_schema.union_schema(
+            [
+                core_schema.list_schema(core_schema.float_schema()),
+                core_schema.list_schema(core_schema.int_schema(), items_strict=False),
+            ]
+        )
+    )
+    # the second choice coerces its items even in the strict pass, which comes before the first choice's lax pass
+    output = v.validate_python(['1'])
+    assert output == [1]
+    assert type(output[0]) is int
+
+
+def test_validate_iterated():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema(), items_strict=True))
+    iterated = v.validate_iterated()
+    iterated.feed([1, '2'])
+    with pytest.raises(ValidationError) as exc_info:
+        iterated.finish()
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('int_type', (1,))]
@@ -1,4 +1,6 @@
+import json
 import re
+import sys
 
 import pytest
 
@@ -148,3 +150,19 @@ def test_any_schema_no_schema():
     assert 'validator:None' in plain_repr(v)
     v = SchemaValidator(core_schema.json_schema(core_schema.int_schema()))
     assert 'validator:Some(' in plain_repr(v)
+
+
+@pytest.mark.skipif(sys.platform == 'emscripten', reason='threads are not supported on emscripten')
+def test_large_json_threads():
+    from concurrent.futures import ThreadPoolExecutor
+
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    payload = json.dumps(list(range(100_000)))
+    assert len(payload) > 100_000
+    with ThreadPoolExecutor(max_workers=4) as executor:
+        results = list(executor.map(v.validate_json, [payload, payload.encode(), bytearray(payload.encode()), payload]))
+    assert all(r == list(range(100_000)) for r in results)
+
+    # error positions are unaffected by releasing the GIL
+    with pytest.raises(ValidationError, match=r'Invalid JSON: trailing comma at line 1 column 688891'):
+        v.validate_json(payload[:-1] + ',]')
@@ -7,7 +7,7 @@ from typing import Any, Dict, Iterator, List, Union
 import pytest
 from dirty_equals import Contains, HasRepr, IsInstance, IsList, IsStr
 
-from pydantic_core import SchemaValidator, ValidationError, core_schema
+from pydantic_core import PydanticOmit, SchemaError, SchemaValidator, ValidationError, core_schema
 
 from ..conftest import Err, PyAndJson, infinite_generator
 
@@ -46,6 +46,82 @@ def test_list_no_copy():
     assert v.validate_python([1, 2, 3]) is not [1, 2, 3]
 
 
+class MyInt(int):
+    pass
+
+
+class MyStr(str):
+    pass
+
+
+@pytest.mark.parametrize(
+    'items_schema,input_value,expected',
+    [
+        (core_schema.int_schema(), [1, 2, 3], [1, 2, 3]),
+        (core_schema.int_schema(), [1, 2, '3'], [1, 2, 3]),
+        (core_schema.int_schema(), [1, True, 3], [1, 1, 3]),
+        (core_schema.int_schema(strict=True), [1, 2, 3], [1, 2, 3]),
+        (core_schema.int_schema(gt=1), [1, 2], Err('Input should be greater than 1 [type=greater_than,')),
+        (core_schema.str_schema(), ['a', 'b'], ['a', 'b']),
+        (core_schema.str_schema(to_upper=True), ['a', 'b'], ['A', 'B']),
+        (core_schema.float_schema(), [1.5, float('inf')], [1.5, float('inf')]),
+        (core_schema.float_schema(), [1.5, 2], [1.5, 2.0]),
+        (
+            core_schema.float_schema(allow_inf_nan=False),
+            [1.5, float('inf')],
+            Err('Input should be a finite number [type=finite_number,'),
+        ),
+        (core_schema.bool_schema(), [True, False], [True, False]),
+        (core_schema.bool_schema(), [True, 0], [True, False]),
+        (core_schema.none_schema(), [None, None], [None, None]),
+        (core_schema.none_schema(), [None, 0], Err('Input should be None [type=none_required,')),
+    ],
+    ids=repr,
+)
+def test_list_exact_items(items_schema, input_value, expected):
+    v = SchemaValidator(core_schema.list_schema(items_schema))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        output = v.validate_python(input_value)
+        assert output == expected
+        assert output is not input_value
+
+
+@pytest.mark.parametrize(
+    'items_schema,input_value,expected_type',
+    [(core_schema.int_schema(), [1, MyInt(2), 3], int), (core_schema.str_schema(), ['a', MyStr('b'), 'c'], str)],
+)
+def test_list_exact_items_subclass(items_schema, input_value, expected_type):
+    v = SchemaValidator(core_schema.list_schema(items_schema))
+    output = v.validate_python(input_value)
+    assert output == input_value
+    assert [type(item) for item in output] == [expected_type] * 3
+
+
+@pytest.mark.parametrize(
+    'kwargs,input_value,expected',
+    [
+        ({'min_length': 3}, [1, 2, 3], [1, 2, 3]),
+        ({'min_length': 3}, [1, 2], Err('List should have at least 3 items after validation, not 2 [type=too_short,')),
+        ({'max_length': 3}, [1, 2, 3], [1, 2, 3]),
+        (
+            {'max_length': 3},
+            [1, 2, 3, 4, 5],
+            Err('List should have at most 3 items after validation, not 4 [type=too_long,'),
+        ),
+    ],
+)
+def test_list_exact_items_length(kwargs: Dict[str, Any], input_value, expected):
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema(), **kwargs))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == expected
+
+
 def gen_ints():
     yield 1
     yield 2
@@ -79,6 +155,64 @@ def test_list_int(input_value, expected):
         assert v.validate_python(input_value) == expected
 
 
+class HintedIterator:
+    """Iterator with a length hint which may be wrong"""
+
+    def __init__(self, items, hint):
+        self.items = iter(items)
+        self.hint = hint
+
+    def __iter__(self):
+        return self
+
+    def __next__(self):
+        return next(self.items)
+
+    def __length_hint__(self):
+        if isinstance(self.hint, Exception):
+            raise self.hint
+        return self.hint
+
+
+@pytest.mark.parametrize('hint', [0, 1, 3, 100, 2**62, NotImplemented, -1, 'x', RuntimeError('broken hint')], ids=repr)
+@pytest.mark.parametrize('items_schema', [core_schema.int_schema(), core_schema.any_schema()], ids=repr)
+def test_list_length_hint(items_schema, hint):
+    v = SchemaValidator(core_schema.list_schema(items_schema))
+    assert v.validate_python(HintedIterator([1, 2, 3], hint)) == [1, 2, 3]
+    assert v.validate_python(iter(range(5))) == [0, 1, 2, 3, 4]
+
+    v = SchemaValidator(core_schema.list_schema(items_schema, max_length=2))
+    with pytest.raises(ValidationError, match='List should have at most 2 items after validation, not 3'):
+        v.validate_python(HintedIterator([1, 2, 3], hint))
+
+    v = SchemaValidator(core_schema.tuple_variable_schema(items_schema))
+    assert v.validate_python(HintedIterator([1, 2, 3], hint)) == (1, 2, 3)
+
+
+def test_list_length_hint_nested():
+    v = SchemaValidator(core_schema.list_schema(core_schema.list_schema(core_schema.int_schema())))
+    outer = HintedIterator([HintedIterator([1, '2'], 100), [3], HintedIterator([], 2)], 1)
+    assert v.validate_python(outer) == [[1, 2], [3], []]
+
+
+def test_nested_outputs():
+    # nested lists and tuples are each built from their own items
+    v = SchemaValidator(
+        core_schema.list_schema(
+            core_schema.tuple_variable_schema(core_schema.list_schema(core_schema.int_schema(), max_length=3))
+        )
+    )
+    outputs = [v.validate_python([(list(range(i % 3)),) * (i % 4)] * (i % 5)) for i in range(100)]
+    assert outputs == [[(list(range(i % 3)),) * (i % 4)] * (i % 5) for i in range(100)]
+    # outputs don't share any containers
+    assert len({id(o) for o in outputs}) == len(outputs)
+
+    with pytest.raises(ValidationError):
+        v.validate_python([([1, 2, 3, 4],)])
+    # a failed validation doesn't affect later calls
+    assert v.validate_python(iter([(), ([5],)])) == [(), ([5],)]
+
+
 def test_list_json():
     v = SchemaValidator({'type': 'list', 'items_schema': {'type': 'int'}})
     assert v.validate_json('[1, "2", 3]') == [1, 2, 3]
@@ -386,6 +520,201 @@ def test_max_length_fail_fast(error_in_func: bool) -> None:
     )
 
 
+@pytest.mark.parametrize(
+    'schema',
+    [
+        core_schema.list_schema(core_schema.int_schema(), max_length=10),
+        core_schema.list_schema(max_length=10),
+        core_schema.set_schema(core_schema.int_schema(), max_length=10),
+        core_schema.frozenset_schema(core_schema.int_schema(), max_length=10),
+        core_schema.tuple_variable_schema(core_schema.int_schema(), max_length=10),
+    ],
+    ids=['list', 'list-any', 'set', 'frozenset', 'tuple'],
+)
+def test_max_length_stops_iteration(schema):
+    consumed: list[int] = []
+
+    def gen():
+        for i in range(1_000_000):
+            consumed.append(i)
+            yield i
+
+    v = SchemaValidator(schema)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(gen())
+    assert [e['type'] for e in exc_info.value.errors()] == ['too_long']
+    # the input isn't consumed beyond the first item over the limit
+    assert len(consumed) == 11
+
+    consumed.clear()
+    assert v.isinstance_python(gen()) is False
+    assert len(consumed) == 11
+
+
+def test_max_length_omit_and_errors():
+    def omit_odd(v: int) -> int:
+        if v % 2:
+            raise PydanticOmit
+        return v
+
+    v = SchemaValidator(
+        core_schema.list_schema(
+            core_schema.no_info_after_validator_function(omit_odd, core_schema.int_schema()), max_length=3
+        )
+    )
+    # omitted items don't count towards the length
+    assert v.validate_python(range(6)) == [0, 2, 4]
+
+    # items which fail validation do count
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([0, 'x', 2, 'y'])
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('too_long', ())]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([0, 1, 'x', 3, 2])
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('int_parsing', (2,))]
+
+    # with fail-fast validation stops at the first error
+    assert v.isinstance_python([0, 'x', 2, 'y']) is False
+    assert v.isinstance_python([0, 1, 2, 3, 4, 5]) is True
+
+
+fail_fast_schemas = pytest.mark.parametrize(
+    'schema_func',
+    [core_schema.list_schema, core_schema.set_schema, core_schema.frozenset_schema, core_schema.tuple_variable_schema],
+    ids=['list', 'set', 'frozenset', 'tuple'],
+)
+
+
+def counting_gen(consumed: List[Any]) -> Iterator[Any]:
+    for item in [1, 'x', 3, 'y', 5]:
+        consumed.append(item)
+        yield item
+
+
+@fail_fast_schemas
+def test_fail_fast(schema_func):
+    consumed: List[Any] = []
+    v = SchemaValidator(schema_func(core_schema.int_schema(), fail_fast=True))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(counting_gen(consumed))
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('int_parsing', (1,))]
+    # the input isn't consumed beyond the first invalid item
+    assert consumed == [1, 'x']
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('[1, "x", 3, "y"]')
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('int_parsing', (1,))]
+
+
+@fail_fast_schemas
+def test_fail_fast_default(schema_func):
+    consumed: List[Any] = []
+    v = SchemaValidator(schema_func(core_schema.int_schema()))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(counting_gen(consumed))
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('int_parsing', (1,)), ('int_parsing', (3,))]
+    assert consumed == [1, 'x', 3, 'y', 5]
+
+
+@fail_fast_schemas
+def test_fail_fast_config(schema_func):
+    v = SchemaValidator(schema_func(core_schema.int_schema()), {'fail_fast': True})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([1, 'x', 3, 'y'])
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('int_parsing', (1,))]
+
+    # the schema takes precedence over config
+    v = SchemaValidator(schema_func(core_schema.int_schema(), fail_fast=False), {'fail_fast': True})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([1, 'x', 3, 'y'])
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('int_parsing', (1,)), ('int_parsing', (3,))]
+
+
+@fail_fast_schemas
+def test_max_errors(schema_func):
+    consumed: List[Any] = []
+
+    def gen():
+        for i in range(1_000_000):
+            consumed.append(i)
+            yield 'x'
+
+    v = SchemaValidator(schema_func(core_schema.int_schema(), max_errors=3))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(gen())
+    errors = exc_info.value.errors(include_url=False)
+    assert [(e['type'], e['loc']) for e in errors] == [
+        ('int_parsing', (0,)),
+        ('int_parsing', (1,)),
+        ('int_parsing', (2,)),
+        ('too_many_errors', ()),
+    ]
+    assert errors[-1]['msg'] == 'Validation stopped after 3 errors, remaining items were not checked'
+    # the number of unchecked items isn't known without consuming the rest of the generator
+    assert errors[-1]['ctx'] == {'max_errors': 3}
+    # the input is only consumed up to the item after the limit was reached, which shows there are items left
+    assert len(consumed) == 4
+
+    # valid items don't count towards the limit
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('[1, "a", 2, "b", 3, "c", 4, "d"]')
+    errors = exc_info.value.errors()
+    assert [(e['type'], e['loc']) for e in errors] == [
+        ('int_parsing', (1,)),
+        ('int_parsing', (3,)),
+        ('int_parsing', (5,)),
+        ('too_many_errors', ()),
+    ]
+    assert errors[-1]['ctx'] == {'max_errors': 3, 'unchecked_items': 2}
+
+    # reaching the limit on the last item doesn't stop validation early
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([1, 'a', 'b', 'c'])
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [
+        ('int_parsing', (1,)),
+        ('int_parsing', (2,)),
+        ('int_parsing', (3,)),
+    ]
+
+    # fewer errors than the limit are reported as usual
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([1, 'a', 2, 'b'])
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('int_parsing', (1,)), ('int_parsing', (3,))]
+
+
+def test_max_errors_nested():
+    # an item with several errors can't push the list over the limit
+    v = SchemaValidator(
+        core_schema.list_schema(core_schema.tuple_variable_schema(core_schema.int_schema()), max_errors=2)
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([('a', 'b', 'c'), ('d',)])
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [
+        ('int_parsing', (0, 0)),
+        ('int_parsing', (0, 1)),
+        ('too_many_errors', ()),
+    ]
+
+
+def test_max_errors_config():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()), {'max_errors': 1})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(['a', 'b'])
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('int_parsing', (0,)), ('too_many_errors', ())]
+    assert exc_info.value.errors()[-1]['ctx'] == {'max_errors': 1, 'unchecked_items': 1}
+
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema(), max_errors=2), {'max_errors': 1})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(['a', 'b', 'c'])
+    assert [e['type'] for e in exc_info.value.errors()] == ['int_parsing', 'int_parsing', 'too_many_errors']
+
+
+def test_max_errors_invalid():
+    with pytest.raises(SchemaError, match='`max_errors` must be greater than 0'):
+        SchemaValidator(core_schema.list_schema(max_errors=0))
+
+
 class MySequence(collections.abc.Sequence):
     def __init__(self, data: List[Any]):
         self._data = data
@@ -1,3 +1,4 @@
+import json
 import re
 from enum import Enum
 from typing import Any, Callable, List
@@ -100,6 +101,29 @@ from ..conftest import Err, PyAndJson, plain_repr
             ),
             id='wrong-str-int',
         ),
+        ([True], True, True),
+        pytest.param(
+            [True],
+            1,
+            Err('Input should be True [type=literal_error, input_value=1, input_type=int]'),
+            id='wrong-bool-int',
+        ),
+        pytest.param(
+            [1],
+            True,
+            Err('Input should be 1 [type=literal_error, input_value=True, input_type=bool]'),
+            id='wrong-int-bool',
+        ),
+        pytest.param(
+            [0, 'x'],
+            False,
+            Err("Input should be 0 or 'x' [type=literal_error, input_value=False, input_type=bool]"),
+            id='wrong-int-str-bool',
+        ),
+        ([True, 1], 1, 1),
+        ([True, 1], True, True),
+        ([1, True, False, 0], False, False),
+        ([1, True, False, 0], 0, 0),
     ],
 )
 def test_literal_py_and_json(py_and_json: PyAndJson, kwarg_expected, input_value, expected):
@@ -378,3 +402,49 @@ def test_mix_str_enum_with_str(reverse: Callable[[List[Any]], List[Any]], err: A
     with pytest.raises(ValidationError) as exc_info:
         v.validate_python('bar_val')
     assert exc_info.value.errors(include_url=False) == err
+
+
+def test_str_subclass():
+    class MyStr(str):
+        pass
+
+    class Foo(str, Enum):
+        foo = 'foo_val'
+
+    v = SchemaValidator(core_schema.literal_schema(['foo_val', 'bar_val']))
+    output = v.validate_python(MyStr('foo_val'))
+    assert output == 'foo_val' and type(output) is str
+    assert v.validate_python(Foo.foo) == 'foo_val'
+
+    with pytest.raises(ValidationError, match=re.escape("Input should be 'foo_val' or 'bar_val'")):
+        v.validate_python(MyStr('spam'))
+
+
+def test_large_literal():
+    expected = [f'value_{i}' for i in range(500)] + [1, True, b'bytes', None]
+    v = SchemaValidator(core_schema.literal_schema(expected))
+    for value in expected:
+        assert v.validate_python(value) == value
+    assert v.validate_json('"value_499"') == 'value_499'
+    assert v.validate_json('true') is True
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(0)
+    # expected values are listed in the order they were given
+    expected_repr = ', '.join(repr(e) for e in expected[:-1]) + ' or None'
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'literal_error',
+            'loc': (),
+            'msg': f'Input should be {expected_repr}',
+            'input': 0,
+            'ctx': {'expected': expected_repr},
+        }
+    ]
+
+
+@pytest.mark.parametrize('expected', [None, True, False])
+def test_literal_singletons(expected):
+    v = SchemaValidator(core_schema.literal_schema([None, True, False]))
+    assert v.validate_python(expected) is expected
+    assert v.validate_json(json.dumps(expected)) is expected
@@ -26,7 +26,9 @@ def test_model_class():
             ),
         )
     )
-    assert repr(v).startswith('SchemaValidator(title="MyModel", validator=Model(\n')
+    assert v.debug_repr().startswith(
+        'SchemaValidator(title="MyModel", schema_type="model", config=None, validator=Model(\n'
+    )
     m = v.validate_python({'field_a': 'test', 'field_b': 12})
     assert isinstance(m, MyModel)
     assert m.field_a == 'test'
@@ -44,6 +46,38 @@ def test_model_class():
     assert m2.__dict__ == {'field_a': 'test', 'field_b': 12}
 
 
+def test_model_nested_strict_override():
+    class Inner:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    class Outer:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    inner_schema = core_schema.model_schema(
+        Inner, core_schema.model_fields_schema({'x': core_schema.model_field(core_schema.int_schema())})
+    )
+    v = SchemaValidator(
+        core_schema.model_schema(
+            Outer,
+            core_schema.model_fields_schema(
+                {'inner': core_schema.model_field(core_schema.list_schema(inner_schema))}
+            ),
+        )
+    )
+    m = v.validate_python({'inner': [{'x': '1'}]})
+    assert m.inner[0].x == 1
+
+    m = v.validate_python({'inner': [{'x': 1}]}, strict=True)
+    assert m.inner[0].x == 1
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'inner': [{'x': '1'}]}, strict=True)
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'int_type', 'loc': ('inner', 0, 'x'), 'msg': 'Input should be a valid integer', 'input': '1'}
+    ]
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_json('{"inner": [{"x": "1"}]}', strict=True)
+
+
 def test_model_class_extra():
     class MyModel:
         # this is not required, but it avoids `__pydantic_fields_set__` being included in `__dict__`
@@ -420,6 +454,34 @@ def test_model_class_instance_direct():
     assert m3.field_a == 'init'
 
 
+def test_model_class_instancecheck():
+    class InstanceCheckMeta(type):
+        def __instancecheck__(cls, instance) -> bool:
+            return hasattr(instance, 'field_a')
+
+    class MyModel(metaclass=InstanceCheckMeta):
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+        field_a: str
+
+    class Other:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    v = SchemaValidator(
+        core_schema.model_schema(
+            MyModel, core_schema.model_fields_schema({'field_a': core_schema.model_field(core_schema.str_schema())})
+        )
+    )
+    m = v.validate_python({'field_a': 'test'})
+    assert v.validate_python(m) is m
+
+    # not a subclass, but passes `__instancecheck__`, so it's used as an instance
+    other = Other()
+    other.field_a = 'other'
+    other.__pydantic_fields_set__ = {'field_a'}
+    other.__pydantic_extra__ = None
+    assert v.validate_python(other) is other
+
+
 def test_model_class_instance_subclass():
     post_init_calls = []
 
@@ -543,7 +605,7 @@ def test_model_class_strict():
             },
         }
     )
-    assert re.search(r'revalidate: \w+', repr(v)).group(0) == 'revalidate: Never'
+    assert re.search(r'revalidate: \w+', v.debug_repr()).group(0) == 'revalidate: Never'
     m = MyModel()
     m2 = v.validate_python(m)
     assert isinstance(m, MyModel)
@@ -639,7 +701,7 @@ def test_revalidate_always():
             },
         }
     )
-    assert re.search(r'revalidate: \w+', repr(v)).group(0) == 'revalidate: Always'
+    assert re.search(r'revalidate: \w+', v.debug_repr()).group(0) == 'revalidate: Always'
 
     m = v.validate_python({'field_a': 'test', 'field_b': 12})
     assert isinstance(m, MyModel)
@@ -822,7 +884,7 @@ def test_revalidate_post_init():
             'config': {'revalidate_instances': 'always'},
         }
     )
-    assert re.search(r'revalidate: \w+', repr(v)).group(0) == 'revalidate: Always'
+    assert re.search(r'revalidate: \w+', v.debug_repr()).group(0) == 'revalidate: Always'
 
     m = v.validate_python({'field_a': 'test', 'field_b': 12})
     assert isinstance(m, MyModel)
@@ -3,6 +3,7 @@ import re
 import sys
 from dataclasses import dataclass
 from datetime import datetime
+from types import MappingProxyType
 from typing import Any, Dict, List, Mapping, Union
 
 import pytest
@@ -663,9 +664,11 @@ def test_aliases_debug():
             },
         }
     )
-    print(repr(v))
-    assert repr(v).startswith('SchemaValidator(title="model-fields", validator=ModelFields(')
-    assert 'PathChoices(' in repr(v)
+    print(v.debug_repr())
+    assert v.debug_repr().startswith(
+        'SchemaValidator(title="model-fields", schema_type="model-fields", config=None, validator=ModelFields('
+    )
+    assert 'PathChoices(' in v.debug_repr()
 
 
 def get_int_key():
@@ -1777,3 +1780,201 @@ def test_extra_behavior_ignore(config: Union[core_schema.CoreConfig, None], sche
         }
     ]
     assert 'not_f' not in m
+
+
+def test_dataclass_input_without_from_attributes():
+    @dataclass
+    class MyDataclass:
+        a: int
+        b: str
+        c: float = 1.5
+
+    v = SchemaValidator(
+        {
+            'type': 'model-fields',
+            'fields': {
+                'a': {'type': 'model-field', 'schema': {'type': 'int'}},
+                'b': {'type': 'model-field', 'schema': {'type': 'str'}},
+            },
+        }
+    )
+    assert v.validate_python(MyDataclass(a='1', b='x')) == ({'a': 1, 'b': 'x'}, None, {'a', 'b'})
+
+    v = SchemaValidator(
+        {
+            'type': 'model-fields',
+            'fields': {'a': {'type': 'model-field', 'schema': {'type': 'int'}}},
+            'extra_behavior': 'allow',
+        }
+    )
+    assert v.validate_python(MyDataclass(a=1, b='x')) == ({'a': 1}, {'b': 'x', 'c': 1.5}, {'a', 'b', 'c'})
+
+    # strict mode still requires a dict
+    with pytest.raises(ValidationError, match=r'Input should be a valid dictionary or instance of Model \[type=model_type'):
+        v.validate_python(MyDataclass(a=1, b='x'), strict=True)
+
+
+class MappingWithAttributes(Mapping):
+    def __init__(self, data, **attributes):
+        self._data = data
+        for k, v in attributes.items():
+            setattr(self, k, v)
+
+    def __getitem__(self, key):
+        return self._data[key]
+
+    def __iter__(self):
+        return iter(self._data)
+
+    def __len__(self):
+        return len(self._data)
+
+
+# supports item access without being a mapping, like a database row
+class ItemsWithAttributes:
+    def __init__(self, data, **attributes):
+        self._data = data
+        for k, v in attributes.items():
+            setattr(self, k, v)
+
+    def __getitem__(self, key):
+        return self._data[key]
+
+
+@pytest.mark.parametrize('lookup_mode', ['mapping-then-attributes', 'attributes-then-mapping'])
+def test_lookup_mode(lookup_mode):
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            {
+                'a': core_schema.model_field(core_schema.int_schema()),
+                'b': core_schema.model_field(core_schema.int_schema()),
+            },
+            lookup_mode=lookup_mode,
+        )
+    )
+    # the same schema validates dicts, objects and a mix of the two
+    assert v.validate_python({'a': 1, 'b': 2}) == ({'a': 1, 'b': 2}, None, {'a', 'b'})
+    assert v.validate_python(Cls(a=1, b=2)) == ({'a': 1, 'b': 2}, None, {'a', 'b'})
+    assert v.validate_python(ItemsWithAttributes({'a': 1}, b=2)) == ({'a': 1, 'b': 2}, None, {'a', 'b'})
+    assert v.validate_python(MappingWithAttributes({'a': 1, 'b': 2})) == ({'a': 1, 'b': 2}, None, {'a', 'b'})
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(ItemsWithAttributes({'a': 1}))
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'missing', 'loc': ('b',), 'msg': 'Field required', 'input': HasRepr(IsStr())}
+    ]
+
+
+def test_lookup_mode_order():
+    fields = {'a': core_schema.model_field(core_schema.int_schema())}
+    value = ItemsWithAttributes({'a': 1}, a=2)
+
+    v = SchemaValidator(core_schema.model_fields_schema(fields, lookup_mode='mapping-then-attributes'))
+    assert v.validate_python(value) == ({'a': 1}, None, {'a'})
+
+    v = SchemaValidator(core_schema.model_fields_schema(fields, lookup_mode='attributes-then-mapping'))
+    assert v.validate_python(value) == ({'a': 2}, None, {'a'})
+
+
+def test_lookup_mode_mapping_attributes_not_read():
+    class MyDict(dict):
+        a = 1
+
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            {'a': core_schema.model_field(core_schema.int_schema())}, lookup_mode='attributes-then-mapping'
+        )
+    )
+    with pytest.raises(ValidationError, match=r'a\n  Field required \[type=missing'):
+        v.validate_python(MyDict())
+    with pytest.raises(ValidationError, match=r'a\n  Field required \[type=missing'):
+        v.validate_python(MappingWithAttributes({}, a=1))
+    # nor from other mappings, even in strict mode where they're validated from attributes
+    with pytest.raises(ValidationError, match=r'a\n  Field required \[type=missing'):
+        v.validate_python(MappingWithAttributes({}, a=1), strict=True)
+
+    # a field named after a mapping method doesn't find the method
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            {'items': core_schema.model_field(core_schema.any_schema())}, lookup_mode='mapping-then-attributes'
+        )
+    )
+    with pytest.raises(ValidationError, match=r'items\n  Field required \[type=missing'):
+        v.validate_python(MappingProxyType({'a': 1}))
+    assert v.validate_python(MappingProxyType({'items': 1})) == ({'items': 1}, None, {'items'})
+
+
+def test_lookup_mode_alias_choices():
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            {'a': core_schema.model_field(core_schema.int_schema(), validation_alias=[['x'], ['y']])},
+            lookup_mode='mapping-then-attributes',
+        )
+    )
+    # all alias choices are tried with item access before attribute access
+    assert v.validate_python(ItemsWithAttributes({'y': 1}, x=2)) == ({'a': 1}, None, {'a'})
+    assert v.validate_python(ItemsWithAttributes({}, y=2)) == ({'a': 2}, None, {'a'})
+    with pytest.raises(ValidationError, match=r'x\n  Field required \[type=missing'):
+        v.validate_python(ItemsWithAttributes({}))
+
+
+def test_lookup_mode_attribute_error():
+    class Foobar:
+        @property
+        def a(self):
+            raise RuntimeError('intentional error')
+
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            {'a': core_schema.model_field(core_schema.int_schema())}, lookup_mode='mapping-then-attributes'
+        )
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(Foobar())
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'get_attribute_error',
+            'loc': ('a',),
+            'msg': 'Error extracting attribute: RuntimeError: intentional error',
+            'input': HasRepr(IsStr()),
+            'ctx': {'error': 'RuntimeError: intentional error'},
+        }
+    ]
+
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            {'a': core_schema.model_field(core_schema.int_schema())}, lookup_mode='attributes-then-mapping'
+        )
+    )
+
+    class FoobarItems(ItemsWithAttributes):
+        @property
+        def a(self):
+            raise RuntimeError('intentional error')
+
+    # the attribute error is only reported if item access finds nothing
+    assert v.validate_python(FoobarItems({'a': 1})) == ({'a': 1}, None, {'a'})
+    with pytest.raises(ValidationError, match='intentional error'):
+        v.validate_python(Foobar())
+
+
+@pytest.mark.parametrize('lookup_mode', ['mapping-then-attributes', 'attributes-then-mapping'])
+def test_lookup_mode_from_attributes_false(lookup_mode):
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            {'a': core_schema.model_field(core_schema.int_schema())}, lookup_mode=lookup_mode
+        )
+    )
+    # `from_attributes=False` on the call overrides the attribute access implied by `lookup_mode`
+    with pytest.raises(ValidationError, match=r'Input should be a valid dictionary or instance of Model \[type=model_type'):
+        v.validate_python(Cls(a=1), from_attributes=False)
+    assert v.validate_python(MappingWithAttributes({'a': 1}, a=2), from_attributes=False) == ({'a': 1}, None, {'a'})
+    with pytest.raises(ValidationError, match=r'a\n  Field required \[type=missing'):
+        v.validate_python(MappingWithAttributes({}, a=2), from_attributes=False)
+    # `from_attributes=True` is the same as leaving it unset
+    assert v.validate_python(Cls(a=1), from_attributes=True) == ({'a': 1}, None, {'a'})
+
+
+def test_invalid_lookup_mode():
+    with pytest.raises(SchemaError, match="Input should be 'mapping-then-attributes' or 'attributes-then-mapping'"):
+        SchemaValidator({'type': 'model-fields', 'fields': {}, 'lookup_mode': 'foobar'})
@@ -13,7 +13,9 @@ def test_model_root():
     v = SchemaValidator(
         core_schema.model_schema(RootModel, core_schema.list_schema(core_schema.int_schema()), root_model=True)
     )
-    assert repr(v).startswith('SchemaValidator(title="RootModel", validator=Model(\n')
+    assert v.debug_repr().startswith(
+        'SchemaValidator(title="RootModel", schema_type="model", config=None, validator=Model(\n'
+    )
 
     m = v.validate_python([1, 2, '3'])
     assert isinstance(m, RootModel)
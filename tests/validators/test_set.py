@@ -4,7 +4,7 @@ from typing import Any, Dict
 
 import pytest
 
-from pydantic_core import SchemaValidator, ValidationError
+from pydantic_core import SchemaValidator, ValidationError, core_schema
 
 from ..conftest import Err, PyAndJson, infinite_generator
 
@@ -138,6 +138,11 @@ def generate_repeats():
         # length check after set creation
         ({'max_length': 3}, [1, 1, 2, 2, 3, 3], {1, 2, 3}),
         ({'max_length': 3}, generate_repeats(), {1, 2, 3}),
+        (
+            {'max_length': 5, 'items_schema': {'type': 'int'}},
+            [1, '1', 1.0, 2, '2', 2.0, 3, '3', 3.0, 1],
+            {1, 2, 3},
+        ),
         (
             {'max_length': 3},
             infinite_generator(),
@@ -277,3 +282,262 @@ def test_set_any(input_value, expected):
     output = v.validate_python(input_value)
     assert output == expected
     assert isinstance(output, set)
+
+
+def test_set_any_max_length():
+    v = SchemaValidator({'type': 'set', 'max_length': 3})
+    assert v.validate_python([1, 1, '1', 1.0]) == {1, '1'}
+    with pytest.raises(ValidationError, match='Set should have at most 3 items after validation, not 4'):
+        v.validate_python([1, 2, 3, 4])
+
+
+@pytest.mark.parametrize('schema_type', ['set', 'frozenset'])
+@pytest.mark.parametrize('strict', [False, True])
+def test_unhashable_items(schema_type, strict):
+    # the items are hashable, but the validated items aren't
+    items_schema = core_schema.no_info_plain_validator_function(lambda x: [x])
+    v = SchemaValidator({'type': schema_type, 'items_schema': items_schema, 'strict': strict})
+    input_value = {'set': set, 'frozenset': frozenset}[schema_type]([1, 2]) if strict else [1, 2]
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(input_value)
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'unhashable_item', 'loc': (0,), 'msg': 'Set items should be hashable', 'input': 1},
+        {'type': 'unhashable_item', 'loc': (1,), 'msg': 'Set items should be hashable', 'input': 2},
+    ]
+
+
+def test_unhashable_items_json(py_and_json: PyAndJson):
+    v = py_and_json({'type': 'set', 'items_schema': {'type': 'list'}})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test([[1], [2]])
+    assert [(e['type'], e['loc'], e['input']) for e in exc_info.value.errors()] == [
+        ('unhashable_item', (0,), [1]),
+        ('unhashable_item', (1,), [2]),
+    ]
+
+
+def test_unhashable_items_any(py_and_json: PyAndJson):
+    v = py_and_json({'type': 'set'})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test([1, [2], {'a': 3}])
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [
+        ('unhashable_item', (1,)),
+        ('unhashable_item', (2,)),
+    ]
+
+
+def test_unhashable_items_fail_fast():
+    v = SchemaValidator(core_schema.set_schema(core_schema.list_schema()))
+    assert v.isinstance_python([[1], [2]]) is False
+    assert v.isinstance_python([(1,)]) is False
+    assert SchemaValidator(core_schema.set_schema(core_schema.tuple_variable_schema())).isinstance_python([[1]])
+
+
+@pytest.mark.parametrize(
+    'kwargs,input_value,expected',
+    [
+        ({'min_length': 3}, [1, 2, 3], {1, 2, 3}),
+        ({'min_length': 3}, [1, 1, 2, 2], Err('Set should have at least 3 items after validation, not 2')),
+        ({'min_length': 3, 'unique_items_error': False}, [1, 1, 2], Err('[type=too_short,')),
+        (
+            {'min_length': 3, 'unique_items_error': True},
+            [1, 1, 2, 2],
+            Err('Set should have at least 3 unique items after validation, not 2 [type=duplicate_items,'),
+        ),
+        # too few items even with duplicates
+        ({'min_length': 3, 'unique_items_error': True}, [1, 1], Err('[type=too_short,')),
+        ({'min_length': 3, 'unique_items_error': True}, [1, '1', 2], Err('[type=duplicate_items,')),
+        ({'min_length': 3, 'unique_items_error': True}, [1, 2, 3, 3], {1, 2, 3}),
+        ({'unique_items_error': True}, [1, 1], {1}),
+        # max_length counts items after removing duplicates
+        ({'max_length': 2, 'unique_items_error': True}, [1, 1, 2, 2], {1, 2}),
+        ({'max_length': 2}, [1, 1, 2, 3], Err('Set should have at most 2 items after validation, not 3')),
+    ],
+)
+def test_unique_items_error(kwargs, input_value, expected):
+    for schema_type, field_type, output_type in [('set', 'Set', set), ('frozenset', 'Frozenset', frozenset)]:
+        v = SchemaValidator({'type': schema_type, 'items_schema': {'type': 'int'}, **kwargs})
+        if isinstance(expected, Err):
+            with pytest.raises(ValidationError, match=re.escape(expected.message.replace('Set', field_type))):
+                v.validate_python(input_value)
+        else:
+            output = v.validate_python(input_value)
+            assert output == expected
+            assert type(output) is output_type
+
+
+def test_unique_items_error_ctx():
+    v = SchemaValidator(core_schema.frozenset_schema(core_schema.int_schema(), min_length=2, unique_items_error=True))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('[1, 1, 1]')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'duplicate_items',
+            'loc': (),
+            'msg': 'Frozenset should have at least 2 unique items after validation, not 1',
+            'input': [1, 1, 1],
+            'ctx': {'field_type': 'Frozenset', 'min_length': 2, 'actual_length': 1},
+        }
+    ]
+
+
+@pytest.mark.parametrize('schema_type', ['set', 'frozenset'])
+def test_generator_streamed(schema_type):
+    consumed = []
+
+    def repeats():
+        # 1, 1, 1, 2, 2, 2, 3, ... forever
+        i = 0
+        while True:
+            i += 1
+            for _ in range(3):
+                consumed.append(i)
+                yield i
+
+    v = SchemaValidator({'type': schema_type, 'items_schema': {'type': 'int'}, 'max_length': 5})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(repeats())
+    assert exc_info.value.errors(include_url=False)[0]['ctx'] == {
+        'field_type': schema_type.capitalize(),
+        'max_length': 5,
+        'actual_length': 6,
+    }
+    # duplicates don't count towards max_length, and iteration stops at the first item over it
+    assert consumed == [1, 1, 1, 2, 2, 2, 3, 3, 3, 4, 4, 4, 5, 5, 5, 6]
+
+
+@pytest.mark.parametrize('schema_type', ['set', 'frozenset'])
+def test_generator_min_length_duplicates(schema_type):
+    v = SchemaValidator({'type': schema_type, 'items_schema': {'type': 'int'}, 'min_length': 3})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(x % 2 for x in range(1000))
+    assert [(e['type'], e['ctx']['actual_length']) for e in exc_info.value.errors()] == [('too_short', 2)]
+    assert v.validate_python(x % 3 for x in range(1000)) == {0, 1, 2}
+
+
+@pytest.mark.parametrize(
+    'schema_type,output_type,type_error', [('set', set, 'set_type'), ('frozenset', frozenset, 'frozen_set_type')]
+)
+def test_lax_iterables(schema_type, output_type, type_error):
+    v = SchemaValidator({'type': schema_type, 'items_schema': {'type': 'int'}, 'min_length': 1})
+    for input_value in [range(3), {0: 'a', 1: 'b', 2: 'c'}.keys(), {'a': 0, 'b': 1, 'c': 2}.values(), iter('012')]:
+        output = v.validate_python(input_value)
+        assert output == {0, 1, 2}
+        assert type(output) is output_type
+
+    # strict mode only accepts the exact type
+    for input_value in [range(3), iter([0, 1, 2])]:
+        with pytest.raises(ValidationError) as exc_info:
+            v.validate_python(input_value, strict=True)
+        assert [e['type'] for e in exc_info.value.errors()] == [type_error]
+
+    # iterating str, bytes or dict would be a surprising coercion
+    for input_value in ['012', b'012', {0: 'a'}]:
+        with pytest.raises(ValidationError) as exc_info:
+            v.validate_python(input_value)
+        assert [e['type'] for e in exc_info.value.errors()] == [type_error]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(iter([]))
+    assert [e['type'] for e in exc_info.value.errors()] == ['too_short']
+
+    def gen():
+        yield 1
+        yield 'x'
+        raise RuntimeError('my error')
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(gen())
+    # an item error is discarded when iteration fails, the iteration error points at the failing index
+    assert [(e['type'], e['loc'], e['msg']) for e in exc_info.value.errors()] == [
+        ('iteration_error', (2,), 'Error iterating over object, error: RuntimeError: my error')
+    ]
+
+
+class Item:
+    __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+    id: int
+    name: str
+
+
+item_schema = core_schema.model_schema(
+    Item,
+    core_schema.model_fields_schema(
+        {
+            'id': core_schema.model_field(core_schema.int_schema()),
+            'name': core_schema.model_field(core_schema.str_schema()),
+        }
+    ),
+)
+
+
+@pytest.mark.parametrize(
+    'schema_func,output_type', [(core_schema.set_schema, set), (core_schema.frozenset_schema, frozenset)]
+)
+def test_hash_key(schema_func, output_type):
+    v = SchemaValidator(schema_func(item_schema, hash_key=lambda item: item.id))
+    output = v.validate_python([{'id': 1, 'name': 'a'}, {'id': 2, 'name': 'b'}, {'id': '1', 'name': 'c'}])
+    assert type(output) is output_type
+    # the first item with each key is kept
+    assert sorted((item.id, item.name) for item in output) == [(1, 'a'), (2, 'b')]
+
+    # without `hash_key` each model instance is distinct
+    v = SchemaValidator(schema_func(item_schema))
+    assert len(v.validate_python([{'id': 1, 'name': 'a'}, {'id': 1, 'name': 'a'}])) == 2
+
+
+def test_hash_key_length():
+    v = SchemaValidator(
+        core_schema.set_schema(item_schema, hash_key=lambda item: item.id, min_length=2, max_length=2)
+    )
+    # lengths are checked against the deduplicated output
+    assert len(v.validate_python([{'id': 1, 'name': 'a'}, {'id': 1, 'name': 'b'}, {'id': 2, 'name': 'c'}])) == 2
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([{'id': 1, 'name': 'a'}, {'id': 1, 'name': 'b'}])
+    assert [e['type'] for e in exc_info.value.errors()] == ['too_short']
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([{'id': i, 'name': 'a'} for i in range(3)])
+    assert [e['type'] for e in exc_info.value.errors()] == ['too_long']
+
+
+def test_hash_key_error():
+    def hash_key(item):
+        if item.name == 'bad':
+            raise ValueError('bad name')
+        return item.id
+
+    v = SchemaValidator(core_schema.set_schema(item_schema, hash_key=hash_key))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([{'id': 1, 'name': 'a'}, {'id': 2, 'name': 'bad'}, {'id': 3, 'name': 'c'}])
+    assert [(e['type'], e['loc'], e['msg']) for e in exc_info.value.errors()] == [
+        ('value_error', (1,), 'Value error, bad name')
+    ]
+
+    # other exceptions are item errors too, rather than escaping validation
+    v = SchemaValidator(core_schema.set_schema(item_schema, hash_key=lambda item: {}[item.name]))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([{'id': 1, 'name': 'a'}])
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'hash_key_error',
+            'loc': (0,),
+            'msg': "Error calling hash_key on set item, error: KeyError: 'a'",
+            'input': {'id': 1, 'name': 'a'},
+            'ctx': {'error': "KeyError: 'a'"},
+        }
+    ]
+
+    v = SchemaValidator(core_schema.set_schema(item_schema, hash_key=lambda item: [item.id]))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([{'id': 1, 'name': 'a'}])
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('unhashable_item', (0,))]
+
+
+def test_hash_key_strict():
+    v = SchemaValidator(core_schema.set_schema(core_schema.float_schema(), hash_key=round))
+    assert v.validate_python([1, '1.2', 2.4]) == {1.0, 2.4}
+    assert len(v.validate_python({1.0, 1.2, 2.4}, strict=True)) == 2
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({1.0, '1.2'}, strict=True)
+    # strict item errors come before the key is computed
+    assert [(e['type'], e['input']) for e in exc_info.value.errors()] == [('float_type', '1.2')]
@@ -164,8 +164,53 @@ def test_invalid_regex():
         '  SchemaError: regex parse error:\n'
         '    (abc\n'
         '    ^\n'
-        'error: unclosed group'
+        'error: unclosed group\n'
+        '  [type=str]'
     )
+    assert exc_info.value.path == ()
+    assert exc_info.value.kind == 'str'
+
+
+def test_invalid_regex_field():
+    with pytest.raises(SchemaError) as exc_info:
+        SchemaValidator(
+            core_schema.typed_dict_schema(
+                {
+                    'a': core_schema.typed_dict_field(core_schema.str_schema(pattern='abc')),
+                    'b': core_schema.typed_dict_field(core_schema.str_schema(pattern='(abc')),
+                }
+            )
+        )
+    assert exc_info.value.args[0] == (
+        'Error building "typed-dict" validator:\n'
+        '  SchemaError: Field "b":\n'
+        '  SchemaError: Error building "str" validator:\n'
+        '  SchemaError: regex parse error:\n'
+        '    (abc\n'
+        '    ^\n'
+        'error: unclosed group\n'
+        '  [path=fields.b.schema, type=str]'
+    )
+    assert exc_info.value.path == ('fields', 'b', 'schema')
+    assert exc_info.value.kind == 'str'
+
+
+def test_shared_pattern():
+    fields = {
+        f'f{i}': core_schema.typed_dict_field(core_schema.str_schema(pattern=r'^\d+$', max_length=i + 2))
+        for i in range(30)
+    }
+    fields['other'] = core_schema.typed_dict_field(core_schema.str_schema(pattern='^x'))
+    v = SchemaValidator(core_schema.typed_dict_schema(fields))
+    data = {f'f{i}': '12' for i in range(30)}
+    assert v.validate_python({**data, 'other': 'xyz'}) == {**data, 'other': 'xyz'}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({**data, 'f3': 'a', 'other': '12'})
+    assert [(e['loc'], e['ctx']) for e in exc_info.value.errors()] == [
+        (('f3',), {'pattern': '^\\d+$'}),
+        (('other',), {'pattern': '^x'}),
+    ]
 
 
 def test_regex_error():
@@ -185,7 +230,11 @@ def test_regex_error():
 
 def test_default_validator():
     v = SchemaValidator(core_schema.str_schema(strict=True, to_lower=False), {'str_strip_whitespace': False})
-    assert plain_repr(v) == 'SchemaValidator(title="str",validator=Str(StrValidator{strict:true}),definitions=[])'
+    assert plain_repr(v) == (
+        'SchemaValidator(title="str",schema_type="str",'
+        "config={'str_strip_whitespace':False},"
+        'validator=Str(StrValidator{strict:true}),definitions=[])'
+    )
 
 
 @pytest.fixture(scope='session', name='FruitEnum')
@@ -219,3 +268,63 @@ def test_lax_subclass(FruitEnum, kwargs):
     assert p == 'pear'
     assert type(p) is str
     assert repr(p) == "'pear'"
+
+
+@pytest.mark.parametrize(
+    'input_value,codepoints,utf8_bytes,graphemes',
+    [
+        ('abc', 3, 3, 3),
+        # astral plane characters are a single code point, but four bytes in UTF-8
+        ('𝐀𝐁', 2, 8, 2),
+        ('🐈', 1, 4, 1),
+        # "e" followed by a combining acute accent
+        ('e\u0301', 2, 3, 1),
+        # a family emoji made of three emoji joined with zero width joiners
+        ('\U0001f468\u200d\U0001f469\u200d\U0001f467', 5, 18, 1),
+    ],
+)
+def test_length_unit(py_and_json: PyAndJson, input_value, codepoints, utf8_bytes, graphemes):
+    for length_unit, length in [('codepoint', codepoints), ('byte', utf8_bytes), ('grapheme', graphemes)]:
+        v = py_and_json(core_schema.str_schema(min_length=length, max_length=length, length_unit=length_unit))
+        assert v.validate_test(input_value) == input_value
+
+        v = py_and_json(core_schema.str_schema(max_length=length - 1, length_unit=length_unit))
+        with pytest.raises(ValidationError) as exc_info:
+            v.validate_test(input_value)
+        # the error reports the length in the chosen unit
+        assert exc_info.value.errors(include_url=False)[0]['ctx'] == {'max_length': length - 1, 'actual_length': length}
+
+        v = py_and_json(core_schema.str_schema(min_length=length + 1, length_unit=length_unit))
+        with pytest.raises(ValidationError) as exc_info:
+            v.validate_test(input_value)
+        assert exc_info.value.errors(include_url=False)[0]['ctx'] == {'min_length': length + 1, 'actual_length': length}
+
+
+def test_length_unit_default():
+    v = SchemaValidator(core_schema.str_schema(max_length=2))
+    assert v.validate_python('é🐈') == 'é🐈'
+    assert v.validate_json('"\\u00e9\\ud83d\\udc08"') == 'é🐈'
+    with pytest.raises(ValidationError, match='String should have at most 2 characters'):
+        v.validate_python('é🐈a')
+
+
+def test_length_unit_context():
+    # the measured length is only reported when `length_unit` is set
+    v = SchemaValidator(core_schema.str_schema(max_length=1))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('ab')
+    assert exc_info.value.errors(include_url=False)[0]['ctx'] == {'max_length': 1}
+
+
+def test_length_unit_strip_whitespace():
+    v = SchemaValidator(core_schema.str_schema(max_length=1, length_unit='grapheme', strip_whitespace=True))
+    # whitespace is stripped before the length is measured
+    assert v.validate_python('  e\u0301\n') == 'e\u0301'
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(' e\u0301e ')
+    assert exc_info.value.errors(include_url=False)[0]['ctx'] == {'max_length': 1, 'actual_length': 2}
+
+
+def test_length_unit_invalid():
+    with pytest.raises(SchemaError, match="Input should be 'codepoint', 'byte' or 'grapheme'"):
+        SchemaValidator({'type': 'str', 'max_length': 1, 'length_unit': 'word'})
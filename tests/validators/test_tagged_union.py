@@ -1,9 +1,10 @@
+from collections.abc import Mapping
 from enum import Enum
 
 import pytest
 from dirty_equals import IsAnyStr
 
-from pydantic_core import SchemaValidator, ValidationError
+from pydantic_core import SchemaValidator, ValidationError, core_schema
 
 from ..conftest import Err, PyAndJson
 from .test_typed_dict import Cls
@@ -112,7 +113,7 @@ def test_simple_tagged_union(py_and_json: PyAndJson, input_value, expected):
             },
         }
     )
-    assert 'discriminator: LookupKey' in repr(v.validator)
+    assert 'discriminator: LookupKey' in v.validator.debug_repr()
     if isinstance(expected, Err):
         with pytest.raises(ValidationError, match=expected.message) as exc_info:
             v.validate_test(input_value)
@@ -187,7 +188,7 @@ def test_int_choice_keys(py_and_json: PyAndJson, input_value, expected):
             },
         }
     )
-    assert 'discriminator: LookupKey' in repr(v.validator)
+    assert 'discriminator: LookupKey' in v.validator.debug_repr()
     if isinstance(expected, Err):
         with pytest.raises(ValidationError, match=expected.message) as exc_info:
             v.validate_test(input_value)
@@ -379,7 +380,7 @@ def test_discriminator_function(py_and_json: PyAndJson, input_value, expected):
             'choices': {'str': {'type': 'literal', 'expected': ['foo', 'bar']}, 'int': {'type': 'int'}},
         }
     )
-    assert 'discriminator: Function' in repr(v.validator)
+    assert 'discriminator: Function' in v.validator.debug_repr()
     if isinstance(expected, Err):
         with pytest.raises(ValidationError, match=expected.message) as exc_info:
             v.validate_python(input_value)
@@ -445,7 +446,7 @@ def test_int_discriminator_function(py_and_json: PyAndJson, input_value, expecte
             'choices': {'a': {'type': 'str'}, 1: {'type': 'int'}},
         }
     )
-    assert 'discriminator: Function' in repr(v.validator)
+    assert 'discriminator: Function' in v.validator.debug_repr()
     if isinstance(expected, Err):
         with pytest.raises(ValidationError, match=expected.message) as exc_info:
             v.validate_python(input_value)
@@ -623,3 +624,41 @@ def test_custom_error_type():
             'input': {'foo': 'other', 'bar': 'Bar'},
         }
     ]
+
+
+def test_strict_override():
+    class MyMapping(Mapping):
+        def __init__(self, data):
+            self._data = data
+
+        def __getitem__(self, key):
+            return self._data[key]
+
+        def __iter__(self):
+            return iter(self._data)
+
+        def __len__(self):
+            return len(self._data)
+
+    v = SchemaValidator(
+        core_schema.tagged_union_schema(
+            discriminator='foobar',
+            choices={
+                'apple': core_schema.typed_dict_schema({'a': core_schema.typed_dict_field(core_schema.int_schema())}),
+            },
+        )
+    )
+    input_value = MyMapping({'foobar': 'apple', 'a': '1'})
+    assert v.validate_python(input_value) == {'a': 1}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(input_value, strict=True)
+    # the discriminator lookup itself is strict, so the error isn't reported against the `apple` choice
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'union_tag_not_found',
+            'loc': (),
+            'msg': "Unable to extract tag using discriminator 'foobar'",
+            'input': input_value,
+            'ctx': {'discriminator': "'foobar'"},
+        }
+    ]
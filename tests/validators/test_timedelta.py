@@ -239,7 +239,9 @@ def test_union():
 def test_pytimedelta_as_timedelta(constraint, expected_duration):
     v = SchemaValidator({'type': 'timedelta', 'gt': constraint})
     # simplest way to check `pytimedelta_as_timedelta` is correct is to extract duration from repr of the validator
-    m = re.search(r'Duration ?\{\s+positive: ?(\w+),\s+day: ?(\d+),\s+second: ?(\d+),\s+microsecond: ?(\d+)', repr(v))
+    m = re.search(
+        r'Duration ?\{\s+positive: ?(\w+),\s+day: ?(\d+),\s+second: ?(\d+),\s+microsecond: ?(\d+)', v.debug_repr()
+    )
     pos, day, sec, micro = m.groups()
     duration = {'positive': pos == 'true', 'day': int(day), 'second': int(sec), 'microsecond': int(micro)}
     assert duration == pytest.approx(expected_duration), constraint
@@ -5,7 +5,7 @@ from typing import Any, Dict, Type
 import pytest
 from dirty_equals import IsNonNegative, IsTuple
 
-from pydantic_core import SchemaValidator, ValidationError
+from pydantic_core import SchemaError, SchemaSerializer, SchemaValidator, ValidationError, core_schema
 
 from ..conftest import Err, PyAndJson, infinite_generator
 
@@ -497,3 +497,115 @@ def test_length_constraints_omit(input_value, expected):
             v.validate_python(input_value)
     else:
         assert v.validate_python(input_value) == expected
+
+
+def variadic_schema(**kwargs: Any) -> Dict[str, Any]:
+    # tuple[int, str, *tuple[float, ...]]
+    return core_schema.tuple_positional_schema(
+        [core_schema.int_schema(), core_schema.str_schema(), core_schema.float_schema()],
+        variadic_item_index=2,
+        **kwargs,
+    )
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ([1, 'a'], (1, 'a')),
+        ([1, 'a', 1.5], (1, 'a', 1.5)),
+        (['1', 'a', 1, '2.5', 3], (1, 'a', 1.0, 2.5, 3.0)),
+        (
+            [1, 'a', 1.5, 'x', 2, 'y'],
+            Err(
+                'type=float_parsing',
+                errors=[
+                    {'type': 'float_parsing', 'loc': (3,)},
+                    {'type': 'float_parsing', 'loc': (5,)},
+                ],
+            ),
+        ),
+        # missing positional items are reported as such, not as too short
+        ([1], Err('type=missing', errors=[{'type': 'missing', 'loc': (1,)}])),
+    ],
+)
+def test_variadic(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(variadic_schema(min_length=0))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)) as exc_info:
+            v.validate_test(input_value)
+        assert [{'type': e['type'], 'loc': e['loc']} for e in exc_info.value.errors()] == expected.errors
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_variadic_strict():
+    v = SchemaValidator(variadic_schema(strict=True))
+    assert v.validate_python((1, 'a', 1.5, 2.5)) == (1, 'a', 1.5, 2.5)
+    with pytest.raises(ValidationError, match='Input should be a valid tuple'):
+        v.validate_python([1, 'a', 1.5])
+
+    v = SchemaValidator(variadic_schema())
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python((1, 'a', 1.5, '2.5'), strict=True)
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('float_type', (3,))]
+    assert v.validate_json('[1, "a", 1.5, 2.5]', strict=True) == (1, 'a', 1.5, 2.5)
+
+
+def test_variadic_length():
+    v = SchemaValidator(variadic_schema(min_length=1, max_length=2))
+    assert v.title == 'tuple[int, str, float, ...]'
+    assert v.validate_python([1, 'a', 1]) == (1, 'a', 1.0)
+    assert v.validate_python([1, 'a', 1, 2]) == (1, 'a', 1.0, 2.0)
+
+    # the lengths apply to the variadic items, the errors report the length of the whole tuple
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([1, 'a'])
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'too_short',
+            'loc': (),
+            'msg': 'Tuple should have at least 3 items after validation, not 2',
+            'input': [1, 'a'],
+            'ctx': {'field_type': 'Tuple', 'min_length': 3, 'actual_length': 2},
+        }
+    ]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('[1, "a", 1, 2, 3]')
+    assert [(e['type'], e['ctx']) for e in exc_info.value.errors()] == [
+        ('too_long', {'field_type': 'Tuple', 'max_length': 4, 'actual_length': 5})
+    ]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([1])
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('missing', (1,))]
+
+
+def test_variadic_only():
+    v = SchemaValidator(core_schema.tuple_positional_schema([core_schema.int_schema()], variadic_item_index=0))
+    assert v.title == 'tuple[int, ...]'
+    assert v.validate_python([]) == ()
+    assert v.validate_python(['1', 2]) == (1, 2)
+
+
+def test_variadic_serialization():
+    s = SchemaSerializer(variadic_schema())
+    assert s.to_python((1, 'a', 1.5, 2.5), mode='json') == [1, 'a', 1.5, 2.5]
+    assert s.to_json((1, 'a', 1.5)) == b'[1,"a",1.5]'
+
+
+def test_variadic_invalid_schema():
+    with pytest.raises(SchemaError, match='`variadic_item_index` must be the index of the last item in `items_schema`'):
+        SchemaValidator(
+            core_schema.tuple_positional_schema(
+                [core_schema.int_schema(), core_schema.str_schema()], variadic_item_index=0
+            )
+        )
+    with pytest.raises(SchemaError, match='`variadic_item_index` and `extra_schema` cannot both be set'):
+        SchemaValidator(
+            core_schema.tuple_positional_schema(
+                [core_schema.int_schema()], variadic_item_index=0, extra_schema=core_schema.int_schema()
+            )
+        )
+    with pytest.raises(SchemaError, match='`min_length` and `max_length` require `extra_schema`'):
+        SchemaValidator(core_schema.tuple_positional_schema([core_schema.int_schema()], max_length=1))
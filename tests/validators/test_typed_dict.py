@@ -1,9 +1,10 @@
 import math
 import re
+from dataclasses import dataclass, field
 from typing import Any, Dict, Mapping, Union
 
 import pytest
-from dirty_equals import FunctionCheck
+from dirty_equals import FunctionCheck, HasRepr
 
 from pydantic_core import CoreConfig, SchemaError, SchemaValidator, ValidationError, __version__, core_schema
 
@@ -555,9 +556,11 @@ def test_aliases_debug():
             },
         }
     )
-    print(repr(v))
-    assert repr(v).startswith('SchemaValidator(title="typed-dict", validator=TypedDict(')
-    assert 'PathChoices(' in repr(v)
+    print(v.debug_repr())
+    assert v.debug_repr().startswith(
+        'SchemaValidator(title="typed-dict", schema_type="typed-dict", config=None, validator=TypedDict('
+    )
+    assert 'PathChoices(' in v.debug_repr()
 
 
 def get_int_key():
@@ -1158,3 +1161,107 @@ def test_extra_behavior_ignore(config: Union[core_schema.CoreConfig, None], sche
 
     m: Dict[str, Any] = v.validate_python({'f': 'x', 'extra_field': 123})
     assert m == {'f': 'x'}
+
+
+@dataclass
+class InnerDataclass:
+    x: int
+
+
+@dataclass
+class OuterDataclass:
+    a: str
+    inner: InnerDataclass
+    not_in_init: int = field(init=False, default=0)
+
+
+def test_dataclass_input():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.str_schema()),
+                'inner': core_schema.typed_dict_field(
+                    core_schema.typed_dict_schema({'x': core_schema.typed_dict_field(core_schema.int_schema())})
+                ),
+                'not_in_init': core_schema.typed_dict_field(core_schema.int_schema()),
+            }
+        )
+    )
+    assert v.validate_python(OuterDataclass(a='hello', inner=InnerDataclass(x='1'))) == {
+        'a': 'hello',
+        'inner': {'x': 1},
+        'not_in_init': 0,
+    }
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(OuterDataclass(a='hello', inner=InnerDataclass(x='wrong')))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': ('inner', 'x'),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'wrong',
+        }
+    ]
+
+
+def test_dataclass_input_extra():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {'x': core_schema.typed_dict_field(core_schema.int_schema())}, extra_behavior='forbid'
+        )
+    )
+    assert v.validate_python(InnerDataclass(x=1)) == {'x': 1}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(OuterDataclass(a='hello', inner=InnerDataclass(x=1)))
+    assert {e['type'] for e in exc_info.value.errors(include_url=False)} == {'missing', 'extra_forbidden'}
+
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {'a': core_schema.typed_dict_field(core_schema.str_schema())}, extra_behavior='allow'
+        )
+    )
+    assert v.validate_python(OuterDataclass(a='hello', inner=InnerDataclass(x=1))) == {
+        'a': 'hello',
+        'inner': InnerDataclass(x=1),
+        'not_in_init': 0,
+    }
+
+
+def test_dataclass_input_strict():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema({'x': core_schema.typed_dict_field(core_schema.int_schema())}, strict=True)
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(InnerDataclass(x=1))
+    assert exc_info.value.errors(include_url=False)[0]['type'] == 'dict_type'
+
+    v = SchemaValidator(core_schema.typed_dict_schema({'x': core_schema.typed_dict_field(core_schema.int_schema())}))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(InnerDataclass)
+    assert exc_info.value.errors(include_url=False)[0]['type'] == 'dict_type'
+
+
+def test_from_attributes_override():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'sub': core_schema.typed_dict_field(
+                    core_schema.list_schema(
+                        core_schema.typed_dict_schema({'b': core_schema.typed_dict_field(core_schema.str_schema())})
+                    )
+                ),
+            }
+        )
+    )
+    input_value = Cls(a='1', sub=[Cls(b='x'), {'b': 'y'}])
+    with pytest.raises(ValidationError, match='Input should be a valid dictionary'):
+        v.validate_python(input_value)
+    assert v.validate_python(input_value, from_attributes=True) == {'a': 1, 'sub': [{'b': 'x'}, {'b': 'y'}]}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(Cls(a='1', sub=[Cls(c='x')]), from_attributes=True)
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'missing', 'loc': ('sub', 0, 'b'), 'msg': 'Field required', 'input': HasRepr("Cls(c='x')")}
+    ]
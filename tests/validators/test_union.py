@@ -256,7 +256,7 @@ def test_empty_choices():
 
 def test_one_choice():
     v = SchemaValidator({'type': 'union', 'choices': [{'type': 'str'}]})
-    assert plain_repr(v) == 'SchemaValidator(title="str",validator=Str(StrValidator{strict:false}),definitions=[])'
+    assert plain_repr(v) == 'SchemaValidator(title="str",schema_type="union",config=None,validator=Str(StrValidator{strict:false}),definitions=[])'  # noqa: E501
     assert v.validate_python('hello') == 'hello'
 
 
@@ -411,3 +411,61 @@ def test_strict_reference():
 
     assert repr(v.validate_python((1, 2))) == '(1.0, 2)'
     assert repr(v.validate_python((1.0, (2.0, 3)))) == '(1.0, (2.0, 3))'
+
+
+def test_container_type_preserved():
+    list_schema = core_schema.list_schema(core_schema.int_schema())
+    set_schema = core_schema.set_schema(core_schema.int_schema())
+    v = SchemaValidator(core_schema.union_schema([list_schema, set_schema]))
+    assert v.validate_python({1, 2}) == {1, 2}
+    assert v.validate_python([1, 2]) == [1, 2]
+    # items need coercing, so the strict pass fails, but the input's container type still wins
+    output = v.validate_python({'1', 2})
+    assert output == {1, 2}
+    assert type(output) is set
+    output = v.validate_python(['1', 2])
+    assert output == [1, 2]
+    assert type(output) is list
+
+    v = SchemaValidator(core_schema.union_schema([set_schema, list_schema]))
+    assert v.validate_python(['1', 2]) == [1, 2]
+    assert v.validate_python({'1', 2}) == {1, 2}
+    # JSON arrays have no preferred container type, so the first choice wins
+    assert v.validate_json('["1", 2]') == {1, 2}
+
+
+def test_container_type_no_exact_match():
+    v = SchemaValidator(
+        core_schema.union_schema(
+            [core_schema.list_schema(core_schema.int_schema()), core_schema.set_schema(core_schema.int_schema())]
+        )
+    )
+    # neither a tuple nor a frozenset is the native type of a choice, so the first choice which coerces wins
+    assert v.validate_python((1, '2')) == [1, 2]
+    assert v.validate_python(frozenset({'1'})) == [1]
+
+
+def test_container_type_native_choice_fails():
+    v = SchemaValidator(
+        core_schema.union_schema(
+            [
+                core_schema.list_schema(core_schema.int_schema()),
+                core_schema.frozenset_schema(core_schema.int_schema(), max_length=1),
+            ]
+        )
+    )
+    # the frozenset choice is tried first, but fails, so the input is coerced to a list
+    assert v.validate_python(frozenset({1, 2})) in ([1, 2], [2, 1])
+
+    v = SchemaValidator(
+        core_schema.union_schema(
+            [core_schema.list_schema(core_schema.int_schema()), core_schema.set_schema(core_schema.int_schema())]
+        )
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'x'})
+    # errors are still reported in the order of the choices
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [
+        ('int_parsing', ('list[int]', 0)),
+        ('int_parsing', ('set[int]', 0)),
+    ]
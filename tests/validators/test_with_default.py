@@ -6,6 +6,7 @@ import pytest
 
 from pydantic_core import (
     ArgsKwargs,
+    PydanticUndefined,
     PydanticUseDefault,
     SchemaError,
     SchemaValidator,
@@ -618,3 +619,16 @@ def test_use_default_error() -> None:
     validator = SchemaValidator(core_schema.no_info_wrap_validator_function(val_func, core_schema.int_schema()))
     with pytest.raises(SchemaError, match='Uncaught UseDefault error, please check your usage of `default` validators'):
         validator.validate_python('')
+
+
+def test_default_undefined_identity():
+    v = SchemaValidator(core_schema.with_default_schema(core_schema.nullable_schema(core_schema.int_schema())))
+    assert v.validate_python(None) is None
+    assert v.validate_json('null') is None
+
+    v = SchemaValidator(core_schema.with_default_schema(core_schema.int_schema(), default=None))
+    assert v.validate_python(PydanticUndefined) is None
+    assert v.validate_python(1) == 1
+    assert v.validate_json('1') == 1
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_json('"PydanticUndefined"')